@@ -138,6 +138,8 @@ build_exceptions! {
     // create table or alter table add column with internal column name
     TableWithInternalColumnName(1110),
     EmptyShareEndpointConfig(1111),
+    RecursiveCteIterationsExceeded(1112),
+    CurrentTransactionIsAborted(1113),
 
     // Data Related Errors
 
@@ -210,6 +212,8 @@ build_exceptions! {
     /// - not exists.
     /// - and without `IF EXISTS`
     CatalogNotFound(2320),
+    IndexAlreadyExists(2321),
+    UnknownIndex(2322),
 
 
     // Cluster error codes.
@@ -50,6 +50,14 @@ mod column_node;
 pub use column_node::ColumnNode;
 pub use column_node::ColumnNodes;
 
+mod orc;
+pub use orc::orc_stripe_range;
+pub use orc::read_orc_metadata_async;
+pub use orc::read_orc_stripe;
+pub use orc::read_orc_stripe_async;
+pub use orc::OrcColumn;
+pub use orc::OrcFileMetadata;
+
 mod parquet;
 pub use parquet::read_parquet_metas_in_parallel;
 pub use parquet::read_parquet_schema_async;
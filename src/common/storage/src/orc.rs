@@ -0,0 +1,157 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::orc::format::read::read_metadata;
+use common_arrow::arrow::io::orc::format::read::read_stripe_column;
+use common_arrow::arrow::io::orc::format::read::read_stripe_footer;
+pub use common_arrow::arrow::io::orc::format::read::Column as OrcColumn;
+pub use common_arrow::arrow::io::orc::format::read::FileMetadata as OrcFileMetadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+
+/// The size of the tail read first to get the metadata of an orc file, it is enough for
+/// the postscript and the footer of most files.
+const ORC_TAIL_SIZE: u64 = 64 * 1024;
+
+/// Read the metadata of an orc file from its tail. The tail is read again with a larger
+/// size if the footer and the metadata do not fit in it.
+#[async_backtrace::framed]
+pub async fn read_orc_metadata_async(
+    operator: &Operator,
+    path: &str,
+    file_size: u64,
+) -> Result<OrcFileMetadata> {
+    let mut tail_size = std::cmp::min(ORC_TAIL_SIZE, file_size);
+    loop {
+        let start = file_size - tail_size;
+        let tail = operator.range_read(path, start..file_size).await?;
+        match read_metadata(&mut RangeCursor::new(&tail, start)) {
+            Ok(metadata) => return Ok(metadata),
+            Err(_) if tail_size < file_size => {
+                tail_size = std::cmp::min(tail_size * 4, file_size);
+            }
+            Err(e) => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Read orc file '{}''s meta error: {}",
+                    path, e
+                )));
+            }
+        }
+    }
+}
+
+/// The byte range of a stripe in the file, including its indexes, data and footer.
+pub fn orc_stripe_range(metadata: &OrcFileMetadata, stripe: usize) -> Range<u64> {
+    let info = &metadata.footer.stripes[stripe];
+    let start = info.offset();
+    start..start + info.index_length() + info.data_length() + info.footer_length()
+}
+
+/// Read the columns of a stripe, with one range read of the whole stripe.
+///
+/// `column_ids` are the ids of the columns in the types of the file footer, where 0 is
+/// the root struct and the top level fields are its subtypes.
+#[async_backtrace::framed]
+pub async fn read_orc_stripe_async(
+    operator: &Operator,
+    path: &str,
+    metadata: &OrcFileMetadata,
+    stripe: usize,
+    column_ids: &[u32],
+) -> Result<Vec<OrcColumn>> {
+    let range = orc_stripe_range(metadata, stripe);
+    let data = operator.range_read(path, range.clone()).await?;
+    read_orc_stripe(path, &data, range.start, metadata, stripe, column_ids)
+}
+
+/// Read the columns of a stripe from `data`, which are the bytes of the file starting at
+/// `offset`, e.g. the whole file or the range of the stripe.
+pub fn read_orc_stripe(
+    path: &str,
+    data: &[u8],
+    offset: u64,
+    metadata: &OrcFileMetadata,
+    stripe: usize,
+    column_ids: &[u32],
+) -> Result<Vec<OrcColumn>> {
+    let to_error = |e: common_arrow::arrow::io::orc::format::error::Error| {
+        ErrorCode::BadBytes(format!(
+            "Read stripe {} of orc file '{}' error: {}",
+            stripe, path, e
+        ))
+    };
+    let mut reader = RangeCursor::new(data, offset);
+    let footer =
+        read_stripe_footer(&mut reader, metadata, stripe, &mut vec![]).map_err(to_error)?;
+    let footer = Arc::new(footer);
+    column_ids
+        .iter()
+        .map(|column_id| {
+            read_stripe_column(
+                &mut reader,
+                metadata,
+                stripe,
+                footer.clone(),
+                *column_id,
+                vec![],
+            )
+            .map_err(to_error)
+        })
+        .collect()
+}
+
+/// A cursor over a range of a file, its positions are the offsets in the file.
+struct RangeCursor<'a> {
+    inner: Cursor<&'a [u8]>,
+    offset: u64,
+}
+
+impl<'a> RangeCursor<'a> {
+    fn new(data: &'a [u8], offset: u64) -> Self {
+        Self {
+            inner: Cursor::new(data),
+            offset,
+        }
+    }
+}
+
+impl Read for RangeCursor<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl Seek for RangeCursor<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) if pos < self.offset => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("seek to {} before the start {} of range", pos, self.offset),
+                ));
+            }
+            SeekFrom::Start(pos) => SeekFrom::Start(pos - self.offset),
+            pos => pos,
+        };
+        Ok(self.inner.seek(pos)? + self.offset)
+    }
+}
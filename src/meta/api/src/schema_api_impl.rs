@@ -25,6 +25,7 @@ use common_meta_app::app_error::DatabaseAlreadyExists;
 use common_meta_app::app_error::DropDbWithDropTime;
 use common_meta_app::app_error::DropTableWithDropTime;
 use common_meta_app::app_error::DuplicatedUpsertFiles;
+use common_meta_app::app_error::IndexAlreadyExists;
 use common_meta_app::app_error::ShareHasNoGrantedPrivilege;
 use common_meta_app::app_error::TableAlreadyExists;
 use common_meta_app::app_error::TableVersionMismatched;
@@ -35,6 +36,7 @@ use common_meta_app::app_error::UndropTableAlreadyExists;
 use common_meta_app::app_error::UndropTableHasNoHistory;
 use common_meta_app::app_error::UndropTableWithNoDropTime;
 use common_meta_app::app_error::UnknownDatabaseId;
+use common_meta_app::app_error::UnknownIndex;
 use common_meta_app::app_error::UnknownTable;
 use common_meta_app::app_error::UnknownTableId;
 use common_meta_app::app_error::WrongShare;
@@ -44,6 +46,8 @@ use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableIndexReply;
+use common_meta_app::schema::CreateTableIndexReq;
 use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DBIdTableName;
@@ -59,6 +63,8 @@ use common_meta_app::schema::DbIdListKey;
 use common_meta_app::schema::DropDatabaseReply;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::DropTableIndexReply;
+use common_meta_app::schema::DropTableIndexReq;
 use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::GetDatabaseReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
@@ -86,6 +92,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -2232,6 +2240,208 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         }
     }
 
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        // The table whose copied files must not be duplicated, if any.
+        let dedup_table_id = req
+            .update_table_metas
+            .iter()
+            .find(|req| {
+                req.copied_files
+                    .as_ref()
+                    .map(|v| v.fail_if_duplicated)
+                    .unwrap_or(false)
+            })
+            .map(|req| req.table_id);
+
+        let mut txn_failed = false;
+        loop {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+
+            for req in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: req.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(req.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                txn_req.condition.push(
+                    // table is not changed
+                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
+                );
+                txn_req.if_then.push(
+                    txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?), // tb_id -> tb_meta
+                );
+
+                if let Some(req) = &req.copied_files {
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            req,
+                            tb_meta_seq,
+                            req.fail_if_duplicated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations)
+                }
+            }
+
+            // The versions of the tables still match, so the last txn failed because
+            // of the copied files.
+            if txn_failed {
+                if let Some(table_id) = dedup_table_id {
+                    return Err(KVAppError::AppError(AppError::from(
+                        DuplicatedUpsertFiles::new(table_id, "update_multi_table_meta"),
+                    )));
+                }
+            }
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(succ = display(succ), "update_multi_table_meta");
+
+            if succ {
+                return Ok(UpdateMultiTableMetaReply {});
+            }
+            txn_failed = true;
+        }
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn create_table_index(
+        &self,
+        req: CreateTableIndexReq,
+    ) -> Result<CreateTableIndexReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let tbid = TableId {
+            table_id: req.table_id,
+        };
+
+        loop {
+            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                get_pb_value(self, &tbid).await?;
+
+            if tb_meta_seq == 0 || table_meta.is_none() {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "create_table_index"),
+                )));
+            }
+            let mut table_meta = table_meta.unwrap();
+
+            if table_meta.indexes.contains_key(&req.index.name) {
+                if req.if_not_exists {
+                    return Ok(CreateTableIndexReply {});
+                }
+                return Err(KVAppError::AppError(AppError::IndexAlreadyExists(
+                    IndexAlreadyExists::new(&req.index.name, "create_table_index"),
+                )));
+            }
+            table_meta
+                .indexes
+                .insert(req.index.name.clone(), req.index.clone());
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // table is not changed
+                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&tbid, serialize_struct(&table_meta)?), // tb_id -> tb_meta
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                id = debug(&tbid),
+                succ = display(succ),
+                "create_table_index"
+            );
+
+            if succ {
+                return Ok(CreateTableIndexReply {});
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn drop_table_index(
+        &self,
+        req: DropTableIndexReq,
+    ) -> Result<DropTableIndexReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let tbid = TableId {
+            table_id: req.table_id,
+        };
+
+        loop {
+            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                get_pb_value(self, &tbid).await?;
+
+            if tb_meta_seq == 0 || table_meta.is_none() {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "drop_table_index"),
+                )));
+            }
+            let mut table_meta = table_meta.unwrap();
+
+            if table_meta.indexes.remove(&req.name).is_none() {
+                if req.if_exists {
+                    return Ok(DropTableIndexReply {});
+                }
+                return Err(KVAppError::AppError(AppError::UnknownIndex(
+                    UnknownIndex::new(&req.name, "drop_table_index"),
+                )));
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // table is not changed
+                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&tbid, serialize_struct(&table_meta)?), // tb_id -> tb_meta
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(id = debug(&tbid), succ = display(succ), "drop_table_index");
+
+            if succ {
+                return Ok(DropTableIndexReply {});
+            }
+        }
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn gc_dropped_data(
         &self,
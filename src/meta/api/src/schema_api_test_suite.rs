@@ -26,6 +26,7 @@ use common_expression::TableSchema;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableIndexReq;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DBIdTableName;
 use common_meta_app::schema::DatabaseId;
@@ -38,6 +39,7 @@ use common_meta_app::schema::DbIdList;
 use common_meta_app::schema::DbIdListKey;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::DropTableIndexReq;
 use common_meta_app::schema::GetDatabaseReq;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
@@ -52,6 +54,8 @@ use common_meta_app::schema::TableIdList;
 use common_meta_app::schema::TableIdListKey;
 use common_meta_app::schema::TableIdToName;
 use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableIndex;
+use common_meta_app::schema::TableIndexType;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
@@ -59,6 +63,7 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_app::schema::UpsertTableOptionReq;
@@ -237,7 +242,9 @@ impl SchemaApiTestSuite {
         suite.table_create_get_drop(&b.build().await).await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite.table_update_multi_meta(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
+        suite.table_index_create_drop(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
         suite.table_list_all(&b.build().await).await?;
         suite
@@ -2202,6 +2209,228 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_update_multi_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let schema = || {
+            Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]))
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: TableMeta {
+                        schema: schema(),
+                        engine: "JSON".to_string(),
+                        ..TableMeta::default()
+                    },
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        info!("--- update the metas of all the tables");
+        {
+            let mut update_table_metas = vec![];
+            let mut new_table_metas = vec![];
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                let mut new_table_meta = table.meta.clone();
+                new_table_meta.statistics = TableStatistics {
+                    data_bytes: 1,
+                    ..Default::default()
+                };
+                update_table_metas.push(UpdateTableMetaReq {
+                    table_id: table.ident.table_id,
+                    seq: MatchSeq::Exact(table.ident.seq),
+                    new_table_meta: new_table_meta.clone(),
+                    copied_files: None,
+                });
+                new_table_metas.push(new_table_meta);
+            }
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await?;
+
+            for (tbl_name, new_table_meta) in tbl_names.iter().zip(new_table_metas) {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                assert_eq!(table.meta, new_table_meta);
+            }
+        }
+
+        info!("--- version mismatch of one table, none of the tables is updated");
+        {
+            let mut update_table_metas = vec![];
+            let mut old_table_metas = vec![];
+            for (i, tbl_name) in tbl_names.iter().enumerate() {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                let mut new_table_meta = table.meta.clone();
+                new_table_meta.statistics = TableStatistics {
+                    data_bytes: 2,
+                    ..Default::default()
+                };
+                update_table_metas.push(UpdateTableMetaReq {
+                    table_id: table.ident.table_id,
+                    seq: MatchSeq::Exact(table.ident.seq + i as u64),
+                    new_table_meta,
+                    copied_files: None,
+                });
+                old_table_metas.push(table.meta.clone());
+            }
+            let err = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await
+                .unwrap_err();
+            let err = ErrorCode::from(err);
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            for (tbl_name, old_table_meta) in tbl_names.iter().zip(old_table_metas) {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                assert_eq!(table.meta, old_table_meta);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_index_create_drop<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "tb2";
+
+        let schema = || {
+            Arc::new(TableSchema::new(vec![
+                TableField::new("number", TableDataType::Number(NumberDataType::UInt64)),
+                TableField::new("msg", TableDataType::String),
+            ]))
+        };
+
+        let index = || TableIndex {
+            name: "idx1".to_string(),
+            index_type: TableIndexType::Inverted,
+            column_ids: vec![1],
+            query: "".to_string(),
+        };
+
+        info!("--- prepare db and table");
+        let table_id = {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta {
+                    schema: schema(),
+                    engine: "JSON".to_string(),
+                    ..TableMeta::default()
+                },
+            };
+            mt.create_table(req).await?.table_id
+        };
+
+        info!("--- create table index");
+        {
+            let req = CreateTableIndexReq {
+                if_not_exists: false,
+                table_id,
+                index: index(),
+            };
+            mt.create_table_index(req.clone()).await?;
+
+            let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(table.meta.indexes.get("idx1"), Some(&index()));
+
+            let err = mt.create_table_index(req.clone()).await.unwrap_err();
+            let err = ErrorCode::from(err);
+            assert_eq!(ErrorCode::IndexAlreadyExists("").code(), err.code());
+
+            mt.create_table_index(CreateTableIndexReq {
+                if_not_exists: true,
+                ..req
+            })
+            .await?;
+        }
+
+        info!("--- create table index with not exist table id");
+        {
+            let err = mt
+                .create_table_index(CreateTableIndexReq {
+                    if_not_exists: false,
+                    table_id: 1024,
+                    index: index(),
+                })
+                .await
+                .unwrap_err();
+            let err = ErrorCode::from(err);
+            assert_eq!(ErrorCode::UnknownTableId("").code(), err.code());
+        }
+
+        info!("--- drop table index");
+        {
+            let req = DropTableIndexReq {
+                if_exists: false,
+                table_id,
+                name: "idx1".to_string(),
+            };
+            mt.drop_table_index(req.clone()).await?;
+
+            let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert!(table.meta.indexes.is_empty());
+
+            let err = mt.drop_table_index(req.clone()).await.unwrap_err();
+            let err = ErrorCode::from(err);
+            assert_eq!(ErrorCode::UnknownIndex("").code(), err.code());
+
+            mt.drop_table_index(DropTableIndexReq {
+                if_exists: true,
+                ..req
+            })
+            .await?;
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn database_drop_out_of_retention_time_history<
         MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>,
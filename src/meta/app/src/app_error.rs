@@ -114,6 +114,38 @@ impl TableAlreadyExists {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("IndexAlreadyExists: {index_name} while {context}")]
+pub struct IndexAlreadyExists {
+    index_name: String,
+    context: String,
+}
+
+impl IndexAlreadyExists {
+    pub fn new(index_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            index_name: index_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("UnknownIndex: {index_name} while {context}")]
+pub struct UnknownIndex {
+    index_name: String,
+    context: String,
+}
+
+impl UnknownIndex {
+    pub fn new(index_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            index_name: index_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("CreateTableWithDropTime: create {table_name} with drop time")]
 pub struct CreateTableWithDropTime {
@@ -553,6 +585,12 @@ pub enum AppError {
     #[error(transparent)]
     CreateTableWithDropTime(#[from] CreateTableWithDropTime),
 
+    #[error(transparent)]
+    IndexAlreadyExists(#[from] IndexAlreadyExists),
+
+    #[error(transparent)]
+    UnknownIndex(#[from] UnknownIndex),
+
     #[error(transparent)]
     UndropTableAlreadyExists(#[from] UndropTableAlreadyExists),
 
@@ -682,6 +720,18 @@ impl AppErrorMessage for TableAlreadyExists {
     }
 }
 
+impl AppErrorMessage for IndexAlreadyExists {
+    fn message(&self) -> String {
+        format!("Index '{}' already exists", self.index_name)
+    }
+}
+
+impl AppErrorMessage for UnknownIndex {
+    fn message(&self) -> String {
+        format!("Unknown index '{}'", self.index_name)
+    }
+}
+
 impl AppErrorMessage for CreateTableWithDropTime {
     fn message(&self) -> String {
         format!("Create Table '{}' with drop time", self.table_name)
@@ -862,6 +912,8 @@ impl From<AppError> for ErrorCode {
             AppError::CreateTableWithDropTime(err) => {
                 ErrorCode::CreateTableWithDropTime(err.message())
             }
+            AppError::IndexAlreadyExists(err) => ErrorCode::IndexAlreadyExists(err.message()),
+            AppError::UnknownIndex(err) => ErrorCode::UnknownIndex(err.message()),
             AppError::UndropTableAlreadyExists(err) => {
                 ErrorCode::UndropTableAlreadyExists(err.message())
             }
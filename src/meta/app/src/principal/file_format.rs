@@ -96,6 +96,9 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Avro(AvroFileFormatParams),
+    Orc(OrcFileFormatParams),
+    Arrow(ArrowFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -107,6 +110,9 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
+            FileFormatParams::Orc(_) => StageFileFormatType::Orc,
+            FileFormatParams::Arrow(_) => StageFileFormatType::Arrow,
         }
     }
 
@@ -124,6 +130,13 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
+            StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
+            StageFileFormatType::Arrow => {
+                Ok(FileFormatParams::Arrow(ArrowFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -139,6 +152,9 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Avro(_) => StageFileCompression::None,
+            FileFormatParams::Orc(_) => StageFileCompression::None,
+            FileFormatParams::Arrow(_) => StageFileCompression::None,
         }
     }
 
@@ -164,6 +180,9 @@ impl FileFormatParams {
                 FileFormatParams::NdJson(NdJsonFileFormatParams { compression })
             }
             StageFileFormatType::Parquet => FileFormatParams::Parquet(ParquetFileFormatParams {}),
+            StageFileFormatType::Avro => FileFormatParams::Avro(AvroFileFormatParams {}),
+            StageFileFormatType::Orc => FileFormatParams::Orc(OrcFileFormatParams {}),
+            StageFileFormatType::Arrow => FileFormatParams::Arrow(ArrowFileFormatParams {}),
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = ast.take_compression()?;
@@ -403,6 +422,19 @@ impl NdJsonFileFormatParams {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {}
 
+/// The data blocks of avro files are compressed by the codec in their headers, so there
+/// is no compression option.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {}
+
+/// The streams of orc files are compressed by the codec in their postscripts.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcFileFormatParams {}
+
+/// Files in the arrow ipc file format, a.k.a. feather v2.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArrowFileFormatParams {}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -448,6 +480,15 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(_) => {
                 write!(f, "TYPE = PARQUET")
             }
+            FileFormatParams::Avro(_) => {
+                write!(f, "TYPE = AVRO")
+            }
+            FileFormatParams::Orc(_) => {
+                write!(f, "TYPE = ORC")
+            }
+            FileFormatParams::Arrow(_) => {
+                write!(f, "TYPE = ARROW")
+            }
         }
     }
 }
@@ -24,6 +24,7 @@ use std::sync::Arc;
 use chrono::DateTime;
 use chrono::Utc;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_meta_types::MatchSeq;
@@ -202,6 +203,36 @@ pub struct TableStatistics {
     pub index_data_bytes: u64,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TableIndexType {
+    Bloom,
+    Inverted,
+    Ngram,
+    Aggregating,
+}
+
+impl Display for TableIndexType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TableIndexType::Bloom => write!(f, "BLOOM"),
+            TableIndexType::Inverted => write!(f, "INVERTED"),
+            TableIndexType::Ngram => write!(f, "NGRAM"),
+            TableIndexType::Aggregating => write!(f, "AGGREGATING"),
+        }
+    }
+}
+
+/// A secondary index defined on some columns of a table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableIndex {
+    pub name: String,
+    pub index_type: TableIndexType,
+    pub column_ids: Vec<ColumnId>,
+    /// The defining `SELECT ... GROUP BY ...` query of an aggregating index,
+    /// empty for the other index types.
+    pub query: String,
+}
+
 /// The essential state that defines what a table is.
 ///
 /// It is what a meta store just needs to save.
@@ -230,6 +261,8 @@ pub struct TableMeta {
     pub statistics: TableStatistics,
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
+    // secondary indexes of the table, index name -> index definition.
+    pub indexes: BTreeMap<String, TableIndex>,
 }
 
 impl TableMeta {
@@ -326,6 +359,7 @@ impl Default for TableMeta {
             drop_on: None,
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
+            indexes: BTreeMap::new(),
         }
     }
 }
@@ -342,6 +376,23 @@ impl TableMeta {
         self.default_cluster_key_id
             .zip(self.default_cluster_key.clone())
     }
+
+    /// Returns the ids of columns covered by the indexes of the given type,
+    /// `None` if no index of the type is defined.
+    pub fn index_column_ids(&self, index_type: TableIndexType) -> Option<Vec<ColumnId>> {
+        let mut column_ids = None::<Vec<ColumnId>>;
+        for index in self.indexes.values() {
+            if index.index_type == index_type {
+                let ids = column_ids.get_or_insert_with(Vec::new);
+                for id in index.column_ids.iter() {
+                    if !ids.contains(id) {
+                        ids.push(*id);
+                    }
+                }
+            }
+        }
+        column_ids
+    }
 }
 
 impl Display for TableMeta {
@@ -569,6 +620,13 @@ pub struct UpdateTableMetaReq {
     pub copied_files: Option<UpsertTableCopiedFileReq>,
 }
 
+/// Update the metas of several tables in one transaction, either all of them are
+/// updated or none of them is.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
 impl UpsertTableOptionReq {
     pub fn new(
         table_ident: &TableIdent,
@@ -598,11 +656,54 @@ pub struct UpsertTableOptionReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateTableIndexReq {
+    pub if_not_exists: bool,
+    pub table_id: u64,
+    pub index: TableIndex,
+}
+
+impl Display for CreateTableIndexReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "create_table_index(if_not_exists={}):{}={:?}",
+            self.if_not_exists, self.table_id, self.index
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateTableIndexReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropTableIndexReq {
+    pub if_exists: bool,
+    pub table_id: u64,
+    pub name: String,
+}
+
+impl Display for DropTableIndexReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "drop_table_index(if_exists={}):{}={}",
+            self.if_exists, self.table_id, self.name
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropTableIndexReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpdateTableMetaReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
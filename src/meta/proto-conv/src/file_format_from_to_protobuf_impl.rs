@@ -39,6 +39,7 @@ impl FromToProto for mt::principal::StageFileFormatType {
             pb::StageFileFormatType::NdJson => Ok(mt::principal::StageFileFormatType::NdJson),
             pb::StageFileFormatType::Avro => Ok(mt::principal::StageFileFormatType::Avro),
             pb::StageFileFormatType::Orc => Ok(mt::principal::StageFileFormatType::Orc),
+            pb::StageFileFormatType::Arrow => Ok(mt::principal::StageFileFormatType::Arrow),
             pb::StageFileFormatType::Parquet => Ok(mt::principal::StageFileFormatType::Parquet),
             pb::StageFileFormatType::Xml => Ok(mt::principal::StageFileFormatType::Xml),
         }
@@ -52,6 +53,7 @@ impl FromToProto for mt::principal::StageFileFormatType {
             mt::principal::StageFileFormatType::NdJson => Ok(pb::StageFileFormatType::NdJson),
             mt::principal::StageFileFormatType::Avro => Ok(pb::StageFileFormatType::Avro),
             mt::principal::StageFileFormatType::Orc => Ok(pb::StageFileFormatType::Orc),
+            mt::principal::StageFileFormatType::Arrow => Ok(pb::StageFileFormatType::Arrow),
             mt::principal::StageFileFormatType::Parquet => Ok(pb::StageFileFormatType::Parquet),
             mt::principal::StageFileFormatType::Xml => Ok(pb::StageFileFormatType::Xml),
             mt::principal::StageFileFormatType::None => Err(Incompatible {
@@ -243,6 +245,21 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Avro(p)) => {
+                Ok(mt::principal::FileFormatParams::Avro(
+                    mt::principal::AvroFileFormatParams::from_pb(p)?,
+                ))
+            }
+            Some(pb::file_format_params::Format::Orc(p)) => {
+                Ok(mt::principal::FileFormatParams::Orc(
+                    mt::principal::OrcFileFormatParams::from_pb(p)?,
+                ))
+            }
+            Some(pb::file_format_params::Format::Arrow(p)) => {
+                Ok(mt::principal::FileFormatParams::Arrow(
+                    mt::principal::ArrowFileFormatParams::from_pb(p)?,
+                ))
+            }
             None => Err(Incompatible {
                 reason: "FileFormatParams.format cannot be None".to_string(),
             }),
@@ -281,6 +298,21 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Avro(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Avro(
+                    mt::principal::AvroFileFormatParams::to_pb(p)?,
+                )),
+            }),
+            Self::Orc(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Orc(
+                    mt::principal::OrcFileFormatParams::to_pb(p)?,
+                )),
+            }),
+            Self::Arrow(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Arrow(
+                    mt::principal::ArrowFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -305,6 +337,66 @@ impl FromToProto for mt::principal::ParquetFileFormatParams {
     }
 }
 
+impl FromToProto for mt::principal::AvroFileFormatParams {
+    type PB = pb::AvroFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AvroFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::AvroFileFormatParams {})
+    }
+
+    fn to_pb(&self) -> Result<pb::AvroFileFormatParams, Incompatible> {
+        Ok(pb::AvroFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+        })
+    }
+}
+
+impl FromToProto for mt::principal::OrcFileFormatParams {
+    type PB = pb::OrcFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::OrcFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::OrcFileFormatParams {})
+    }
+
+    fn to_pb(&self) -> Result<pb::OrcFileFormatParams, Incompatible> {
+        Ok(pb::OrcFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+        })
+    }
+}
+
+impl FromToProto for mt::principal::ArrowFileFormatParams {
+    type PB = pb::ArrowFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::ArrowFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::ArrowFileFormatParams {})
+    }
+
+    fn to_pb(&self) -> Result<pb::ArrowFileFormatParams, Incompatible> {
+        Ok(pb::ArrowFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+        })
+    }
+}
+
 impl FromToProto for mt::principal::NdJsonFileFormatParams {
     type PB = pb::NdJsonFileFormatParams;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -177,11 +177,14 @@ impl FromToProto for ex::TableDataType {
                     Dt24::EmptyArrayT(_) => ex::TableDataType::EmptyArray,
                     Dt24::BoolT(_) => ex::TableDataType::Boolean,
                     Dt24::StringT(_) => ex::TableDataType::String,
+                    Dt24::BinaryT(_) => ex::TableDataType::Binary,
+                    Dt24::BitmapT(_) => ex::TableDataType::Bitmap,
                     Dt24::NumberT(n) => {
                         ex::TableDataType::Number(ex::types::NumberDataType::from_pb(n)?)
                     }
                     Dt24::TimestampT(_) => ex::TableDataType::Timestamp,
                     Dt24::DateT(_) => ex::TableDataType::Date,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
                     Dt24::NullableT(x) => ex::TableDataType::Nullable(Box::new(
                         ex::TableDataType::from_pb(Box::into_inner(x))?,
                     )),
@@ -227,6 +230,8 @@ impl FromToProto for ex::TableDataType {
             TableDataType::EmptyMap => new_pb_dt24(Dt24::EmptyMapT(pb::Empty {})),
             TableDataType::Boolean => new_pb_dt24(Dt24::BoolT(pb::Empty {})),
             TableDataType::String => new_pb_dt24(Dt24::StringT(pb::Empty {})),
+            TableDataType::Binary => new_pb_dt24(Dt24::BinaryT(pb::Empty {})),
+            TableDataType::Bitmap => new_pb_dt24(Dt24::BitmapT(pb::Empty {})),
             TableDataType::Number(n) => {
                 let x = n.to_pb()?;
                 new_pb_dt24(Dt24::NumberT(x))
@@ -237,6 +242,7 @@ impl FromToProto for ex::TableDataType {
             }
             TableDataType::Timestamp => new_pb_dt24(Dt24::TimestampT(pb::Empty {})),
             TableDataType::Date => new_pb_dt24(Dt24::DateT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
             TableDataType::Nullable(v) => {
                 let x = v.to_pb()?;
                 new_pb_dt24(Dt24::NullableT(Box::new(x)))
@@ -15,6 +15,7 @@
 //! This mod is the key point about compatibility.
 //! Everytime update anything in this file, update the `VER` and let the tests pass.
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::sync::Arc;
 
@@ -24,6 +25,7 @@ use common_expression as ex;
 use common_meta_app::schema as mt;
 use common_meta_app::storage::StorageParams;
 use common_protos::pb;
+use num::FromPrimitive;
 
 use crate::reader_check_msg;
 use crate::FromToProto;
@@ -221,6 +223,11 @@ impl FromToProto for mt::TableMeta {
                 .transpose()?
                 .unwrap_or_default(),
             shared_by: BTreeSet::from_iter(p.shared_by.into_iter()),
+            indexes: p
+                .indexes
+                .into_iter()
+                .map(|(name, index)| Ok((name, mt::TableIndex::from_pb(index)?)))
+                .collect::<Result<BTreeMap<_, _>, Incompatible>>()?,
         };
         Ok(v)
     }
@@ -256,6 +263,11 @@ impl FromToProto for mt::TableMeta {
             field_comments: self.field_comments.clone(),
             statistics: Some(self.statistics.to_pb()?),
             shared_by: Vec::from_iter(self.shared_by.clone().into_iter()),
+            indexes: self
+                .indexes
+                .iter()
+                .map(|(name, index)| Ok((name.clone(), index.to_pb()?)))
+                .collect::<Result<_, Incompatible>>()?,
         };
         Ok(p)
     }
@@ -292,6 +304,64 @@ impl FromToProto for mt::TableStatistics {
     }
 }
 
+impl FromToProto for mt::TableIndex {
+    type PB = pb::TableIndex;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableIndex) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let index_type = FromPrimitive::from_i32(p.index_type).ok_or_else(|| Incompatible {
+            reason: format!("invalid TableIndex.index_type: {}", p.index_type),
+        })?;
+
+        let v = Self {
+            name: p.name,
+            index_type: mt::TableIndexType::from_pb(index_type)?,
+            column_ids: p.column_ids,
+            query: p.query,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableIndex, Incompatible> {
+        let p = pb::TableIndex {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            index_type: self.index_type.to_pb()? as i32,
+            column_ids: self.column_ids.clone(),
+            query: self.query.clone(),
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableIndexType {
+    type PB = pb::table_index::IndexType;
+    fn get_pb_ver(_p: &Self::PB) -> u64 {
+        0
+    }
+    fn from_pb(p: pb::table_index::IndexType) -> Result<Self, Incompatible> {
+        match p {
+            pb::table_index::IndexType::Bloom => Ok(mt::TableIndexType::Bloom),
+            pb::table_index::IndexType::Inverted => Ok(mt::TableIndexType::Inverted),
+            pb::table_index::IndexType::Ngram => Ok(mt::TableIndexType::Ngram),
+            pb::table_index::IndexType::Aggregating => Ok(mt::TableIndexType::Aggregating),
+        }
+    }
+
+    fn to_pb(&self) -> Result<pb::table_index::IndexType, Incompatible> {
+        match self {
+            mt::TableIndexType::Bloom => Ok(pb::table_index::IndexType::Bloom),
+            mt::TableIndexType::Inverted => Ok(pb::table_index::IndexType::Inverted),
+            mt::TableIndexType::Ngram => Ok(pb::table_index::IndexType::Ngram),
+            mt::TableIndexType::Aggregating => Ok(pb::table_index::IndexType::Aggregating),
+        }
+    }
+}
+
 impl FromToProto for mt::TableIdList {
     type PB = pb::TableIdList;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -63,6 +63,14 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (31, "2023-02-21: Add: CopyOptions::max_files", ),
     (32, "2023-04-05: Add: file_format.proto/FileFormatParams", ),
     (33, "2023-04-13: Update: add `shared_by` field into TableMeta", ),
+    (34, "2023-04-20: Add: metadata.proto/DataType Interval type", ),
+    (35, "2023-04-24: Add: metadata.proto/DataType Binary type", ),
+    (36, "2023-04-25: Add: metadata.proto/DataType Bitmap type", ),
+    (37, "2023-04-27: Add: table.proto/TableMeta::indexes", ),
+    (38, "2023-04-28: Add: table.proto/TableIndex::query, IndexType::Aggregating", ),
+    (39, "2023-05-04: Add: file_format.proto/FileFormatParams::avro", ),
+    (40, "2023-05-06: Add: file_format.proto/FileFormatParams::orc", ),
+    (41, "2023-05-08: Add: file_format.proto/FileFormatParams::arrow, StageFileFormatType::Arrow", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
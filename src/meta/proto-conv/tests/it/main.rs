@@ -40,3 +40,11 @@ mod v030_user_stage;
 mod v031_copy_max_file;
 mod v032_file_format_params;
 mod v033_table_meta;
+mod v034_schema;
+mod v035_schema;
+mod v036_schema;
+mod v037_table_meta;
+mod v038_table_meta;
+mod v039_file_format_params;
+mod v040_file_format_params;
+mod v041_file_format_params;
@@ -197,6 +197,12 @@ fn new_table_meta() -> mt::TableMeta {
         drop_on: None,
         statistics: Default::default(),
         shared_by: btreeset! {1},
+        indexes: btreemap! {s("idx1") => mt::TableIndex {
+            name: s("idx1"),
+            index_type: mt::TableIndexType::Inverted,
+            column_ids: vec![0, 1],
+            query: s(""),
+        }},
     }
 }
 
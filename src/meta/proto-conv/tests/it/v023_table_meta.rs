@@ -145,6 +145,7 @@ fn test_decode_v23_table_meta() -> anyhow::Result<()> {
         drop_on: None,
         statistics: Default::default(),
         shared_by: BTreeSet::new(),
+        indexes: btreemap! {},
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -0,0 +1,68 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_expression as ce;
+use common_meta_app::schema as mt;
+use maplit::btreemap;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v38_table_meta() -> anyhow::Result<()> {
+    let table_meta_v38 = vec![
+        10, 58, 10, 22, 10, 3, 109, 115, 103, 26, 9, 146, 2, 0, 160, 6, 38, 168, 6, 24, 160, 6, 38,
+        168, 6, 24, 10, 24, 10, 3, 116, 97, 103, 26, 9, 146, 2, 0, 160, 6, 38, 168, 6, 24, 32, 1,
+        160, 6, 38, 168, 6, 24, 24, 2, 160, 6, 38, 168, 6, 24, 50, 4, 70, 85, 83, 69, 82, 7, 100,
+        101, 102, 97, 117, 108, 116, 162, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49,
+        50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 170, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50,
+        57, 32, 49, 50, 58, 48, 48, 58, 49, 48, 32, 85, 84, 67, 186, 1, 6, 160, 6, 38, 168, 6, 24,
+        234, 1, 66, 10, 4, 105, 100, 120, 49, 18, 58, 10, 4, 105, 100, 120, 49, 16, 3, 26, 1, 1,
+        34, 39, 83, 69, 76, 69, 67, 84, 32, 116, 97, 103, 44, 32, 99, 111, 117, 110, 116, 40, 41,
+        32, 70, 82, 79, 77, 32, 116, 32, 71, 82, 79, 85, 80, 32, 66, 89, 32, 116, 97, 103, 160, 6,
+        38, 168, 6, 24, 160, 6, 38, 168, 6, 24,
+    ];
+
+    let want = || mt::TableMeta {
+        schema: Arc::new(ce::TableSchema::new(vec![
+            ce::TableField::new("msg", ce::TableDataType::String),
+            ce::TableField::new("tag", ce::TableDataType::String),
+        ])),
+        engine: "FUSE".to_string(),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
+        indexes: btreemap! {"idx1".to_string() => mt::TableIndex {
+            name: "idx1".to_string(),
+            index_type: mt::TableIndexType::Aggregating,
+            column_ids: vec![1],
+            query: "SELECT tag, count() FROM t GROUP BY tag".to_string(),
+        }},
+        ..Default::default()
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_meta_v38.as_slice(), 38, want())
+}
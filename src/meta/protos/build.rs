@@ -101,5 +101,6 @@ fn build_proto() -> Result<()> {
             "#[derive(num_derive::FromPrimitive)]",
         )
         .type_attribute("StageType", "#[derive(num_derive::FromPrimitive)]")
+        .type_attribute("IndexType", "#[derive(num_derive::FromPrimitive)]")
         .compile_with_config(config, &proto_defs, &[proto_path])
 }
@@ -156,7 +156,7 @@ pub enum Expr {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Literal>,
-        window: Option<Window>,
+        window: Option<WindowDesc>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -422,7 +422,10 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    Interval,
     String,
+    Binary,
+    Bitmap,
     Array(Box<TypeName>),
     Map {
         key_type: Box<TypeName>,
@@ -453,6 +456,14 @@ pub enum TrimWhere {
     Trailing,
 }
 
+/// The `[IGNORE | RESPECT] NULLS OVER <window>` part of a window function call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowDesc {
+    /// `Some(true)` for `IGNORE NULLS`, `Some(false)` for `RESPECT NULLS`.
+    pub ignore_nulls: Option<bool>,
+    pub window: Window,
+}
+
 #[derive(Debug, Clone, PartialEq, EnumAsInner)]
 pub enum Window {
     WindowReference(WindowRef),
@@ -833,9 +844,18 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
             TypeName::String => {
                 write!(f, "STRING")?;
             }
+            TypeName::Binary => {
+                write!(f, "BINARY")?;
+            }
+            TypeName::Bitmap => {
+                write!(f, "BITMAP")?;
+            }
             TypeName::Array(ty) => {
                 write!(f, "ARRAY({})", ty)?;
             }
@@ -935,6 +955,17 @@ impl Display for WindowDefinition {
     }
 }
 
+impl Display for WindowDesc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.ignore_nulls {
+            Some(true) => write!(f, "IGNORE NULLS ")?,
+            Some(false) => write!(f, "RESPECT NULLS ")?,
+            None => {}
+        }
+        write!(f, "OVER ({})", self.window)
+    }
+}
+
 impl Display for Window {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let window_fmt = match *self {
@@ -1185,7 +1216,7 @@ impl Display for Expr {
                 write!(f, ")")?;
 
                 if let Some(window) = window {
-                    write!(f, " OVER ({window})")?;
+                    write!(f, " {window}")?;
                 }
             }
             Expr::Case {
@@ -430,7 +430,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        _over: &'ast Option<Window>,
+        _over: &'ast Option<WindowDesc>,
     ) {
         let mut children = Vec::with_capacity(args.len());
         for arg in args.iter() {
@@ -764,6 +764,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         let purge_name_node = FormatTreeNode::new(purge_name_ctx);
         children.push(purge_name_node);
 
+        if let Some(partition_by) = &copy.partition_by {
+            let mut partition_by_children = Vec::with_capacity(partition_by.len());
+            for target in partition_by.iter() {
+                self.visit_select_target(target);
+                partition_by_children.push(self.children.pop().unwrap());
+            }
+            let partition_by_name = "PartitionBy".to_string();
+            let partition_by_format_ctx =
+                AstFormatContext::with_children(partition_by_name, partition_by_children.len());
+            let partition_by_node =
+                FormatTreeNode::with_children(partition_by_format_ctx, partition_by_children);
+            children.push(partition_by_node);
+        }
+
         let name = "Copy".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1066,6 +1080,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             let options_node = FormatTreeNode::with_children(options_format_ctx, options_children);
             children.push(options_node);
         }
+        if let Some(clone_from) = &stmt.clone_from {
+            let clone_name = format!("CloneFrom {}", clone_from);
+            let clone_format_ctx = AstFormatContext::new(clone_name);
+            let clone_node = FormatTreeNode::new(clone_format_ctx);
+            children.push(clone_node);
+        }
         let name = "CreateDatabase".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1274,6 +1294,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                let mut children = Vec::new();
+                self.visit_table_ref(catalog, database, table);
+                children.push(self.children.pop().unwrap());
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1417,6 +1455,26 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_vacuum_table(&mut self, stmt: &'ast VacuumTableStmt) {
+        let mut children = Vec::new();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        if let Some(retain_hours) = stmt.retain_hours {
+            let retain_name = format!("Retain {retain_hours} Hours");
+            let retain_format_ctx = AstFormatContext::new(retain_name);
+            children.push(FormatTreeNode::new(retain_format_ctx));
+        }
+        if stmt.dry_run {
+            let dry_run_format_ctx = AstFormatContext::new("DryRun".to_string());
+            children.push(FormatTreeNode::new(dry_run_format_ctx));
+        }
+
+        let name = "VacuumTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_exists_table(&mut self, stmt: &'ast ExistsTableStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let child = self.children.pop().unwrap();
@@ -1427,6 +1485,69 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_index(&mut self, stmt: &'ast CreateIndexStmt) {
+        let mut children = Vec::new();
+        let index_type_format_ctx = AstFormatContext::new(format!("IndexType {}", stmt.index_type));
+        children.push(FormatTreeNode::new(index_type_format_ctx));
+        let index_name_format_ctx = AstFormatContext::new(format!("Index {}", stmt.index_name));
+        children.push(FormatTreeNode::new(index_name_format_ctx));
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_format_ctx =
+            AstFormatContext::with_children(columns_name, columns_children.len());
+        children.push(FormatTreeNode::with_children(
+            columns_format_ctx,
+            columns_children,
+        ));
+
+        let name = "CreateIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_create_aggregating_index(&mut self, stmt: &'ast CreateAggregatingIndexStmt) {
+        let index_name_format_ctx = AstFormatContext::new(format!("Index {}", stmt.index_name));
+        let index_child = FormatTreeNode::new(index_name_format_ctx);
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateAggregatingIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_index(&mut self, stmt: &'ast DropIndexStmt) {
+        let index_name_format_ctx = AstFormatContext::new(format!("Index {}", stmt.index_name));
+        let index_child = FormatTreeNode::new(index_name_format_ctx);
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_index(&mut self, stmt: &'ast RefreshIndexStmt) {
+        let index_name_format_ctx = AstFormatContext::new(format!("Index {}", stmt.index_name));
+        let index_child = FormatTreeNode::new(index_name_format_ctx);
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "RefreshIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_view(&mut self, stmt: &'ast CreateViewStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
         let view_child = self.children.pop().unwrap();
@@ -1461,6 +1582,50 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_materialized_view(&mut self, stmt: &'ast CreateMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let view_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        self.visit_table_ref(&None, &stmt.table_database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_show_users(&mut self) {
         let name = "ShowUsers".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -2039,6 +2204,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_begin(&mut self) {
+        let name = "Begin".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_commit(&mut self) {
+        let name = "Commit".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_rollback(&mut self) {
+        let name = "Rollback".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -2473,6 +2659,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
         }
 
+        if let Some(match_condition) = &join.match_condition {
+            self.visit_expr(match_condition);
+            let child = self.children.pop().unwrap();
+            let match_condition_name = "MatchCondition".to_string();
+            let match_condition_format_ctx =
+                AstFormatContext::with_children(match_condition_name, 1);
+            let match_condition_node =
+                FormatTreeNode::with_children(match_condition_format_ctx, vec![child]);
+            children.push(match_condition_node);
+        }
+
         let name = "Join".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
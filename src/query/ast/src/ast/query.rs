@@ -267,6 +267,8 @@ pub struct TableAlias {
 pub struct Join {
     pub op: JoinOperator,
     pub condition: JoinCondition,
+    // `MATCH_CONDITION (expr)` of ASOF join
+    pub match_condition: Option<Box<Expr>>,
     pub left: Box<TableReference>,
     pub right: Box<TableReference>,
 }
@@ -284,6 +286,9 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // Asof joins must work with a match condition
+    Asof,
+    LeftAsof,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -477,8 +482,17 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsof => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
+                if let Some(match_condition) = &join.match_condition {
+                    write!(f, " MATCH_CONDITION ({match_condition})")?;
+                }
                 match &join.condition {
                     JoinCondition::On(expr) => {
                         write!(f, " ON {expr}")?;
@@ -22,10 +22,12 @@ use std::io::Result;
 
 use url::Url;
 
+use crate::ast::write_comma_separated_list;
 use crate::ast::write_quoted_comma_separated_list;
 use crate::ast::write_space_separated_map;
 use crate::ast::Identifier;
 use crate::ast::Query;
+use crate::ast::SelectTarget;
 
 /// CopyStmt is the parsed statement of `COPY`.
 ///
@@ -51,6 +53,9 @@ pub struct CopyStmt {
     pub purge: bool,
     pub force: bool,
     pub on_error: String,
+    /// The keys of the hive style partitions to unload into, e.g.
+    /// `PARTITION BY (to_yyyymm(ts) AS month)`.
+    pub partition_by: Option<Vec<SelectTarget>>,
 }
 
 impl CopyStmt {
@@ -68,6 +73,7 @@ impl CopyStmt {
             CopyOption::Purge(v) => self.purge = v,
             CopyOption::Force(v) => self.force = v,
             CopyOption::OnError(v) => self.on_error = v,
+            CopyOption::PartitionBy(v) => self.partition_by = Some(v),
         }
     }
 }
@@ -121,6 +127,12 @@ impl Display for CopyStmt {
         write!(f, " FORCE = {}", self.force)?;
         write!(f, " ON_ERROR = '{}'", self.on_error)?;
 
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " PARTITION BY (")?;
+            write_comma_separated_list(f, partition_by)?;
+            write!(f, ")")?;
+        }
+
         Ok(())
     }
 }
@@ -392,4 +404,5 @@ pub enum CopyOption {
     Purge(bool),
     Force(bool),
     OnError(String),
+    PartitionBy(Vec<SelectTarget>),
 }
@@ -69,6 +69,7 @@ pub struct CreateDatabaseStmt {
     pub engine: Option<DatabaseEngine>,
     pub options: Vec<SQLProperty>,
     pub from_share: Option<ShareNameIdent>,
+    pub clone_from: Option<Identifier>,
 }
 
 impl Display for CreateDatabaseStmt {
@@ -88,6 +89,9 @@ impl Display for CreateDatabaseStmt {
                 from_share.tenant, from_share.share_name
             )?;
         }
+        if let Some(clone_from) = &self.clone_from {
+            write!(f, " CLONE {clone_from}")?;
+        }
         // TODO(leiysky): display rest information
         Ok(())
     }
@@ -18,14 +18,17 @@ mod columns;
 mod copy;
 mod database;
 mod explain;
+mod index;
 mod insert;
 mod kill;
+mod merge_into;
 mod presign;
 mod replace;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
 mod unset;
 mod update;
@@ -38,14 +41,17 @@ pub use columns::*;
 pub use copy::*;
 pub use database::*;
 pub use explain::*;
+pub use index::*;
 pub use insert::*;
 pub use kill::*;
+pub use merge_into::*;
 pub use presign::*;
 pub use replace::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
 pub use unset::*;
 pub use update::*;
@@ -75,6 +75,7 @@ pub enum Statement {
 
     Insert(InsertStmt),
     Replace(ReplaceStmt),
+    MergeInto(MergeIntoStmt),
 
     Delete {
         table_reference: TableReference,
@@ -113,14 +114,27 @@ pub enum Statement {
     TruncateTable(TruncateTableStmt),
     OptimizeTable(OptimizeTableStmt),
     AnalyzeTable(AnalyzeTableStmt),
+    VacuumTable(VacuumTableStmt),
     ExistsTable(ExistsTableStmt),
     // Columns
     ShowColumns(ShowColumnsStmt),
 
+    // Indexes
+    CreateIndex(CreateIndexStmt),
+    CreateAggregatingIndex(CreateAggregatingIndexStmt),
+    DropIndex(DropIndexStmt),
+    RefreshIndex(RefreshIndexStmt),
+
     // Views
     CreateView(CreateViewStmt),
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+
+    // Streams
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
 
     // User
     ShowUsers,
@@ -209,6 +223,11 @@ pub enum Statement {
     ShowShares(ShowSharesStmt),
     ShowObjectGrantPrivileges(ShowObjectGrantPrivilegesStmt),
     ShowGrantsOfShare(ShowGrantsOfShareStmt),
+
+    // Transactions
+    Begin,
+    Commit,
+    Rollback,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -269,6 +288,7 @@ impl Display for Statement {
             Statement::Query(query) => write!(f, "{query}")?,
             Statement::Insert(insert) => write!(f, "{insert}")?,
             Statement::Replace(replace) => write!(f, "{replace}")?,
+            Statement::MergeInto(merge_into) => write!(f, "{merge_into}")?,
             Statement::Delete {
                 table_reference,
                 selection,
@@ -360,10 +380,19 @@ impl Display for Statement {
             Statement::TruncateTable(stmt) => write!(f, "{stmt}")?,
             Statement::OptimizeTable(stmt) => write!(f, "{stmt}")?,
             Statement::AnalyzeTable(stmt) => write!(f, "{stmt}")?,
+            Statement::VacuumTable(stmt) => write!(f, "{stmt}")?,
             Statement::ExistsTable(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateAggregatingIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
             Statement::ShowUsers => write!(f, "SHOW USERS")?,
             Statement::ShowRoles => write!(f, "SHOW ROLES")?,
             Statement::CreateUser(stmt) => write!(f, "{stmt}")?,
@@ -504,6 +533,9 @@ impl Display for Statement {
             Statement::ShowShares(stmt) => write!(f, "{stmt}")?,
             Statement::ShowObjectGrantPrivileges(stmt) => write!(f, "{stmt}")?,
             Statement::ShowGrantsOfShare(stmt) => write!(f, "{stmt}")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Rollback => write!(f, "ROLLBACK")?,
         }
         Ok(())
     }
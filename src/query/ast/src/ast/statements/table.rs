@@ -168,6 +168,12 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -186,6 +192,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -448,6 +467,36 @@ impl Display for AnalyzeTableStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct VacuumTableStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub retain_hours: Option<u64>,
+    pub dry_run: bool,
+}
+
+impl Display for VacuumTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "VACUUM TABLE ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if let Some(retain_hours) = self.retain_hours {
+            write!(f, " RETAIN {retain_hours} HOURS")?;
+        }
+        if self.dry_run {
+            write!(f, " DRY RUN")?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExistsTableStmt {
     pub catalog: Option<Identifier>,
@@ -475,6 +524,7 @@ pub enum Engine {
     Fuse,
     View,
     Random,
+    Delta,
 }
 
 impl Display for Engine {
@@ -485,6 +535,7 @@ impl Display for Engine {
             Engine::Fuse => write!(f, "FUSE"),
             Engine::View => write!(f, "VIEW"),
             Engine::Random => write!(f, "RANDOM"),
+            Engine::Delta => write!(f, "DELTA"),
         }
     }
 }
@@ -23,11 +23,13 @@ pub use error::Error;
 pub use error::ErrorKind;
 
 mod visitors;
+pub use visitors::walk_cte_mut;
 pub use visitors::walk_expr;
 pub use visitors::walk_expr_mut;
 pub use visitors::walk_query;
 pub use visitors::walk_query_mut;
 pub use visitors::walk_statement_mut;
+pub use visitors::walk_table_reference_mut;
 pub use visitors::Visitor;
 pub use visitors::VisitorMut;
 
@@ -433,6 +433,8 @@ pub fn join_operator(i: Input) -> IResult<JoinOperator> {
         value(JoinOperator::RightOuter, rule! { RIGHT ~ OUTER? }),
         value(JoinOperator::FullOuter, rule! { FULL ~ OUTER? }),
         value(JoinOperator::CrossJoin, rule! { CROSS }),
+        value(JoinOperator::LeftAsof, rule! { ASOF ~ LEFT ~ OUTER? }),
+        value(JoinOperator::Asof, rule! { ASOF }),
     ))(i)
 }
 
@@ -513,6 +515,8 @@ pub enum TableReferenceElement {
     },
     // ON expr | USING (ident, ...)
     JoinCondition(JoinCondition),
+    // MATCH_CONDITION (expr)
+    JoinMatchCondition(Expr),
     Group(TableReference),
     Stage {
         location: FileLocation,
@@ -582,6 +586,12 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
         |(_, _, idents, _)| TableReferenceElement::JoinCondition(JoinCondition::Using(idents)),
     );
+    let join_match_condition = map(
+        rule! {
+            MATCH_CONDITION ~ "(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| TableReferenceElement::JoinMatchCondition(expr),
+    );
 
     let table_function = map(
         rule! {
@@ -638,6 +648,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         | #join
         | #join_condition_on
         | #join_condition_using
+        | #join_match_condition
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
 }
@@ -654,7 +665,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
     fn query(&mut self, input: &Self::Input) -> Result<Affix, &'static str> {
         let affix = match &input.elem {
             TableReferenceElement::Join { .. } => Affix::Infix(Precedence(10), Associativity::Left),
-            TableReferenceElement::JoinCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::JoinCondition(..)
+            | TableReferenceElement::JoinMatchCondition(..) => Affix::Postfix(Precedence(5)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -749,6 +761,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     join: Join {
                         op,
                         condition,
+                        match_condition: None,
                         left: Box::new(lhs),
                         right: Box::new(rhs),
                     },
@@ -787,6 +800,24 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 },
                 _ => Err("join condition must apply to a join"),
             },
+            TableReferenceElement::JoinMatchCondition(expr) => match &mut lhs {
+                TableReference::Join {
+                    join:
+                        Join {
+                            op: JoinOperator::Asof | JoinOperator::LeftAsof,
+                            match_condition,
+                            ..
+                        },
+                    ..
+                } => {
+                    if match_condition.is_some() {
+                        return Err("match condition already set");
+                    }
+                    *match_condition = Some(Box::new(expr));
+                    Ok(lhs)
+                }
+                _ => Err("match condition must apply to an asof join"),
+            },
             _ => unreachable!(),
         }
     }
@@ -893,6 +924,13 @@ pub fn window_spec_ident(i: Input) -> IResult<Window> {
     ))(i)
 }
 
+pub fn null_treatment(i: Input) -> IResult<bool> {
+    alt((
+        value(true, rule! { IGNORE ~ NULLS }),
+        value(false, rule! { RESPECT ~ NULLS }),
+    ))(i)
+}
+
 pub fn window_clause(i: Input) -> IResult<WindowDefinition> {
     map(
         rule! {
@@ -52,6 +52,7 @@ pub enum ShowGrantOption {
 pub enum CreateDatabaseOption {
     DatabaseEngine(DatabaseEngine),
     FromShare(ShareNameIdent),
+    CloneFrom(Identifier),
 }
 
 pub fn statement(i: Input) -> IResult<StatementMsg> {
@@ -144,6 +145,36 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let merge_into = map(
+        rule! {
+            MERGE ~ INTO ~ #period_separated_idents_1_to_3 ~ #table_alias?
+            ~ USING ~ #table_reference
+            ~ ON ~ ^#expr
+            ~ #merge_option+
+        },
+        |(
+            _,
+            _,
+            (catalog, database, table),
+            target_alias,
+            _,
+            source,
+            _,
+            join_expr,
+            merge_options,
+        )| {
+            Statement::MergeInto(MergeIntoStmt {
+                catalog,
+                database,
+                table,
+                target_alias,
+                source,
+                join_expr,
+                merge_options,
+            })
+        },
+    );
+
     let delete = map(
         rule! {
             DELETE ~ FROM ~ #table_reference_only
@@ -315,6 +346,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                         engine: Some(engine),
                         options: vec![],
                         from_share: None,
+                        clone_from: None,
                     })
                 }
                 Some(CreateDatabaseOption::FromShare(share_name)) => {
@@ -325,6 +357,18 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                         engine: None,
                         options: vec![],
                         from_share: Some(share_name),
+                        clone_from: None,
+                    })
+                }
+                Some(CreateDatabaseOption::CloneFrom(source)) => {
+                    Statement::CreateDatabase(CreateDatabaseStmt {
+                        if_not_exists: opt_if_not_exists.is_some(),
+                        catalog,
+                        database,
+                        engine: None,
+                        options: vec![],
+                        from_share: None,
+                        clone_from: Some(source),
                     })
                 }
                 None => Statement::CreateDatabase(CreateDatabaseStmt {
@@ -334,6 +378,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     engine: None,
                     options: vec![],
                     from_share: None,
+                    clone_from: None,
                 }),
             }
         },
@@ -607,6 +652,22 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let vacuum_table = map(
+        rule! {
+            VACUUM ~ TABLE ~ #period_separated_idents_1_to_3
+            ~ ( RETAIN ~ ^#literal_u64 ~ ^HOURS )?
+            ~ ( DRY ~ ^RUN )?
+        },
+        |(_, _, (catalog, database, table), opt_retain, opt_dry_run)| {
+            Statement::VacuumTable(VacuumTableStmt {
+                catalog,
+                database,
+                table,
+                retain_hours: opt_retain.map(|(_, hours, _)| hours),
+                dry_run: opt_dry_run.is_some(),
+            })
+        },
+    );
     let exists_table = map(
         rule! {
             EXISTS ~ TABLE ~ #period_separated_idents_1_to_3
@@ -619,6 +680,75 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_index = map(
+        rule! {
+            CREATE ~ #table_index_type ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+            ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(
+            _,
+            index_type,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+        )| {
+            Statement::CreateIndex(CreateIndexStmt {
+                index_type,
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+            })
+        },
+    );
+    let create_aggregating_index = map(
+        rule! {
+            CREATE ~ AGGREGATING ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, index_name, _, query)| {
+            Statement::CreateAggregatingIndex(CreateAggregatingIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                query: Box::new(query),
+            })
+        },
+    );
+    let drop_index = map(
+        rule! {
+            DROP ~ INDEX ~ ( IF ~ EXISTS )? ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropIndex(DropIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+    let refresh_index = map(
+        rule! {
+            REFRESH ~ INDEX ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, index_name, _, (catalog, database, table))| {
+            Statement::RefreshIndex(RefreshIndexStmt {
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
     let create_view = map(
         rule! {
             CREATE ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
@@ -671,6 +801,64 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                query: Box::new(query),
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #period_separated_idents_1_to_2
+        },
+        |(_, _, opt_if_not_exists, (catalog, database, stream), _, _, (table_database, table))| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
     let show_users = value(Statement::ShowUsers, rule! { SHOW ~ USERS });
     let create_user = map(
         rule! {
@@ -937,6 +1125,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 purge: Default::default(),
                 force: Default::default(),
                 on_error: "abort".to_string(),
+                partition_by: Default::default(),
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -1124,6 +1313,10 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
 
     let show_file_formats = value(Statement::ShowFileFormats, rule! { SHOW ~ FILE ~ FORMATS });
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT ~ TRANSACTION? });
+    let rollback = value(Statement::Rollback, rule! { ROLLBACK ~ TRANSACTION? });
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
@@ -1150,6 +1343,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #merge_into : "`MERGE INTO <table> USING <source> ON <condition> WHEN [NOT] MATCHED [AND <condition>] THEN ...`"
         ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
@@ -1170,6 +1364,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #truncate_table : "`TRUNCATE TABLE [<database>.]<table> [PURGE]`"
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
             | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
+            | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN <n> HOURS] [DRY RUN]`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
             | #show_table_functions : "`SHOW TABLE_FUNCTIONS [<show_limit>]`"
         ),
@@ -1177,6 +1372,16 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+            | #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table>`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
+        ),
+        rule!(
+            #create_index : "`CREATE {BLOOM | INVERTED | NGRAM} INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...)`"
+            | #create_aggregating_index : "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ... FROM <table> GROUP BY ...`"
+            | #drop_index : "`DROP INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #refresh_index : "`REFRESH INDEX <index> ON [<database>.]<table>`"
         ),
         rule!(
             #show_users : "`SHOW USERS`"
@@ -1246,6 +1451,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         | #create_catalog: "`CREATE CATALOG [IF NOT EXISTS] <catalog> TYPE=<catalog_type> CONNECTION=<catalog_options>`"
         | #drop_catalog: "`DROP CATALOG [IF EXISTS] <catalog>`"
         ),
+        // transaction
+        rule!(
+            #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT [TRANSACTION]`"
+            | #rollback : "`ROLLBACK [TRANSACTION]`"
+        ),
     ));
 
     map(
@@ -1576,10 +1787,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let clone = map(
+        rule! {
+            CLONE ~ #period_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, p)| p),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -1676,6 +1899,14 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
     ))(i)
 }
 
+pub fn table_index_type(i: Input) -> IResult<TableIndexType> {
+    alt((
+        value(TableIndexType::Bloom, rule! { BLOOM }),
+        value(TableIndexType::Inverted, rule! { INVERTED }),
+        value(TableIndexType::Ngram, rule! { NGRAM }),
+    ))(i)
+}
+
 pub fn kill_target(i: Input) -> IResult<KillTarget> {
     alt((
         value(KillTarget::Query, rule! { QUERY }),
@@ -1774,6 +2005,7 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Fuse, rule! { FUSE }),
         value(Engine::View, rule! { VIEW }),
         value(Engine::Random, rule! { RANDOM }),
+        value(Engine::Delta, rule! { DELTA }),
     ));
 
     map(
@@ -1815,12 +2047,24 @@ pub fn create_database_option(i: Input) -> IResult<CreateDatabaseOption> {
         },
     ),));
 
-    map(
+    let engine_or_share = map(
         rule! {
             ENGINE ~  ^"=" ~ ^#create_db_engine
             | FROM ~ SHARE ~ ^#share_from
         },
         |(_, _, option)| option,
+    );
+
+    let clone_from = map(
+        rule! {
+            CLONE ~ ^#ident
+        },
+        |(_, source)| CreateDatabaseOption::CloneFrom(source),
+    );
+
+    rule!(
+        #engine_or_share
+        | #clone_from
     )(i)
 }
 
@@ -1918,9 +2162,22 @@ pub fn copy_option(i: Input) -> IResult<CopyOption> {
         map(rule! {ON_ERROR ~ "=" ~ #ident}, |(_, _, on_error)| {
             CopyOption::OnError(on_error.to_string())
         }),
+        map(
+            rule! { PARTITION ~ ^BY ~ "(" ~ #comma_separated_list1(partition_by_target) ~ ")" },
+            |(_, _, _, targets, _)| CopyOption::PartitionBy(targets),
+        ),
     ))(i)
 }
 
+pub fn partition_by_target(i: Input) -> IResult<SelectTarget> {
+    map(rule! { #expr ~ ( AS ~ #ident )? }, |(expr, alias)| {
+        SelectTarget::AliasedExpr {
+            expr: Box::new(expr),
+            alias: alias.map(|(_, alias)| alias),
+        }
+    })(i)
+}
+
 pub fn presign_action(i: Input) -> IResult<PresignAction> {
     alt((
         value(PresignAction::Download, rule! { DOWNLOAD }),
@@ -1972,3 +2229,45 @@ pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
         UpdateExpr { name, expr }
     })(i)
 }
+
+pub fn merge_option(i: Input) -> IResult<MergeOption> {
+    let match_operation = alt((
+        map(
+            rule! { UPDATE ~ SET ~ ^#comma_separated_list1(update_expr) },
+            |(_, _, update_list)| MatchOperation::Update { update_list },
+        ),
+        value(MatchOperation::Delete, rule! { DELETE }),
+    ));
+    let matched_clause = map(
+        rule! {
+            WHEN ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN ~ ^#match_operation
+        },
+        |(_, _, opt_selection, _, operation)| {
+            MergeOption::Match(MatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                operation,
+            })
+        },
+    );
+    let unmatched_clause = map(
+        rule! {
+            WHEN ~ NOT ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN ~ INSERT
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ VALUES ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, opt_selection, _, _, opt_columns, _, _, values, _)| {
+            MergeOption::Unmatch(UnmatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+                values,
+            })
+        },
+    );
+
+    rule!(
+        #matched_clause
+        | #unmatched_clause
+    )(i)
+}
@@ -208,14 +208,14 @@ pub trait Visitor<'ast>: Sized {
         _name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        over: &'ast Option<Window>,
+        over: &'ast Option<WindowDesc>,
     ) {
         for arg in args {
             walk_expr(self, arg);
         }
 
         if let Some(over) = over {
-            self.visit_window(over);
+            self.visit_window(&over.window);
         }
     }
 
@@ -377,6 +377,8 @@ pub trait Visitor<'ast>: Sized {
     fn visit_insert(&mut self, _insert: &'ast InsertStmt) {}
     fn visit_replace(&mut self, _replace: &'ast ReplaceStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &'ast MergeIntoStmt) {}
+
     fn visit_insert_source(&mut self, _insert_source: &'ast InsertSource) {}
 
     fn visit_delete(
@@ -440,14 +442,32 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_analyze_table(&mut self, _stmt: &'ast AnalyzeTableStmt) {}
 
+    fn visit_vacuum_table(&mut self, _stmt: &'ast VacuumTableStmt) {}
+
     fn visit_exists_table(&mut self, _stmt: &'ast ExistsTableStmt) {}
 
+    fn visit_create_index(&mut self, _stmt: &'ast CreateIndexStmt) {}
+
+    fn visit_create_aggregating_index(&mut self, _stmt: &'ast CreateAggregatingIndexStmt) {}
+
+    fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
+
+    fn visit_refresh_index(&mut self, _stmt: &'ast RefreshIndexStmt) {}
+
     fn visit_create_view(&mut self, _stmt: &'ast CreateViewStmt) {}
 
     fn visit_alter_view(&mut self, _stmt: &'ast AlterViewStmt) {}
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &'ast CreateUserStmt) {}
@@ -539,6 +559,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_grants_of_share(&mut self, _stmt: &'ast ShowGrantsOfShareStmt) {}
 
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_rollback(&mut self) {}
+
     fn visit_with(&mut self, with: &'ast With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter() {
@@ -622,6 +648,7 @@ pub trait Visitor<'ast>: Sized {
             left,
             right,
             condition,
+            match_condition,
             ..
         } = join;
 
@@ -629,6 +656,10 @@ pub trait Visitor<'ast>: Sized {
         walk_table_reference(self, right);
 
         walk_join_condition(self, condition);
+
+        if let Some(match_condition) = match_condition {
+            self.visit_expr(match_condition);
+        }
     }
     fn visit_window_definition(&mut self, window_definition: &'ast WindowDefinition) {
         walk_window_definition(self, window_definition);
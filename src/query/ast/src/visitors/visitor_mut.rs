@@ -208,14 +208,14 @@ pub trait VisitorMut: Sized {
         _name: &mut Identifier,
         args: &mut [Expr],
         _params: &mut [Literal],
-        over: &mut Option<Window>,
+        over: &mut Option<WindowDesc>,
     ) {
         for arg in args.iter_mut() {
             walk_expr_mut(self, arg);
         }
 
         if let Some(over) = over {
-            match over {
+            match &mut over.window {
                 Window::WindowReference(reference) => {
                     self.visit_identifier(&mut reference.window_name);
                 }
@@ -378,6 +378,8 @@ pub trait VisitorMut: Sized {
     fn visit_insert(&mut self, _insert: &mut InsertStmt) {}
     fn visit_replace(&mut self, _replace: &mut ReplaceStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &mut MergeIntoStmt) {}
+
     fn visit_insert_source(&mut self, _insert_source: &mut InsertSource) {}
 
     fn visit_delete(
@@ -441,14 +443,32 @@ pub trait VisitorMut: Sized {
 
     fn visit_analyze_table(&mut self, _stmt: &mut AnalyzeTableStmt) {}
 
+    fn visit_vacuum_table(&mut self, _stmt: &mut VacuumTableStmt) {}
+
     fn visit_exists_table(&mut self, _stmt: &mut ExistsTableStmt) {}
 
+    fn visit_create_index(&mut self, _stmt: &mut CreateIndexStmt) {}
+
+    fn visit_create_aggregating_index(&mut self, _stmt: &mut CreateAggregatingIndexStmt) {}
+
+    fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
+
+    fn visit_refresh_index(&mut self, _stmt: &mut RefreshIndexStmt) {}
+
     fn visit_create_view(&mut self, _stmt: &mut CreateViewStmt) {}
 
     fn visit_alter_view(&mut self, _stmt: &mut AlterViewStmt) {}
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &mut CreateUserStmt) {}
@@ -540,6 +560,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_grants_of_share(&mut self, _stmt: &mut ShowGrantsOfShareStmt) {}
 
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_rollback(&mut self) {}
+
     fn visit_with(&mut self, with: &mut With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter_mut() {
@@ -578,7 +604,7 @@ pub trait VisitorMut: Sized {
         }
 
         for table_ref in from.iter_mut() {
-            walk_table_reference_mut(self, table_ref);
+            self.visit_table_reference(table_ref);
         }
 
         if let Some(selection) = selection {
@@ -623,12 +649,17 @@ pub trait VisitorMut: Sized {
             left,
             right,
             condition,
+            match_condition,
             ..
         } = join;
 
-        walk_table_reference_mut(self, left);
-        walk_table_reference_mut(self, right);
+        self.visit_table_reference(left);
+        self.visit_table_reference(right);
 
         walk_join_condition_mut(self, condition);
+
+        if let Some(match_condition) = match_condition {
+            self.visit_expr(match_condition);
+        }
     }
 }
@@ -86,6 +86,8 @@ fn test_statement() {
         r#"create table if not exists a.b (c tuple(m integer, n string), d tuple(integer, string));"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table t2 clone t1;"#,
+        r#"create table if not exists db.t2 clone db.t1 at (snapshot => 'abc');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
@@ -100,6 +102,7 @@ fn test_statement() {
         r#"create database ctl.t engine = Default;"#,
         r#"create database t engine = Default;"#,
         r#"create database t FROM SHARE a.s;"#,
+        r#"create database d2 clone d1;"#,
         r#"drop database ctl.t;"#,
         r#"drop database if exists t;"#,
         r#"create table c(a DateTime null, b DateTime(3));"#,
@@ -108,6 +111,19 @@ fn test_statement() {
         r#"drop view v;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"create inverted index idx1 on t(c1, c2);"#,
+        r#"create aggregating index idx1 as select a, count(*) from t group by a;"#,
+        r#"drop index if exists idx1 on db.t;"#,
+        r#"refresh index idx1 on t;"#,
+        r#"create materialized view if not exists db.mv as select a from t;"#,
+        r#"refresh materialized view mv;"#,
+        r#"vacuum table t;"#,
+        r#"vacuum table db.t retain 12 hours dry run;"#,
+        r#"create stream if not exists db.s on table db.t;"#,
+        r#"drop stream s;"#,
+        r#"begin;"#,
+        r#"commit transaction;"#,
+        r#"rollback;"#,
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
@@ -275,6 +291,7 @@ fn test_statement() {
                     skip_header = 1
                 )
                 size_limit=10;"#,
+        r#"COPY INTO @my_stage FROM mytable FILE_FORMAT = (type = PARQUET) PARTITION BY (to_yyyymm(ts) AS ym, region);"#,
         r#"COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
                 CREDENTIALS = (
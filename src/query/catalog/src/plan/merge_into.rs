@@ -0,0 +1,47 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::FieldIndex;
+use common_expression::RemoteExpr;
+
+/// Describes how the rows of the source are merged into the target table.
+///
+/// The join condition and the matched clauses are evaluated on blocks consisting of
+/// the columns of the target table followed by the columns of the source, while the
+/// unmatched clauses are evaluated on the columns of the source only.
+#[derive(Clone, Debug)]
+pub struct MergeIntoDesc {
+    /// Equi-join keys of the target table, used to find the candidate source rows.
+    pub target_keys: Vec<RemoteExpr>,
+    /// Equi-join keys of the source, in the same order as `target_keys`.
+    pub source_keys: Vec<RemoteExpr>,
+    /// The whole `ON` condition.
+    pub join_condition: RemoteExpr,
+    pub matched: Vec<MergeMatchedAction>,
+    pub unmatched: Vec<MergeUnmatchedAction>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MergeMatchedAction {
+    pub condition: Option<RemoteExpr>,
+    /// The updated fields and their new values, `None` means deletion.
+    pub update_list: Option<Vec<(FieldIndex, RemoteExpr)>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MergeUnmatchedAction {
+    pub condition: Option<RemoteExpr>,
+    /// Values of all the fields of the target table.
+    pub values: Vec<RemoteExpr>,
+}
@@ -14,6 +14,7 @@
 
 mod datasource;
 mod internal_column;
+mod merge_into;
 mod partition;
 mod partition_statistics;
 mod projection;
@@ -22,6 +23,7 @@ mod pushdown;
 
 pub use datasource::*;
 pub use internal_column::*;
+pub use merge_into::*;
 pub use partition::*;
 pub use partition_statistics::PartStatistics;
 pub use projection::Projection;
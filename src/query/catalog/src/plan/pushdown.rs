@@ -14,6 +14,7 @@
 
 use std::fmt::Debug;
 
+use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
@@ -38,6 +39,43 @@ pub struct VirtualColumnInfo {
     pub data_type: Box<TableDataType>,
 }
 
+/// Information of the aggregating index read instead of the table data.
+///
+/// The scan outputs the `group_by` columns, followed by a string column of the
+/// serialized partial states for each of the `aggregates`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AggIndexInfo {
+    /// Name of the index
+    pub index_name: String,
+    /// The defining query of the index
+    pub query: String,
+    /// Names of the group columns to be output, which are part of the group columns of the index
+    pub group_by: Vec<String>,
+    /// Offsets of the aggregate functions in the index definition to be output
+    pub aggregates: Vec<usize>,
+}
+
+impl AggIndexInfo {
+    /// Name of the states column of the aggregate function at `offset` of the index definition.
+    pub fn state_field_name(offset: usize) -> String {
+        format!("_agg_state_{offset}")
+    }
+
+    pub fn output_schema(&self, source_schema: &TableSchema) -> Result<TableSchema> {
+        let mut fields = Vec::with_capacity(self.group_by.len() + self.aggregates.len());
+        for name in self.group_by.iter() {
+            fields.push(source_schema.field_with_name(name)?.clone());
+        }
+        for offset in self.aggregates.iter() {
+            fields.push(TableField::new(
+                &Self::state_field_name(*offset),
+                TableDataType::String,
+            ));
+        }
+        Ok(TableSchema::new(fields))
+    }
+}
+
 /// Information about prewhere optimization.
 ///
 /// Prewhere steps:
@@ -89,6 +127,8 @@ pub struct PushDownInfo {
     pub order_by: Vec<(RemoteExpr<String>, bool, bool)>,
     /// Optional virtual columns
     pub virtual_columns: Option<Vec<VirtualColumnInfo>>,
+    /// Optional aggregating index to read the partial states from
+    pub agg_index: Option<AggIndexInfo>,
 }
 
 /// TopK is a wrapper for topk push down items.
@@ -39,6 +39,7 @@ use common_storage::StorageMetrics;
 
 use crate::plan::DataSourceInfo;
 use crate::plan::DataSourcePlan;
+use crate::plan::MergeIntoDesc;
 use crate::plan::PartStatistics;
 use crate::plan::Partitions;
 use crate::plan::PushDownInfo;
@@ -260,6 +261,22 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    #[async_backtrace::framed]
+    async fn merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        merge_into: MergeIntoDesc,
+    ) -> Result<()> {
+        let (_, _, _) = (ctx, pipeline, merge_into);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "table {}, engine type {}, does not support MERGE INTO",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     #[async_backtrace::framed]
     async fn delete(
         &self,
@@ -354,6 +371,17 @@ pub trait Table: Sync + Send {
             self.get_table_info().engine(),
         )))
     }
+
+    /// Rebuild the files of the index for the existing data.
+    #[async_backtrace::framed]
+    async fn refresh_index(&self, ctx: Arc<dyn TableContext>, index_name: &str) -> Result<()> {
+        let (_, _) = (ctx, index_name);
+        Err(ErrorCode::Unimplemented(format!(
+            "table {},  of engine type {}, does not support refresh index",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
 }
 
 #[async_trait::async_trait]
@@ -399,6 +427,24 @@ pub struct ColumnStatistics {
     pub max: Scalar,
     pub null_count: u64,
     pub number_of_distinct_values: u64,
+    /// Histogram collected by `ANALYZE TABLE`, if any.
+    pub histogram: Option<ColumnHistogram>,
+}
+
+/// Equi-depth histogram of the non-null values of a column.
+#[derive(Debug, Clone)]
+pub struct ColumnHistogram {
+    /// Buckets ordered by their inclusive upper bounds.
+    pub buckets: Vec<HistogramBucket>,
+    /// The most common values and their estimated number of rows.
+    pub most_common_values: Vec<(Scalar, u64)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HistogramBucket {
+    pub upper_bound: Scalar,
+    pub num_values: f64,
+    pub num_distinct: f64,
 }
 
 pub enum CompactTarget {
@@ -45,6 +45,7 @@ use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 #[derive(Debug)]
 pub struct ProcessInfo {
@@ -154,4 +155,7 @@ pub trait TableContext: Send + Sync {
         files: &[StageFileInfo],
         max_files: Option<usize>,
     ) -> Result<Vec<StageFileInfo>>;
+
+    /// Get the explicit transaction of the session.
+    fn txn_mgr(&self) -> TxnManagerRef;
 }
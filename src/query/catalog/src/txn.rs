@@ -0,0 +1,149 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+/// The state of the explicit transaction of a session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxnState {
+    /// Not in a transaction, each statement commits on its own.
+    AutoCommit,
+    /// In a transaction started by `BEGIN`, the table metas updated by the statements
+    /// are buffered until `COMMIT`.
+    Active,
+    /// A statement of the transaction failed, the transaction can only be ended.
+    Fail,
+}
+
+/// Buffers the table metas updated in the explicit transaction of a session, which are
+/// committed together by `COMMIT`, or dropped by `ROLLBACK`.
+pub struct TxnManager {
+    state: TxnState,
+    /// The catalog of the tables updated in the transaction.
+    catalog: Option<String>,
+    /// The uncommitted version of each table updated in the transaction, by table id.
+    tables: BTreeMap<u64, TableInfo>,
+    update_table_metas: BTreeMap<u64, UpdateTableMetaReq>,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            catalog: None,
+            tables: BTreeMap::new(),
+            update_table_metas: BTreeMap::new(),
+        }))
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.state == TxnState::Fail
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    /// End the transaction, the buffered table metas are dropped.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.catalog = None;
+        self.tables.clear();
+        self.update_table_metas.clear();
+    }
+
+    /// Buffer the update of a table meta instead of committing it.
+    ///
+    /// All the updates of a table in the transaction are committed against the version
+    /// of the table the transaction started from, so the commit fails if the table is
+    /// changed by others in the meantime.
+    pub fn update_table_meta(
+        &mut self,
+        table_info: &TableInfo,
+        mut req: UpdateTableMetaReq,
+    ) -> Result<()> {
+        let catalog = table_info.catalog();
+        match &self.catalog {
+            Some(txn_catalog) if txn_catalog != catalog => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "a transaction can only update the tables of one catalog, got {} and {}",
+                    txn_catalog, catalog
+                )));
+            }
+            _ => self.catalog = Some(catalog.to_string()),
+        }
+
+        if let Some(prev) = self.update_table_metas.remove(&req.table_id) {
+            req.seq = prev.seq;
+            req.copied_files = match (prev.copied_files, req.copied_files) {
+                (Some(mut prev_files), Some(files)) => {
+                    prev_files.file_info.extend(files.file_info);
+                    prev_files.expire_at = files.expire_at;
+                    prev_files.fail_if_duplicated |= files.fail_if_duplicated;
+                    Some(prev_files)
+                }
+                (prev_files, files) => files.or(prev_files),
+            };
+        }
+
+        let mut table_info = table_info.clone();
+        table_info.meta = req.new_table_meta.clone();
+        self.tables.insert(req.table_id, table_info);
+        self.update_table_metas.insert(req.table_id, req);
+        Ok(())
+    }
+
+    /// Get the uncommitted version of a table, if it is updated in the transaction.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.tables.get(&table_id).cloned()
+    }
+
+    /// The catalog of the tables updated in the transaction, `None` if no table is updated.
+    pub fn catalog(&self) -> Option<&str> {
+        self.catalog.as_deref()
+    }
+
+    /// The request to commit all the table metas buffered in the transaction.
+    pub fn req(&self) -> UpdateMultiTableMetaReq {
+        UpdateMultiTableMetaReq {
+            update_table_metas: self.update_table_metas.values().cloned().collect(),
+        }
+    }
+}
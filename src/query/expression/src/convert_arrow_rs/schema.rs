@@ -19,6 +19,7 @@ use arrow_schema::ArrowError;
 use arrow_schema::DataType as ArrowDataType;
 use arrow_schema::Field as ArrowField;
 use arrow_schema::Fields;
+use arrow_schema::IntervalUnit;
 use arrow_schema::Schema as ArrowSchema;
 use arrow_schema::TimeUnit;
 
@@ -29,6 +30,8 @@ use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::DataField;
 use crate::DataSchema;
+use crate::ARROW_EXT_TYPE_BINARY;
+use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
 use crate::ARROW_EXT_TYPE_VARIANT;
@@ -51,6 +54,7 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Interval => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -91,6 +95,8 @@ impl From<&DataType> for ArrowDataType {
 
             DataType::EmptyArray => ArrowDataType::Null,
             DataType::EmptyMap => ArrowDataType::Null,
+            DataType::Binary => ArrowDataType::LargeBinary,
+            DataType::Bitmap => ArrowDataType::LargeBinary,
             DataType::Variant => ArrowDataType::LargeBinary,
 
             _ => unreachable!(),
@@ -134,6 +140,12 @@ impl From<&DataField> for ArrowField {
                     ARROW_EXT_TYPE_EMPTY_MAP.to_string(),
                 );
             }
+            DataType::Binary => {
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BINARY.to_string());
+            }
+            DataType::Bitmap => {
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BITMAP.to_string());
+            }
             DataType::Variant => {
                 metadata.insert(
                     EXTENSION_KEY.to_string(),
@@ -194,6 +206,8 @@ impl TryFrom<&ArrowField> for DataType {
             Some(ARROW_EXT_TYPE_EMPTY_ARRAY) => Some(DataType::EmptyArray),
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => Some(DataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => Some(DataType::Variant),
+            Some(ARROW_EXT_TYPE_BINARY) => Some(DataType::Binary),
+            Some(ARROW_EXT_TYPE_BITMAP) => Some(DataType::Bitmap),
             _ => None,
         };
 
@@ -223,6 +237,7 @@ impl TryFrom<&ArrowField> for DataType {
             ArrowDataType::Float64 => DataType::Number(NumberDataType::Float64),
             ArrowDataType::Timestamp(_unit, _tz) => DataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => DataType::Date,
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => DataType::Interval,
             ArrowDataType::Utf8
             | ArrowDataType::LargeUtf8
             | ArrowDataType::Binary
@@ -36,10 +36,13 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
             crate::types::number::NumberScalar::Float64(x) => DataValue::Float64((*x).into()),
         },
         Scalar::Decimal(_) => unimplemented!("decimal type is not supported"),
+        Scalar::Interval(_) => unimplemented!("interval type is not supported"),
         Scalar::Timestamp(x) => DataValue::Int64(*x),
         Scalar::Date(x) => DataValue::Int64(*x as i64),
         Scalar::Boolean(x) => DataValue::Boolean(*x),
-        Scalar::String(x) | Scalar::Variant(x) => DataValue::String(x.clone()),
+        Scalar::String(x) | Scalar::Binary(x) | Scalar::Bitmap(x) | Scalar::Variant(x) => {
+            DataValue::String(x.clone())
+        }
         Scalar::Array(x) => {
             let values = (0..x.len())
                 .map(|idx| scalar_to_datavalue(&x.index(idx).unwrap().to_owned()))
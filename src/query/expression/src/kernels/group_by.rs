@@ -43,7 +43,10 @@ impl DataBlock {
     pub fn choose_hash_method_with_types(hash_key_types: &[DataType]) -> Result<HashMethodKind> {
         if hash_key_types.len() == 1 {
             let typ = hash_key_types[0].clone();
-            if matches!(typ, DataType::String | DataType::Variant) {
+            if matches!(
+                typ,
+                DataType::String | DataType::Binary | DataType::Bitmap | DataType::Variant
+            ) {
                 return Ok(HashMethodKind::SingleString(
                     HashMethodSingleString::default(),
                 ));
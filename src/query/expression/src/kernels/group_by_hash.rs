@@ -170,6 +170,8 @@ impl HashMethod for HashMethodSingleString {
     fn build_keys_iter<'a>(&self, key_state: &'a KeysState) -> Result<Self::HashKeyIter<'a>> {
         match key_state {
             KeysState::Column(Column::String(col)) => Ok(col.iter()),
+            KeysState::Column(Column::Binary(col)) => Ok(col.iter()),
+            KeysState::Column(Column::Bitmap(col)) => Ok(col.iter()),
             KeysState::Column(Column::Variant(col)) => Ok(col.iter()),
             _ => unreachable!(),
         }
@@ -526,7 +528,7 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
             NumberColumn::NUM_TYPE(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         }),
         Column::Boolean(v) => vec.push(v.get_bit(row) as u8),
-        Column::String(v) => {
+        Column::String(v) | Column::Binary(v) | Column::Bitmap(v) => {
             BinaryWrite::write_binary(vec, unsafe { v.index_unchecked(row) }).unwrap()
         }
         Column::Decimal(_) => {
@@ -538,6 +540,11 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
         }
         Column::Timestamp(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         Column::Date(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
+        Column::Interval(v) => {
+            vec.extend_from_slice(v[row].months().to_le_bytes().as_ref());
+            vec.extend_from_slice(v[row].days().to_le_bytes().as_ref());
+            vec.extend_from_slice(v[row].microseconds().to_le_bytes().as_ref());
+        }
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             BinaryWrite::write_uvarint(vec, data.len() as u64).unwrap();
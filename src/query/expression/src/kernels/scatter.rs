@@ -25,9 +25,12 @@ use crate::types::number::NumberColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -192,6 +195,12 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::Interval(column) => Self::scatter_scalars::<IntervalType, _>(
+                column,
+                Vec::with_capacity(length),
+                indices,
+                scatter_size,
+            ),
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -263,6 +272,18 @@ impl Column {
                 }
                 res
             }
+            Column::Binary(column) => Self::scatter_scalars::<BinaryType, _>(
+                column,
+                StringColumnBuilder::with_capacity(length, 0),
+                indices,
+                scatter_size,
+            ),
+            Column::Bitmap(column) => Self::scatter_scalars::<BitmapType, _>(
+                column,
+                StringColumnBuilder::with_capacity(length, 0),
+                indices,
+                scatter_size,
+            ),
             Column::Variant(column) => Self::scatter_scalars::<VariantType, _>(
                 column,
                 StringColumnBuilder::with_capacity(length, 0),
@@ -24,7 +24,10 @@ use crate::types::number::NumberColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -87,6 +90,8 @@ impl Column {
             }),
             Column::Boolean(bm) => Self::take_arg_types::<BooleanType, _>(bm, indices),
             Column::String(column) => Self::take_arg_types::<StringType, _>(column, indices),
+            Column::Binary(column) => Self::take_arg_types::<BinaryType, _>(column, indices),
+            Column::Bitmap(column) => Self::take_arg_types::<BitmapType, _>(column, indices),
             Column::Timestamp(column) => {
                 let ts = Self::take_arg_types::<NumberType<i64>, _>(column, indices)
                     .into_number()
@@ -103,6 +108,7 @@ impl Column {
                     .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => Self::take_arg_types::<IntervalType, _>(column, indices),
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
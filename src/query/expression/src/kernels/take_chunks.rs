@@ -23,9 +23,12 @@ use crate::types::number::NumberColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -237,6 +240,14 @@ impl Column {
                 let builder = StringType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<StringType>(columns, builder, indices)
             }
+            Column::Binary(_) => {
+                let builder = BinaryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<BinaryType>(columns, builder, indices)
+            }
+            Column::Bitmap(_) => {
+                let builder = BitmapType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<BitmapType>(columns, builder, indices)
+            }
             Column::Timestamp(_) => {
                 let builder = TimestampType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<TimestampType>(columns, builder, indices)
@@ -245,6 +256,10 @@ impl Column {
                 let builder = DateType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<DateType>(columns, builder, indices)
             }
+            Column::Interval(_) => {
+                let builder = IntervalType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<IntervalType>(columns, builder, indices)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(result_size + 1);
                 offsets.push(0);
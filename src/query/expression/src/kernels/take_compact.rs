@@ -27,6 +27,8 @@ use crate::types::string::StringColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::MapType;
 use crate::types::NumberType;
@@ -99,6 +101,12 @@ impl Column {
             Column::String(column) => {
                 StringType::upcast_column(Self::take_string_types(column, indices, row_num))
             }
+            Column::Binary(column) => {
+                BinaryType::upcast_column(Self::take_string_types(column, indices, row_num))
+            }
+            Column::Bitmap(column) => {
+                BitmapType::upcast_column(Self::take_string_types(column, indices, row_num))
+            }
             Column::Timestamp(column) => {
                 let builder = Self::take_primitive_types(column, indices, row_num);
                 let ts = <NumberType<i64>>::upcast_column(<NumberType<i64>>::column_from_vec(
@@ -123,6 +131,10 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_primitive_types(column, indices, row_num);
+                Column::Interval(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
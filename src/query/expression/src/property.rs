@@ -176,6 +176,7 @@ impl Domain {
             },
             DataType::Timestamp => Domain::Timestamp(TimestampType::full_domain()),
             DataType::Date => Domain::Date(DateType::full_domain()),
+            DataType::Interval => Domain::Undefined,
             DataType::Null => Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -201,6 +202,8 @@ impl Domain {
                 };
                 Domain::Map(Some(inner_domain))
             }
+            DataType::Binary => Domain::Undefined,
+            DataType::Bitmap => Domain::Undefined,
             DataType::Variant => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
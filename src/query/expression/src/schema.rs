@@ -20,6 +20,7 @@ use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::IntervalUnit;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::datatypes::TimeUnit;
 use common_exception::ErrorCode;
@@ -34,6 +35,8 @@ use crate::types::DataType;
 use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::Scalar;
+use crate::ARROW_EXT_TYPE_BINARY;
+use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
 use crate::ARROW_EXT_TYPE_VARIANT;
@@ -87,10 +90,13 @@ pub enum TableDataType {
     EmptyMap,
     Boolean,
     String,
+    Binary,
+    Bitmap,
     Number(NumberDataType),
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
     Nullable(Box<TableDataType>),
     Array(Box<TableDataType>),
     Map(Box<TableDataType>),
@@ -949,10 +955,13 @@ impl From<&TableDataType> for DataType {
             TableDataType::EmptyMap => DataType::EmptyMap,
             TableDataType::Boolean => DataType::Boolean,
             TableDataType::String => DataType::String,
+            TableDataType::Binary => DataType::Binary,
+            TableDataType::Bitmap => DataType::Bitmap,
             TableDataType::Number(ty) => DataType::Number(*ty),
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
             TableDataType::Date => DataType::Date,
+            TableDataType::Interval => DataType::Interval,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
             TableDataType::Array(ty) => DataType::Array(Box::new((&**ty).into())),
             TableDataType::Map(ty) => DataType::Map(Box::new((&**ty).into())),
@@ -1189,13 +1198,15 @@ impl From<&ArrowField> for TableDataType {
             | ArrowDataType::FixedSizeList(f, _) =>
                 TableDataType::Array(Box::new(f.as_ref().into())),
 
-            ArrowDataType::Binary
-            | ArrowDataType::LargeBinary
-            | ArrowDataType::Utf8
-            | ArrowDataType::LargeUtf8 => TableDataType::String,
+            // Parquet `BYTE_ARRAY` without the `UTF8` annotation is read as `Binary`.
+            ArrowDataType::Binary => TableDataType::Binary,
+            ArrowDataType::LargeBinary | ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => {
+                TableDataType::String
+            }
 
             ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => TableDataType::Interval,
             ArrowDataType::Map(f, _) => {
                 let inner_ty = f.as_ref().into();
                 TableDataType::Map(Box::new(inner_ty))
@@ -1210,6 +1221,8 @@ impl From<&ArrowField> for TableDataType {
             }
             ArrowDataType::Extension(custom_name, _, _) => match custom_name.as_str() {
                 ARROW_EXT_TYPE_VARIANT => TableDataType::Variant,
+                ARROW_EXT_TYPE_BINARY => TableDataType::Binary,
+                ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
@@ -1298,6 +1311,7 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Interval => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1335,6 +1349,16 @@ impl From<&DataType> for ArrowDataType {
                     .collect();
                 ArrowDataType::Struct(fields)
             }
+            DataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            DataType::Bitmap => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BITMAP.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
             DataType::Variant => ArrowDataType::Extension(
                 ARROW_EXT_TYPE_VARIANT.to_string(),
                 Box::new(ArrowDataType::LargeBinary),
@@ -1373,6 +1397,7 @@ impl From<&TableDataType> for ArrowDataType {
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             TableDataType::Date => ArrowDataType::Date32,
+            TableDataType::Interval => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1414,6 +1439,16 @@ impl From<&TableDataType> for ArrowDataType {
                     .collect();
                 ArrowDataType::Struct(fields)
             }
+            TableDataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            TableDataType::Bitmap => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BITMAP.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
             TableDataType::Variant => ArrowDataType::Extension(
                 ARROW_EXT_TYPE_VARIANT.to_string(),
                 Box::new(ArrowDataType::LargeBinary),
@@ -1437,10 +1472,13 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::EmptyArray => Ok(TableDataType::EmptyArray),
         DataType::EmptyMap => Ok(TableDataType::EmptyMap),
         DataType::String => Ok(TableDataType::String),
+        DataType::Binary => Ok(TableDataType::Binary),
+        DataType::Bitmap => Ok(TableDataType::Bitmap),
         DataType::Number(number_type) => Ok(TableDataType::Number(*number_type)),
         DataType::Timestamp => Ok(TableDataType::Timestamp),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
+        DataType::Interval => Ok(TableDataType::Interval),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
         )?))),
@@ -588,6 +588,8 @@ pub fn get_simple_cast_function(is_try: bool, dest_type: &DataType) -> Option<St
 
 pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_string",
+    "to_binary",
+    "to_bitmap",
     "to_uint8",
     "to_uint16",
     "to_uint32",
@@ -600,6 +602,7 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_float64",
     "to_timestamp",
     "to_date",
+    "to_interval",
     "to_variant",
     "to_boolean",
     "to_decimal",
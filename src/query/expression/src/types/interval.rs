@@ -0,0 +1,413 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::timestamp::MICROS_IN_A_SEC;
+use crate::property::Domain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+/// The number of days in a month, used when comparing or justifying intervals, same as Postgres.
+pub const DAYS_IN_A_MONTH: i64 = 30;
+
+/// An interval of time, represented by the number of months, days and microseconds.
+///
+/// The three parts are kept separately because the length of a month or a day is
+/// not fixed, e.g. `'2023-01-31' + INTERVAL '1 month'` is `'2023-02-28'`.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct months_days_micros(pub i32, pub i32, pub i64);
+
+impl months_days_micros {
+    pub fn new(months: i32, days: i32, micros: i64) -> Self {
+        months_days_micros(months, days, micros)
+    }
+
+    #[inline]
+    pub fn months(&self) -> i32 {
+        self.0
+    }
+
+    #[inline]
+    pub fn days(&self) -> i32 {
+        self.1
+    }
+
+    #[inline]
+    pub fn microseconds(&self) -> i64 {
+        self.2
+    }
+
+    /// The total length in microseconds, assuming a month has 30 days and a day has 24 hours.
+    pub fn total_micros(&self) -> i128 {
+        (self.0 as i128 * DAYS_IN_A_MONTH as i128 + self.1 as i128) * MICROS_IN_A_DAY as i128
+            + self.2 as i128
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(months_days_micros(
+            self.0.checked_add(other.0)?,
+            self.1.checked_add(other.1)?,
+            self.2.checked_add(other.2)?,
+        ))
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(months_days_micros(
+            self.0.checked_neg()?,
+            self.1.checked_neg()?,
+            self.2.checked_neg()?,
+        ))
+    }
+}
+
+impl PartialOrd for months_days_micros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for months_days_micros {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then_with(|| (self.0, self.1, self.2).cmp(&(other.0, other.1, other.2)))
+    }
+}
+
+/// Display the interval in the Postgres style, e.g. `1 year 2 mons 3 days 04:05:06.5`.
+impl Display for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let years = self.0 / 12;
+        let months = self.0 % 12;
+        let parts = [
+            (years as i64, "year", "years"),
+            (months as i64, "mon", "mons"),
+            (self.1 as i64, "day", "days"),
+        ];
+
+        let mut first = true;
+        for (value, unit, plural) in parts {
+            if value == 0 {
+                continue;
+            }
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+            let unit = if value == 1 { unit } else { plural };
+            write!(f, "{value} {unit}")?;
+        }
+
+        if self.2 != 0 || first {
+            if !first {
+                write!(f, " ")?;
+            }
+            let sign = if self.2 < 0 { "-" } else { "" };
+            let micros = self.2.unsigned_abs();
+            let hours = micros / MICROS_IN_AN_HOUR as u64;
+            let minutes = micros % MICROS_IN_AN_HOUR as u64 / MICROS_IN_A_MINUTE as u64;
+            let seconds = micros % MICROS_IN_A_MINUTE as u64 / MICROS_IN_A_SEC as u64;
+            let fraction = micros % MICROS_IN_A_SEC as u64;
+            write!(f, "{sign}{hours:02}:{minutes:02}:{seconds:02}")?;
+            if fraction != 0 {
+                let fraction = format!("{fraction:06}");
+                write!(f, ".{}", fraction.trim_end_matches('0'))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = months_days_micros;
+    type ScalarRef<'a> = months_days_micros;
+    type Column = Buffer<months_days_micros>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, months_days_micros>>;
+    type ColumnBuilder = Vec<months_days_micros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: months_days_micros) -> months_days_micros {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Parse an interval from the Postgres style input, e.g. `1 day 3 hours`, `-2 mons 04:05:06`
+/// or `3 years ago`.
+pub fn string_to_interval(interval_str: impl AsRef<[u8]>) -> Result<months_days_micros, String> {
+    let interval_str = std::str::from_utf8(interval_str.as_ref())
+        .map_err(|_| "invalid utf-8 in interval".to_string())?;
+    let invalid = || format!("invalid interval: '{interval_str}'");
+
+    let mut tokens = interval_str
+        .trim()
+        .trim_start_matches('@')
+        .split_whitespace()
+        .peekable();
+    if tokens.peek().is_none() {
+        return Err(invalid());
+    }
+
+    let (mut months, mut days, mut micros) = (0f64, 0f64, 0f64);
+    let mut ago = false;
+    while let Some(token) = tokens.next() {
+        if ago {
+            // `ago` must be the last token.
+            return Err(invalid());
+        }
+        if token.eq_ignore_ascii_case("ago") {
+            ago = true;
+            continue;
+        }
+        if token.contains(':') {
+            micros += parse_time_part(token).ok_or_else(invalid)?;
+            continue;
+        }
+
+        // The unit can be attached to the number, e.g. `3h`.
+        let split = token
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+            .unwrap_or(token.len());
+        let (number, unit) = token.split_at(split);
+        let number = number.parse::<f64>().map_err(|_| invalid())?;
+        let unit = if unit.is_empty() {
+            tokens.next().ok_or_else(invalid)?
+        } else {
+            unit
+        };
+
+        match unit.to_ascii_lowercase().as_str() {
+            "microsecond" | "microseconds" | "us" | "usec" | "usecs" => micros += number,
+            "millisecond" | "milliseconds" | "ms" | "msec" | "msecs" => micros += number * 1e3,
+            "second" | "seconds" | "sec" | "secs" | "s" => micros += number * 1e6,
+            "minute" | "minutes" | "min" | "mins" | "m" => {
+                micros += number * MICROS_IN_A_MINUTE as f64
+            }
+            "hour" | "hours" | "hr" | "hrs" | "h" => micros += number * MICROS_IN_AN_HOUR as f64,
+            "day" | "days" | "d" => days += number,
+            "week" | "weeks" | "w" => days += number * 7.0,
+            "month" | "months" | "mon" | "mons" => months += number,
+            "quarter" | "quarters" => months += number * 3.0,
+            "year" | "years" | "yr" | "yrs" | "y" => months += number * 12.0,
+            "decade" | "decades" => months += number * 120.0,
+            "century" | "centuries" => months += number * 1200.0,
+            "millennium" | "millennia" => months += number * 12000.0,
+            _ => return Err(invalid()),
+        }
+    }
+
+    // Fractional months and days spill into the smaller parts, same as Postgres.
+    let whole_months = months.trunc();
+    days += (months - whole_months) * DAYS_IN_A_MONTH as f64;
+    let whole_days = days.trunc();
+    micros += (days - whole_days) * MICROS_IN_A_DAY as f64;
+
+    if whole_months.abs() > i32::MAX as f64
+        || whole_days.abs() > i32::MAX as f64
+        || micros.abs() > i64::MAX as f64
+    {
+        return Err(format!("interval out of range: '{interval_str}'"));
+    }
+
+    let interval = months_days_micros(
+        whole_months as i32,
+        whole_days as i32,
+        micros.round() as i64,
+    );
+    if ago {
+        interval.checked_neg().ok_or_else(invalid)
+    } else {
+        Ok(interval)
+    }
+}
+
+// Parse the time part of interval in the form of `[-]HH:MM[:SS[.ffffff]]` into microseconds.
+fn parse_time_part(time: &str) -> Option<f64> {
+    let (negative, time) = match time.strip_prefix('-') {
+        Some(time) => (true, time),
+        None => (false, time.strip_prefix('+').unwrap_or(time)),
+    };
+    let mut parts = time.split(':');
+    let hours = parts.next()?.parse::<u64>().ok()?;
+    let minutes = parts.next()?.parse::<u64>().ok()?;
+    let seconds = match parts.next() {
+        Some(seconds) => seconds.parse::<f64>().ok()?,
+        None => 0.0,
+    };
+    if parts.next().is_some() || minutes >= 60 || seconds >= 60.0 || seconds < 0.0 {
+        return None;
+    }
+
+    let micros = hours as f64 * MICROS_IN_AN_HOUR as f64
+        + minutes as f64 * MICROS_IN_A_MINUTE as f64
+        + seconds * MICROS_IN_A_SEC as f64;
+    Some(if negative { -micros } else { micros })
+}
+
+#[inline]
+pub fn interval_to_string(interval: months_days_micros) -> impl Display {
+    interval
+}
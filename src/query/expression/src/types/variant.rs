@@ -15,6 +15,7 @@
 use std::ops::Range;
 
 use super::date::date_to_string;
+use super::interval::interval_to_string;
 use super::number::NumberScalar;
 use super::timestamp::timestamp_to_string;
 use crate::date_helper::TzLUT;
@@ -192,8 +193,10 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::Decimal(x) => x.to_float64().into(),
         ScalarRef::Boolean(b) => jsonb::Value::Bool(b),
         ScalarRef::String(s) => jsonb::Value::String(String::from_utf8_lossy(s)),
+        ScalarRef::Binary(s) | ScalarRef::Bitmap(s) => hex::encode_upper(s).into(),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => interval_to_string(i).to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -54,8 +54,11 @@ macro_rules! for_common_scalar_values {
             { Float64Type },
             { BooleanType },
             { StringType },
+            { BinaryType },
+            { BitmapType },
             { DateType },
             { TimestampType },
+            { IntervalType },
             { VariantType }
         }
     };
@@ -30,6 +30,7 @@ use num_traits::AsPrimitive;
 use once_cell::sync::Lazy;
 
 use crate::types::date::check_date;
+use crate::types::interval::months_days_micros;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::MICROS_IN_A_SEC;
 
@@ -361,6 +362,23 @@ impl AddDaysImpl {
     }
 }
 
+pub struct AddIntervalImpl;
+
+impl AddIntervalImpl {
+    /// Add the months, days and microseconds of the interval to the timestamp in this order,
+    /// so that `'2023-01-31' + INTERVAL '1 month 1 day'` is `'2023-03-01'`.
+    pub fn eval_timestamp(us: i64, tz: TzLUT, interval: months_days_micros) -> Result<i64, String> {
+        let mut us = us;
+        if interval.months() != 0 {
+            us = AddMonthsImpl::eval_timestamp(us, tz, interval.months())?;
+        }
+        if interval.days() != 0 {
+            us = AddDaysImpl::eval_timestamp(us, interval.days())?;
+        }
+        check_timestamp(us.wrapping_add(interval.microseconds()))
+    }
+}
+
 pub struct AddTimesImpl;
 
 impl AddTimesImpl {
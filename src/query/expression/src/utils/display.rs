@@ -39,6 +39,7 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::interval::interval_to_string;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -124,6 +125,7 @@ impl<'a> Debug for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
+            ScalarRef::Interval(i) => write!(f, "{i:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -151,6 +153,8 @@ impl<'a> Debug for ScalarRef<'a> {
                 }
                 write!(f, ")")
             }
+            ScalarRef::Binary(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Bitmap(s) => write!(f, "0x{}", &hex::encode(s)),
             ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
         }
     }
@@ -166,8 +170,11 @@ impl Debug for Column {
             Column::Decimal(col) => write!(f, "{col:?}"),
             Column::Boolean(col) => f.debug_tuple("Boolean").field(col).finish(),
             Column::String(col) => write!(f, "{col:?}"),
+            Column::Binary(col) => write!(f, "{col:?}"),
+            Column::Bitmap(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
             Column::Nullable(col) => write!(f, "{col:?}"),
@@ -198,6 +205,7 @@ impl<'a> Display for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "{}", timestamp_to_string(*t, Tz::UTC)),
             ScalarRef::Date(d) => write!(f, "{}", date_to_string(*d as i64, Tz::UTC)),
+            ScalarRef::Interval(i) => write!(f, "{}", interval_to_string(*i)),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -225,6 +233,8 @@ impl<'a> Display for ScalarRef<'a> {
                 }
                 write!(f, ")")
             }
+            ScalarRef::Binary(s) => write!(f, "{}", hex::encode_upper(s)),
+            ScalarRef::Bitmap(_) => write!(f, "'<bitmap binary>'"),
             ScalarRef::Variant(s) => {
                 let value = jsonb::to_string(s);
                 write!(f, "{value}")
@@ -429,10 +439,13 @@ impl Display for DataType {
         match &self {
             DataType::Boolean => write!(f, "Boolean"),
             DataType::String => write!(f, "String"),
+            DataType::Binary => write!(f, "Binary"),
+            DataType::Bitmap => write!(f, "Bitmap"),
             DataType::Number(num) => write!(f, "{num}"),
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
+            DataType::Interval => write!(f, "Interval"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
             DataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -468,10 +481,13 @@ impl Display for TableDataType {
         match &self {
             TableDataType::Boolean => write!(f, "Boolean"),
             TableDataType::String => write!(f, "String"),
+            TableDataType::Binary => write!(f, "Binary"),
+            TableDataType::Bitmap => write!(f, "Bitmap"),
             TableDataType::Number(num) => write!(f, "{num}"),
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
             TableDataType::Date => write!(f, "Date"),
+            TableDataType::Interval => write!(f, "Interval"),
             TableDataType::Null => write!(f, "NULL"),
             TableDataType::Nullable(inner) => write!(f, "{inner} NULL"),
             TableDataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -26,9 +26,11 @@ use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::buffer::Buffer;
 use common_arrow::arrow::compute::cast as arrow_cast;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::datatypes::IntervalUnit;
 use common_arrow::arrow::datatypes::TimeUnit;
 use common_arrow::arrow::offset::OffsetsBuffer;
 use common_arrow::arrow::trusted_len::TrustedLen;
+use common_arrow::arrow::types::months_days_ns;
 use common_exception::Result;
 use common_io::prelude::BinaryRead;
 use enum_as_inner::EnumAsInner;
@@ -53,6 +55,8 @@ use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
+use crate::types::interval::months_days_micros;
+use crate::types::interval::MICROS_IN_A_DAY;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableDomain;
@@ -103,8 +107,11 @@ pub enum Scalar {
     Decimal(DecimalScalar),
     Timestamp(i64),
     Date(i32),
+    Interval(months_days_micros),
     Boolean(bool),
     String(Vec<u8>),
+    Binary(Vec<u8>),
+    Bitmap(Vec<u8>),
     Array(Column),
     Map(Column),
     Tuple(Vec<Scalar>),
@@ -121,8 +128,11 @@ pub enum ScalarRef<'a> {
     Decimal(DecimalScalar),
     Boolean(bool),
     String(&'a [u8]),
+    Binary(&'a [u8]),
+    Bitmap(&'a [u8]),
     Timestamp(i64),
     Date(i32),
+    Interval(months_days_micros),
     Array(Column),
     Map(Column),
     Tuple(Vec<ScalarRef<'a>>),
@@ -138,8 +148,11 @@ pub enum Column {
     Decimal(DecimalColumn),
     Boolean(Bitmap),
     String(StringColumn),
+    Binary(StringColumn),
+    Bitmap(StringColumn),
     Timestamp(Buffer<i64>),
     Date(Buffer<i32>),
+    Interval(Buffer<months_days_micros>),
     Array(Box<ArrayColumn<AnyType>>),
     Map(Box<ArrayColumn<AnyType>>),
     Nullable(Box<NullableColumn<AnyType>>),
@@ -156,8 +169,11 @@ pub enum ColumnBuilder {
     Decimal(DecimalColumnBuilder),
     Boolean(MutableBitmap),
     String(StringColumnBuilder),
+    Binary(StringColumnBuilder),
+    Bitmap(StringColumnBuilder),
     Timestamp(Vec<i64>),
     Date(Vec<i32>),
+    Interval(Vec<months_days_micros>),
     Array(Box<ArrayColumnBuilder<AnyType>>),
     Map(Box<ArrayColumnBuilder<AnyType>>),
     Nullable(Box<NullableColumnBuilder<AnyType>>),
@@ -300,8 +316,11 @@ impl Scalar {
             Scalar::Decimal(d) => ScalarRef::Decimal(*d),
             Scalar::Boolean(b) => ScalarRef::Boolean(*b),
             Scalar::String(s) => ScalarRef::String(s.as_slice()),
+            Scalar::Binary(s) => ScalarRef::Binary(s.as_slice()),
+            Scalar::Bitmap(s) => ScalarRef::Bitmap(s.as_slice()),
             Scalar::Timestamp(t) => ScalarRef::Timestamp(*t),
             Scalar::Date(d) => ScalarRef::Date(*d),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
@@ -316,6 +335,8 @@ impl Scalar {
             DataType::EmptyMap => Scalar::EmptyMap,
             DataType::Boolean => Scalar::Boolean(false),
             DataType::String => Scalar::String(vec![]),
+            DataType::Binary => Scalar::Binary(vec![]),
+            DataType::Bitmap => Scalar::Bitmap(vec![]),
             DataType::Number(num_ty) => Scalar::Number(match num_ty {
                 NumberDataType::UInt8 => NumberScalar::UInt8(0),
                 NumberDataType::UInt16 => NumberScalar::UInt16(0),
@@ -331,6 +352,7 @@ impl Scalar {
             DataType::Decimal(ty) => Scalar::Decimal(ty.default_scalar()),
             DataType::Timestamp => Scalar::Timestamp(0),
             DataType::Date => Scalar::Date(0),
+            DataType::Interval => Scalar::Interval(months_days_micros::default()),
             DataType::Nullable(_) => Scalar::Null,
             DataType::Array(ty) => {
                 let builder = ColumnBuilder::with_capacity(ty, 0);
@@ -370,8 +392,11 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Decimal(d) => Scalar::Decimal(*d),
             ScalarRef::Boolean(b) => Scalar::Boolean(*b),
             ScalarRef::String(s) => Scalar::String(s.to_vec()),
+            ScalarRef::Binary(s) => Scalar::Binary(s.to_vec()),
+            ScalarRef::Bitmap(s) => Scalar::Bitmap(s.to_vec()),
             ScalarRef::Timestamp(t) => Scalar::Timestamp(*t),
             ScalarRef::Date(d) => Scalar::Date(*d),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Map(col) => Scalar::Map(col.clone()),
             ScalarRef::Tuple(fields) => {
@@ -415,6 +440,7 @@ impl<'a> ScalarRef<'a> {
             }),
             ScalarRef::Timestamp(t) => Domain::Timestamp(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Date(d) => Domain::Date(SimpleDomain { min: *d, max: *d }),
+            ScalarRef::Interval(_) => Domain::Undefined,
             ScalarRef::Array(array) => {
                 if array.len() == 0 {
                     Domain::Array(None)
@@ -446,6 +472,8 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
+            ScalarRef::Binary(_) => Domain::Undefined,
+            ScalarRef::Bitmap(_) => Domain::Undefined,
             ScalarRef::Variant(_) => Domain::Undefined,
         }
     }
@@ -467,8 +495,11 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Decimal(DecimalScalar::Decimal256(_, _)) => 32,
             ScalarRef::Boolean(_) => 1,
             ScalarRef::String(s) => s.len(),
+            ScalarRef::Binary(s) => s.len(),
+            ScalarRef::Bitmap(s) => s.len(),
             ScalarRef::Timestamp(_) => 8,
             ScalarRef::Date(_) => 4,
+            ScalarRef::Interval(_) => 16,
             ScalarRef::Array(col) => col.memory_size(),
             ScalarRef::Map(col) => col.memory_size(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
@@ -493,8 +524,11 @@ impl<'a> ScalarRef<'a> {
             }),
             ScalarRef::Boolean(_) => DataType::Boolean,
             ScalarRef::String(_) => DataType::String,
+            ScalarRef::Binary(_) => DataType::Binary,
+            ScalarRef::Bitmap(_) => DataType::Bitmap,
             ScalarRef::Timestamp(_) => DataType::Timestamp,
             ScalarRef::Date(_) => DataType::Date,
+            ScalarRef::Interval(_) => DataType::Interval,
             ScalarRef::Array(array) => DataType::Array(Box::new(array.data_type())),
             ScalarRef::Map(col) => DataType::Map(Box::new(col.data_type())),
             ScalarRef::Tuple(fields) => {
@@ -519,8 +553,11 @@ impl PartialOrd for Scalar {
             (Scalar::Decimal(d1), Scalar::Decimal(d2)) => d1.partial_cmp(d2),
             (Scalar::Boolean(b1), Scalar::Boolean(b2)) => b1.partial_cmp(b2),
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
+            (Scalar::Binary(s1), Scalar::Binary(s2)) => s1.partial_cmp(s2),
+            (Scalar::Bitmap(s1), Scalar::Bitmap(s2)) => s1.partial_cmp(s2),
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Map(m1), Scalar::Map(m2)) => m1.partial_cmp(m2),
             (Scalar::Tuple(t1), Scalar::Tuple(t2)) => t1.partial_cmp(t2),
@@ -554,8 +591,11 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::Decimal(d1), ScalarRef::Decimal(d2)) => d1.partial_cmp(d2),
             (ScalarRef::Boolean(b1), ScalarRef::Boolean(b2)) => b1.partial_cmp(b2),
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
+            (ScalarRef::Binary(s1), ScalarRef::Binary(s2)) => s1.partial_cmp(s2),
+            (ScalarRef::Bitmap(s1), ScalarRef::Bitmap(s2)) => s1.partial_cmp(s2),
             (ScalarRef::Timestamp(t1), ScalarRef::Timestamp(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Date(d1), ScalarRef::Date(d2)) => d1.partial_cmp(d2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
             (ScalarRef::Array(a1), ScalarRef::Array(a2)) => a1.partial_cmp(a2),
             (ScalarRef::Map(m1), ScalarRef::Map(m2)) => m1.partial_cmp(m2),
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
@@ -593,8 +633,11 @@ impl Hash for ScalarRef<'_> {
             }),
             ScalarRef::Boolean(v) => v.hash(state),
             ScalarRef::String(v) => v.hash(state),
+            ScalarRef::Binary(v) => v.hash(state),
+            ScalarRef::Bitmap(v) => v.hash(state),
             ScalarRef::Timestamp(v) => v.hash(state),
             ScalarRef::Date(v) => v.hash(state),
+            ScalarRef::Interval(v) => v.hash(state),
             ScalarRef::Array(v) => {
                 let str = serialize_column(v);
                 str.hash(state);
@@ -631,10 +674,15 @@ impl PartialOrd for Column {
             (Column::Decimal(col1), Column::Decimal(col2)) => col1.partial_cmp(col2),
             (Column::Boolean(col1), Column::Boolean(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::String(col1), Column::String(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Binary(col1), Column::Binary(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Bitmap(col1), Column::Bitmap(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Timestamp(col1), Column::Timestamp(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Nullable(col1), Column::Nullable(col2)) => {
@@ -659,6 +707,8 @@ pub const EXTENSION_KEY: &str = "Extension";
 pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
+pub const ARROW_EXT_TYPE_BINARY: &str = "Binary";
+pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -670,8 +720,11 @@ impl Column {
             Column::Decimal(col) => col.len(),
             Column::Boolean(col) => col.len(),
             Column::String(col) => col.len(),
+            Column::Binary(col) => col.len(),
+            Column::Bitmap(col) => col.len(),
             Column::Timestamp(col) => col.len(),
             Column::Date(col) => col.len(),
+            Column::Interval(col) => col.len(),
             Column::Array(col) => col.len(),
             Column::Map(col) => col.len(),
             Column::Nullable(col) => col.len(),
@@ -689,8 +742,11 @@ impl Column {
             Column::Decimal(col) => Some(ScalarRef::Decimal(col.index(index)?)),
             Column::Boolean(col) => Some(ScalarRef::Boolean(col.get(index)?)),
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
+            Column::Binary(col) => Some(ScalarRef::Binary(col.index(index)?)),
+            Column::Bitmap(col) => Some(ScalarRef::Bitmap(col.index(index)?)),
             Column::Timestamp(col) => Some(ScalarRef::Timestamp(col.get(index).cloned()?)),
             Column::Date(col) => Some(ScalarRef::Date(col.get(index).cloned()?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Map(col) => Some(ScalarRef::Map(col.index(index)?)),
             Column::Nullable(col) => Some(col.index(index)?.unwrap_or(ScalarRef::Null)),
@@ -716,8 +772,11 @@ impl Column {
             Column::Decimal(col) => ScalarRef::Decimal(col.index_unchecked(index)),
             Column::Boolean(col) => ScalarRef::Boolean(col.get_bit_unchecked(index)),
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
+            Column::Binary(col) => ScalarRef::Binary(col.index_unchecked(index)),
+            Column::Bitmap(col) => ScalarRef::Bitmap(col.index_unchecked(index)),
             Column::Timestamp(col) => ScalarRef::Timestamp(*col.get_unchecked(index)),
             Column::Date(col) => ScalarRef::Date(*col.get_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Map(col) => ScalarRef::Map(col.index_unchecked(index)),
             Column::Nullable(col) => col.index_unchecked(index).unwrap_or(ScalarRef::Null),
@@ -760,12 +819,17 @@ impl Column {
                 Column::Boolean(col.clone().sliced(range.start, range.end - range.start))
             }
             Column::String(col) => Column::String(col.slice(range)),
+            Column::Binary(col) => Column::Binary(col.slice(range)),
+            Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
             Column::Timestamp(col) => {
                 Column::Timestamp(col.clone().sliced(range.start, range.end - range.start))
             }
             Column::Date(col) => {
                 Column::Date(col.clone().sliced(range.start, range.end - range.start))
             }
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
             Column::Array(col) => Column::Array(Box::new(col.slice(range))),
             Column::Map(col) => Column::Map(Box::new(col.slice(range))),
             Column::Nullable(col) => Column::Nullable(Box::new(col.slice(range))),
@@ -823,6 +887,7 @@ impl Column {
                     max: *max,
                 })
             }
+            Column::Interval(_) => Domain::Undefined,
             Column::Array(col) => {
                 if col.len() == 0 {
                     Domain::Array(None)
@@ -856,6 +921,8 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
+            Column::Binary(_) => Domain::Undefined,
+            Column::Bitmap(_) => Domain::Undefined,
             Column::Variant(_) => Domain::Undefined,
         }
     }
@@ -874,8 +941,11 @@ impl Column {
             }),
             Column::Boolean(_) => DataType::Boolean,
             Column::String(_) => DataType::String,
+            Column::Binary(_) => DataType::Binary,
+            Column::Bitmap(_) => DataType::Bitmap,
             Column::Timestamp(_) => DataType::Timestamp,
             Column::Date(_) => DataType::Date,
+            Column::Interval(_) => DataType::Interval,
             Column::Array(array) => {
                 let inner = array.values.data_type();
                 DataType::Array(Box::new(inner))
@@ -1053,6 +1123,25 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => {
+                // the nanoseconds of arrow only cover about 292 years, the longer intervals are clamped
+                let values: Buffer<months_days_ns> = col
+                    .iter()
+                    .map(|x| {
+                        months_days_ns::new(
+                            x.months(),
+                            x.days(),
+                            x.microseconds().saturating_mul(1_000),
+                        )
+                    })
+                    .collect();
+                Box::new(
+                    common_arrow::arrow::array::PrimitiveArray::<months_days_ns>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
@@ -1105,7 +1194,7 @@ impl Column {
                 )
                 .unwrap(),
             ),
-            Column::Variant(col) => {
+            Column::Binary(col) | Column::Bitmap(col) | Column::Variant(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1282,10 +1371,15 @@ impl Column {
                 let offsets = arrow_col.offsets().clone().into_inner();
 
                 let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
-                Column::String(StringColumn {
+                let column = StringColumn {
                     data: arrow_col.values().clone(),
                     offsets,
-                })
+                };
+                if data_type.is_binary() {
+                    Column::Binary(column)
+                } else {
+                    Column::String(column)
+                }
             }
             // TODO: deprecate it and use LargeBinary instead
             ArrowDataType::Binary => {
@@ -1300,10 +1394,15 @@ impl Column {
                     .map(|x| *x as u64)
                     .collect::<Vec<_>>();
 
-                Column::String(StringColumn {
+                let column = StringColumn {
                     data: arrow_col.values().clone(),
                     offsets: offsets.into(),
-                })
+                };
+                if data_type.is_binary() {
+                    Column::Binary(column)
+                } else {
+                    Column::String(column)
+                }
             }
             // TODO: deprecate it and use LargeBinary instead
             ArrowDataType::Utf8 => {
@@ -1373,6 +1472,16 @@ impl Column {
                     .values()
                     .clone(),
             ),
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => Column::Interval(
+                arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::MonthsDaysNsArray>()
+                    .expect("fail to read from arrow: array should be `MonthsDaysNsArray`")
+                    .values()
+                    .iter()
+                    .map(|x| months_days_micros::new(x.months(), x.days(), x.ns() / 1_000))
+                    .collect(),
+            ),
             ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_VARIANT => {
                 let arrow_col = arrow_col
                     .as_any()
@@ -1389,6 +1498,30 @@ impl Column {
                     offsets: offsets.into(),
                 })
             }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_BINARY => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col.offsets().clone().into_inner();
+                let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                Column::Binary(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets,
+                })
+            }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_BITMAP => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col.offsets().clone().into_inner();
+                let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                Column::Bitmap(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets,
+                })
+            }
             ArrowDataType::List(f) => {
                 let array_list = arrow_cast::cast(
                     arrow_col,
@@ -1563,6 +1696,18 @@ impl Column {
                     .map(|_| SmallRng::from_entropy().gen_range(DATE_MIN..=DATE_MAX))
                     .collect::<Vec<i32>>(),
             ),
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        months_days_micros::new(
+                            rng.gen_range(-1200..=1200),
+                            rng.gen_range(-365..=365),
+                            rng.gen_range(-MICROS_IN_A_DAY..=MICROS_IN_A_DAY),
+                        )
+                    })
+                    .collect::<Vec<months_days_micros>>(),
+            ),
             DataType::Nullable(ty) => Column::Nullable(Box::new(NullableColumn {
                 column: Column::random(ty, len),
                 validity: Bitmap::from(
@@ -1604,6 +1749,15 @@ impl Column {
                     .collect::<Vec<_>>();
                 Column::Tuple(fields)
             }
+            DataType::Binary => BinaryType::from_data((0..len).map(|_| {
+                let len = SmallRng::from_entropy().gen_range(0..=8);
+                (0..len)
+                    .map(|_| SmallRng::from_entropy().gen::<u8>())
+                    .collect::<Vec<_>>()
+            })),
+            // Empty bytes are an empty bitmap, which is the only one we can build
+            // without a roaring bitmap implementation.
+            DataType::Bitmap => BitmapType::from_data(vec![vec![]; len]),
             DataType::Variant => {
                 let mut data = Vec::with_capacity(len);
                 for _ in 0..len {
@@ -1689,9 +1843,12 @@ impl Column {
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
             Column::Boolean(c) => c.as_slice().0.len(),
-            Column::String(col) => col.data.len() + col.offsets.len() * 8,
+            Column::String(col) | Column::Binary(col) | Column::Bitmap(col) => {
+                col.data.len() + col.offsets.len() * 8
+            }
             Column::Timestamp(col) => col.len() * 8,
             Column::Date(col) => col.len() * 4,
+            Column::Interval(col) => col.len() * 16,
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
@@ -1764,8 +1921,11 @@ impl ColumnBuilder {
             Column::Decimal(col) => ColumnBuilder::Decimal(DecimalColumnBuilder::from_column(col)),
             Column::Boolean(col) => ColumnBuilder::Boolean(bitmap_into_mut(col)),
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
+            Column::Binary(col) => ColumnBuilder::Binary(StringColumnBuilder::from_column(col)),
+            Column::Bitmap(col) => ColumnBuilder::Bitmap(StringColumnBuilder::from_column(col)),
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
             }
@@ -1822,8 +1982,11 @@ impl ColumnBuilder {
             }
             ScalarRef::Boolean(b) => ColumnBuilder::Boolean(constant_bitmap(*b, n)),
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Binary(s) => ColumnBuilder::Binary(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Bitmap(s) => ColumnBuilder::Bitmap(StringColumnBuilder::repeat(s, n)),
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
+            ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
             }
@@ -1854,8 +2017,11 @@ impl ColumnBuilder {
             ColumnBuilder::Decimal(col) => col.len(),
             ColumnBuilder::Boolean(builder) => builder.len(),
             ColumnBuilder::String(builder) => builder.len(),
+            ColumnBuilder::Binary(builder) => builder.len(),
+            ColumnBuilder::Bitmap(builder) => builder.len(),
             ColumnBuilder::Timestamp(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
             ColumnBuilder::Nullable(builder) => builder.len(),
@@ -1886,9 +2052,12 @@ impl ColumnBuilder {
                 builder.len() * 32
             }
             ColumnBuilder::Boolean(c) => c.as_slice().len(),
-            ColumnBuilder::String(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::String(col)
+            | ColumnBuilder::Binary(col)
+            | ColumnBuilder::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Timestamp(col) => col.len() * 8,
             ColumnBuilder::Date(col) => col.len() * 4,
+            ColumnBuilder::Interval(col) => col.len() * 16,
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Nullable(c) => c.builder.memory_size() + c.validity.as_slice().len(),
@@ -1911,8 +2080,11 @@ impl ColumnBuilder {
             }),
             ColumnBuilder::Boolean(_) => DataType::Boolean,
             ColumnBuilder::String(_) => DataType::String,
+            ColumnBuilder::Binary(_) => DataType::Binary,
+            ColumnBuilder::Bitmap(_) => DataType::Bitmap,
             ColumnBuilder::Timestamp(_) => DataType::Timestamp,
             ColumnBuilder::Date(_) => DataType::Date,
+            ColumnBuilder::Interval(_) => DataType::Interval,
             ColumnBuilder::Array(col) => {
                 let inner = col.builder.data_type();
                 DataType::Array(Box::new(inner))
@@ -1955,8 +2127,17 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::String(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Binary => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Binary(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
+            DataType::Bitmap => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Bitmap(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Timestamp => ColumnBuilder::Timestamp(Vec::with_capacity(capacity)),
             DataType::Date => ColumnBuilder::Date(Vec::with_capacity(capacity)),
+            DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
             DataType::Nullable(ty) => ColumnBuilder::Nullable(Box::new(NullableColumnBuilder {
                 builder: Self::with_capacity_hint(ty, capacity, enable_datasize_hint),
                 validity: MutableBitmap::with_capacity(capacity),
@@ -2010,10 +2191,19 @@ impl ColumnBuilder {
                 builder.put_slice(value);
                 builder.commit_row();
             }
+            (ColumnBuilder::Binary(builder), ScalarRef::Binary(value)) => {
+                builder.put_slice(value);
+                builder.commit_row();
+            }
+            (ColumnBuilder::Bitmap(builder), ScalarRef::Bitmap(value)) => {
+                builder.put_slice(value);
+                builder.commit_row();
+            }
             (ColumnBuilder::Timestamp(builder), ScalarRef::Timestamp(value)) => {
                 builder.push(value);
             }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => builder.push(value),
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => builder.push(value),
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 builder.push(value);
             }
@@ -2049,8 +2239,11 @@ impl ColumnBuilder {
             ColumnBuilder::Decimal(builder) => builder.push_default(),
             ColumnBuilder::Boolean(builder) => builder.push(false),
             ColumnBuilder::String(builder) => builder.commit_row(),
+            ColumnBuilder::Binary(builder) => builder.commit_row(),
+            ColumnBuilder::Bitmap(builder) => builder.commit_row(),
             ColumnBuilder::Timestamp(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
+            ColumnBuilder::Interval(builder) => builder.push(months_days_micros::default()),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Map(builder) => builder.push_default(),
             ColumnBuilder::Nullable(builder) => builder.push_null(),
@@ -2087,7 +2280,10 @@ impl ColumnBuilder {
                 let v: bool = reader.read_scalar()?;
                 builder.push(v);
             }
-            ColumnBuilder::String(builder) | ColumnBuilder::Variant(builder) => {
+            ColumnBuilder::String(builder)
+            | ColumnBuilder::Binary(builder)
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Variant(builder) => {
                 let offset: u64 = reader.read_uvarint()?;
                 builder.data.resize(offset as usize + builder.data.len(), 0);
                 let last = *builder.offsets.last().unwrap() as usize;
@@ -2103,6 +2299,12 @@ impl ColumnBuilder {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                let months: i32 = reader.read_scalar()?;
+                let days: i32 = reader.read_scalar()?;
+                let micros: i64 = reader.read_scalar()?;
+                builder.push(months_days_micros::new(months, days, micros));
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_uvarint()?;
                 for _ in 0..len {
@@ -2171,7 +2373,10 @@ impl ColumnBuilder {
                     builder.push(v);
                 }
             }
-            ColumnBuilder::String(builder) | ColumnBuilder::Variant(builder) => {
+            ColumnBuilder::String(builder)
+            | ColumnBuilder::Binary(builder)
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Variant(builder) => {
                 for row in 0..rows {
                     let reader = &reader[step * row..];
                     builder.put_slice(reader);
@@ -2193,6 +2398,15 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let months: i32 = reader.read_scalar()?;
+                    let days: i32 = reader.read_scalar()?;
+                    let micros: i64 = reader.read_scalar()?;
+                    builder.push(months_days_micros::new(months, days, micros));
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2263,8 +2477,11 @@ impl ColumnBuilder {
             ColumnBuilder::Decimal(builder) => builder.pop().map(Scalar::Decimal),
             ColumnBuilder::Boolean(builder) => builder.pop().map(Scalar::Boolean),
             ColumnBuilder::String(builder) => builder.pop().map(Scalar::String),
+            ColumnBuilder::Binary(builder) => builder.pop().map(Scalar::Binary),
+            ColumnBuilder::Bitmap(builder) => builder.pop().map(Scalar::Bitmap),
             ColumnBuilder::Timestamp(builder) => builder.pop().map(Scalar::Timestamp),
             ColumnBuilder::Date(builder) => builder.pop().map(Scalar::Date),
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
             ColumnBuilder::Nullable(builder) => Some(builder.pop()?.unwrap_or(Scalar::Null)),
@@ -2307,6 +2524,12 @@ impl ColumnBuilder {
             (ColumnBuilder::String(builder), Column::String(other)) => {
                 builder.append_column(other);
             }
+            (ColumnBuilder::Binary(builder), Column::Binary(other)) => {
+                builder.append_column(other);
+            }
+            (ColumnBuilder::Bitmap(builder), Column::Bitmap(other)) => {
+                builder.append_column(other);
+            }
             (ColumnBuilder::Variant(builder), Column::Variant(other)) => {
                 builder.append_column(other);
             }
@@ -2316,6 +2539,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2344,8 +2570,11 @@ impl ColumnBuilder {
             ColumnBuilder::Decimal(builder) => Column::Decimal(builder.build()),
             ColumnBuilder::Boolean(builder) => Column::Boolean(builder.into()),
             ColumnBuilder::String(builder) => Column::String(builder.build()),
+            ColumnBuilder::Binary(builder) => Column::Binary(builder.build()),
+            ColumnBuilder::Bitmap(builder) => Column::Bitmap(builder.build()),
             ColumnBuilder::Timestamp(builder) => Column::Timestamp(builder.into()),
             ColumnBuilder::Date(builder) => Column::Date(builder.into()),
+            ColumnBuilder::Interval(builder) => Column::Interval(builder.into()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
             ColumnBuilder::Nullable(builder) => Column::Nullable(Box::new(builder.build())),
@@ -2367,8 +2596,11 @@ impl ColumnBuilder {
             ColumnBuilder::Decimal(builder) => Scalar::Decimal(builder.build_scalar()),
             ColumnBuilder::Boolean(builder) => Scalar::Boolean(builder.get(0)),
             ColumnBuilder::String(builder) => Scalar::String(builder.build_scalar()),
+            ColumnBuilder::Binary(builder) => Scalar::Binary(builder.build_scalar()),
+            ColumnBuilder::Bitmap(builder) => Scalar::Bitmap(builder.build_scalar()),
             ColumnBuilder::Timestamp(builder) => Scalar::Timestamp(builder[0]),
             ColumnBuilder::Date(builder) => Scalar::Date(builder[0]),
+            ColumnBuilder::Interval(builder) => Scalar::Interval(builder[0]),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
             ColumnBuilder::Nullable(builder) => builder.build_scalar().unwrap_or(Scalar::Null),
@@ -0,0 +1,101 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::types::months_days_ns;
+use common_exception::Result;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
+use common_expression::Column;
+
+#[test]
+fn test_string_to_interval() -> Result<()> {
+    let cases = vec![
+        ("1 day", months_days_micros::new(0, 1, 0)),
+        (
+            "1 day 3 hours",
+            months_days_micros::new(0, 1, 10_800_000_000),
+        ),
+        ("2 years 3 mons", months_days_micros::new(27, 0, 0)),
+        ("1 week 2d", months_days_micros::new(0, 9, 0)),
+        ("1.5 months", months_days_micros::new(1, 15, 0)),
+        (
+            "1 day 04:05:06.5",
+            months_days_micros::new(0, 1, 14_706_500_000),
+        ),
+        ("-01:30", months_days_micros::new(0, 0, -5_400_000_000)),
+        ("@ 3 days ago", months_days_micros::new(0, -3, 0)),
+        ("100 ms", months_days_micros::new(0, 0, 100_000)),
+    ];
+
+    for (s, want) in cases {
+        match string_to_interval(s) {
+            Ok(got) => assert_eq!(want, got, "{s}: {want:?} != {got:?}"),
+            Err(e) => panic!("{s}: {want:?} != {e:?}"),
+        }
+    }
+
+    let cases = vec!["", "1", "day", "1 fortnight", "1 day ago 2 hours", "10:61"];
+
+    for s in cases {
+        let r = string_to_interval(s);
+        assert!(r.is_err(), "{s}: {r:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_interval_to_string() -> Result<()> {
+    let cases = vec![
+        (months_days_micros::new(0, 0, 0), "00:00:00"),
+        (
+            months_days_micros::new(0, 1, 10_800_000_000),
+            "1 day 03:00:00",
+        ),
+        (months_days_micros::new(14, 3, 0), "1 year 2 mons 3 days"),
+        (months_days_micros::new(0, 0, 14_706_500_000), "04:05:06.5"),
+        (
+            months_days_micros::new(-1, 0, -5_400_000_000),
+            "-1 mons -01:30:00",
+        ),
+    ];
+
+    for (interval, want) in cases {
+        assert_eq!(interval_to_string(interval).to_string(), want);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_interval_as_arrow() {
+    let column = Column::Interval(
+        vec![
+            months_days_micros::new(1, 2, 3),
+            months_days_micros::new(0, 0, i64::MAX),
+            months_days_micros::new(0, 0, i64::MIN),
+        ]
+        .into(),
+    );
+    let array = column.as_arrow();
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .unwrap();
+    assert_eq!(array.value(0), months_days_ns::new(1, 2, 3_000));
+    assert_eq!(array.value(1), months_days_ns::new(0, 0, i64::MAX));
+    assert_eq!(array.value(2), months_days_ns::new(0, 0, i64::MIN));
+}
@@ -20,6 +20,7 @@ extern crate core;
 mod common;
 mod decimal;
 mod group_by;
+mod interval;
 mod kernel;
 mod serde;
 mod sort;
@@ -0,0 +1,346 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::io::Cursor;
+
+use apache_avro::schema::Schema;
+use apache_avro::types::Value;
+use chrono_tz::Tz;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::serialize::read_decimal_with_size;
+use common_expression::serialize::uniform_date;
+use common_expression::types::array::ArrayColumnBuilder;
+use common_expression::types::date::check_date;
+use common_expression::types::decimal::Decimal;
+use common_expression::types::decimal::DecimalColumnBuilder;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::nullable::NullableColumnBuilder;
+use common_expression::types::number::Number;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::AnyType;
+use common_expression::types::NumberColumnBuilder;
+use common_expression::with_decimal_type;
+use common_expression::with_number_mapped_type;
+use common_expression::ColumnBuilder;
+use common_io::cursor_ext::BufferReadDateTimeExt;
+use num::BigInt;
+use num::NumCast;
+use num::Signed;
+
+use crate::FieldDecoder;
+use crate::FileFormatOptionsExt;
+
+/// Decodes the values of avro records. Each value is read with its writer schema, which
+/// carries what the value itself does not, e.g. the scale of a decimal.
+pub struct FieldDecoderAvro {
+    pub timezone: Tz,
+    pub ident_case_sensitive: bool,
+}
+
+impl FieldDecoder for FieldDecoderAvro {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl FieldDecoderAvro {
+    pub fn create(options: &FileFormatOptionsExt) -> Self {
+        FieldDecoderAvro {
+            timezone: options.timezone,
+            ident_case_sensitive: options.ident_case_sensitive,
+        }
+    }
+
+    pub fn read_field(
+        &self,
+        column: &mut ColumnBuilder,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        // The value of a union is read as the value of the branch it takes.
+        if let Value::Union(index, value) = value {
+            return match schema {
+                Schema::Union(union) => match union.variants().get(*index as usize) {
+                    Some(schema) => self.read_field(column, value, schema),
+                    None => Err(ErrorCode::BadBytes(format!(
+                        "Incorrect avro union value, branch {} does not exist",
+                        index
+                    ))),
+                },
+                _ => Err(ErrorCode::BadBytes(
+                    "Incorrect avro union value, the schema is not a union",
+                )),
+            };
+        }
+
+        match column {
+            ColumnBuilder::Null { len } => {
+                *len += 1;
+                Ok(())
+            }
+            ColumnBuilder::Nullable(c) => self.read_nullable(c, value, schema),
+            ColumnBuilder::Boolean(c) => self.read_bool(c, value),
+            ColumnBuilder::Number(c) => with_number_mapped_type!(|NUM_TYPE| match c {
+                NumberColumnBuilder::NUM_TYPE(c) => self.read_number(c, value),
+            }),
+            ColumnBuilder::Decimal(c) => with_decimal_type!(|DECIMAL_TYPE| match c {
+                DecimalColumnBuilder::DECIMAL_TYPE(c, size) => {
+                    self.read_decimal(c, *size, value, schema)
+                }
+            }),
+            ColumnBuilder::Date(c) => self.read_date(c, value),
+            ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::String(c) => self.read_string(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
+            ColumnBuilder::Array(c) => self.read_array(c, value, schema),
+            ColumnBuilder::Map(c) => self.read_map(c, value, schema),
+            ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value, schema),
+            ColumnBuilder::Variant(c) => self.read_variant(c, value),
+            _ => Err(ErrorCode::Unimplemented(format!(
+                "can not read avro value into column of type {}",
+                column.data_type()
+            ))),
+        }
+    }
+
+    fn read_nullable(
+        &self,
+        column: &mut NullableColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        match value {
+            Value::Null => {
+                column.push_null();
+            }
+            other => {
+                self.read_field(&mut column.builder, other, schema)?;
+                column.validity.push(true);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_bool(&self, column: &mut MutableBitmap, value: &Value) -> Result<()> {
+        match value {
+            Value::Boolean(v) => column.push(*v),
+            _ => return Err(ErrorCode::BadBytes("Incorrect avro value, must be boolean")),
+        }
+        Ok(())
+    }
+
+    fn read_number<T: Number>(&self, column: &mut Vec<T>, value: &Value) -> Result<()> {
+        let v: Option<T> = match value {
+            Value::Int(v) | Value::TimeMillis(v) => NumCast::from(*v),
+            Value::Long(v) | Value::TimeMicros(v) => NumCast::from(*v),
+            Value::Float(v) => NumCast::from(*v),
+            Value::Double(v) => NumCast::from(*v),
+            _ => return Err(ErrorCode::BadBytes("Incorrect avro value, must be number")),
+        };
+        match v {
+            Some(v) => {
+                column.push(v);
+                Ok(())
+            }
+            None => Err(ErrorCode::BadBytes(format!(
+                "Incorrect avro value, {:?} is out of the range of {}",
+                value,
+                T::data_type()
+            ))),
+        }
+    }
+
+    fn read_decimal<D: Decimal>(
+        &self,
+        column: &mut Vec<D>,
+        size: DecimalSize,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        let v = match (value, schema) {
+            (Value::Decimal(decimal), Schema::Decimal { scale, .. }) => {
+                let bytes = <Vec<u8>>::try_from(decimal).map_err(|e| {
+                    ErrorCode::BadBytes(format!("Incorrect avro decimal value: {}", e))
+                })?;
+                decimal_to_string(&BigInt::from_signed_bytes_be(&bytes), *scale)
+            }
+            (Value::Int(v), _) => v.to_string(),
+            (Value::Long(v), _) => v.to_string(),
+            (Value::Float(v), _) => v.to_string(),
+            (Value::Double(v), _) => v.to_string(),
+            (Value::String(v), _) => v.clone(),
+            _ => return Err(ErrorCode::BadBytes("Incorrect avro value, must be decimal")),
+        };
+        let (n, _) = read_decimal_with_size::<D>(v.as_bytes(), size, true)?;
+        column.push(n);
+        Ok(())
+    }
+
+    fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
+        match value {
+            Value::Date(days) | Value::Int(days) => {
+                let days = check_date(*days as i64)?;
+                column.push(days);
+                Ok(())
+            }
+            Value::String(v) => {
+                let mut reader = Cursor::new(v.as_bytes());
+                let date = reader.read_date_text(&self.timezone)?;
+                let days = uniform_date(date);
+                check_date(days as i64)?;
+                column.push(days);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect avro value, must be date")),
+        }
+    }
+
+    fn read_timestamp(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
+        let micros = match value {
+            Value::TimestampMicros(v) | Value::Long(v) => *v,
+            Value::TimestampMillis(v) => *v * 1000,
+            Value::String(v) => {
+                let mut reader = Cursor::new(v.as_bytes());
+                let ts = reader.read_timestamp_text(&self.timezone)?;
+                ts.timestamp_micros()
+            }
+            _ => {
+                return Err(ErrorCode::BadBytes(
+                    "Incorrect avro value, must be timestamp",
+                ));
+            }
+        };
+        check_timestamp(micros)?;
+        column.push(micros);
+        Ok(())
+    }
+
+    fn read_string(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) | Value::Enum(_, v) => column.put_str(v),
+            Value::Bytes(v) | Value::Fixed(_, v) => column.put_slice(v),
+            Value::Uuid(v) => column.put_str(&v.to_string()),
+            _ => return Err(ErrorCode::BadBytes("Incorrect avro value, must be string")),
+        }
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_binary(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::Bytes(v) | Value::Fixed(_, v) => column.put_slice(v),
+            Value::String(v) => column.put_str(v),
+            _ => return Err(ErrorCode::BadBytes("Incorrect avro value, must be bytes")),
+        }
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_variant(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        let json = serde_json::Value::try_from(value.clone())
+            .map_err(|e| ErrorCode::BadBytes(format!("Incorrect avro value for variant: {}", e)))?;
+        let v = jsonb::Value::from(&json);
+        v.write_to_vec(&mut column.data);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_array(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        match (value, schema) {
+            (Value::Array(values), Schema::Array(item_schema)) => {
+                for value in values {
+                    self.read_field(&mut column.builder, value, item_schema)?;
+                }
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect avro value, must be array")),
+        }
+    }
+
+    fn read_map(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        const KEY: usize = 0;
+        const VALUE: usize = 1;
+        let map_builder = column.builder.as_tuple_mut().unwrap();
+        match (value, schema) {
+            (Value::Map(entries), Schema::Map(value_schema)) => {
+                for (key, value) in entries.iter() {
+                    let key = Value::String(key.to_string());
+                    self.read_field(&mut map_builder[KEY], &key, &Schema::String)?;
+                    self.read_field(&mut map_builder[VALUE], value, value_schema)?;
+                }
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect avro value, must be map")),
+        }
+    }
+
+    fn read_tuple(
+        &self,
+        fields: &mut Vec<ColumnBuilder>,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        match (value, schema) {
+            (
+                Value::Record(values),
+                Schema::Record {
+                    fields: schemas, ..
+                },
+            ) => {
+                if fields.len() != values.len() {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Incorrect avro value, expect {} values, but get {} values",
+                        fields.len(),
+                        values.len()
+                    )));
+                }
+                for ((field, (_, value)), schema) in
+                    fields.iter_mut().zip(values.iter()).zip(schemas.iter())
+                {
+                    self.read_field(field, value, &schema.schema)?;
+                }
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect avro value, must be record")),
+        }
+    }
+}
+
+/// Render an unscaled decimal as text, e.g. `-12345` of scale 2 is `-123.45`.
+fn decimal_to_string(unscaled: &BigInt, scale: usize) -> String {
+    let sign = if unscaled.is_negative() { "-" } else { "" };
+    let digits = unscaled.abs().to_string();
+    if scale == 0 {
+        return format!("{sign}{digits}");
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{sign}{integer}.{fraction}")
+}
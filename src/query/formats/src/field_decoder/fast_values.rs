@@ -131,6 +131,12 @@ impl FastFieldDecoderValues {
             ColumnBuilder::Date(c) => self.read_date(c, reader, positions),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, positions),
             ColumnBuilder::String(c) => self.read_string(c, reader, positions),
+            // Quoted literals are taken as raw bytes, the same as `CAST(<string> AS BINARY)`.
+            ColumnBuilder::Binary(c) => self.read_string(c, reader, positions),
+            // Fallback to the expression parser, which casts the literal with `to_bitmap`.
+            ColumnBuilder::Bitmap(_) => Err(ErrorCode::Unimplemented(
+                "Bitmap values are not supported by the fast values parser",
+            )),
             ColumnBuilder::Array(c) => self.read_array(c, reader, positions),
             ColumnBuilder::Map(c) => self.read_map(c, reader, positions),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, positions),
@@ -15,6 +15,8 @@
 use std::any::Any;
 use std::io::Cursor;
 
+use base64::engine::general_purpose;
+use base64::Engine;
 use chrono_tz::Tz;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_exception::ErrorCode;
@@ -83,6 +85,7 @@ impl FieldJsonAstDecoder {
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
             ColumnBuilder::String(c) => self.read_string(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
@@ -186,6 +189,20 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_binary(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(s) => {
+                let decoded = general_purpose::STANDARD.decode(s).map_err(|_| {
+                    ErrorCode::BadBytes(format!("Invalid base64 value for binary: {:?}", s))
+                })?;
+                column.put_slice(&decoded);
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be string")),
+        }
+    }
+
     fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
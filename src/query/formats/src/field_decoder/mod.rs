@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod avro;
 mod csv;
 mod fast_values;
 mod json_ast;
@@ -22,6 +23,7 @@ mod xml;
 
 use std::any::Any;
 
+pub use avro::FieldDecoderAvro;
 pub use csv::FieldDecoderCSV;
 pub use fast_values::FastFieldDecoderValues;
 pub use json_ast::FieldJsonAstDecoder;
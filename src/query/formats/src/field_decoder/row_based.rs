@@ -88,6 +88,7 @@ pub trait FieldDecoderRowBased: FieldDecoder {
             ColumnBuilder::Date(c) => self.read_date(c, reader, raw),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, raw),
             ColumnBuilder::String(c) => self.read_string(c, reader, raw),
+            ColumnBuilder::Binary(c) => self.read_binary(c, reader, raw),
             ColumnBuilder::Array(c) => self.read_array(c, reader, raw),
             ColumnBuilder::Map(c) => self.read_map(c, reader, raw),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, raw),
@@ -206,6 +207,25 @@ pub trait FieldDecoderRowBased: FieldDecoder {
         _raw: bool,
     ) -> Result<()>;
 
+    fn read_binary<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let decoded = hex::decode(&buf).map_err(|_| {
+            ErrorCode::BadBytes(format!(
+                "Invalid hex value for binary: {:?}",
+                String::from_utf8_lossy(&buf)
+            ))
+        })?;
+        column.put_slice(&decoded);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_date<R: AsRef<[u8]>>(
         &self,
         column: &mut Vec<i32>,
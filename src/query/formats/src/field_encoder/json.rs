@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use base64::engine::general_purpose;
+use base64::Engine;
 use common_expression::types::array::ArrayColumn;
 use common_expression::types::decimal::DecimalColumn;
 use common_expression::types::string::StringColumn;
@@ -69,6 +71,18 @@ impl FieldEncoderRowBased for FieldEncoderJSON {
         }
     }
 
+    fn write_binary(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = general_purpose::STANDARD.encode(v);
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
     fn write_variant(
         &self,
         column: &StringColumn,
@@ -17,6 +17,8 @@ use common_arrow::arrow::buffer::Buffer;
 use common_expression::types::array::ArrayColumn;
 use common_expression::types::date::date_to_string;
 use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::months_days_micros;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::NumberColumn;
 use common_expression::types::string::StringColumn;
@@ -59,7 +61,10 @@ pub trait FieldEncoderRowBased {
             Column::Decimal(c) => self.write_decimal(c, row_index, out_buf),
             Column::Date(c) => self.write_date(c, row_index, out_buf, raw),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, raw),
+            Column::Interval(c) => self.write_interval(c, row_index, out_buf, raw),
             Column::String(c) => self.write_string(c, row_index, out_buf, raw),
+            Column::Binary(c) => self.write_binary(c, row_index, out_buf, raw),
+            Column::Bitmap(_) => self.write_bitmap(out_buf, raw),
             Column::Nullable(box c) => self.write_nullable(c, row_index, out_buf, raw),
             Column::Array(box c) => self.write_array(c, row_index, out_buf, raw),
             Column::Map(box c) => self.write_map(c, row_index, out_buf, raw),
@@ -146,6 +151,24 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(unsafe { column.index_unchecked(row_index) }, out_buf, raw);
     }
 
+    /// Binary values are written as upper case hex strings in text formats.
+    fn write_binary(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = hex::encode_upper(v);
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
+    /// Bitmaps are opaque in text formats, use `to_string` to show the values.
+    fn write_bitmap(&self, out_buf: &mut Vec<u8>, raw: bool) {
+        self.write_string_inner(b"<bitmap binary>", out_buf, raw);
+    }
+
     fn write_date(&self, column: &Buffer<i32>, row_index: usize, out_buf: &mut Vec<u8>, raw: bool) {
         let v = unsafe { column.get_unchecked(row_index) };
         let s = date_to_string(*v as i64, self.common_settings().timezone).to_string();
@@ -164,6 +187,18 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    fn write_interval(
+        &self,
+        column: &Buffer<months_days_micros>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = interval_to_string(*v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
     fn write_variant(
         &self,
         column: &StringColumn,
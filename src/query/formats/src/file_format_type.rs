@@ -20,6 +20,8 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageFileFormatType;
 use common_settings::Settings;
 
+use crate::output_format::ArrowOutputFormat;
+use crate::output_format::ArrowStreamOutputFormat;
 use crate::output_format::CSVOutputFormat;
 use crate::output_format::CSVWithNamesAndTypesOutputFormat;
 use crate::output_format::CSVWithNamesOutputFormat;
@@ -42,6 +44,8 @@ pub struct FileFormatOptionsExt {
     pub headers: usize,
     pub json_compact: bool,
     pub json_strings: bool,
+    // write arrow in the ipc streaming format instead of the ipc file format
+    pub arrow_stream: bool,
     pub timezone: Tz,
 }
 
@@ -53,6 +57,7 @@ impl FileFormatOptionsExt {
             headers: 0,
             json_compact: false,
             json_strings: false,
+            arrow_stream: false,
             timezone,
         };
         Ok(options)
@@ -69,6 +74,7 @@ impl FileFormatOptionsExt {
             headers: 0,
             json_compact: false,
             json_strings: false,
+            arrow_stream: false,
             timezone,
         };
         let suf = &clickhouse_type.suffixes;
@@ -77,6 +83,7 @@ impl FileFormatOptionsExt {
             options.json_compact = json.is_compact;
             options.json_strings = json.is_strings;
         }
+        options.arrow_stream = suf.arrow_stream;
         Ok(options)
     }
 
@@ -149,6 +156,10 @@ impl FileFormatOptionsExt {
             }
             FileFormatParams::Parquet(_) => Box::new(ParquetOutputFormat::create(schema, self)),
             FileFormatParams::Json(_) => Box::new(JSONOutputFormat::create(schema, self)),
+            FileFormatParams::Arrow(_) => match self.arrow_stream {
+                false => Box::new(ArrowOutputFormat::create(schema, self)),
+                true => Box::new(ArrowStreamOutputFormat::create(schema, self)),
+            },
             others => {
                 return Err(ErrorCode::InvalidArgument(format!(
                     "Unsupported output file format:{:?}",
@@ -165,7 +176,7 @@ impl FileFormatTypeExt for StageFileFormatType {
         match self {
             StageFileFormatType::Tsv => "text/tab-separated-values; charset=UTF-8",
             StageFileFormatType::Csv => "text/csv; charset=UTF-8",
-            StageFileFormatType::Parquet => "application/octet-stream",
+            StageFileFormatType::Parquet | StageFileFormatType::Arrow => "application/octet-stream",
             StageFileFormatType::NdJson => "application/x-ndjson; charset=UTF-8",
             StageFileFormatType::Json => "application/json; charset=UTF-8",
             _ => "text/plain; charset=UTF-8",
@@ -0,0 +1,148 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::ipc::write::FileWriter;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+/// Output in the arrow ipc file format, the file footer is written after all the
+/// blocks, so the blocks are buffered until `finalize`.
+#[derive(Default)]
+pub struct ArrowOutputFormat {
+    schema: TableSchemaRef,
+    data_blocks: Vec<DataBlock>,
+}
+
+impl ArrowOutputFormat {
+    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
+        Self {
+            schema,
+            data_blocks: vec![],
+        }
+    }
+}
+
+impl OutputFormat for ArrowOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        self.data_blocks.push(block.clone());
+        Ok(vec![])
+    }
+
+    fn buffer_size(&mut self) -> usize {
+        self.data_blocks.iter().map(|b| b.memory_size()).sum()
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        // the schema and the footer are written even if there is no block, which
+        // makes a valid file of no record batch
+        let blocks = std::mem::take(&mut self.data_blocks);
+        let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
+        let mut writer = FileWriter::new(
+            &mut buf,
+            self.schema.to_arrow(),
+            None,
+            WriteOptions::default(),
+        );
+        writer.start()?;
+        for block in blocks {
+            let chunk: ArrowChunk<Box<dyn Array>> = block.try_into()?;
+            writer.write(&chunk, None)?;
+        }
+        writer.finish()?;
+        Ok(buf)
+    }
+}
+
+/// Output in the arrow ipc streaming format, each block is written as a record batch
+/// once serialized, so the result can be consumed before the query finishes.
+pub struct ArrowStreamOutputFormat {
+    schema: ArrowSchema,
+    writer: StreamWriter<SharedBuffer>,
+    buffer: SharedBuffer,
+    started: bool,
+}
+
+impl ArrowStreamOutputFormat {
+    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
+        let buffer = SharedBuffer::default();
+        Self {
+            schema: schema.to_arrow(),
+            writer: StreamWriter::new(buffer.clone(), WriteOptions::default()),
+            buffer,
+            started: false,
+        }
+    }
+
+    // the schema message is written before the first record batch
+    fn try_start(&mut self) -> Result<()> {
+        if !self.started {
+            self.writer.start(&self.schema, None)?;
+            self.started = true;
+        }
+        Ok(())
+    }
+}
+
+impl OutputFormat for ArrowStreamOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        self.try_start()?;
+        let chunk: ArrowChunk<Box<dyn Array>> = block.clone().try_into()?;
+        self.writer.write(&chunk, None)?;
+        Ok(self.buffer.take())
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        self.try_start()?;
+        self.writer.finish()?;
+        Ok(self.buffer.take())
+    }
+}
+
+/// The buffer written by the stream writer, the written bytes are taken out after
+/// each record batch.
+#[derive(Clone, Default)]
+struct SharedBuffer {
+    inner: Arc<Mutex<Vec<u8>>>,
+}
+
+impl SharedBuffer {
+    fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.inner.lock().unwrap())
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.inner.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use base64::engine::general_purpose;
+use base64::Engine;
 use common_expression::date_helper::DateConverter;
+use common_expression::types::interval::interval_to_string;
 use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
@@ -95,9 +98,12 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => serde_json::to_value(interval_to_string(v).to_string()).unwrap(),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::String(x) => JsonValue::String(String::from_utf8_lossy(x).to_string()),
+        ScalarRef::Binary(x) => JsonValue::String(general_purpose::STANDARD.encode(x)),
+        ScalarRef::Bitmap(_) => JsonValue::String("<bitmap binary>".to_string()),
         ScalarRef::Array(x) => {
             let vals = x
                 .iter()
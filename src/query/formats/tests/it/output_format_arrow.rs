@@ -0,0 +1,111 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::FileReader;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+use pretty_assertions::assert_eq;
+
+use crate::get_output_format_clickhouse;
+use crate::output_format_utils::get_simple_block;
+
+fn check_chunks(schema: &TableSchemaRef, block: &DataBlock, chunks: &[Chunk<Box<dyn Array>>]) {
+    // each block is written as one record batch
+    assert_eq!(chunks.len(), 2);
+    let expect: Chunk<Box<dyn Array>> = block.clone().try_into().unwrap();
+    for chunk in chunks {
+        assert_eq!(chunk.len(), block.num_rows());
+        assert_eq!(chunk.arrays().len(), schema.num_fields());
+        // the float column is skipped, for NaN is not equal to itself
+        for i in [0, 1, 2, 4] {
+            assert_eq!(chunk.arrays()[i], expect.arrays()[i]);
+        }
+    }
+}
+
+#[test]
+fn test_arrow_stream_output() -> Result<()> {
+    for is_nullable in [false, true] {
+        let (schema, block) = get_simple_block(is_nullable);
+        let mut formatter = get_output_format_clickhouse("ArrowStream", schema.clone())?;
+        let mut buffer = formatter.serialize_prefix()?;
+        buffer.extend(formatter.serialize_block(&block)?);
+        buffer.extend(formatter.serialize_block(&block)?);
+        buffer.extend(formatter.finalize()?);
+
+        let mut reader = Cursor::new(buffer);
+        let metadata = read_stream_metadata(&mut reader)?;
+        assert_eq!(metadata.schema, schema.to_arrow());
+        let mut chunks = vec![];
+        for state in StreamReader::new(reader, metadata, None) {
+            match state? {
+                StreamState::Some(chunk) => chunks.push(chunk),
+                StreamState::Waiting => unreachable!(),
+            }
+        }
+        check_chunks(&schema, &block, &chunks);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_arrow_file_output() -> Result<()> {
+    for is_nullable in [false, true] {
+        let (schema, block) = get_simple_block(is_nullable);
+        let mut formatter = get_output_format_clickhouse("Arrow", schema.clone())?;
+        let mut buffer = formatter.serialize_prefix()?;
+        buffer.extend(formatter.serialize_block(&block)?);
+        buffer.extend(formatter.serialize_block(&block)?);
+        buffer.extend(formatter.finalize()?);
+
+        let mut reader = Cursor::new(buffer);
+        let metadata = read_file_metadata(&mut reader)?;
+        assert_eq!(metadata.schema, schema.to_arrow());
+        let chunks = FileReader::new(reader, metadata, None, None)
+            .collect::<common_arrow::arrow::error::Result<Vec<_>>>()?;
+        check_chunks(&schema, &block, &chunks);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_arrow_output_without_block() -> Result<()> {
+    let (schema, _) = get_simple_block(true);
+
+    let mut formatter = get_output_format_clickhouse("Arrow", schema.clone())?;
+    let mut buffer = formatter.serialize_prefix()?;
+    buffer.extend(formatter.finalize()?);
+    let mut reader = Cursor::new(buffer);
+    let metadata = read_file_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema.to_arrow());
+    assert!(metadata.blocks.is_empty());
+
+    let mut formatter = get_output_format_clickhouse("ArrowStream", schema.clone())?;
+    let mut buffer = formatter.serialize_prefix()?;
+    buffer.extend(formatter.finalize()?);
+    let mut reader = Cursor::new(buffer);
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema.to_arrow());
+    assert_eq!(StreamReader::new(reader, metadata, None).count(), 0);
+    Ok(())
+}
@@ -0,0 +1,244 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::BitmapType;
+use common_expression::types::DataType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_io::prelude::deserialize_from_slice;
+use common_io::prelude::serialize_into_buf;
+use roaring::RoaringTreemap;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::assert_unary_arguments;
+use crate::scalars::deserialize_bitmap;
+use crate::scalars::push_bitmap;
+
+pub trait BitmapAggOp: Send + Sync + 'static {
+    fn operate(lhs: &mut RoaringTreemap, rhs: RoaringTreemap);
+}
+
+pub struct BitmapUnionOp;
+
+impl BitmapAggOp for BitmapUnionOp {
+    fn operate(lhs: &mut RoaringTreemap, rhs: RoaringTreemap) {
+        *lhs |= rhs;
+    }
+}
+
+pub struct BitmapIntersectOp;
+
+impl BitmapAggOp for BitmapIntersectOp {
+    fn operate(lhs: &mut RoaringTreemap, rhs: RoaringTreemap) {
+        *lhs &= rhs;
+    }
+}
+
+struct AggregateBitmapState {
+    // `None` until the first bitmap is added, so the intersection is not
+    // always empty.
+    bitmap: Option<RoaringTreemap>,
+}
+
+impl AggregateBitmapState {
+    fn add<OP: BitmapAggOp>(&mut self, other: RoaringTreemap) {
+        match &mut self.bitmap {
+            Some(bitmap) => OP::operate(bitmap, other),
+            None => self.bitmap = Some(other),
+        }
+    }
+
+    fn add_bytes<OP: BitmapAggOp>(&mut self, bytes: &[u8]) -> Result<()> {
+        let other = deserialize_bitmap(bytes)?;
+        self.add::<OP>(other);
+        Ok(())
+    }
+
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        serialize_into_buf(writer, &self.bitmap.is_some())?;
+        if let Some(bitmap) = &self.bitmap {
+            bitmap.serialize_into(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let has_bitmap: bool = deserialize_from_slice(reader)?;
+        self.bitmap = if has_bitmap {
+            Some(RoaringTreemap::deserialize_from(&mut *reader)?)
+        } else {
+            None
+        };
+        Ok(())
+    }
+}
+
+pub struct AggregateBitmapFunction<OP> {
+    display_name: String,
+    _op: PhantomData<OP>,
+}
+
+impl<OP: BitmapAggOp> AggregateFunction for AggregateBitmapFunction<OP> {
+    fn name(&self) -> &str {
+        "AggregateBitmapFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Bitmap)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateBitmapState { bitmap: None });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateBitmapState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = BitmapType::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<AggregateBitmapState>();
+        match validity {
+            Some(validity) => {
+                for (v, b) in column.iter().zip(validity.iter()) {
+                    if b {
+                        state.add_bytes::<OP>(v)?;
+                    }
+                }
+            }
+            None => {
+                for v in column.iter() {
+                    state.add_bytes::<OP>(v)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = BitmapType::try_downcast_column(&columns[0]).unwrap();
+        for (v, place) in column.iter().zip(places.iter()) {
+            let state = place.next(offset).get::<AggregateBitmapState>();
+            state.add_bytes::<OP>(v)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = BitmapType::try_downcast_column(&columns[0]).unwrap();
+        if let Some(v) = BitmapType::index_column(&column, row) {
+            let state = place.get::<AggregateBitmapState>();
+            state.add_bytes::<OP>(v)?;
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateBitmapState>();
+        let state = place.get::<AggregateBitmapState>();
+        if let Some(other) = &rhs.bitmap {
+            state.add::<OP>(other.clone());
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let builder = BitmapType::try_downcast_builder(builder).unwrap();
+        match &state.bitmap {
+            Some(bitmap) => push_bitmap(bitmap, builder),
+            None => push_bitmap(&RoaringTreemap::new(), builder),
+        }
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateBitmapState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<OP> fmt::Display for AggregateBitmapFunction<OP> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_bitmap_function<OP: BitmapAggOp>(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    argument_types: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, argument_types.len())?;
+    if argument_types[0].remove_nullable() != DataType::Bitmap {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "The argument of aggregate function {} must be bitmap",
+            display_name
+        )));
+    }
+    Ok(Arc::new(AggregateBitmapFunction::<OP> {
+        display_name: display_name.to_string(),
+        _op: PhantomData,
+    }))
+}
+
+pub fn aggregate_bitmap_union_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitmap_function::<BitmapUnionOp>,
+    ))
+}
+
+pub fn aggregate_bitmap_intersect_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitmap_function::<BitmapIntersectOp>,
+    ))
+}
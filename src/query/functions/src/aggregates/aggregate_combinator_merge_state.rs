@@ -0,0 +1,160 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+
+use super::StateAddr;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Accumulates the serialized states of the nested function instead of its arguments.
+///
+/// The single argument is a string column holding the states written by
+/// `AggregateFunction::serialize`, e.g. the partial states stored in an aggregating
+/// index, each of them is deserialized and merged into the target state.
+#[derive(Clone)]
+pub struct AggregateMergeStateCombinator {
+    name: String,
+    nested: AggregateFunctionRef,
+}
+
+impl AggregateMergeStateCombinator {
+    pub fn create(nested: AggregateFunctionRef) -> AggregateFunctionRef {
+        Arc::new(AggregateMergeStateCombinator {
+            name: format!("MergeStateCombinator({})", nested.name()),
+            nested,
+        })
+    }
+
+    fn merge_rows(
+        &self,
+        columns: &[Column],
+        rows: impl Iterator<Item = (StateAddr, usize)>,
+    ) -> Result<()> {
+        let states = StringType::try_downcast_column(&columns[0]).ok_or_else(|| {
+            ErrorCode::BadDataValueType(format!(
+                "{} expects the serialized states, but got {:?}",
+                self.name,
+                columns[0].data_type()
+            ))
+        })?;
+
+        let arena = Bump::new();
+        let temp: StateAddr = arena.alloc_layout(self.nested.state_layout()).into();
+        for (place, row) in rows {
+            let mut data = unsafe { states.index_unchecked(row) };
+            self.nested.init_state(temp);
+            let res = self
+                .nested
+                .deserialize(temp, &mut data)
+                .and_then(|_| self.nested.merge(place, temp));
+            if self.nested.need_manual_drop_state() {
+                unsafe { self.nested.drop_state(temp) };
+            }
+            res?;
+        }
+        Ok(())
+    }
+}
+
+impl AggregateFunction for AggregateMergeStateCombinator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        self.nested.return_type()
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        self.nested.init_state(place);
+    }
+
+    fn state_layout(&self) -> Layout {
+        self.nested.state_layout()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let rows = (0..input_rows)
+            .filter(|row| validity.map(|v| v.get_bit(*row)).unwrap_or(true))
+            .map(|row| (place, row));
+        self.merge_rows(columns, rows)
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let rows = places
+            .iter()
+            .enumerate()
+            .map(|(row, place)| (place.next(offset), row));
+        self.merge_rows(columns, rows)
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        self.merge_rows(columns, std::iter::once((place, row)))
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        self.nested.serialize(place, writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        self.nested.deserialize(place, reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        self.nested.merge(place, rhs)
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        self.nested.merge_result(place, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        self.nested.need_manual_drop_state()
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        self.nested.drop_state(place);
+    }
+}
+
+impl fmt::Display for AggregateMergeStateCombinator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}_merge_state", self.nested)
+    }
+}
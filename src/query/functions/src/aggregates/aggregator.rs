@@ -16,6 +16,8 @@ use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_func
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_intersect_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_union_function_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
@@ -76,6 +78,11 @@ impl Aggregators {
         factory.register("kurtosis", aggregate_kurtosis_function_desc());
         factory.register("skewness", aggregate_skewness_function_desc());
         factory.register("string_agg", aggregate_string_agg_function_desc());
+        factory.register("bitmap_union", aggregate_bitmap_union_function_desc());
+        factory.register(
+            "bitmap_intersect",
+            aggregate_bitmap_intersect_function_desc(),
+        );
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
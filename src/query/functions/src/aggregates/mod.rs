@@ -23,8 +23,10 @@ mod aggregate_approx_count_distinct;
 mod aggregate_arg_min_max;
 mod aggregate_array_agg;
 mod aggregate_avg;
+mod aggregate_bitmap;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
+mod aggregate_combinator_merge_state;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
 mod aggregate_kurtosis;
@@ -46,8 +48,10 @@ pub use adaptors::*;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_array_agg::AggregateArrayAggFunction;
 pub use aggregate_avg::AggregateAvgFunction;
+pub use aggregate_bitmap::AggregateBitmapFunction;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
+pub use aggregate_combinator_merge_state::AggregateMergeStateCombinator;
 pub use aggregate_count::AggregateCountFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
 pub use aggregate_function::AggregateFunction;
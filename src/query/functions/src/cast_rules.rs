@@ -71,6 +71,7 @@ pub const GENERAL_CAST_RULES: AutoCastRules = &[
     (DataType::String, DataType::Timestamp),
     (DataType::String, DataType::Date),
     (DataType::String, DataType::Boolean),
+    (DataType::String, DataType::Binary),
     (DataType::Date, DataType::Timestamp),
     (
         DataType::Number(NumberDataType::UInt8),
@@ -0,0 +1,107 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use base64::engine::general_purpose;
+use common_expression::types::nullable::NullableColumn;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::BinaryType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::utils::arrow::constant_bitmap;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // `String` and `Binary` share the same column layout, so the conversions
+    // between them only reinterpret the bytes.
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "to_binary",
+        |_| FunctionDomain::Full,
+        |val, _| match val {
+            ValueRef::Scalar(val) => Value::Scalar(val.to_vec()),
+            ValueRef::Column(col) => Value::Column(col),
+        },
+    );
+
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_to_binary",
+        |_| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(())),
+            })
+        },
+        |val, _| match val {
+            ValueRef::Scalar(val) => Value::Scalar(Some(val.to_vec())),
+            ValueRef::Column(col) => Value::Column(NullableColumn {
+                validity: constant_bitmap(true, col.len()).into(),
+                column: col,
+            }),
+        },
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        |val, _| match val {
+            ValueRef::Scalar(val) => Value::Scalar(val.to_vec()),
+            ValueRef::Column(col) => Value::Column(col),
+        },
+    );
+
+    registry.register_combine_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_to_string",
+        |_| FunctionDomain::Full,
+        |val, _| match val {
+            ValueRef::Scalar(val) => Value::Scalar(Some(val.to_vec())),
+            ValueRef::Column(col) => Value::Column(NullableColumn {
+                validity: constant_bitmap(true, col.len()).into(),
+                column: col,
+            }),
+        },
+    );
+
+    registry.register_1_arg::<BinaryType, NumberType<u64>, _, _>(
+        "length",
+        |_| FunctionDomain::Full,
+        |val, _| val.len() as u64,
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "hex",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            let old_len = output.data.len();
+            output.data.resize(old_len + val.len() * 2, 0);
+            hex::encode_to_slice(val, &mut output.data[old_len..]).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_base64",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            base64::write::EncoderWriter::new(&mut output.data, &general_purpose::STANDARD)
+                .write_all(val)
+                .unwrap();
+            output.commit_row();
+        }),
+    );
+}
@@ -0,0 +1,180 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::BitmapType;
+use common_expression::types::BooleanType;
+use common_expression::types::NullableType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use roaring::RoaringTreemap;
+
+/// Deserialize a bitmap value, empty bytes are taken as an empty bitmap.
+pub fn deserialize_bitmap(buf: &[u8]) -> std::io::Result<RoaringTreemap> {
+    if buf.is_empty() {
+        Ok(RoaringTreemap::new())
+    } else {
+        RoaringTreemap::deserialize_from(buf)
+    }
+}
+
+pub fn push_bitmap(bitmap: &RoaringTreemap, builder: &mut StringColumnBuilder) {
+    bitmap.serialize_into(&mut builder.data).unwrap();
+    builder.commit_row();
+}
+
+/// Parse a comma separated list of `u64`, e.g. `'1,2,3'`.
+fn parse_bitmap(s: &[u8]) -> Result<RoaringTreemap, String> {
+    let s = std::str::from_utf8(s).map_err(|e| e.to_string())?;
+    let mut bitmap = RoaringTreemap::new();
+    for v in s.split(',').map(|v| v.trim()).filter(|v| !v.is_empty()) {
+        let v = v
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid value for bitmap: {:?}", v))?;
+        bitmap.insert(v);
+    }
+    Ok(bitmap)
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, BitmapType, _, _>(
+        "to_bitmap",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, BitmapType>(
+            |s, output, ctx| match parse_bitmap(s) {
+                Ok(bitmap) => push_bitmap(&bitmap, output),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.commit_row();
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<NumberType<u64>, BitmapType, _, _>(
+        "to_bitmap",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<NumberType<u64>, BitmapType>(|v, output, _| {
+            let mut bitmap = RoaringTreemap::new();
+            bitmap.insert(v);
+            push_bitmap(&bitmap, output);
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<StringType, BitmapType, _, _>(
+        "try_to_bitmap",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, NullableType<BitmapType>>(|s, output, _| {
+            match parse_bitmap(s) {
+                Ok(bitmap) => {
+                    output.validity.push(true);
+                    push_bitmap(&bitmap, &mut output.builder);
+                }
+                Err(_) => output.push_null(),
+            }
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<NumberType<u64>, BitmapType, _, _>(
+        "try_to_bitmap",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<NumberType<u64>, NullableType<BitmapType>>(
+            |v, output, _| {
+                let mut bitmap = RoaringTreemap::new();
+                bitmap.insert(v);
+                output.validity.push(true);
+                push_bitmap(&bitmap, &mut output.builder);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BitmapType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BitmapType, StringType>(|b, output, ctx| {
+            match deserialize_bitmap(b) {
+                Ok(bitmap) => {
+                    for (i, v) in bitmap.iter().enumerate() {
+                        if i != 0 {
+                            output.put_char(',');
+                        }
+                        write!(output.data, "{v}").unwrap();
+                    }
+                }
+                Err(err) => ctx.set_error(output.len(), err.to_string()),
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BitmapType, NumberType<u64>, _, _>(
+        "bitmap_count",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BitmapType, NumberType<u64>>(|b, output, ctx| {
+            match deserialize_bitmap(b) {
+                Ok(bitmap) => output.push(bitmap.len()),
+                Err(err) => {
+                    ctx.set_error(output.len(), err.to_string());
+                    output.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<BitmapType, NumberType<u64>, BooleanType, _, _>(
+        "bitmap_contains",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BitmapType, NumberType<u64>, BooleanType>(
+            |b, v, output, ctx| match deserialize_bitmap(b) {
+                Ok(bitmap) => output.push(bitmap.contains(v)),
+                Err(err) => {
+                    ctx.set_error(output.len(), err.to_string());
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    register_bitmap_logic_op(registry, "bitmap_and", |a, b| a & b);
+    register_bitmap_logic_op(registry, "bitmap_or", |a, b| a | b);
+    register_bitmap_logic_op(registry, "bitmap_xor", |a, b| a ^ b);
+    // `bitmap_not(a, b)` keeps the values of `a` which are not in `b`.
+    register_bitmap_logic_op(registry, "bitmap_not", |a, b| a - b);
+}
+
+fn register_bitmap_logic_op(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    op: fn(RoaringTreemap, RoaringTreemap) -> RoaringTreemap,
+) {
+    registry.register_passthrough_nullable_2_arg::<BitmapType, BitmapType, BitmapType, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BitmapType, BitmapType, BitmapType>(
+            move |a, b, output, ctx| match (deserialize_bitmap(a), deserialize_bitmap(b)) {
+                (Ok(a), Ok(b)) => push_bitmap(&op(a, b), output),
+                (Err(err), _) | (_, Err(err)) => {
+                    ctx.set_error(output.len(), err.to_string());
+                    output.commit_row();
+                }
+            },
+        ),
+    );
+}
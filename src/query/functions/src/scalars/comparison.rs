@@ -21,11 +21,13 @@ use common_expression::types::boolean::BooleanDomain;
 use common_expression::types::AnyType;
 use common_expression::types::ArgType;
 use common_expression::types::ArrayType;
+use common_expression::types::BinaryType;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::GenericType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberClass;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -54,8 +56,10 @@ use crate::scalars::string_multi_args::regexp;
 pub fn register(registry: &mut FunctionRegistry) {
     register_variant_cmp(registry);
     register_string_cmp(registry);
+    register_binary_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_interval_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
@@ -159,6 +163,39 @@ fn register_string_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, StringType);
 }
 
+fn register_binary_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
 fn register_date_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, DateType);
 }
@@ -167,6 +204,41 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+// Same as Postgres, intervals are compared by their total length, so `INTERVAL '1 month'`
+// is equal to `INTERVAL '30 days'`.
+fn register_interval_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.total_micros() == rhs.total_micros(),
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.total_micros() != rhs.total_micros(),
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.total_micros() > rhs.total_micros(),
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.total_micros() >= rhs.total_micros(),
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.total_micros() < rhs.total_micros(),
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.total_micros() <= rhs.total_micros(),
+    );
+}
+
 fn register_boolean_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<BooleanType, BooleanType, BooleanType, _, _>(
         "eq",
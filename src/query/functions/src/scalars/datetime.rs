@@ -23,6 +23,10 @@ use common_expression::types::date::date_to_string;
 use common_expression::types::date::string_to_date;
 use common_expression::types::date::DATE_MAX;
 use common_expression::types::date::DATE_MIN;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
+use common_expression::types::interval::MICROS_IN_A_DAY;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::Int64Type;
@@ -40,6 +44,7 @@ use common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use common_expression::types::timestamp::MICROS_IN_A_SEC;
 use common_expression::types::DateType;
 use common_expression::types::Int32Type;
+use common_expression::types::IntervalType;
 use common_expression::types::NullableType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -71,8 +76,12 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_timestamp_to_date(registry);
     register_number_to_date(registry);
 
-    // cast([date | timestamp] AS string)
-    // to_string([date | timestamp])
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+
+    // cast([date | timestamp | interval] AS string)
+    // to_string([date | timestamp | interval])
     register_to_string(registry);
 
     // cast([date | timestamp] AS [uint8 | int8 | ...])
@@ -96,6 +105,9 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
+
+    // timestamp +/- interval, interval +/- interval
+    register_interval_add_sub(registry);
 }
 
 /// Check if timestamp is within range, and return the timestamp in micros.
@@ -325,6 +337,37 @@ fn register_number_to_date(registry: &mut FunctionRegistry) {
     }
 }
 
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `INTERVAL`: {e}"),
+                    );
+                    output.push(months_days_micros::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
 fn register_to_string(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<DateType, StringType, _, _>(
         "to_string",
@@ -344,6 +387,15 @@ fn register_to_string(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{}", interval_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
     registry.register_combine_nullable_1_arg::<DateType, StringType, _, _>(
         "try_to_string",
         |_| {
@@ -1027,23 +1079,99 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
         |a, b, _| a - b,
     );
 
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+    // Same as Postgres, the difference of two timestamps is an interval of days and microseconds.
+    registry.register_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
         "minus",
-        |lhs, rhs| {
-            (|| {
-                let lm = lhs.max;
-                let ln = lhs.min;
-                let rm = rhs.max;
-                let rn = rhs.min;
-
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
-                }))
-            })()
-            .unwrap_or(FunctionDomain::Full)
+        |_, _| FunctionDomain::Full,
+        |a, b, _| {
+            let diff = a - b;
+            months_days_micros::new(0, (diff / MICROS_IN_A_DAY) as i32, diff % MICROS_IN_A_DAY)
         },
-        |a, b, _| a - b,
+    );
+}
+
+fn register_interval_add_sub(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, builder, ctx| match AddIntervalImpl::eval_timestamp(
+                    ts, ctx.tz, interval,
+                ) {
+                    Ok(t) => builder.push(t),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, builder, ctx| match AddIntervalImpl::eval_timestamp(
+                    ts, ctx.tz, interval,
+                ) {
+                    Ok(t) => builder.push(t),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, builder, ctx| {
+                    let result = interval
+                        .checked_neg()
+                        .ok_or_else(|| "Interval overflow".to_string())
+                        .and_then(|interval| AddIntervalImpl::eval_timestamp(ts, ctx.tz, interval));
+                    match result {
+                        Ok(t) => builder.push(t),
+                        Err(e) => {
+                            ctx.set_error(builder.len(), e);
+                            builder.push(0);
+                        }
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, builder, ctx| match a.checked_add(&b) {
+                Some(interval) => builder.push(interval),
+                None => {
+                    ctx.set_error(builder.len(), "Interval overflow");
+                    builder.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, builder, ctx| match b.checked_neg().and_then(|b| a.checked_add(&b)) {
+                Some(interval) => builder.push(interval),
+                None => {
+                    ctx.set_error(builder.len(), "Interval overflow");
+                    builder.push(months_days_micros::default());
+                }
+            },
+        ),
     );
 }
 
@@ -0,0 +1,46 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_expression::types::BooleanType;
+use common_expression::types::StringType;
+use common_expression::vectorize_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+
+/// Split the text into lowercase terms, any character which is not
+/// alphanumeric is taken as a separator.
+///
+/// It's shared by the `match` function and the inverted index, so that
+/// the terms looked up in the index are the same as the ones evaluated.
+pub fn tokenize(text: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+        .collect()
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // `match(text, query)` is true if the text contains all the terms of the query.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _| FunctionDomain::Full,
+        vectorize_2_arg::<StringType, StringType, BooleanType>(|text, query, _| {
+            let terms = tokenize(text).into_iter().collect::<HashSet<_>>();
+            tokenize(query).iter().all(|term| terms.contains(term))
+        }),
+    );
+}
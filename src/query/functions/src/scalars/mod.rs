@@ -17,9 +17,12 @@ use common_expression::FunctionRegistry;
 mod arithmetic;
 mod arithmetic_modulo;
 mod array;
+mod binary;
+mod bitmap;
 mod boolean;
 mod control;
 mod datetime;
+mod fulltext;
 mod geo;
 mod map;
 mod math;
@@ -34,10 +37,13 @@ mod other;
 mod string;
 mod string_multi_args;
 
+pub use bitmap::deserialize_bitmap;
+pub use bitmap::push_bitmap;
 pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use fulltext::tokenize;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -57,4 +63,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     other::register(registry);
     decimal::register(registry);
     vector::register(registry);
+    binary::register(registry);
+    bitmap::register(registry);
+    fulltext::register(registry);
 }
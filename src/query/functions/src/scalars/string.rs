@@ -23,6 +23,7 @@ use common_expression::types::number::UInt64Type;
 use common_expression::types::string::StringColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::string::StringDomain;
+use common_expression::types::BooleanType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::vectorize_with_builder_1_arg;
@@ -298,6 +299,12 @@ pub fn register(registry: &mut FunctionRegistry) {
         move |substr: &[u8], str: &[u8], pos: u64, _| find_at(str, substr, pos),
     );
 
+    registry.register_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "contains",
+        |_, _| FunctionDomain::Full,
+        |str: &[u8], substr: &[u8], _| str.find(substr).is_some(),
+    );
+
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "to_base64",
         |_| FunctionDomain::Full,
@@ -505,8 +505,11 @@ fn transform_data_type(target_type: common_ast::ast::TypeName) -> DataType {
             DataType::Decimal(DecimalDataType::from_size(DecimalSize { precision, scale }).unwrap())
         }
         common_ast::ast::TypeName::String => DataType::String,
+        common_ast::ast::TypeName::Binary => DataType::Binary,
+        common_ast::ast::TypeName::Bitmap => DataType::Bitmap,
         common_ast::ast::TypeName::Timestamp => DataType::Timestamp,
         common_ast::ast::TypeName::Date => DataType::Date,
+        common_ast::ast::TypeName::Interval => DataType::Interval,
         common_ast::ast::TypeName::Array(item_type) => {
             DataType::Array(Box::new(transform_data_type(*item_type)))
         }
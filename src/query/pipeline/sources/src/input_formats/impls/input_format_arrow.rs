@@ -0,0 +1,258 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::FileMetadata;
+use common_arrow::arrow::io::ipc::read::FileReader;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use opendal::Operator;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// Reads files in the arrow ipc file format, each file is a split.
+pub struct InputFormatArrow;
+
+fn read_metadata(path: &str, data: &[u8]) -> Result<FileMetadata> {
+    read_file_metadata(&mut Cursor::new(data))
+        .map_err(|e| ErrorCode::BadBytes(format!("Read arrow file '{}''s meta error: {}", path, e)))
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatArrow {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        Ok(file_infos
+            .into_iter()
+            .map(|info| {
+                let size = info.size as usize;
+                let file = Arc::new(FileInfo {
+                    path: info.path,
+                    size,
+                    num_splits: 1,
+                    compress_alg: None,
+                });
+                Arc::new(SplitInfo {
+                    file,
+                    seq_in_file: 0,
+                    offset: 0,
+                    size,
+                    num_file_splits: 1,
+                    format_info: None,
+                })
+            })
+            .collect())
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let data = op.read(path).await?;
+        let metadata = read_metadata(path, &data)?;
+        Ok(Arc::new(TableSchema::from(&metadata.schema)))
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ArrowFormatPipe::execute_copy_aligned(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ArrowFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct ArrowFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for ArrowFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = ArrowFileInMemory;
+    type AligningState = ArrowAligningState;
+    type BlockBuilder = ArrowBlockBuilder;
+
+    #[async_backtrace::framed]
+    async fn read_split(
+        ctx: Arc<InputContext>,
+        split_info: Arc<SplitInfo>,
+    ) -> Result<Self::RowBatch> {
+        let op = ctx.source.get_operator()?;
+        let data = op.read(&split_info.file.path).await?;
+        ArrowFileInMemory::try_create(split_info.file.path.clone(), data)
+    }
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<ArrowAligningState> {
+        Ok(ArrowAligningState {
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<ArrowBlockBuilder> {
+        Ok(ArrowBlockBuilder { ctx: ctx.clone() })
+    }
+}
+
+pub struct ArrowFileInMemory {
+    pub path: String,
+    pub data: Vec<u8>,
+    pub metadata: FileMetadata,
+}
+
+impl ArrowFileInMemory {
+    fn try_create(path: String, data: Vec<u8>) -> Result<Self> {
+        let metadata = read_metadata(&path, &data)?;
+        Ok(Self {
+            path,
+            data,
+            metadata,
+        })
+    }
+
+    // the indices of the fields of the schema in the file
+    fn get_projection(&self, schema: &TableSchemaRef) -> Result<Vec<usize>> {
+        let fields = &self.metadata.schema.fields;
+        schema
+            .fields()
+            .iter()
+            .map(|f| {
+                fields
+                    .iter()
+                    .rposition(|c| c.name.eq_ignore_ascii_case(f.name()))
+                    .ok_or_else(|| {
+                        ErrorCode::TableSchemaMismatch(format!(
+                            "schema field size mismatch, expected to find column: {}",
+                            f.name()
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
+
+impl Debug for ArrowFileInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ArrowFileInMemory")
+    }
+}
+
+impl RowBatchTrait for ArrowFileInMemory {
+    fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn rows(&self) -> usize {
+        // the number of rows is unknown until the record batches are read
+        0
+    }
+}
+
+pub struct ArrowBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl BlockBuilderTrait for ArrowBlockBuilder {
+    type Pipe = ArrowFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<ArrowFileInMemory>) -> Result<Vec<DataBlock>> {
+        if let Some(file) = batch {
+            let projection = file.get_projection(&self.ctx.schema)?;
+            let fields: Vec<DataField> = projection
+                .iter()
+                .map(|i| DataField::from(&file.metadata.schema.fields[*i]))
+                .collect::<Vec<_>>();
+            let input_schema = DataSchema::new(fields);
+
+            // the projected columns are read in the order of the file
+            let mut sorted = projection.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            let reader = FileReader::new(
+                Cursor::new(file.data),
+                file.metadata,
+                Some(sorted.clone()),
+                None,
+            );
+            let mut blocks = vec![];
+            for chunk in reader {
+                let chunk = chunk.map_err(|e| {
+                    ErrorCode::BadBytes(format!(
+                        "fail to read record batch of arrow file '{}': {}",
+                        file.path, e
+                    ))
+                })?;
+                let arrays = chunk.into_arrays();
+                let arrays = projection
+                    .iter()
+                    .map(|i| arrays[sorted.binary_search(i).unwrap()].clone())
+                    .collect::<Vec<_>>();
+                let chunk = common_arrow::arrow::chunk::Chunk::try_new(arrays)?;
+                blocks.push(DataBlock::from_arrow_chunk(&chunk, &input_schema)?);
+            }
+            Ok(blocks)
+        } else {
+            Ok(vec![])
+        }
+    }
+}
+
+pub struct ArrowAligningState {
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for ArrowAligningState {
+    type Pipe = ArrowFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<ArrowFileInMemory>> {
+        if let Some(buffer) = read_batch {
+            self.buffers.push(buffer);
+            return Ok(vec![]);
+        }
+
+        let file_in_memory = self.buffers.concat();
+        let file =
+            ArrowFileInMemory::try_create(self.split_info.file.path.clone(), file_in_memory)?;
+        Ok(vec![file])
+    }
+}
@@ -0,0 +1,310 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use apache_avro::schema::RecordField;
+use apache_avro::schema::Schema;
+use apache_avro::types::Value;
+use apache_avro::Error as AvroError;
+use apache_avro::Reader;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalDataType;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_formats::FieldDecoder;
+use common_formats::FieldDecoderAvro;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::InputError;
+use opendal::Operator;
+
+use crate::input_formats::impls::input_format_xml::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+const HEADER_READ_SIZE: u64 = 4 * 1024;
+
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    fn read_row(
+        field_decoder: &FieldDecoderAvro,
+        values: &[(String, Value)],
+        record_fields: &[RecordField],
+        field_indices: &[Option<usize>],
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+    ) -> Result<()> {
+        for ((field, column), index) in schema
+            .fields()
+            .iter()
+            .zip(columns.iter_mut())
+            .zip(field_indices.iter())
+        {
+            match index {
+                Some(index) => {
+                    let value = &values[*index].1;
+                    let schema = &record_fields[*index].schema;
+                    if let Err(e) = field_decoder.read_field(column, value, schema) {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "{}. column={} value={:?}",
+                            e.message(),
+                            field.name(),
+                            value
+                        )));
+                    }
+                }
+                None => column.push_default(),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormatTextBase for InputFormatAvro {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Avro
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldDecoderAvro::create(options))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(
+        builder: &mut BlockBuilder<Self>,
+        batch: RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldDecoderAvro>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let path = &batch.split_info.file.path;
+
+        // The values are read with the writer schema embedded in the file header.
+        let reader = Reader::new(Cursor::new(&batch.data))
+            .map_err(|e| avro_error(&e.to_string(), path, 0))?;
+        let writer_schema = reader.writer_schema().clone();
+        let record_fields = match &writer_schema {
+            Schema::Record { fields, .. } => fields,
+            _ => {
+                return Err(avro_error(
+                    "the schema of the file must be a record",
+                    path,
+                    0,
+                ));
+            }
+        };
+        let field_indices = builder
+            .ctx
+            .schema
+            .fields()
+            .iter()
+            .map(|field| {
+                record_fields.iter().position(|record_field| {
+                    if field_decoder.ident_case_sensitive {
+                        record_field.name == *field.name()
+                    } else {
+                        record_field.name.eq_ignore_ascii_case(field.name())
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // for deal with on_error mode
+        let mut num_rows = 0usize;
+        let mut error_map: HashMap<u16, InputError> = HashMap::new();
+        for (row, value) in reader.enumerate() {
+            // A broken data block can not be skipped, the rows after it are unknown.
+            let value = value.map_err(|e| avro_error(&e.to_string(), path, row))?;
+            let values = match &value {
+                Value::Record(values) => values,
+                _ => return Err(avro_error("the value must be a record", path, row)),
+            };
+            if let Err(e) = Self::read_row(
+                field_decoder,
+                values,
+                record_fields,
+                &field_indices,
+                columns,
+                &builder.ctx.schema,
+            ) {
+                let e = avro_error(&e.message(), path, row);
+                match builder.ctx.on_error_mode {
+                    OnErrorMode::Continue => {
+                        Self::on_error_continue(columns, num_rows, e, &mut error_map);
+                        continue;
+                    }
+                    OnErrorMode::AbortNum(n) => {
+                        Self::on_error_abort(columns, num_rows, n, &builder.ctx.on_error_count, e)?;
+                        continue;
+                    }
+                    _ => return Err(e),
+                }
+            }
+            num_rows += 1;
+        }
+        Ok(error_map)
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_schema(path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        // Only the header is read, it holds the schema and is small unless the schema is huge,
+        // the range is enlarged until the whole header is read.
+        let file_size = op.stat(path).await?.content_length();
+        let mut read_size = HEADER_READ_SIZE.min(file_size);
+        loop {
+            let data = op.range_read(path, 0..read_size).await?;
+            match Reader::new(Cursor::new(&data)) {
+                Ok(reader) => {
+                    return Ok(Arc::new(avro_schema_to_table_schema(
+                        reader.writer_schema(),
+                    )?));
+                }
+                Err(e) if read_size < file_size && !matches!(e, AvroError::HeaderMagic) => {
+                    read_size = (read_size * 2).min(file_size);
+                }
+                Err(e) => return Err(avro_error(&e.to_string(), path, 0)),
+            }
+        }
+    }
+}
+
+fn avro_error(msg: &str, path: &str, row: usize) -> ErrorCode {
+    let row = row + 1;
+    let msg = format!("fail to parse AVRO {}:{} {} ", path, row, msg);
+
+    ErrorCode::BadBytes(msg)
+}
+
+/// The fields of the top level record of an avro schema are the columns of the table.
+fn avro_schema_to_table_schema(schema: &Schema) -> Result<TableSchema> {
+    match schema {
+        Schema::Record { fields, .. } => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    Ok(TableField::new(
+                        &field.name,
+                        avro_type_to_table_type(&field.schema)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(TableSchema::new(fields))
+        }
+        _ => Err(ErrorCode::BadBytes(format!(
+            "the schema of avro file must be a record, but got {:?}",
+            schema
+        ))),
+    }
+}
+
+fn avro_type_to_table_type(schema: &Schema) -> Result<TableDataType> {
+    let ty = match schema {
+        Schema::Null => TableDataType::Null,
+        Schema::Boolean => TableDataType::Boolean,
+        Schema::Int | Schema::TimeMillis => TableDataType::Number(NumberDataType::Int32),
+        Schema::Long | Schema::TimeMicros => TableDataType::Number(NumberDataType::Int64),
+        Schema::Float => TableDataType::Number(NumberDataType::Float32),
+        Schema::Double => TableDataType::Number(NumberDataType::Float64),
+        Schema::Bytes | Schema::Fixed { .. } => TableDataType::Binary,
+        Schema::String | Schema::Enum { .. } | Schema::Uuid => TableDataType::String,
+        Schema::Decimal {
+            precision, scale, ..
+        } => {
+            let (precision, scale) = match (u8::try_from(*precision), u8::try_from(*scale)) {
+                (Ok(precision), Ok(scale)) => (precision, scale),
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "avro decimal precision {} and scale {} are too large",
+                        precision, scale
+                    )));
+                }
+            };
+            let size = DecimalSize { precision, scale };
+            TableDataType::Decimal(DecimalDataType::from_size(size)?)
+        }
+        Schema::Date => TableDataType::Date,
+        Schema::TimestampMillis | Schema::TimestampMicros => TableDataType::Timestamp,
+        Schema::Array(item) => TableDataType::Array(Box::new(avro_type_to_table_type(item)?)),
+        Schema::Map(value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, avro_type_to_table_type(value)?],
+        })),
+        Schema::Union(union) => {
+            // `["null", T]` is a nullable `T`, the values of other unions are kept as variants.
+            let branches = union
+                .variants()
+                .iter()
+                .filter(|branch| !matches!(branch, Schema::Null))
+                .collect::<Vec<_>>();
+            let ty = match branches.as_slice() {
+                [] => return Ok(TableDataType::Null),
+                [branch] => avro_type_to_table_type(branch)?,
+                _ => TableDataType::Variant,
+            };
+            if branches.len() < union.variants().len() {
+                ty.wrap_nullable()
+            } else {
+                ty
+            }
+        }
+        Schema::Record { fields, .. } => TableDataType::Tuple {
+            fields_name: fields.iter().map(|field| field.name.clone()).collect(),
+            fields_type: fields
+                .iter()
+                .map(|field| avro_type_to_table_type(&field.schema))
+                .collect::<Result<Vec<_>>>()?,
+        },
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "avro type {:?} is not supported yet",
+                schema
+            )));
+        }
+    };
+    Ok(ty)
+}
@@ -0,0 +1,408 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::mem;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::io::orc::format::read::read_metadata;
+use common_arrow::arrow::io::orc::read::deserialize;
+use common_arrow::arrow::io::orc::read::infer_schema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::orc_stripe_range;
+use common_storage::read_orc_metadata_async;
+use common_storage::read_orc_stripe;
+use common_storage::read_orc_stripe_async;
+use common_storage::OrcColumn;
+use common_storage::OrcFileMetadata;
+use common_storage::StageFileInfo;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use opendal::Operator;
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::DynData;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// The number of orc files whose metadata are read concurrently when making splits.
+const READ_METADATA_CONCURRENCY: usize = 16;
+
+pub struct InputFormatOrc;
+
+impl InputFormatOrc {
+    fn make_splits(
+        file_infos: Vec<StageFileInfo>,
+        metas: Vec<OrcFileMetadata>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let mut infos = vec![];
+        for (info, metadata) in file_infos.into_iter().zip(metas.into_iter()) {
+            let file_meta = Arc::new(FileMeta::try_create(metadata)?);
+            let num_stripes = file_meta.metadata.footer.stripes.len();
+            let file_info = Arc::new(FileInfo {
+                path: info.path.clone(),
+                size: info.size as usize,
+                num_splits: num_stripes,
+                compress_alg: None,
+            });
+
+            // Each stripe is a split, its columns are read with one request.
+            for stripe in 0..num_stripes {
+                let range = orc_stripe_range(&file_meta.metadata, stripe);
+                infos.push(Arc::new(SplitInfo {
+                    file: file_info.clone(),
+                    seq_in_file: stripe,
+                    offset: range.start as usize,
+                    size: (range.end - range.start) as usize,
+                    num_file_splits: num_stripes,
+                    format_info: Some(Arc::new(SplitMeta {
+                        file: file_meta.clone(),
+                        stripe,
+                    })),
+                }));
+            }
+        }
+        Ok(infos)
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatOrc {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let metas = futures::stream::iter(file_infos.iter())
+            .map(|info| read_orc_metadata_async(op, &info.path, info.size))
+            .buffered(READ_METADATA_CONCURRENCY)
+            .try_collect::<Vec<_>>()
+            .await?;
+        Self::make_splits(file_infos, metas)
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let size = op.stat(path).await?.content_length();
+        let metadata = read_orc_metadata_async(op, path, size).await?;
+        let arrow_schema = infer_schema(&metadata.footer)?;
+        Ok(Arc::new(TableSchema::from(&arrow_schema)))
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_copy_aligned(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = SplitMeta;
+    type ReadBatch = Vec<u8>;
+    type RowBatch = StripeInMemory;
+    type AligningState = OrcAligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    #[async_backtrace::framed]
+    async fn read_split(
+        ctx: Arc<InputContext>,
+        split_info: Arc<SplitInfo>,
+    ) -> Result<Self::RowBatch> {
+        let meta = Self::get_split_meta(&split_info).expect("must success");
+        let op = ctx.source.get_operator()?;
+        let (fields, column_ids) = meta.file.get_used_fields(&ctx.schema)?;
+        let columns = read_orc_stripe_async(
+            &op,
+            &split_info.file.path,
+            &meta.file.metadata,
+            meta.stripe,
+            &column_ids,
+        )
+        .await?;
+        Ok(StripeInMemory::create(
+            split_info.to_string(),
+            &meta.file.metadata,
+            meta.stripe,
+            fields,
+            columns,
+        ))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<OrcAligningState> {
+        Ok(OrcAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(_ctx: &Arc<InputContext>) -> Result<OrcBlockBuilder> {
+        Ok(OrcBlockBuilder {})
+    }
+}
+
+pub struct FileMeta {
+    pub metadata: OrcFileMetadata,
+    // all top level fields in the orc file
+    pub fields: Vec<Field>,
+    // the ids of the columns of the fields in the types of the file footer
+    pub column_ids: Vec<u32>,
+}
+
+impl FileMeta {
+    fn try_create(metadata: OrcFileMetadata) -> Result<Self> {
+        let fields = infer_schema(&metadata.footer)?.fields;
+        let column_ids = match metadata.footer.types.first() {
+            Some(root) => root.subtypes.clone(),
+            None => vec![],
+        };
+        if fields.len() != column_ids.len() {
+            return Err(ErrorCode::BadBytes(format!(
+                "orc file has {} fields but {} columns",
+                fields.len(),
+                column_ids.len()
+            )));
+        }
+        Ok(Self {
+            metadata,
+            fields,
+            column_ids,
+        })
+    }
+
+    fn get_used_fields(&self, schema: &TableSchemaRef) -> Result<(Vec<Field>, Vec<u32>)> {
+        let mut read_fields = Vec::with_capacity(schema.num_fields());
+        let mut column_ids = Vec::with_capacity(schema.num_fields());
+        for f in schema.fields().iter() {
+            match self
+                .fields
+                .iter()
+                .rposition(|c| c.name.eq_ignore_ascii_case(f.name()))
+            {
+                Some(i) => {
+                    read_fields.push(self.fields[i].clone());
+                    column_ids.push(self.column_ids[i]);
+                }
+                None => {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "schema field size mismatch, expected to find column: {}",
+                        f.name()
+                    )));
+                }
+            }
+        }
+        Ok((read_fields, column_ids))
+    }
+}
+
+#[derive(Clone)]
+pub struct SplitMeta {
+    pub file: Arc<FileMeta>,
+    pub stripe: usize,
+}
+
+impl Debug for SplitMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "orc split meta")
+    }
+}
+
+impl serde::Serialize for SplitMeta {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        Err(S::Error::custom("orc split meta can not be serialized"))
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for SplitMeta {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> Result<Self, D::Error> {
+        Err(D::Error::custom("orc split meta can not be deserialized"))
+    }
+}
+
+#[typetag::serde(name = "orc_split")]
+impl DynData for SplitMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct StripeInMemory {
+    pub split_info: String,
+    pub size: usize,
+    pub num_rows: usize,
+    // in the order of the schema of the input context
+    pub fields_to_read: Vec<Field>,
+    pub columns: Vec<OrcColumn>,
+}
+
+impl StripeInMemory {
+    fn create(
+        split_info: String,
+        metadata: &OrcFileMetadata,
+        stripe: usize,
+        fields_to_read: Vec<Field>,
+        columns: Vec<OrcColumn>,
+    ) -> Self {
+        let range = orc_stripe_range(metadata, stripe);
+        Self {
+            split_info,
+            size: (range.end - range.start) as usize,
+            num_rows: metadata.footer.stripes[stripe].number_of_rows() as usize,
+            fields_to_read,
+            columns,
+        }
+    }
+
+    fn get_arrow_chunk(&mut self) -> Result<ArrowChunk<Box<dyn Array>>> {
+        let columns = mem::take(&mut self.columns);
+        let arrays = self
+            .fields_to_read
+            .iter()
+            .zip(columns.iter())
+            .map(|(field, column)| deserialize(field.data_type.clone(), column))
+            .collect::<common_arrow::arrow::error::Result<Vec<_>>>()
+            .map_err(|e| {
+                ErrorCode::BadBytes(format!(
+                    "fail to deserialize orc stripe {}: {}",
+                    self.split_info, e
+                ))
+            })?;
+        Ok(ArrowChunk::try_new(arrays)?)
+    }
+}
+
+impl Debug for StripeInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StripeInMemory")
+    }
+}
+
+impl RowBatchTrait for StripeInMemory {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn rows(&self) -> usize {
+        self.num_rows
+    }
+}
+
+pub struct OrcBlockBuilder {}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn deserialize(&mut self, mut batch: Option<StripeInMemory>) -> Result<Vec<DataBlock>> {
+        if let Some(stripe) = batch.as_mut() {
+            let chunk = stripe.get_arrow_chunk()?;
+            let fields: Vec<DataField> = stripe
+                .fields_to_read
+                .iter()
+                .map(DataField::from)
+                .collect::<Vec<_>>();
+            let input_schema = DataSchema::new(fields);
+            let block = DataBlock::from_arrow_chunk(&chunk, &input_schema)?;
+            Ok(vec![block])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
+
+pub struct OrcAligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for OrcAligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<StripeInMemory>> {
+        if let Some(buffer) = read_batch {
+            self.buffers.push(buffer);
+            return Ok(vec![]);
+        }
+
+        let path = &self.split_info.file.path;
+        let file_in_memory = self.buffers.concat();
+        let metadata = read_metadata(&mut Cursor::new(&file_in_memory)).map_err(|e| {
+            ErrorCode::BadBytes(format!("Read orc file '{}''s meta error: {}", path, e))
+        })?;
+        let file_meta = FileMeta::try_create(metadata)?;
+        let (fields, column_ids) = file_meta.get_used_fields(&self.ctx.schema)?;
+        let num_stripes = file_meta.metadata.footer.stripes.len();
+        let mut stripes = Vec::with_capacity(num_stripes);
+        for stripe in 0..num_stripes {
+            let columns = read_orc_stripe(
+                path,
+                &file_in_memory,
+                0,
+                &file_meta.metadata,
+                stripe,
+                &column_ids,
+            )?;
+            stripes.push(StripeInMemory::create(
+                self.split_info.to_string(),
+                &file_meta.metadata,
+                stripe,
+                fields.clone(),
+                columns,
+            ));
+        }
+        tracing::info!(
+            "align orc file {} of {} bytes to {} stripes",
+            path,
+            file_in_memory.len(),
+            stripes.len()
+        );
+        Ok(stripes)
+    }
+}
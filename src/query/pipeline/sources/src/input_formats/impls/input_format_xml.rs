@@ -101,7 +101,7 @@ pub struct AligningStateWholeFile {
 }
 
 impl AligningStateWholeFile {
-    fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+    pub fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
         Ok(Self {
             split_info: split_info.clone(),
             bufs: vec![],
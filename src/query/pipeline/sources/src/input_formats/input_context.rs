@@ -39,8 +39,11 @@ use common_settings::Settings;
 use dashmap::DashMap;
 use opendal::Operator;
 
+use crate::input_formats::impls::InputFormatArrow;
+use crate::input_formats::impls::InputFormatAvro;
 use crate::input_formats::impls::InputFormatCSV;
 use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::impls::InputFormatOrc;
 use crate::input_formats::impls::InputFormatParquet;
 use crate::input_formats::impls::InputFormatTSV;
 use crate::input_formats::impls::InputFormatXML;
@@ -145,6 +148,9 @@ impl InputContext {
             FileFormatParams::NdJson(_) => Ok(Arc::new(InputFormatNDJson::create())),
             FileFormatParams::Parquet(_) => Ok(Arc::new(InputFormatParquet {})),
             FileFormatParams::Xml(_) => Ok(Arc::new(InputFormatXML::create())),
+            FileFormatParams::Avro(_) => Ok(Arc::new(InputFormatAvro::create())),
+            FileFormatParams::Orc(_) => Ok(Arc::new(InputFormatOrc {})),
+            FileFormatParams::Arrow(_) => Ok(Arc::new(InputFormatArrow {})),
             format => Err(ErrorCode::Internal(format!(
                 "Unsupported file format: {:?}",
                 format
@@ -216,6 +216,7 @@ impl AligningStateTextBased for AligningStateRowDelimiter {
     }
 }
 
+#[async_trait::async_trait]
 pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
     type AligningState: AligningStateTextBased;
 
@@ -244,6 +245,13 @@ pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
         batch: RowBatch,
     ) -> Result<HashMap<u16, InputError>>;
 
+    /// Only the formats that carry a schema in their files, e.g. avro, can infer it.
+    async fn infer_schema(_path: &str, _op: &Operator) -> Result<TableSchemaRef> {
+        Err(ErrorCode::Unimplemented(
+            "infer_schema is not implemented for this format yet.",
+        ))
+    }
+
     fn on_error_continue(
         columns: &mut Vec<ColumnBuilder>,
         num_rows: usize,
@@ -373,10 +381,8 @@ impl<T: InputFormatTextBase> InputFormat for T {
     }
 
     #[async_backtrace::framed]
-    async fn infer_schema(&self, _path: &str, _op: &Operator) -> Result<TableSchemaRef> {
-        Err(ErrorCode::Unimplemented(
-            "infer_schema is not implemented for this format yet.",
-        ))
+    async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        T::infer_schema(path, op).await
     }
 
     fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
@@ -12,7 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
-mod sort;
+pub mod sort;
 pub mod transform;
 pub mod transform_accumulating;
 pub mod transform_accumulating_async;
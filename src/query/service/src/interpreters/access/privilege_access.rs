@@ -91,6 +91,17 @@ impl AccessChecker for PrivilegeAccess {
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
                     .await?;
             }
+            Plan::CloneDatabase(plan) => {
+                session
+                    .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.source_database.clone()),
+                        vec![UserPrivilegeType::Select],
+                    )
+                    .await?;
+            }
             Plan::DropDatabase(_) | Plan::UndropDatabase(_) | Plan::DropUDF(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Drop])
@@ -139,6 +150,24 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CloneTable(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.source_database.clone(),
+                            plan.source_table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                    )
+                    .await?;
+            }
             Plan::DropTable(plan) => {
                 session
                     .validate_privilege(
@@ -275,6 +304,54 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::VacuumTable(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Super],
+                    )
+                    .await?;
+            }
+            Plan::CreateIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::RefreshIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             // Others.
             Plan::Insert(plan) => {
                 session
@@ -324,6 +401,22 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::MergeInto(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![
+                            UserPrivilegeType::Insert,
+                            UserPrivilegeType::Update,
+                            UserPrivilegeType::Delete,
+                        ],
+                    )
+                    .await?;
+            }
             Plan::CreateView(plan) => {
                 session
                     .validate_privilege(
@@ -348,6 +441,52 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.view_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Insert],
+                    )
+                    .await?;
+            }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.table_database.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
             Plan::CreateUser(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::CreateUser])
@@ -465,6 +604,8 @@ impl AccessChecker for PrivilegeAccess {
             Plan::ExplainSyntax { .. } => {}
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
+            // Note: No need to check privileges, the statements in the transaction are checked
+            Plan::Begin | Plan::Commit | Plan::Rollback => {}
         }
 
         Ok(())
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 mod grant;
+mod stream;
 mod table;
 
 pub use grant::validate_grant_object_exists;
+pub use stream::consume_streams;
 pub use table::append2table;
@@ -0,0 +1,98 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_pipeline_core::Pipeline;
+use common_storages_fuse::FuseTable;
+use common_storages_stream::stream_table::StreamTable;
+use storages_common_table_meta::table::OPT_KEY_STREAM_OFFSET;
+
+use crate::sessions::QueryContext;
+
+/// Advance the offsets of the streams consumed by a DML statement once its pipeline
+/// finishes successfully, or right now if the statement has no pipeline to run.
+#[async_backtrace::framed]
+pub async fn consume_streams(ctx: Arc<QueryContext>, pipeline: &mut Pipeline) -> Result<()> {
+    let streams = ctx.get_consumed_streams();
+    if streams.is_empty() {
+        return Ok(());
+    }
+
+    if pipeline.is_empty() {
+        return advance_stream_offsets(ctx, streams).await;
+    }
+
+    pipeline.set_on_finished(move |may_error| {
+        if may_error.is_none() {
+            return GlobalIORuntime::instance()
+                .block_on(async move { advance_stream_offsets(ctx, streams).await });
+        }
+
+        Err(may_error.as_ref().unwrap().clone())
+    });
+    Ok(())
+}
+
+/// Move the offset of each stream to the snapshot of its source table read by the
+/// query, so the changes consumed by the query are not returned by the stream again.
+#[async_backtrace::framed]
+async fn advance_stream_offsets(
+    ctx: Arc<QueryContext>,
+    streams: Vec<Arc<dyn Table>>,
+) -> Result<()> {
+    for table in streams {
+        let stream = StreamTable::try_from_table(table.as_ref())?;
+        let source = stream.source_table(ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(source.as_ref())?;
+        let offset = fuse_table.snapshot_loc().await?;
+        if offset.as_deref() == stream.offset() {
+            continue;
+        }
+
+        let table_info = stream.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        match offset {
+            Some(offset) => {
+                new_table_meta
+                    .options
+                    .insert(OPT_KEY_STREAM_OFFSET.to_string(), offset);
+            }
+            None => {
+                new_table_meta.options.remove(OPT_KEY_STREAM_OFFSET);
+            }
+        }
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+        };
+        // In an explicit transaction, the offset is advanced when the transaction commits.
+        let txn_mgr = ctx.txn_mgr();
+        if txn_mgr.lock().is_active() {
+            txn_mgr.lock().update_table_meta(table_info, req)?;
+            continue;
+        }
+        let catalog = ctx.get_catalog(table_info.catalog())?;
+        catalog.update_table_meta(table_info, req).await?;
+    }
+    Ok(())
+}
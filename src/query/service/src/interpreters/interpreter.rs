@@ -56,6 +56,7 @@ pub trait Interpreter: Sync + Send {
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
+                ctx.txn_mgr().lock().set_fail();
                 InterpreterMetrics::record_query_error(&ctx);
                 log_query_finished(&ctx, Some(build_error.clone()));
                 return Err(build_error);
@@ -69,6 +70,21 @@ pub trait Interpreter: Sync + Send {
             return Ok(Box::pin(DataBlockStream::create(None, vec![])));
         }
 
+        // A statement failing in an explicit transaction fails the transaction, no matter
+        // whether the pipeline or one of its finished callbacks fails.
+        let txn_mgr = ctx.txn_mgr();
+        let on_finished = build_res.main_pipeline.take_on_finished();
+        build_res.main_pipeline.set_on_finished(move |may_error| {
+            let res = on_finished(may_error).and_then(|_| match may_error {
+                None => Ok(()),
+                Some(error) => Err(error.clone()),
+            });
+            if res.is_err() {
+                txn_mgr.lock().set_fail();
+            }
+            res
+        });
+
         let query_ctx = ctx.clone();
         build_res.main_pipeline.set_on_finished(move |may_error| {
             InterpreterMetrics::record_query_finished(&query_ctx, may_error.clone());
@@ -110,9 +110,14 @@ impl CopyInterpreter {
         stage: &StageInfo,
         path: &str,
         query: &Plan,
+        partition_by: &[String],
     ) -> Result<PipelineBuildResult> {
         let (mut build_res, data_schema) = self.build_query(query).await?;
-        let table_schema = infer_table_schema(&data_schema)?;
+        // The partition keys are the last columns of the query, which are not unloaded.
+        let num_data_fields = data_schema.num_fields() - partition_by.len();
+        let unload_schema =
+            DataSchemaRefExt::create(data_schema.fields()[..num_data_fields].to_vec());
+        let table_schema = infer_table_schema(&unload_schema)?;
         let stage_table_info = StageTableInfo {
             schema: table_schema,
             stage_info: stage.clone(),
@@ -123,6 +128,16 @@ impl CopyInterpreter {
             },
             files_to_copy: None,
         };
+        if !partition_by.is_empty() {
+            StageTable::append_partitioned_data(
+                self.ctx.clone(),
+                &stage_table_info,
+                partition_by,
+                &mut build_res.main_pipeline,
+            )?;
+            return Ok(build_res);
+        }
+
         let table = StageTable::try_create(stage_table_info)?;
         append2table(
             self.ctx.clone(),
@@ -602,8 +617,15 @@ impl Interpreter for CopyInterpreter {
                 .await
             }
             CopyPlan::IntoStage {
-                stage, from, path, ..
-            } => self.build_copy_into_stage_pipeline(stage, path, from).await,
+                stage,
+                from,
+                path,
+                partition_by,
+                ..
+            } => {
+                self.build_copy_into_stage_pipeline(stage, path, from, partition_by)
+                    .await
+            }
         }
     }
 }
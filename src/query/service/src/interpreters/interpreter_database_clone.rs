@@ -0,0 +1,131 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CloneDatabasePlan;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::interpreter_table_clone::clone_fuse_table;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Create a database with the clones of the tables of the source database.
+///
+/// The fuse tables are cloned without copying their data, the other tables, like
+/// views, are created with the same definitions.
+pub struct CloneDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CloneDatabasePlan,
+}
+
+impl CloneDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CloneDatabasePlan) -> Result<Self> {
+        Ok(CloneDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CloneDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "CloneDatabaseInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        if plan.if_not_exists
+            && catalog
+                .exists_database(&plan.tenant, &plan.database)
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let source = catalog
+            .get_database(&plan.tenant, &plan.source_database)
+            .await?;
+        let source_meta = &source.get_db_info().meta;
+        if source_meta.from_share.is_some() {
+            return Err(ErrorCode::BadArguments(format!(
+                "cannot clone database {} created from share",
+                plan.source_database
+            )));
+        }
+
+        catalog
+            .create_database(CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: plan.tenant.clone(),
+                    db_name: plan.database.clone(),
+                },
+                meta: DatabaseMeta {
+                    engine: source_meta.engine.clone(),
+                    engine_options: source_meta.engine_options.clone(),
+                    options: source_meta.options.clone(),
+                    comment: source_meta.comment.clone(),
+                    ..Default::default()
+                },
+            })
+            .await?;
+
+        for table in source.list_tables().await? {
+            match FuseTable::try_from_table(table.as_ref()) {
+                Ok(fuse_table) => {
+                    clone_fuse_table(
+                        &self.ctx,
+                        catalog.as_ref(),
+                        fuse_table,
+                        &plan.tenant,
+                        &plan.database,
+                        table.name(),
+                    )
+                    .await?
+                }
+                Err(_) => {
+                    let table_meta = TableMeta {
+                        created_on: Utc::now(),
+                        updated_on: Utc::now(),
+                        ..table.get_table_info().meta.clone()
+                    };
+                    catalog
+                        .create_table(CreateTableReq {
+                            if_not_exists: false,
+                            name_ident: TableNameIdent {
+                                tenant: plan.tenant.clone(),
+                                db_name: plan.database.clone(),
+                                table_name: table.name().to_string(),
+                            },
+                            table_meta,
+                        })
+                        .await?;
+                }
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -20,6 +20,7 @@ use common_expression::DataSchemaRef;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::executor::cast_expr_to_non_null_boolean;
 
+use crate::interpreters::common::consume_streams;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -90,6 +91,7 @@ impl Interpreter for DeleteInterpreter {
         )
         .await?;
 
+        consume_streams(self.ctx.clone(), &mut build_res.main_pipeline).await?;
         Ok(build_res)
     }
 }
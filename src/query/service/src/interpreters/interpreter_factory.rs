@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use tracing::error;
@@ -41,6 +42,7 @@ use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::UpdateInterpreter;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 use crate::sql::plans::Plan;
 
 /// InterpreterFactory is the entry of Interpreter.
@@ -57,6 +59,13 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+
+        // A failed transaction can only be ended by `COMMIT` or `ROLLBACK`.
+        if ctx.txn_mgr().lock().is_fail() && !matches!(plan, Plan::Commit | Plan::Rollback) {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "current transaction is aborted, commands ignored until end of transaction block",
+            ));
+        }
         Self::get_inner(ctx, plan)
     }
 
@@ -134,6 +143,9 @@ impl InterpreterFactory {
             Plan::CreateDatabase(create_database) => Ok(Arc::new(
                 CreateDatabaseInterpreter::try_create(ctx, *create_database.clone())?,
             )),
+            Plan::CloneDatabase(clone_database) => Ok(Arc::new(
+                CloneDatabaseInterpreter::try_create(ctx, *clone_database.clone())?,
+            )),
             Plan::DropDatabase(drop_database) => Ok(Arc::new(DropDatabaseInterpreter::try_create(
                 ctx,
                 *drop_database.clone(),
@@ -158,6 +170,10 @@ impl InterpreterFactory {
                 ctx,
                 *create_table.clone(),
             )?)),
+            Plan::CloneTable(clone_table) => Ok(Arc::new(CloneTableInterpreter::try_create(
+                ctx,
+                *clone_table.clone(),
+            )?)),
             Plan::DropTable(drop_table) => Ok(Arc::new(DropTableInterpreter::try_create(
                 ctx,
                 *drop_table.clone(),
@@ -195,11 +211,29 @@ impl InterpreterFactory {
                 ctx,
                 *analyze_table.clone(),
             )?)),
+            Plan::VacuumTable(vacuum_table) => Ok(Arc::new(VacuumTableInterpreter::try_create(
+                ctx,
+                *vacuum_table.clone(),
+            )?)),
             Plan::ExistsTable(exists_table) => Ok(Arc::new(ExistsTableInterpreter::try_create(
                 ctx,
                 *exists_table.clone(),
             )?)),
 
+            // Indexes
+            Plan::CreateIndex(create_index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
+                ctx,
+                *create_index.clone(),
+            )?)),
+            Plan::DropIndex(drop_index) => Ok(Arc::new(DropIndexInterpreter::try_create(
+                ctx,
+                *drop_index.clone(),
+            )?)),
+            Plan::RefreshIndex(refresh_index) => Ok(Arc::new(RefreshIndexInterpreter::try_create(
+                ctx,
+                *refresh_index.clone(),
+            )?)),
+
             // Views
             Plan::CreateView(create_view) => Ok(Arc::new(CreateViewInterpreter::try_create(
                 ctx,
@@ -213,6 +247,28 @@ impl InterpreterFactory {
                 ctx,
                 *drop_view.clone(),
             )?)),
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(Arc::new(CreateMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *create_materialized_view.clone(),
+                )?))
+            }
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(Arc::new(RefreshMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *refresh_materialized_view.clone(),
+                )?))
+            }
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
 
             // Users
             Plan::CreateUser(create_user) => Ok(Arc::new(CreateUserInterpreter::try_create(
@@ -242,6 +298,10 @@ impl InterpreterFactory {
                 *update.clone(),
             )?)),
 
+            Plan::MergeInto(merge_into) => {
+                MergeIntoInterpreter::try_create(ctx, *merge_into.clone())
+            }
+
             // Roles
             Plan::CreateRole(create_role) => Ok(Arc::new(CreateRoleInterpreter::try_create(
                 ctx,
@@ -381,6 +441,11 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+
+            // Transactions
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Rollback => Ok(Arc::new(RollbackInterpreter::try_create(ctx)?)),
         }
     }
 }
@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableIndexReq;
+use common_sql::plans::CreateIndexPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateIndexPlan,
+}
+
+impl CreateIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateIndexPlan) -> Result<Self> {
+        Ok(CreateIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(self.ctx.get_tenant().as_str(), &plan.database, &plan.table)
+            .await?;
+
+        // Only the fuse engine builds and uses the secondary indexes.
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support index",
+                &plan.database,
+                &plan.table,
+                table.engine()
+            )));
+        }
+
+        let req = CreateTableIndexReq {
+            if_not_exists: plan.if_not_exists,
+            table_id: table.get_id(),
+            index: plan.index.clone(),
+        };
+        catalog.create_table_index(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
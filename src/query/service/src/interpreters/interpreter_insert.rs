@@ -85,6 +85,7 @@ use tracing::error;
 use tracing::info;
 
 use crate::interpreters::common::append2table;
+use crate::interpreters::common::consume_streams;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
@@ -515,6 +516,7 @@ impl Interpreter for InsertInterpreter {
                     Err(may_error.as_ref().unwrap().clone())
                 });
 
+                consume_streams(self.ctx.clone(), &mut build_res.main_pipeline).await?;
                 return Ok(build_res);
             }
         };
@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::catalog::Catalog;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateMaterializedViewPlan;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+
+use crate::interpreters::interpreter_materialized_view_refresh::refresh_materialized_view;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+
+        // check whether view has exists
+        if catalog
+            .exists_table(tenant.as_str(), &self.plan.database, &self.plan.view_name)
+            .await?
+        {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::ViewAlreadyExists(format!(
+                    "{}.{} as view Already Exists",
+                    self.plan.database, self.plan.view_name
+                )))
+            };
+        }
+
+        let mut options = self.plan.options.clone();
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            self.plan.subquery.clone(),
+        );
+        let req = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.view_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: self.plan.schema.clone(),
+                engine: "FUSE".to_string(),
+                options,
+                ..Default::default()
+            },
+        };
+        catalog.create_table(req).await?;
+
+        // Populate the view by the result of the query, the view is dropped
+        // if it fails to be populated.
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.view_name)
+            .await?;
+        let table_id = table.get_table_info().ident.table_id;
+        let mut build_res = match refresh_materialized_view(self.ctx.clone(), table).await {
+            Ok(build_res) => build_res,
+            Err(cause) => {
+                drop_view_table(catalog, table_id).await?;
+                return Err(cause);
+            }
+        };
+
+        let on_finished = build_res.main_pipeline.take_on_finished();
+        build_res.main_pipeline.set_on_finished(move |may_error| {
+            let res = on_finished(may_error).and_then(|_| match may_error {
+                None => Ok(()),
+                Some(error) => Err(error.clone()),
+            });
+            if res.is_err() {
+                GlobalIORuntime::instance().block_on(drop_view_table(catalog, table_id))?;
+            }
+            res
+        });
+        Ok(build_res)
+    }
+}
+
+#[async_backtrace::framed]
+async fn drop_view_table(catalog: Arc<dyn Catalog>, table_id: u64) -> Result<()> {
+    catalog
+        .drop_table_by_id(DropTableByIdReq {
+            if_exists: true,
+            tb_id: table_id,
+        })
+        .await?;
+    Ok(())
+}
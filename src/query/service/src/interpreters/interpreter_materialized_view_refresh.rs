@@ -0,0 +1,272 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_sql::executor::DistributedInsertSelect;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::PhysicalPlanBuilder;
+use common_sql::optimizer::SExpr;
+use common_sql::plans::Plan;
+use common_sql::plans::RefreshMaterializedViewPlan;
+use common_sql::plans::RelOperator;
+use common_sql::MetadataRef;
+use common_sql::Planner;
+use common_storages_fuse::operations::AppendOperationLogEntry;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::MaterializedViewSources;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCES;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use tracing::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.view_name)
+            .await?;
+        if !table
+            .options()
+            .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not a materialized view",
+                plan.database, plan.view_name
+            )));
+        }
+
+        refresh_materialized_view(self.ctx.clone(), table).await
+    }
+}
+
+/// Build the pipeline which refreshes the materialized view stored in `table`.
+///
+/// If the query of the view only filters and projects the rows of a single table,
+/// and the table is only appended since the last refresh, the rows appended are
+/// inserted into the view. Otherwise, the view is overwritten by the result of the
+/// query.
+///
+/// The refresh state of the view is committed along with the data, so a refresh
+/// fails if the view is refreshed concurrently.
+#[async_backtrace::framed]
+pub(crate) async fn refresh_materialized_view(
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+) -> Result<PipelineBuildResult> {
+    let view = FuseTable::try_from_table(table.as_ref())?.clone();
+    let options = view.get_table_info().options();
+    let query = options
+        .get(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+        .ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "Logical error, materialized view {} must have a query inside",
+                view.name()
+            ))
+        })?;
+    let last_sources = match options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCES) {
+        Some(sources) => Some(MaterializedViewSources::try_from(sources.as_str())?),
+        None => None,
+    };
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(query).await?;
+    let (s_expr, metadata, bind_context) = match &plan {
+        Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } => (s_expr, metadata, bind_context),
+        _ => {
+            return Err(ErrorCode::Internal(
+                "Logical error, materialized view query must be a query",
+            ));
+        }
+    };
+
+    let sources = source_snapshots(metadata);
+    if last_sources.as_ref() == Some(&sources) {
+        info!("materialized view {} is up to date", view.name());
+        return Ok(PipelineBuildResult::create());
+    }
+
+    let overwrite = if try_read_appended(metadata, s_expr, last_sources.as_ref()).await? {
+        info!("refresh materialized view {} incrementally", view.name());
+        false
+    } else {
+        info!("refresh materialized view {} by recomputing", view.name());
+        true
+    };
+
+    let select_plan = PhysicalPlanBuilder::new(metadata.clone(), ctx.clone())
+        .build(s_expr)
+        .await?;
+    let insert_schema = Arc::new(DataSchema::from(view.schema().as_ref()));
+    let cast_needed = plan.schema() != insert_schema;
+    let insert_select = |input: Box<PhysicalPlan>| {
+        PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
+            input,
+            catalog: view.get_table_info().meta.catalog.clone(),
+            table_info: view.get_table_info().clone(),
+            select_schema: plan.schema(),
+            select_column_bindings: bind_context.columns.clone(),
+            insert_schema: insert_schema.clone(),
+            cast_needed,
+        }))
+    };
+    let insert_select_plan = match select_plan {
+        PhysicalPlan::Exchange(mut exchange) => {
+            // insert can be dispatched to different nodes
+            exchange.input = Box::new(insert_select(exchange.input.clone()));
+            PhysicalPlan::Exchange(exchange)
+        }
+        other_plan => insert_select(Box::new(other_plan)),
+    };
+
+    let mut build_res = build_query_pipeline(&ctx, &[], &insert_select_plan, false, false).await?;
+
+    let mut new_options = BTreeMap::new();
+    new_options.insert(
+        OPT_KEY_MATERIALIZED_VIEW_SOURCES.to_owned(),
+        sources.to_string(),
+    );
+    new_options.insert(
+        OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON.to_owned(),
+        Utc::now().format("%Y-%m-%d %H:%M:%S.%3f %z").to_string(),
+    );
+    build_res.main_pipeline.set_on_finished(move |may_error| {
+        let ctx = ctx.clone();
+        let view = view.clone();
+        let new_options = new_options.clone();
+
+        if may_error.is_none() {
+            let append_entries = ctx.consume_precommit_blocks();
+            // We must put the commit operation to global runtime, which will avoid the "dispatch dropped without returning error" in tower
+            return GlobalIORuntime::instance().block_on(async move {
+                let append_log_entries = append_entries
+                    .iter()
+                    .map(AppendOperationLogEntry::try_from)
+                    .collect::<Result<Vec<AppendOperationLogEntry>>>()?;
+                view.commit_with_options(ctx, append_log_entries, new_options, overwrite)
+                    .await
+            });
+        }
+
+        Err(may_error.as_ref().unwrap().clone())
+    });
+
+    Ok(build_res)
+}
+
+/// The snapshots of the fuse tables read by the query of a materialized view.
+fn source_snapshots(metadata: &MetadataRef) -> MaterializedViewSources {
+    let mut sources = MaterializedViewSources::default();
+    for entry in metadata.read().tables() {
+        let table = entry.table();
+        if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+            let table_info = fuse_table.get_table_info();
+            sources.snapshots.insert(
+                table_info.ident.table_id,
+                table_info.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned(),
+            );
+        }
+    }
+    sources
+}
+
+/// Make the query read only the rows appended to its source table since the last
+/// refresh, returns false if the view must be recomputed.
+#[async_backtrace::framed]
+async fn try_read_appended(
+    metadata: &MetadataRef,
+    s_expr: &SExpr,
+    last_sources: Option<&MaterializedViewSources>,
+) -> Result<bool> {
+    let last_sources = match last_sources {
+        Some(sources) => sources,
+        None => return Ok(false),
+    };
+    if !is_select_project(s_expr) {
+        return Ok(false);
+    }
+
+    let (table_index, table) = {
+        let metadata = metadata.read();
+        match metadata.tables() {
+            [entry] => (entry.index(), entry.table()),
+            _ => return Ok(false),
+        }
+    };
+    let fuse_table = match FuseTable::try_from_table(table.as_ref()) {
+        Ok(fuse_table) => fuse_table,
+        Err(_) => return Ok(false),
+    };
+    let base_location = match last_sources.snapshots.get(&table.get_id()) {
+        Some(Some(location)) => location,
+        _ => return Ok(false),
+    };
+
+    match fuse_table.navigate_to_appended_since(base_location).await? {
+        Some(appended) => {
+            metadata.write().replace_table(table_index, appended);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Whether the plan only filters and projects the rows of the scanned table,
+/// so the result of the appended rows could be appended to the view.
+fn is_select_project(s_expr: &SExpr) -> bool {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => scan.limit.is_none() && scan.order_by.is_none(),
+        RelOperator::EvalScalar(_) | RelOperator::Filter(_) | RelOperator::Exchange(_) => {
+            s_expr.children().iter().all(is_select_project)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::plan::MergeIntoDesc;
+use common_catalog::plan::MergeMatchedAction;
+use common_catalog::plan::MergeUnmatchedAction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchemaRef;
+use common_expression::Expr;
+use common_expression::RemoteExpr;
+use common_sql::executor::cast_expr_to_non_null_boolean;
+use common_sql::plans::MergeInto;
+use common_sql::plans::Plan;
+use common_sql::IndexType;
+use common_sql::ScalarExpr;
+
+use crate::interpreters::common::consume_streams;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Interprets the MERGE INTO statement.
+///
+/// The source is evaluated by a query pipeline, and the target table merges
+/// its output: the rows joined with the source are updated or deleted by the
+/// WHEN MATCHED clauses, and the source rows without a match are inserted by
+/// the WHEN NOT MATCHED clauses.
+pub struct MergeIntoInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: MergeInto,
+}
+
+impl MergeIntoInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: MergeInto) -> Result<InterpreterPtr> {
+        Ok(Arc::new(MergeIntoInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for MergeIntoInterpreter {
+    fn name(&self) -> &str {
+        "MergeIntoInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", name = "merge_into_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+
+        if table.get_table_info().meta.default_cluster_key_id.is_some() {
+            return Err(ErrorCode::StorageOther(
+                "merge into table with cluster key definition is not supported yet",
+            ));
+        }
+
+        let merge_into = self.build_merge_into_desc()?;
+        let mut build_res = self.build_source_pipeline().await?;
+        table
+            .merge_into(self.ctx.clone(), &mut build_res.main_pipeline, merge_into)
+            .await?;
+
+        consume_streams(self.ctx.clone(), &mut build_res.main_pipeline).await?;
+        Ok(build_res)
+    }
+}
+
+impl MergeIntoInterpreter {
+    #[async_backtrace::framed]
+    async fn build_source_pipeline(&self) -> Result<PipelineBuildResult> {
+        let (s_expr, metadata, bind_context, formatted_ast) = match self.plan.source.as_ref() {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                formatted_ast,
+                ..
+            } => (s_expr, metadata, bind_context, formatted_ast),
+            v => unreachable!("Source plan must be Query, but it's {}", v),
+        };
+
+        let select_interpreter = SelectInterpreter::try_create(
+            self.ctx.clone(),
+            *(bind_context.clone()),
+            *s_expr.clone(),
+            metadata.clone(),
+            formatted_ast.clone(),
+            false,
+        )?;
+        select_interpreter.execute2().await
+    }
+
+    // Lower the bound expressions to the offsets of the blocks they are evaluated on:
+    // the target columns followed by the source columns for the join condition and
+    // the WHEN MATCHED clauses, and the source columns only for the WHEN NOT MATCHED clauses.
+    fn build_merge_into_desc(&self) -> Result<MergeIntoDesc> {
+        let plan = &self.plan;
+        let target_len = plan.target_columns.len();
+        let target_offsets = plan
+            .target_columns
+            .iter()
+            .enumerate()
+            .map(|(offset, index)| (*index, offset))
+            .collect::<HashMap<_, _>>();
+        let source_offsets = plan
+            .source_columns
+            .iter()
+            .enumerate()
+            .map(|(offset, index)| (*index, offset))
+            .collect::<HashMap<_, _>>();
+        let mut join_offsets = target_offsets.clone();
+        join_offsets.extend(
+            source_offsets
+                .iter()
+                .map(|(index, offset)| (*index, target_len + offset)),
+        );
+
+        let (target_keys, source_keys) = plan
+            .join_keys
+            .iter()
+            .map(|(target_key, source_key)| {
+                Ok((
+                    lower_scalar(target_key, &target_offsets)?,
+                    lower_scalar(source_key, &source_offsets)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        let join_condition = lower_condition(&plan.join_condition, &join_offsets)?;
+
+        let matched = plan
+            .matched_evaluators
+            .iter()
+            .map(|evaluator| {
+                let condition = evaluator
+                    .condition
+                    .as_ref()
+                    .map(|condition| lower_condition(condition, &join_offsets))
+                    .transpose()?;
+                let update_list = evaluator
+                    .update
+                    .as_ref()
+                    .map(|update| {
+                        let mut update_list = update
+                            .iter()
+                            .map(|(field_index, scalar)| {
+                                Ok((*field_index, lower_scalar(scalar, &join_offsets)?))
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        update_list.sort_by_key(|(field_index, _)| *field_index);
+                        Ok::<_, ErrorCode>(update_list)
+                    })
+                    .transpose()?;
+                Ok(MergeMatchedAction {
+                    condition,
+                    update_list,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let unmatched = plan
+            .unmatched_evaluators
+            .iter()
+            .map(|evaluator| {
+                let condition = evaluator
+                    .condition
+                    .as_ref()
+                    .map(|condition| lower_condition(condition, &source_offsets))
+                    .transpose()?;
+                let values = evaluator
+                    .values
+                    .iter()
+                    .map(|value| lower_scalar(value, &source_offsets))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(MergeUnmatchedAction { condition, values })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(MergeIntoDesc {
+            target_keys,
+            source_keys,
+            join_condition,
+            matched,
+            unmatched,
+        })
+    }
+}
+
+fn lower_expr(scalar: &ScalarExpr, offsets: &HashMap<IndexType, usize>) -> Result<Expr> {
+    if let Some(index) = scalar
+        .used_columns()
+        .into_iter()
+        .find(|index| !offsets.contains_key(index))
+    {
+        return Err(ErrorCode::Internal(format!(
+            "column {index} is not available to merge into"
+        )));
+    }
+    Ok(scalar
+        .as_expr_with_col_index()?
+        .project_column_ref(|index| offsets[index]))
+}
+
+fn lower_scalar(scalar: &ScalarExpr, offsets: &HashMap<IndexType, usize>) -> Result<RemoteExpr> {
+    Ok(lower_expr(scalar, offsets)?.as_remote_expr())
+}
+
+fn lower_condition(scalar: &ScalarExpr, offsets: &HashMap<IndexType, usize>) -> Result<RemoteExpr> {
+    Ok(cast_expr_to_non_null_boolean(lower_expr(scalar, offsets)?)?.as_remote_expr())
+}
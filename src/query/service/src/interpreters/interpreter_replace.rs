@@ -25,6 +25,7 @@ use common_sql::plans::Plan;
 use common_sql::plans::Replace;
 use common_sql::NameResolutionContext;
 
+use crate::interpreters::common::consume_streams;
 use crate::interpreters::interpreter_insert::ValueSource;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -92,6 +93,8 @@ impl Interpreter for ReplaceInterpreter {
                 on_conflict_fields,
             )
             .await?;
+
+        consume_streams(self.ctx.clone(), &mut pipeline.main_pipeline).await?;
         Ok(pipeline)
     }
 }
@@ -0,0 +1,138 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::catalog::Catalog;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CloneTablePlan;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CloneTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CloneTablePlan,
+}
+
+impl CloneTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CloneTablePlan) -> Result<Self> {
+        Ok(CloneTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CloneTableInterpreter {
+    fn name(&self) -> &str {
+        "CloneTableInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        if plan.if_not_exists
+            && catalog
+                .exists_table(&plan.tenant, &plan.database, &plan.table)
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let source = catalog
+            .get_table(&plan.tenant, &plan.source_database, &plan.source_table)
+            .await?;
+        check_clone_source(source.as_ref())?;
+        let source = match &plan.navigation {
+            Some(navigation) => source.navigate_to(navigation).await?,
+            None => source,
+        };
+
+        clone_fuse_table(
+            &self.ctx,
+            catalog.as_ref(),
+            FuseTable::try_from_table(source.as_ref())?,
+            &plan.tenant,
+            &plan.database,
+            &plan.table,
+        )
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+pub(crate) fn check_clone_source(source: &dyn Table) -> Result<()> {
+    if FuseTable::try_from_table(source).is_err() {
+        return Err(ErrorCode::TableEngineNotSupported(format!(
+            "cannot clone table {} of engine {}, only fuse tables can be cloned",
+            source.name(),
+            source.engine()
+        )));
+    }
+    Ok(())
+}
+
+/// Create the table `database`.`table` as a clone of the fuse table `source`, which
+/// shares the data files of the source without copying them.
+pub(crate) async fn clone_fuse_table(
+    ctx: &Arc<QueryContext>,
+    catalog: &dyn Catalog,
+    source: &FuseTable,
+    tenant: &str,
+    database: &str,
+    table: &str,
+) -> Result<()> {
+    let db_id = catalog
+        .get_database(tenant, database)
+        .await?
+        .get_db_info()
+        .ident
+        .db_id;
+    let table_meta = source.build_clone_table_meta(db_id).await?;
+    catalog
+        .create_table(CreateTableReq {
+            if_not_exists: false,
+            name_ident: TableNameIdent {
+                tenant: tenant.to_string(),
+                db_name: database.to_string(),
+                table_name: table.to_string(),
+            },
+            table_meta,
+        })
+        .await?;
+
+    // The clone is dropped if it fails to share the data of the source.
+    let clone_table = catalog.get_table(tenant, database, table).await?;
+    let res = match FuseTable::try_from_table(clone_table.as_ref()) {
+        Ok(clone_fuse_table) => source.do_clone(ctx.as_ref(), clone_fuse_table).await,
+        Err(e) => Err(e),
+    };
+    if res.is_err() {
+        catalog
+            .drop_table_by_id(DropTableByIdReq {
+                if_exists: true,
+                tb_id: clone_table.get_table_info().ident.table_id,
+            })
+            .await?;
+    }
+    res
+}
@@ -23,9 +23,12 @@ use common_expression::DataSchemaRef;
 use common_expression::Scalar;
 use common_expression::Value;
 use common_sql::plans::ShowCreateTablePlan;
+use common_storages_stream::stream_table::StreamTable;
+use common_storages_stream::stream_table::STREAM_ENGINE;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_internal_opt_key;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
 use tracing::debug;
 
 use crate::interpreters::Interpreter;
@@ -93,6 +96,54 @@ impl Interpreter for ShowCreateTableInterpreter {
                 ));
             }
         }
+        if let Some(query) = table.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+            let view_create_sql = format!(
+                "CREATE MATERIALIZED VIEW `{}`.`{}` AS {}",
+                &self.plan.database, name, query
+            );
+            let block = DataBlock::new(
+                vec![
+                    BlockEntry {
+                        data_type: DataType::String,
+                        value: Value::Scalar(Scalar::String(name.as_bytes().to_vec())),
+                    },
+                    BlockEntry {
+                        data_type: DataType::String,
+                        value: Value::Scalar(Scalar::String(view_create_sql.into_bytes())),
+                    },
+                ],
+                1,
+            );
+            debug!("Show create materialized view executor result: {:?}", block);
+
+            return PipelineBuildResult::from_blocks(vec![block]);
+        }
+        if engine == STREAM_ENGINE {
+            let stream = StreamTable::try_from_table(table.as_ref())?;
+            let stream_create_sql = format!(
+                "CREATE STREAM `{}`.`{}` ON TABLE `{}`.`{}`",
+                &self.plan.database,
+                name,
+                stream.source_database(),
+                stream.source_table_name()
+            );
+            let block = DataBlock::new(
+                vec![
+                    BlockEntry {
+                        data_type: DataType::String,
+                        value: Value::Scalar(Scalar::String(name.as_bytes().to_vec())),
+                    },
+                    BlockEntry {
+                        data_type: DataType::String,
+                        value: Value::Scalar(Scalar::String(stream_create_sql.into_bytes())),
+                    },
+                ],
+                1,
+            );
+            debug!("Show create stream executor result: {:?}", block);
+
+            return PipelineBuildResult::from_blocks(vec![block]);
+        }
         let schema = table.schema();
         let field_comments = table.field_comments();
         let n_fields = schema.fields().len();
@@ -0,0 +1,78 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use tracing::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+
+    #[async_backtrace::framed]
+    async fn commit(&self) -> Result<()> {
+        let (catalog, req) = {
+            let txn_mgr = self.ctx.txn_mgr();
+            let txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_fail() {
+                return Err(ErrorCode::CurrentTransactionIsAborted(
+                    "current transaction is aborted, all the changes are rolled back",
+                ));
+            }
+            match txn_mgr.catalog() {
+                Some(catalog) => (catalog.to_string(), txn_mgr.req()),
+                None => return Ok(()),
+            }
+        };
+
+        info!(
+            "commit the transaction, updated tables: {:?}",
+            req.update_table_metas
+                .iter()
+                .map(|req| req.table_id)
+                .collect::<Vec<_>>()
+        );
+        let catalog = self.ctx.get_catalog(&catalog)?;
+        catalog.update_multi_table_meta(req).await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The transaction is ended whether the commit succeeds or not.
+        let res = self.commit().await;
+        self.ctx.txn_mgr().lock().clear();
+        res?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -28,6 +28,7 @@ use common_sql::ColumnBinding;
 use common_sql::ScalarExpr;
 use common_sql::Visibility;
 
+use crate::interpreters::common::consume_streams;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -145,6 +146,8 @@ impl Interpreter for UpdateInterpreter {
             &mut build_res.main_pipeline,
         )
         .await?;
+
+        consume_streams(self.ctx.clone(), &mut build_res.main_pipeline).await?;
         Ok(build_res)
     }
 }
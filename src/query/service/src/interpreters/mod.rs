@@ -22,6 +22,7 @@ mod interpreter_cluster_key_alter;
 mod interpreter_cluster_key_drop;
 mod interpreter_clustering_history;
 mod interpreter_copy;
+mod interpreter_database_clone;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_rename;
@@ -33,8 +34,14 @@ mod interpreter_factory;
 mod interpreter_file_format_create;
 mod interpreter_file_format_drop;
 mod interpreter_file_format_show;
+mod interpreter_index_create;
+mod interpreter_index_drop;
+mod interpreter_index_refresh;
 mod interpreter_insert;
 mod interpreter_kill;
+mod interpreter_materialized_view_create;
+mod interpreter_materialized_view_refresh;
+mod interpreter_merge_into;
 mod interpreter_metrics;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
@@ -62,8 +69,11 @@ mod interpreter_share_show;
 mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
+mod interpreter_stream_create;
+mod interpreter_stream_drop;
 mod interpreter_table_add_column;
 mod interpreter_table_analyze;
+mod interpreter_table_clone;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
@@ -76,6 +86,10 @@ mod interpreter_table_revert;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
+mod interpreter_table_vacuum;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
+mod interpreter_txn_rollback;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -100,6 +114,7 @@ pub use interpreter_call::CallInterpreter;
 pub use interpreter_cluster_key_alter::AlterTableClusterKeyInterpreter;
 pub use interpreter_cluster_key_drop::DropTableClusterKeyInterpreter;
 pub use interpreter_clustering_history::InterpreterClusteringHistory;
+pub use interpreter_database_clone::CloneDatabaseInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
@@ -108,8 +123,14 @@ pub use interpreter_database_undrop::UndropDatabaseInterpreter;
 pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
+pub use interpreter_index_create::CreateIndexInterpreter;
+pub use interpreter_index_drop::DropIndexInterpreter;
+pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_materialized_view_create::CreateMaterializedViewInterpreter;
+pub use interpreter_materialized_view_refresh::RefreshMaterializedViewInterpreter;
+pub use interpreter_merge_into::MergeIntoInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
@@ -134,8 +155,11 @@ pub use interpreter_share_show::ShowSharesInterpreter;
 pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
+pub use interpreter_stream_create::CreateStreamInterpreter;
+pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
+pub use interpreter_table_clone::CloneTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
@@ -147,6 +171,10 @@ pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
+pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
+pub use interpreter_txn_rollback::RollbackInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
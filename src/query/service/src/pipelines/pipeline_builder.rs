@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use async_channel::Receiver;
@@ -32,15 +33,18 @@ use common_expression::RemoteExpr;
 use common_expression::SortColumnDescription;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::AggregateMergeStateCombinator;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::pipe::PipeItem;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
+use common_pipeline_sinks::AsyncSinker;
 use common_pipeline_sinks::EmptySink;
 use common_pipeline_sinks::Sinker;
 use common_pipeline_sinks::UnionReceiveSink;
+use common_pipeline_sources::BlocksSource;
 use common_pipeline_transforms::processors::transforms::try_add_multi_sort_merge;
 use common_pipeline_transforms::processors::transforms::try_create_transform_sort_merge;
 use common_profile::ProfSpanSetRef;
@@ -57,9 +61,12 @@ use common_sql::executor::ExchangeSource;
 use common_sql::executor::Filter;
 use common_sql::executor::HashJoin;
 use common_sql::executor::Limit;
+use common_sql::executor::MergeJoin;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
 use common_sql::executor::ProjectSet;
+use common_sql::executor::RecursiveCte;
+use common_sql::executor::RecursiveCteScan;
 use common_sql::executor::RuntimeFilterSource;
 use common_sql::executor::Sort;
 use common_sql::executor::TableScan;
@@ -70,6 +77,7 @@ use common_sql::ColumnBinding;
 use common_sql::IndexType;
 use common_storage::DataOperator;
 use common_storages_fuse::operations::FillInternalColumnProcessor;
+use parking_lot::Mutex;
 use petgraph::matrix_graph::Zero;
 
 use super::processors::transforms::FrameBound;
@@ -81,17 +89,22 @@ use crate::api::ExchangeInjector;
 use crate::pipelines::processors::transforms::build_partition_bucket;
 use crate::pipelines::processors::transforms::AggregateInjector;
 use crate::pipelines::processors::transforms::FinalSingleStateAggregator;
+use crate::pipelines::processors::transforms::GraceHashJoinState;
 use crate::pipelines::processors::transforms::HashJoinDesc;
 use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
+use crate::pipelines::processors::transforms::RecursiveCteStates;
 use crate::pipelines::processors::transforms::RightSemiAntiJoinCompactor;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
+use crate::pipelines::processors::transforms::SinkGraceBuildHashTable;
 use crate::pipelines::processors::transforms::TransformAggregateSpillWriter;
 use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::TransformLeftJoin;
 use crate::pipelines::processors::transforms::TransformMarkJoin;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
+use crate::pipelines::processors::transforms::TransformMergeJoin;
 use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
+use crate::pipelines::processors::transforms::TransformRecursiveCte;
 use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
 use crate::pipelines::processors::transforms::TransformWindow;
@@ -108,6 +121,7 @@ use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::processors::TransformRuntimeFilter;
 use crate::pipelines::processors::TransformSortPartial;
+use crate::pipelines::processors::TransformSortSpill;
 use crate::pipelines::Pipeline;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -123,6 +137,8 @@ pub struct PipelineBuilder {
     pub join_state: Option<Arc<JoinHashTable>>,
     // record the index of join build side pipeline in `pipelines`
     pub index: Option<usize>,
+    // Working tables of the recursive CTEs being evaluated
+    pub recursive_cte_states: RecursiveCteStates,
 
     enable_profiling: bool,
     prof_span_set: ProfSpanSetRef,
@@ -144,6 +160,7 @@ impl PipelineBuilder {
             prof_span_set,
             exchange_injector: DefaultExchangeInjector::create(),
             index: None,
+            recursive_cte_states: RecursiveCteStates::new(),
         }
     }
 
@@ -179,6 +196,7 @@ impl PipelineBuilder {
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
             PhysicalPlan::HashJoin(join) => self.build_join(join),
+            PhysicalPlan::MergeJoin(join) => self.build_merge_join(join),
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
             PhysicalPlan::ExchangeSource(source) => self.build_exchange_source(source),
             PhysicalPlan::UnionAll(union_all) => self.build_union_all(union_all),
@@ -192,13 +210,31 @@ impl PipelineBuilder {
             PhysicalPlan::RuntimeFilterSource(runtime_filter_source) => {
                 self.build_runtime_filter_source(runtime_filter_source)
             }
+            PhysicalPlan::RecursiveCte(recursive_cte) => self.build_recursive_cte(recursive_cte),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
         }
     }
 
     fn build_join(&mut self, join: &HashJoin) -> Result<()> {
         let state = self.build_join_state(join)?;
-        self.expand_build_side_pipeline(&join.build, join, state.clone())?;
-        self.build_join_probe(join, state)
+        // The build side is partitioned and spilled to storage if it exceeds the memory threshold.
+        let grace_state = if GraceHashJoinState::is_supported(join)
+            && !self
+                .ctx
+                .get_settings()
+                .get_spilling_bytes_threshold_per_proc()?
+                .is_zero()
+        {
+            Some(GraceHashJoinState::try_create(
+                self.ctx.clone(),
+                join,
+                state.clone(),
+            )?)
+        } else {
+            None
+        };
+        self.expand_build_side_pipeline(&join.build, join, state.clone(), grace_state.clone())?;
+        self.build_join_probe(join, state, grace_state)
     }
 
     fn build_join_state(&mut self, join: &HashJoin) -> Result<Arc<JoinHashTable>> {
@@ -216,22 +252,30 @@ impl PipelineBuilder {
         build: &PhysicalPlan,
         hash_join_plan: &HashJoin,
         join_state: Arc<JoinHashTable>,
+        grace_state: Option<Arc<GraceHashJoinState>>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
-        let build_side_builder = PipelineBuilder::create(
+        let mut build_side_builder = PipelineBuilder::create(
             build_side_context,
             self.enable_profiling,
             self.prof_span_set.clone(),
         );
+        build_side_builder.recursive_cte_states = self.recursive_cte_states.clone();
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
 
         let create_sink_processor = |input| {
-            let transform = Sinker::<SinkBuildHashTable>::create(
-                input,
-                SinkBuildHashTable::try_create(join_state.clone())?,
-            );
+            let transform = match &grace_state {
+                Some(grace_state) => AsyncSinker::<SinkGraceBuildHashTable>::create(
+                    input,
+                    SinkGraceBuildHashTable::try_create(grace_state.clone())?,
+                ),
+                None => Sinker::<SinkBuildHashTable>::create(
+                    input,
+                    SinkBuildHashTable::try_create(join_state.clone())?,
+                ),
+            };
 
             if self.enable_profiling {
                 Ok(ProcessorPtr::create(ProfileWrapper::create(
@@ -533,6 +577,7 @@ impl PipelineBuilder {
             &aggregate.group_by,
             &aggregate.agg_funcs,
             None,
+            aggregate.from_agg_index(),
         )?;
 
         if params.group_columns.is_empty() {
@@ -663,6 +708,7 @@ impl PipelineBuilder {
             &aggregate.group_by,
             &aggregate.agg_funcs,
             aggregate.limit,
+            false,
         )?;
 
         if params.group_columns.is_empty() {
@@ -743,6 +789,7 @@ impl PipelineBuilder {
         group_by: &[IndexType],
         agg_funcs: &[AggregateFunctionDesc],
         limit: Option<usize>,
+        from_agg_index: bool,
     ) -> Result<Arc<AggregatorParams>> {
         let mut agg_args = Vec::with_capacity(agg_funcs.len());
         let (group_by, group_data_types) = group_by
@@ -759,11 +806,17 @@ impl PipelineBuilder {
             .iter()
             .map(|agg_func| {
                 agg_args.push(agg_func.args.clone());
-                AggregateFunctionFactory::instance().get(
+                let func = AggregateFunctionFactory::instance().get(
                     agg_func.sig.name.as_str(),
                     agg_func.sig.params.clone(),
                     agg_func.sig.args.clone(),
-                )
+                )?;
+                if from_agg_index {
+                    // Merge the partial states read from the aggregating index.
+                    Ok(AggregateMergeStateCombinator::create(func))
+                } else {
+                    Ok(func)
+                }
             })
             .collect::<Result<_>>()?;
 
@@ -941,16 +994,37 @@ impl PipelineBuilder {
             }
         })?;
 
-        // Merge
+        // Merge, sorted runs are spilled to storage if the sort exceeds the memory threshold.
+        // The sort with limit only keeps the top rows of each block, so it doesn't need to spill.
+        let spilling_bytes_threshold = self
+            .ctx
+            .get_settings()
+            .get_sort_spilling_bytes_threshold_per_proc()?;
+        let enable_spilling = limit.is_none() && spilling_bytes_threshold != 0;
+        let operator = DataOperator::instance().operator();
+        let location_prefix = format!("_sort_spill/{}", self.ctx.get_tenant());
         self.main_pipeline.add_transform(|input, output| {
-            let transform = try_create_transform_sort_merge(
-                input,
-                output,
-                input_schema.clone(),
-                block_size,
-                limit,
-                sort_desc.clone(),
-            )?;
+            let transform = if enable_spilling {
+                TransformSortSpill::try_create(
+                    input,
+                    output,
+                    operator.clone(),
+                    location_prefix.clone(),
+                    input_schema.clone(),
+                    block_size,
+                    sort_desc.clone(),
+                    spilling_bytes_threshold,
+                )?
+            } else {
+                try_create_transform_sort_merge(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    block_size,
+                    limit,
+                    sort_desc.clone(),
+                )?
+            };
 
             if self.enable_profiling {
                 Ok(ProcessorPtr::create(ProfileWrapper::create(
@@ -992,7 +1066,12 @@ impl PipelineBuilder {
         })
     }
 
-    fn build_join_probe(&mut self, join: &HashJoin, state: Arc<JoinHashTable>) -> Result<()> {
+    fn build_join_probe(
+        &mut self,
+        join: &HashJoin,
+        state: Arc<JoinHashTable>,
+        grace_state: Option<Arc<GraceHashJoinState>>,
+    ) -> Result<()> {
         self.build_pipeline(&join.probe)?;
 
         self.main_pipeline.add_transform(|input, output| {
@@ -1001,6 +1080,7 @@ impl PipelineBuilder {
                 input,
                 output,
                 state.clone(),
+                grace_state.clone(),
                 join.output_schema()?,
             )?;
 
@@ -1127,14 +1207,16 @@ impl PipelineBuilder {
         self.build_pipeline(&exchange_sink.input)
     }
 
-    fn expand_union_all(
+    /// Build the input in a separate pipeline, whose output blocks are sent to the receiver.
+    fn expand_to_receiver(
         &mut self,
         input: &PhysicalPlan,
-        union_plan: &UnionAll,
+        plan_id: u32,
     ) -> Result<Receiver<DataBlock>> {
         let union_ctx = QueryContext::create_from(self.ctx.clone());
-        let pipeline_builder =
+        let mut pipeline_builder =
             PipelineBuilder::create(union_ctx, self.enable_profiling, self.prof_span_set.clone());
+        pipeline_builder.recursive_cte_states = self.recursive_cte_states.clone();
         let mut build_res = pipeline_builder.finalize(input)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -1147,7 +1229,7 @@ impl PipelineBuilder {
             if self.enable_profiling {
                 Ok(ProcessorPtr::create(ProfileWrapper::create(
                     transform,
-                    union_plan.plan_id,
+                    plan_id,
                     self.prof_span_set.clone(),
                 )))
             } else {
@@ -1163,7 +1245,7 @@ impl PipelineBuilder {
 
     pub fn build_union_all(&mut self, union_all: &UnionAll) -> Result<()> {
         self.build_pipeline(&union_all.left)?;
-        let union_all_receiver = self.expand_union_all(&union_all.right, union_all)?;
+        let union_all_receiver = self.expand_to_receiver(&union_all.right, union_all.plan_id)?;
         self.main_pipeline
             .add_transform(|transform_input_port, transform_output_port| {
                 let transform = TransformMergeBlock::try_create(
@@ -1188,6 +1270,90 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_merge_join(&mut self, join: &MergeJoin) -> Result<()> {
+        // Both inputs are sorted, the right input is sent to the merge join
+        // processor through a channel in order.
+        self.build_pipeline(&join.left)?;
+        let right_receiver = self.expand_to_receiver(&join.right, join.plan_id)?;
+
+        self.main_pipeline.resize(1)?;
+        self.main_pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                let transform = TransformMergeJoin::try_create(
+                    self.ctx.clone(),
+                    transform_input_port,
+                    transform_output_port,
+                    join,
+                    right_receiver.clone(),
+                )?;
+
+                if self.enable_profiling {
+                    Ok(ProcessorPtr::create(ProfileWrapper::create(
+                        transform,
+                        join.plan_id,
+                        self.prof_span_set.clone(),
+                    )))
+                } else {
+                    Ok(ProcessorPtr::create(transform))
+                }
+            })
+    }
+
+    pub fn build_recursive_cte(&mut self, recursive_cte: &RecursiveCte) -> Result<()> {
+        self.build_pipeline(&recursive_cte.anchor)?;
+
+        let working_table = Arc::new(Mutex::new(VecDeque::new()));
+        self.recursive_cte_states
+            .insert(recursive_cte.cte_index, working_table.clone());
+
+        // Iterations of the recursive term are driven by a single processor.
+        self.main_pipeline.resize(1)?;
+        self.main_pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                let transform = TransformRecursiveCte::try_create(
+                    self.ctx.clone(),
+                    transform_input_port,
+                    transform_output_port,
+                    recursive_cte.anchor.output_schema()?,
+                    recursive_cte.recursive.as_ref().clone(),
+                    recursive_cte.pairs.clone(),
+                    recursive_cte.distinct,
+                    working_table.clone(),
+                    self.recursive_cte_states.clone(),
+                    self.enable_profiling,
+                    self.prof_span_set.clone(),
+                )?;
+
+                if self.enable_profiling {
+                    Ok(ProcessorPtr::create(ProfileWrapper::create(
+                        transform,
+                        recursive_cte.plan_id,
+                        self.prof_span_set.clone(),
+                    )))
+                } else {
+                    Ok(ProcessorPtr::create(transform))
+                }
+            })
+    }
+
+    pub fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let working_table = self
+            .recursive_cte_states
+            .get(&scan.cte_index)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Cannot find the working table of recursive CTE {}",
+                    scan.cte_index
+                ))
+            })?;
+
+        self.main_pipeline.add_source(
+            |output| BlocksSource::create(self.ctx.clone(), output, working_table.clone()),
+            1,
+        )
+    }
+
     pub fn build_distributed_insert_select(
         &mut self,
         insert_select: &DistributedInsertSelect,
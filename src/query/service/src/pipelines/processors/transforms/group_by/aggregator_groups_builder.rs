@@ -82,7 +82,10 @@ pub struct SerializedKeysGroupColumnsBuilder<'a> {
 impl<'a> SerializedKeysGroupColumnsBuilder<'a> {
     pub fn create(capacity: usize, data_capacity: usize, params: &AggregatorParams) -> Self {
         let (single_builder, data) = if params.group_data_types.len() == 1
-            && (params.group_data_types[0].is_string() || params.group_data_types[0].is_variant())
+            && (params.group_data_types[0].is_string()
+                || params.group_data_types[0].is_binary()
+                || params.group_data_types[0].is_bitmap()
+                || params.group_data_types[0].is_variant())
         {
             (
                 Some(StringColumnBuilder::with_capacity(capacity, data_capacity)),
@@ -118,6 +121,8 @@ impl<'a> GroupColumnsBuilder for SerializedKeysGroupColumnsBuilder<'a> {
             let col = builder.build();
             match self.group_data_types[0] {
                 DataType::String => return Ok(vec![Column::String(col)]),
+                DataType::Binary => return Ok(vec![Column::Binary(col)]),
+                DataType::Bitmap => return Ok(vec![Column::Bitmap(col)]),
                 DataType::Variant => return Ok(vec![Column::Variant(col)]),
                 _ => {}
             }
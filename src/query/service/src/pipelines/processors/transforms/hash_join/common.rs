@@ -367,7 +367,7 @@ impl JoinHashTable {
         let mut data_block = data_block;
         if matches!(
             self.hash_join_desc.join_type,
-            JoinType::Left | JoinType::Full
+            JoinType::Left | JoinType::Full | JoinType::LeftAsof
         ) {
             let mut validity = MutableBitmap::new();
             validity.extend_constant(data_block.num_rows(), true);
@@ -23,6 +23,7 @@ use common_sql::executor::HashJoin;
 use parking_lot::RwLock;
 
 use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
+use crate::sql::plans::ComparisonOp;
 use crate::sql::plans::JoinType;
 
 pub const JOIN_MAX_BLOCK_SIZE: usize = 65535;
@@ -58,12 +59,20 @@ impl JoinState {
     }
 }
 
+/// Match condition of asof join, `probe_key op build_key`.
+pub struct AsofJoinDesc {
+    pub(crate) probe_key: Expr,
+    pub(crate) build_key: Expr,
+    pub(crate) op: ComparisonOp,
+}
+
 pub struct HashJoinDesc {
     pub(crate) build_keys: Vec<Expr>,
     pub(crate) probe_keys: Vec<Expr>,
     pub(crate) join_type: JoinType,
     pub(crate) other_predicate: Option<Expr>,
     pub(crate) marker_join_desc: MarkJoinDesc,
+    pub(crate) asof_join_desc: Option<AsofJoinDesc>,
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
     pub(crate) join_state: JoinState,
@@ -93,6 +102,14 @@ impl HashJoinDesc {
                 has_null: RwLock::new(false),
                 // marker_index: join.marker_index,
             },
+            asof_join_desc: join
+                .asof_condition
+                .as_ref()
+                .map(|asof_condition| AsofJoinDesc {
+                    probe_key: asof_condition.probe_key.as_expr(&BUILTIN_FUNCTIONS),
+                    build_key: asof_condition.build_key.as_expr(&BUILTIN_FUNCTIONS),
+                    op: asof_condition.op,
+                }),
             from_correlated_subquery: join.from_correlated_subquery,
             join_state: JoinState::create()?,
         })
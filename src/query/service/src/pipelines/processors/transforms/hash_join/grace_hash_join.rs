@@ -0,0 +1,523 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use common_base::base::tokio::sync::Notify;
+use common_base::base::GlobalUniqName;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::with_hash_method;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::HashMethod;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_hashtable::FastHash;
+use common_sql::executor::HashJoin;
+use common_storage::DataOperator;
+use opendal::Operator;
+use tracing::error;
+use tracing::info;
+
+use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
+use crate::pipelines::processors::HashJoinState;
+use crate::pipelines::processors::HashTable;
+use crate::pipelines::processors::JoinHashTable;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::JoinType;
+
+/// The build and probe data are divided into `1 << SPILL_PARTITION_BITS` partitions
+/// by the hash of the join keys.
+const SPILL_PARTITION_BITS: usize = 3;
+const SPILL_PARTITIONS: usize = 1 << SPILL_PARTITION_BITS;
+/// The spilled partitions that still exceed the threshold are partitioned again
+/// with the next bits of the hash, until this level is reached.
+const MAX_SPILL_LEVEL: usize = 4;
+
+#[derive(Clone)]
+pub struct SpilledFile {
+    location: String,
+    columns_layout: Vec<usize>,
+}
+
+/// A partition whose build and probe data have been written to storage.
+pub struct SpilledPartition {
+    /// The number of hash bit groups that have been used to divide the partition.
+    level: usize,
+    build_files: Vec<SpilledFile>,
+    probe_files: Vec<SpilledFile>,
+}
+
+/// GraceHashJoinState is shared by the build sinks and the probe processors of a hash join
+/// whose build side may not fit in memory.
+///
+/// The first partition of the build side is always kept in the in-memory hash table, the other
+/// partitions are buffered and written to storage once the buffered bytes exceed the threshold.
+/// If the build side has been spilled, the probe rows of the spilled partitions are written to
+/// storage too, and each spilled partition is joined after all the probe inputs are finished.
+pub struct GraceHashJoinState {
+    ctx: Arc<QueryContext>,
+    hash_join: HashJoin,
+    join_table: Arc<JoinHashTable>,
+    operator: Operator,
+    location_prefix: String,
+    pub(crate) spilling_bytes_threshold: usize,
+
+    build_ref_count: Mutex<usize>,
+    /// Build blocks left in the buffers of the finished build sinks.
+    pending_build_blocks: Mutex<Vec<Vec<DataBlock>>>,
+    build_spilled: AtomicBool,
+    build_files: Mutex<Vec<Vec<SpilledFile>>>,
+
+    probe_ref_count: Mutex<usize>,
+    probe_files: Mutex<Vec<Vec<SpilledFile>>>,
+    partitions: Mutex<VecDeque<SpilledPartition>>,
+    probe_finished: Mutex<bool>,
+    probe_finished_notify: Arc<Notify>,
+    interrupted: AtomicBool,
+}
+
+impl GraceHashJoinState {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        hash_join: &HashJoin,
+        join_table: Arc<JoinHashTable>,
+    ) -> Result<Arc<GraceHashJoinState>> {
+        let spilling_bytes_threshold =
+            ctx.get_settings().get_spilling_bytes_threshold_per_proc()?;
+        let location_prefix = format!("_hash_join_spill/{}", ctx.get_tenant());
+        Ok(Arc::new(GraceHashJoinState {
+            ctx,
+            hash_join: hash_join.clone(),
+            join_table,
+            operator: DataOperator::instance().operator(),
+            location_prefix,
+            spilling_bytes_threshold,
+            build_ref_count: Mutex::new(0),
+            pending_build_blocks: Mutex::new(vec![vec![]; SPILL_PARTITIONS]),
+            build_spilled: AtomicBool::new(false),
+            build_files: Mutex::new(vec![vec![]; SPILL_PARTITIONS]),
+            probe_ref_count: Mutex::new(0),
+            probe_files: Mutex::new(vec![vec![]; SPILL_PARTITIONS]),
+            partitions: Mutex::new(VecDeque::new()),
+            probe_finished: Mutex::new(false),
+            probe_finished_notify: Arc::new(Notify::new()),
+            interrupted: AtomicBool::new(false),
+        }))
+    }
+
+    /// Only the joins whose results are determined by each probe block alone can be
+    /// joined partition by partition.
+    pub fn is_supported(hash_join: &HashJoin) -> bool {
+        matches!(
+            hash_join.join_type,
+            JoinType::Inner | JoinType::LeftSemi | JoinType::LeftAnti
+        ) && !hash_join.contain_runtime_filter
+    }
+
+    pub fn partitions_num() -> usize {
+        SPILL_PARTITIONS
+    }
+
+    pub fn is_build_spilled(&self) -> bool {
+        self.build_spilled.load(Ordering::Acquire)
+    }
+
+    /// Divide the block into partitions by the hash of the build keys or the probe keys.
+    pub fn partition_block(
+        &self,
+        block: &DataBlock,
+        build_side: bool,
+        level: usize,
+    ) -> Result<Vec<DataBlock>> {
+        let desc = &self.join_table.hash_join_desc;
+        let key_exprs: &[Expr] = if build_side {
+            &desc.build_keys
+        } else {
+            &desc.probe_keys
+        };
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let keys = key_exprs
+            .iter()
+            .map(|expr| {
+                let return_type = expr.data_type();
+                Ok((
+                    evaluator
+                        .run(expr)?
+                        .convert_to_full_column(return_type, block.num_rows()),
+                    return_type.clone(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let indices = with_hash_method!(|T| match &*self.join_table.hash_table.read() {
+            HashTable::T(table) => {
+                let keys_state = table
+                    .hash_method
+                    .build_keys_state(&keys, block.num_rows())?;
+                table
+                    .hash_method
+                    .build_keys_iter(&keys_state)?
+                    .map(|key| partition_index(key.fast_hash(), level))
+                    .collect::<Vec<_>>()
+            }
+        });
+
+        DataBlock::scatter(block, &indices, SPILL_PARTITIONS)
+    }
+
+    pub fn attach_build(&self) -> Result<()> {
+        *self.build_ref_count.lock().unwrap() += 1;
+        self.join_table.attach()
+    }
+
+    /// Add the resident partition of the build side into the in-memory hash table.
+    pub fn build(&self, block: DataBlock) -> Result<()> {
+        self.join_table.build(block)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn spill_build(&self, partitions: Vec<Vec<DataBlock>>) -> Result<()> {
+        self.build_spilled.store(true, Ordering::Release);
+        let files = self.write_partitions(partitions).await?;
+        register_files(&self.build_files, files);
+        Ok(())
+    }
+
+    /// Called by each build sink when its input is finished. The last one decides whether the
+    /// remaining buffered blocks are spilled or added into the in-memory hash table.
+    #[async_backtrace::framed]
+    pub async fn detach_build(&self, partitions: Vec<Vec<DataBlock>>) -> Result<()> {
+        let pending_blocks = {
+            let mut pending_blocks = self.pending_build_blocks.lock().unwrap();
+            for (index, blocks) in partitions.into_iter().enumerate() {
+                pending_blocks[index].extend(blocks);
+            }
+
+            let mut count = self.build_ref_count.lock().unwrap();
+            *count -= 1;
+            match *count == 0 {
+                true => Some(std::mem::take(&mut *pending_blocks)),
+                false => None,
+            }
+        };
+
+        if let Some(pending_blocks) = pending_blocks {
+            if self.is_build_spilled() {
+                self.spill_build(pending_blocks).await?;
+            } else {
+                for block in pending_blocks.into_iter().flatten() {
+                    self.join_table.build(block)?;
+                }
+            }
+        }
+
+        self.join_table.detach()
+    }
+
+    pub fn attach_probe(&self) -> Result<()> {
+        *self.probe_ref_count.lock().unwrap() += 1;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn spill_probe(&self, partitions: Vec<Vec<DataBlock>>) -> Result<()> {
+        let files = self.write_partitions(partitions).await?;
+        register_files(&self.probe_files, files);
+        Ok(())
+    }
+
+    /// Called by each probe processor when its input is finished. The last one collects
+    /// the spilled partitions that need to be joined.
+    #[async_backtrace::framed]
+    pub async fn detach_probe(&self, partitions: Vec<Vec<DataBlock>>) -> Result<()> {
+        self.spill_probe(partitions).await?;
+
+        let is_last = {
+            let mut count = self.probe_ref_count.lock().unwrap();
+            *count -= 1;
+            *count == 0
+        };
+        if !is_last {
+            return Ok(());
+        }
+
+        let build_files = std::mem::take(&mut *self.build_files.lock().unwrap());
+        let probe_files = std::mem::take(&mut *self.probe_files.lock().unwrap());
+        let mut unused_files = vec![];
+        {
+            let mut spilled_partitions = self.partitions.lock().unwrap();
+            for (build_files, probe_files) in build_files.into_iter().zip(probe_files) {
+                // The join only outputs rows driven by the probe side.
+                if probe_files.is_empty() {
+                    unused_files.extend(build_files);
+                    continue;
+                }
+                spilled_partitions.push_back(SpilledPartition {
+                    level: 1,
+                    build_files,
+                    probe_files,
+                });
+            }
+        }
+        for file in unused_files {
+            self.delete_spilled_file(&file).await;
+        }
+
+        *self.probe_finished.lock().unwrap() = true;
+        self.probe_finished_notify.notify_waiters();
+        Ok(())
+    }
+
+    /// Wait until all the probe processors have spilled their probe data.
+    #[async_backtrace::framed]
+    pub async fn wait_probe_finish(&self) -> Result<()> {
+        let notified = {
+            let finished_guard = self.probe_finished.lock().unwrap();
+
+            match *finished_guard || self.interrupted.load(Ordering::Acquire) {
+                true => None,
+                false => Some(self.probe_finished_notify.notified()),
+            }
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+
+        if self.interrupted.load(Ordering::Acquire) {
+            return Err(ErrorCode::AbortedQuery(
+                "Aborted query, because the hash join is interrupted.",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Wake up the probe processors waiting for the others, they will never finish
+    /// once the query is aborted.
+    pub fn interrupt(&self) {
+        let _finished_guard = self.probe_finished.lock().unwrap();
+        self.interrupted.store(true, Ordering::Release);
+        self.probe_finished_notify.notify_waiters();
+    }
+
+    pub fn next_partition(&self) -> Option<SpilledPartition> {
+        self.partitions.lock().unwrap().pop_front()
+    }
+
+    /// Read back the build side of the spilled partition. Returns the hash table of the partition
+    /// and the probe files to join with, or `None` if the partition is too large and has been
+    /// divided into smaller partitions.
+    #[async_backtrace::framed]
+    pub async fn restore_partition(
+        &self,
+        partition: SpilledPartition,
+    ) -> Result<Option<(Arc<JoinHashTable>, Vec<SpilledFile>)>> {
+        let mut build_blocks = Vec::with_capacity(partition.build_files.len());
+        let mut build_bytes = 0;
+        for file in partition.build_files.iter() {
+            let block = self.read_spilled_file(file).await?;
+            build_bytes += block.memory_size();
+            build_blocks.push(block);
+        }
+
+        if build_bytes > self.spilling_bytes_threshold && partition.level < MAX_SPILL_LEVEL {
+            self.repartition(partition.level, build_blocks, partition.probe_files)
+                .await?;
+            return Ok(None);
+        }
+
+        let partition_table = JoinHashTable::create_join_state(
+            self.ctx.clone(),
+            &self.hash_join.build_keys,
+            self.hash_join.build.output_schema()?,
+            self.hash_join.probe.output_schema()?,
+            HashJoinDesc::create(&self.hash_join)?,
+        )?;
+        partition_table.attach()?;
+        for block in build_blocks {
+            partition_table.build(block)?;
+        }
+        partition_table.detach()?;
+
+        Ok(Some((partition_table, partition.probe_files)))
+    }
+
+    async fn repartition(
+        &self,
+        level: usize,
+        build_blocks: Vec<DataBlock>,
+        probe_files: Vec<SpilledFile>,
+    ) -> Result<()> {
+        info!(
+            "Hash join spilled partition of level {} exceeds the threshold, partition it again",
+            level
+        );
+
+        let mut build_partitions = vec![vec![]; SPILL_PARTITIONS];
+        for block in build_blocks {
+            for (index, block) in self
+                .partition_block(&block, true, level)?
+                .into_iter()
+                .enumerate()
+            {
+                if !block.is_empty() {
+                    build_partitions[index].push(block);
+                }
+            }
+        }
+        let build_files = self.write_partitions(build_partitions).await?;
+
+        let mut sub_probe_files = vec![vec![]; SPILL_PARTITIONS];
+        for file in probe_files.iter() {
+            let block = self.read_spilled_file(file).await?;
+            let probe_partitions = self
+                .partition_block(&block, false, level)?
+                .into_iter()
+                .map(|block| vec![block])
+                .collect();
+            let files = self.write_partitions(probe_partitions).await?;
+            for (index, files) in files.into_iter().enumerate() {
+                sub_probe_files[index].extend(files);
+            }
+        }
+
+        let mut unused_files = vec![];
+        {
+            let mut spilled_partitions = self.partitions.lock().unwrap();
+            for (build_files, probe_files) in build_files.into_iter().zip(sub_probe_files) {
+                if probe_files.is_empty() {
+                    unused_files.extend(build_files);
+                    continue;
+                }
+                spilled_partitions.push_back(SpilledPartition {
+                    level: level + 1,
+                    build_files,
+                    probe_files,
+                });
+            }
+        }
+        for file in unused_files {
+            self.delete_spilled_file(&file).await;
+        }
+        Ok(())
+    }
+
+    async fn write_partitions(
+        &self,
+        partitions: Vec<Vec<DataBlock>>,
+    ) -> Result<Vec<Vec<SpilledFile>>> {
+        let mut files = Vec::with_capacity(partitions.len());
+        for blocks in partitions {
+            let blocks = blocks
+                .into_iter()
+                .filter(|block| !block.is_empty())
+                .collect::<Vec<_>>();
+            if blocks.is_empty() {
+                files.push(vec![]);
+                continue;
+            }
+
+            let instant = Instant::now();
+            let block = DataBlock::concat(&blocks)?;
+            let num_rows = block.num_rows();
+            let mut columns_layout = Vec::with_capacity(block.num_columns());
+            let mut data = vec![];
+            for entry in block.columns() {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                let column_data = serialize_column(&column);
+                columns_layout.push(column_data.len());
+                data.extend(column_data);
+            }
+
+            let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+            self.operator.write(&location, data).await?;
+
+            info!(
+                "Write hash join spill {} successfully, elapsed: {:?}",
+                location,
+                instant.elapsed()
+            );
+
+            files.push(vec![SpilledFile {
+                location,
+                columns_layout,
+            }]);
+        }
+        Ok(files)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_spilled_file(&self, file: &SpilledFile) -> Result<DataBlock> {
+        let instant = Instant::now();
+        let data = self.operator.read(&file.location).await?;
+        self.delete_spilled_file(file).await;
+
+        info!(
+            "Read hash join spill {} successfully, elapsed: {:?}",
+            &file.location,
+            instant.elapsed()
+        );
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(file.columns_layout.len());
+        for column_layout in file.columns_layout.iter() {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "Cannot deserialize hash join spill file {}",
+                        file.location
+                    ))
+                })?;
+            columns.push(column);
+            begin += column_layout;
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+
+    async fn delete_spilled_file(&self, file: &SpilledFile) {
+        if let Err(cause) = self.operator.delete(&file.location).await {
+            error!(
+                "Cannot delete spill file {}, cause: {:?}",
+                &file.location, cause
+            );
+        }
+    }
+}
+
+// Use the high bits of the lower 32 bits, the lowest bits are used by the hash table itself.
+fn partition_index(hash: u64, level: usize) -> u64 {
+    let shift = 32 - (level + 1) * SPILL_PARTITION_BITS;
+    ((hash as u32) >> shift) as u64 & (SPILL_PARTITIONS as u64 - 1)
+}
+
+fn register_files(registry: &Mutex<Vec<Vec<SpilledFile>>>, files: Vec<Vec<SpilledFile>>) {
+    let mut registry = registry.lock().unwrap();
+    for (index, files) in files.into_iter().enumerate() {
+        registry[index].extend(files);
+    }
+}
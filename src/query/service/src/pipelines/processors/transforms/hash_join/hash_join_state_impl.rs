@@ -62,7 +62,9 @@ impl HashJoinState for JoinHashTable {
             | JoinType::RightMark
             | JoinType::Single
             | JoinType::Right
-            | JoinType::Full => self.probe_join(input, probe_state),
+            | JoinType::Full
+            | JoinType::Asof
+            | JoinType::LeftAsof => self.probe_join(input, probe_state),
             JoinType::Cross => self.probe_cross_join(input, probe_state),
         }
     }
@@ -196,6 +196,7 @@ impl JoinHashTable {
     ) -> Result<Self> {
         if hash_join_desc.join_type == JoinType::Left
             || hash_join_desc.join_type == JoinType::Single
+            || hash_join_desc.join_type == JoinType::LeftAsof
         {
             build_data_schema = build_schema_wrap_nullable(&build_data_schema);
         };
@@ -14,6 +14,7 @@
 
 mod common;
 mod desc;
+mod grace_hash_join;
 mod hash_join_state;
 mod hash_join_state_impl;
 mod join_hash_table;
@@ -24,6 +25,9 @@ pub(crate) mod row;
 mod util;
 
 pub use desc::HashJoinDesc;
+pub use grace_hash_join::GraceHashJoinState;
+pub use grace_hash_join::SpilledFile;
+pub use grace_hash_join::SpilledPartition;
 pub use hash_join_state::HashJoinState;
 pub use join_hash_table::FixedKeyHashTable;
 pub use join_hash_table::HashTable;
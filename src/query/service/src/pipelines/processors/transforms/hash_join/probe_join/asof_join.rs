@@ -0,0 +1,262 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter::TrustedLen;
+use std::sync::atomic::Ordering;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_hashtable::HashtableEntryRefLike;
+use common_hashtable::HashtableLike;
+
+use crate::pipelines::processors::transforms::hash_join::desc::AsofJoinDesc;
+use crate::pipelines::processors::transforms::hash_join::desc::JOIN_MAX_BLOCK_SIZE;
+use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
+use crate::pipelines::processors::transforms::hash_join::ProbeState;
+use crate::pipelines::processors::JoinHashTable;
+use crate::sql::plans::ComparisonOp;
+use crate::sql::plans::JoinType;
+
+impl JoinHashTable {
+    pub(crate) fn probe_asof_join<'a, H: HashtableLike<Value = Vec<RowPtr>>, IT>(
+        &self,
+        hash_table: &H,
+        probe_state: &mut ProbeState,
+        keys_iter: IT,
+        input: &DataBlock,
+    ) -> Result<Vec<DataBlock>>
+    where
+        IT: Iterator<Item = &'a H::Key> + TrustedLen,
+        H::Key: 'a,
+    {
+        let asof_join_desc = self
+            .hash_join_desc
+            .asof_join_desc
+            .as_ref()
+            .ok_or_else(|| ErrorCode::Internal("Asof join must have a match condition"))?;
+
+        let valids = &probe_state.valids;
+        let data_blocks = self.row_space.datablocks();
+        let num_rows = data_blocks
+            .iter()
+            .fold(0, |acc, chunk| acc + chunk.num_rows());
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(input, &func_ctx, &BUILTIN_FUNCTIONS);
+        let probe_match_keys = evaluator
+            .run(&asof_join_desc.probe_key)?
+            .convert_to_full_column(asof_join_desc.probe_key.data_type(), input.num_rows());
+
+        // The closest build row (and its match key) found so far for each probe row.
+        let mut matched_build_indexes: Vec<Option<RowPtr>> = vec![None; input.num_rows()];
+        let mut matched_keys = vec![Scalar::Null; input.num_rows()];
+
+        let mut probe_indexes = Vec::with_capacity(JOIN_MAX_BLOCK_SIZE);
+        let mut build_indexes = Vec::with_capacity(JOIN_MAX_BLOCK_SIZE);
+
+        for (i, key) in keys_iter.enumerate() {
+            // A probe row with null match key can't match any build row.
+            if unsafe { probe_match_keys.index_unchecked(i) } == ScalarRef::Null {
+                continue;
+            }
+
+            // If the join is derived from correlated subquery, then null equality is safe.
+            let probe_result_ptr = if self.hash_join_desc.from_correlated_subquery {
+                hash_table.entry(key)
+            } else {
+                self.probe_key(hash_table, key, valids, i)
+            };
+
+            if let Some(v) = probe_result_ptr {
+                for row_ptr in v.get() {
+                    probe_indexes.push(i);
+                    build_indexes.push(*row_ptr);
+
+                    if build_indexes.len() == JOIN_MAX_BLOCK_SIZE {
+                        if self.interrupt.load(Ordering::Relaxed) {
+                            return Err(ErrorCode::AbortedQuery(
+                                "Aborted query, because the server is shutting down or the query was killed.",
+                            ));
+                        }
+
+                        self.update_asof_matches(
+                            asof_join_desc,
+                            &probe_match_keys,
+                            &probe_indexes,
+                            &build_indexes,
+                            &data_blocks,
+                            &num_rows,
+                            &mut matched_build_indexes,
+                            &mut matched_keys,
+                        )?;
+                        probe_indexes.clear();
+                        build_indexes.clear();
+                    }
+                }
+            }
+        }
+
+        if !build_indexes.is_empty() {
+            self.update_asof_matches(
+                asof_join_desc,
+                &probe_match_keys,
+                &probe_indexes,
+                &build_indexes,
+                &data_blocks,
+                &num_rows,
+                &mut matched_build_indexes,
+                &mut matched_keys,
+            )?;
+        }
+
+        self.asof_join_result(input, &matched_build_indexes, &data_blocks, &num_rows)
+    }
+
+    // Evaluate the match condition on candidate rows, and keep the closest build row for each probe row.
+    #[allow(clippy::too_many_arguments)]
+    fn update_asof_matches(
+        &self,
+        asof_join_desc: &AsofJoinDesc,
+        probe_match_keys: &Column,
+        probe_indexes: &[usize],
+        build_indexes: &[RowPtr],
+        data_blocks: &Vec<DataBlock>,
+        num_rows: &usize,
+        matched_build_indexes: &mut [Option<RowPtr>],
+        matched_keys: &mut [Scalar],
+    ) -> Result<()> {
+        let build_block = self
+            .row_space
+            .gather(build_indexes, data_blocks, num_rows)?;
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(&build_block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let build_match_keys = evaluator
+            .run(&asof_join_desc.build_key)?
+            .convert_to_full_column(asof_join_desc.build_key.data_type(), build_block.num_rows());
+
+        for (idx, (probe_index, build_index)) in
+            probe_indexes.iter().zip(build_indexes.iter()).enumerate()
+        {
+            let build_key = unsafe { build_match_keys.index_unchecked(idx) };
+            if build_key == ScalarRef::Null {
+                continue;
+            }
+            let probe_key = unsafe { probe_match_keys.index_unchecked(*probe_index) };
+            let satisfied = match asof_join_desc.op {
+                ComparisonOp::GT => probe_key > build_key,
+                ComparisonOp::GTE => probe_key >= build_key,
+                ComparisonOp::LT => probe_key < build_key,
+                ComparisonOp::LTE => probe_key <= build_key,
+                _ => false,
+            };
+            if !satisfied {
+                continue;
+            }
+
+            // The closest build row has the greatest key for `>` and `>=`, and the least key for `<` and `<=`.
+            let closer = matched_build_indexes[*probe_index].is_none()
+                || match asof_join_desc.op {
+                    ComparisonOp::GT | ComparisonOp::GTE => {
+                        build_key > matched_keys[*probe_index].as_ref()
+                    }
+                    _ => build_key < matched_keys[*probe_index].as_ref(),
+                };
+            if closer {
+                matched_build_indexes[*probe_index] = Some(*build_index);
+                matched_keys[*probe_index] = build_key.to_owned();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn asof_join_result(
+        &self,
+        input: &DataBlock,
+        matched_build_indexes: &[Option<RowPtr>],
+        data_blocks: &Vec<DataBlock>,
+        num_rows: &usize,
+    ) -> Result<Vec<DataBlock>> {
+        if self.hash_join_desc.join_type == JoinType::Asof {
+            let mut probe_indexes = Vec::with_capacity(input.num_rows());
+            let mut build_indexes = Vec::with_capacity(input.num_rows());
+            for (i, row_ptr) in matched_build_indexes.iter().enumerate() {
+                if let Some(row_ptr) = row_ptr {
+                    probe_indexes.push(i as u32);
+                    build_indexes.push(*row_ptr);
+                }
+            }
+
+            return Ok(vec![
+                self.merge_eq_block(
+                    &self
+                        .row_space
+                        .gather(&build_indexes, data_blocks, num_rows)?,
+                    &DataBlock::take(input, &probe_indexes)?,
+                )?,
+            ]);
+        }
+
+        // For left asof join, every probe row is kept, and the build columns of unmatched rows are null.
+        let placeholder = matched_build_indexes.iter().flatten().next().cloned();
+        let build_block = match placeholder {
+            None => {
+                let columns = self
+                    .row_space
+                    .data_schema
+                    .fields()
+                    .iter()
+                    .map(|field| BlockEntry {
+                        data_type: field.data_type().clone(),
+                        value: Value::Scalar(Scalar::Null),
+                    })
+                    .collect::<Vec<_>>();
+                DataBlock::new(columns, input.num_rows())
+            }
+            Some(placeholder) => {
+                let mut validity = MutableBitmap::with_capacity(input.num_rows());
+                let build_indexes = matched_build_indexes
+                    .iter()
+                    .map(|row_ptr| {
+                        validity.push(row_ptr.is_some());
+                        row_ptr.unwrap_or(placeholder)
+                    })
+                    .collect::<Vec<_>>();
+                let build_block = self
+                    .row_space
+                    .gather(&build_indexes, data_blocks, num_rows)?;
+                let validity: Bitmap = validity.into();
+                let nullable_columns = build_block
+                    .columns()
+                    .iter()
+                    .map(|c| Self::set_validity(c, validity.len(), &validity))
+                    .collect::<Vec<_>>();
+                DataBlock::new(nullable_columns, validity.len())
+            }
+        };
+
+        Ok(vec![self.merge_eq_block(&build_block, input)?])
+    }
+}
@@ -58,6 +58,9 @@ impl JoinHashTable {
             JoinType::Right => {
                 self.probe_right_join::<_, _>(hash_table, probe_state, keys_iter, input)
             }
+            JoinType::Asof | JoinType::LeftAsof => {
+                self.probe_asof_join(hash_table, probe_state, keys_iter, input)
+            }
             // Three cases will produce Mark join:
             // 1. uncorrelated ANY subquery: only have one kind of join condition, equi-condition or non-equi-condition.
             // 2. correlated ANY subquery: must have two kinds of join condition, one is equi-condition and the other is non-equi-condition.
@@ -27,11 +27,14 @@ mod profile_wrapper;
 mod runtime_filter;
 mod transform_add_const_columns;
 mod transform_merge_block;
+mod transform_merge_join;
+mod transform_recursive_cte;
 mod transform_resort_addon;
 mod transform_right_join;
 mod transform_right_semi_anti_join;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_sort_spill;
 
 pub use aggregator::build_partition_bucket;
 pub use aggregator::AggregateInjector;
@@ -58,6 +61,7 @@ use common_pipeline_transforms::processors::transforms::transform_compact;
 use common_pipeline_transforms::processors::transforms::transform_sort_merge;
 use common_pipeline_transforms::processors::transforms::transform_sort_partial;
 pub use hash_join::FixedKeyHashTable;
+pub use hash_join::GraceHashJoinState;
 pub use hash_join::HashJoinDesc;
 pub use hash_join::HashJoinState;
 pub use hash_join::HashTable;
@@ -74,6 +78,7 @@ pub use transform_compact::TransformCompact;
 pub use transform_create_sets::SubqueryReceiver;
 pub use transform_create_sets::TransformCreateSets;
 pub use transform_hash_join::SinkBuildHashTable;
+pub use transform_hash_join::SinkGraceBuildHashTable;
 pub use transform_hash_join::TransformHashJoinProbe;
 pub use transform_left_join::LeftJoinCompactor;
 pub use transform_left_join::TransformLeftJoin;
@@ -81,6 +86,9 @@ pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_merge_join::TransformMergeJoin;
+pub use transform_recursive_cte::RecursiveCteStates;
+pub use transform_recursive_cte::TransformRecursiveCte;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_right_join::RightJoinCompactor;
 pub use transform_right_join::TransformRightJoin;
@@ -91,6 +99,7 @@ pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_sort_spill::TransformSortSpill;
 pub use window::FrameBound;
 pub use window::TransformWindow;
 pub use window::WindowFunctionInfo;
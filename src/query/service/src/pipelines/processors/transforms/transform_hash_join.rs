@@ -19,13 +19,18 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_pipeline_sinks::AsyncSink;
 use common_pipeline_sinks::Sink;
 
+use super::hash_join::GraceHashJoinState;
 use super::hash_join::ProbeState;
+use super::hash_join::SpilledFile;
+use super::hash_join::SpilledPartition;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::transforms::hash_join::HashJoinState;
+use crate::pipelines::processors::JoinHashTable;
 use crate::pipelines::processors::Processor;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -57,9 +62,81 @@ impl Sink for SinkBuildHashTable {
     }
 }
 
+/// The build sink of the grace hash join, the partitions of the build side other than the
+/// first one are buffered and spilled to storage once they exceed the threshold.
+pub struct SinkGraceBuildHashTable {
+    grace_state: Arc<GraceHashJoinState>,
+    partitions: Vec<Vec<DataBlock>>,
+    buffered_bytes: usize,
+}
+
+impl SinkGraceBuildHashTable {
+    pub fn try_create(grace_state: Arc<GraceHashJoinState>) -> Result<Self> {
+        grace_state.attach_build()?;
+        Ok(Self {
+            grace_state,
+            partitions: vec![vec![]; GraceHashJoinState::partitions_num()],
+            buffered_bytes: 0,
+        })
+    }
+
+    fn take_partitions(&mut self) -> Vec<Vec<DataBlock>> {
+        self.buffered_bytes = 0;
+        std::mem::replace(&mut self.partitions, vec![
+            vec![];
+            GraceHashJoinState::partitions_num(
+            )
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for SinkGraceBuildHashTable {
+    const NAME: &'static str = "GraceBuildHashTable";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let partitions = self.take_partitions();
+        self.grace_state.detach_build(partitions).await
+    }
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        let partitions = self.grace_state.partition_block(&data_block, true, 0)?;
+        for (index, block) in partitions.into_iter().enumerate() {
+            if block.is_empty() {
+                continue;
+            }
+
+            if index == 0 {
+                self.grace_state.build(block)?;
+            } else {
+                self.buffered_bytes += block.memory_size();
+                self.partitions[index].push(block);
+            }
+        }
+
+        if self.buffered_bytes >= self.grace_state.spilling_bytes_threshold {
+            let partitions = self.take_partitions();
+            self.grace_state.spill_build(partitions).await?;
+        }
+
+        Ok(false)
+    }
+}
+
 enum HashJoinStep {
     Build,
     Probe,
+    /// Spill the buffered probe data and wait for the other probe processors (grace hash join only).
+    WaitProbe,
+    /// Join the spilled partitions (grace hash join only).
+    Restore,
+    /// The output is finished before the probe input, detach from the other probe
+    /// processors without waiting for them (grace hash join only).
+    FinishProbe,
+    Finished,
 }
 
 pub struct TransformHashJoinProbe {
@@ -71,6 +148,15 @@ pub struct TransformHashJoinProbe {
     step: HashJoinStep,
     join_state: Arc<dyn HashJoinState>,
     probe_state: ProbeState,
+
+    grace_state: Option<Arc<GraceHashJoinState>>,
+    /// The probe blocks of the spilled partitions.
+    spill_partitions: Vec<Vec<DataBlock>>,
+    spill_buffered_bytes: usize,
+    spilling_partitions: Option<Vec<Vec<DataBlock>>>,
+    restoring_partition: Option<SpilledPartition>,
+    partition_table: Option<Arc<JoinHashTable>>,
+    partition_probe_files: VecDeque<SpilledFile>,
 }
 
 impl TransformHashJoinProbe {
@@ -79,9 +165,13 @@ impl TransformHashJoinProbe {
         input_port: Arc<InputPort>,
         output_port: Arc<OutputPort>,
         join_state: Arc<dyn HashJoinState>,
+        grace_state: Option<Arc<GraceHashJoinState>>,
         _output_schema: DataSchemaRef,
     ) -> Result<Box<dyn Processor>> {
         let default_block_size = ctx.get_settings().get_max_block_size()?;
+        if let Some(grace_state) = &grace_state {
+            grace_state.attach_probe()?;
+        }
         Ok(Box::new(TransformHashJoinProbe {
             input_data: None,
             output_data_blocks: VecDeque::new(),
@@ -90,9 +180,76 @@ impl TransformHashJoinProbe {
             step: HashJoinStep::Build,
             join_state,
             probe_state: ProbeState::with_capacity(default_block_size as usize),
+            grace_state,
+            spill_partitions: vec![vec![]; GraceHashJoinState::partitions_num()],
+            spill_buffered_bytes: 0,
+            spilling_partitions: None,
+            restoring_partition: None,
+            partition_table: None,
+            partition_probe_files: VecDeque::new(),
         }))
     }
 
+    fn is_build_spilled(&self) -> bool {
+        self.grace_state
+            .as_ref()
+            .map_or(false, |grace_state| grace_state.is_build_spilled())
+    }
+
+    fn take_spill_partitions(&mut self) -> Vec<Vec<DataBlock>> {
+        self.spill_buffered_bytes = 0;
+        std::mem::replace(
+            &mut self.spill_partitions,
+            vec![vec![]; GraceHashJoinState::partitions_num()],
+        )
+    }
+
+    // The rows of the first partition are probed with the in-memory hash table,
+    // the others are buffered and spilled.
+    fn probe_partitioned(&mut self, block: &DataBlock) -> Result<()> {
+        let grace_state = self.grace_state.clone().unwrap();
+        let partitions = grace_state.partition_block(block, false, 0)?;
+        for (index, block) in partitions.into_iter().enumerate() {
+            if block.is_empty() {
+                continue;
+            }
+
+            if index == 0 {
+                self.probe(&block)?;
+            } else {
+                self.spill_buffered_bytes += block.memory_size();
+                self.spill_partitions[index].push(block);
+            }
+        }
+
+        if self.spill_buffered_bytes >= grace_state.spilling_bytes_threshold {
+            self.spilling_partitions = Some(self.take_spill_partitions());
+        }
+        Ok(())
+    }
+
+    fn restore_event(&mut self) -> Result<Event> {
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.partition_table.is_some() {
+            if !self.partition_probe_files.is_empty() {
+                return Ok(Event::Async);
+            }
+            self.partition_table = None;
+        }
+
+        let grace_state = self.grace_state.as_ref().unwrap();
+        if let Some(partition) = grace_state.next_partition() {
+            self.restoring_partition = Some(partition);
+            return Ok(Event::Async);
+        }
+
+        self.output_port.finish();
+        Ok(Event::Finished)
+    }
+
     fn probe(&mut self, block: &DataBlock) -> Result<()> {
         self.probe_state.clear();
         self.output_data_blocks
@@ -113,10 +270,22 @@ impl Processor for TransformHashJoinProbe {
 
     fn event(&mut self) -> Result<Event> {
         match self.step {
-            HashJoinStep::Build => Ok(Event::Async),
-            HashJoinStep::Probe => {
+            HashJoinStep::Build | HashJoinStep::WaitProbe | HashJoinStep::FinishProbe => {
+                Ok(Event::Async)
+            }
+            HashJoinStep::Finished => {
+                self.input_port.finish();
+                self.output_port.finish();
+                Ok(Event::Finished)
+            }
+            HashJoinStep::Probe | HashJoinStep::Restore => {
                 if self.output_port.is_finished() {
                     self.input_port.finish();
+                    // The other probe processors are waiting for this one to detach.
+                    if matches!(self.step, HashJoinStep::Probe) && self.grace_state.is_some() {
+                        self.step = HashJoinStep::FinishProbe;
+                        return Ok(Event::Async);
+                    }
                     return Ok(Event::Finished);
                 }
 
@@ -131,6 +300,15 @@ impl Processor for TransformHashJoinProbe {
                     return Ok(Event::NeedConsume);
                 }
 
+                if let HashJoinStep::Restore = self.step {
+                    return self.restore_event();
+                }
+
+                if self.spilling_partitions.is_some() {
+                    self.input_port.set_not_need_data();
+                    return Ok(Event::Async);
+                }
+
                 if self.input_data.is_some() {
                     return Ok(Event::Sync);
                 }
@@ -142,6 +320,11 @@ impl Processor for TransformHashJoinProbe {
                 }
 
                 if self.input_port.is_finished() {
+                    if self.is_build_spilled() {
+                        self.step = HashJoinStep::WaitProbe;
+                        return Ok(Event::Async);
+                    }
+
                     self.output_port.finish();
                     return Ok(Event::Finished);
                 }
@@ -153,16 +336,35 @@ impl Processor for TransformHashJoinProbe {
     }
 
     fn interrupt(&self) {
-        self.join_state.interrupt()
+        self.join_state.interrupt();
+        if let Some(grace_state) = &self.grace_state {
+            grace_state.interrupt();
+        }
     }
 
     fn process(&mut self) -> Result<()> {
         match self.step {
-            HashJoinStep::Build => Ok(()),
+            HashJoinStep::Build
+            | HashJoinStep::WaitProbe
+            | HashJoinStep::FinishProbe
+            | HashJoinStep::Finished => Ok(()),
             HashJoinStep::Probe => {
                 if let Some(data) = self.input_data.take() {
                     let data = data.convert_to_full();
-                    self.probe(&data)?;
+                    if self.is_build_spilled() {
+                        self.probe_partitioned(&data)?;
+                    } else {
+                        self.probe(&data)?;
+                    }
+                }
+                Ok(())
+            }
+            HashJoinStep::Restore => {
+                if let Some(data) = self.input_data.take() {
+                    let partition_table = self.partition_table.as_ref().unwrap();
+                    self.probe_state.clear();
+                    self.output_data_blocks
+                        .extend(partition_table.probe(&data, &mut self.probe_state)?);
                 }
                 Ok(())
             }
@@ -171,9 +373,47 @@ impl Processor for TransformHashJoinProbe {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        if let HashJoinStep::Build = &self.step {
-            self.join_state.wait_finish().await?;
-            self.step = HashJoinStep::Probe;
+        match self.step {
+            HashJoinStep::Build => {
+                self.join_state.wait_finish().await?;
+                self.step = HashJoinStep::Probe;
+            }
+            HashJoinStep::Probe => {
+                if let Some(partitions) = self.spilling_partitions.take() {
+                    let grace_state = self.grace_state.clone().unwrap();
+                    grace_state.spill_probe(partitions).await?;
+                }
+            }
+            HashJoinStep::WaitProbe => {
+                let grace_state = self.grace_state.clone().unwrap();
+                let partitions = self.take_spill_partitions();
+                grace_state.detach_probe(partitions).await?;
+                grace_state.wait_probe_finish().await?;
+                self.step = HashJoinStep::Restore;
+            }
+            HashJoinStep::FinishProbe => {
+                // The buffered probe rows will never be output, only detach.
+                let grace_state = self.grace_state.clone().unwrap();
+                self.spilling_partitions = None;
+                self.take_spill_partitions();
+                let empty_partitions = vec![vec![]; GraceHashJoinState::partitions_num()];
+                grace_state.detach_probe(empty_partitions).await?;
+                self.step = HashJoinStep::Finished;
+            }
+            HashJoinStep::Finished => {}
+            HashJoinStep::Restore => {
+                let grace_state = self.grace_state.clone().unwrap();
+                if let Some(partition) = self.restoring_partition.take() {
+                    if let Some((partition_table, probe_files)) =
+                        grace_state.restore_partition(partition).await?
+                    {
+                        self.partition_table = Some(partition_table);
+                        self.partition_probe_files = probe_files.into();
+                    }
+                } else if let Some(file) = self.partition_probe_files.pop_front() {
+                    self.input_data = Some(grace_state.read_spilled_file(&file).await?);
+                }
+            }
         }
 
         Ok(())
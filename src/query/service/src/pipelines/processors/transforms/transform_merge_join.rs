@@ -0,0 +1,483 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use async_channel::Receiver;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::compute::sort::row::RowConverter as ArrowRowConverter;
+use common_arrow::arrow::compute::sort::row::Rows as ArrowRows;
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::BooleanType;
+use common_expression::BlockEntry;
+use common_expression::BlockRowIndex;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchemaRefExt;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::SortColumnDescription;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::sort::RowConverter;
+use common_sql::executor::cast_expr_to_non_null_boolean;
+use common_sql::executor::MergeJoin;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MergeJoinStep {
+    NeedLeft,
+    NeedRight,
+    Finished,
+}
+
+/// Position of a row in the buffered blocks, (block index, row index).
+type RowPosition = (usize, usize);
+
+/// One input of the merge join, the blocks are sorted by the join keys.
+struct MergeJoinSide {
+    keys: Vec<Expr>,
+    blocks: Vec<DataBlock>,
+    rows: Vec<ArrowRows>,
+    // Rows with null join keys, they never match any row.
+    validities: Vec<Option<Bitmap>>,
+    position: RowPosition,
+    finished: bool,
+}
+
+impl MergeJoinSide {
+    fn create(keys: Vec<Expr>) -> Self {
+        MergeJoinSide {
+            keys,
+            blocks: vec![],
+            rows: vec![],
+            validities: vec![],
+            position: (0, 0),
+            finished: false,
+        }
+    }
+
+    fn push(
+        &mut self,
+        block: DataBlock,
+        converter: &mut ArrowRowConverter,
+        func_ctx: &FunctionContext,
+    ) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+        let mut validity: Option<Bitmap> = None;
+        let mut entries = Vec::with_capacity(self.keys.len());
+        for key in self.keys.iter() {
+            let column = evaluator
+                .run(key)?
+                .convert_to_full_column(key.data_type(), num_rows);
+            if let Column::Nullable(nullable) = &column {
+                validity = Some(match validity {
+                    Some(validity) => &validity & &nullable.validity,
+                    None => nullable.validity.clone(),
+                });
+            }
+            // Keys of both sides are converted with the same nullable types.
+            entries.push(BlockEntry {
+                data_type: key.data_type().wrap_nullable(),
+                value: Value::Column(column.wrap_nullable()),
+            });
+        }
+
+        self.rows.push(converter.convert(&entries, num_rows)?);
+        self.validities.push(validity);
+        self.blocks.push(block);
+        Ok(())
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.position.0 >= self.blocks.len()
+    }
+
+    fn is_valid(&self) -> bool {
+        let (block_index, row_index) = self.position;
+        match &self.validities[block_index] {
+            Some(validity) => validity.get_bit(row_index),
+            None => true,
+        }
+    }
+
+    fn next_position(&self, position: RowPosition) -> RowPosition {
+        let (block_index, row_index) = position;
+        if row_index + 1 >= self.blocks[block_index].num_rows() {
+            (block_index + 1, 0)
+        } else {
+            (block_index, row_index + 1)
+        }
+    }
+
+    fn advance(&mut self) {
+        self.position = self.next_position(self.position);
+    }
+
+    fn compare(&self, other: &MergeJoinSide) -> Ordering {
+        let (block_index, row_index) = self.position;
+        let (other_block_index, other_row_index) = other.position;
+        self.rows[block_index]
+            .row_unchecked(row_index)
+            .cmp(&other.rows[other_block_index].row_unchecked(other_row_index))
+    }
+
+    /// Find the end position of the rows with the same key as the current row.
+    /// Returns `None` if all the buffered rows have the same key and more data
+    /// is needed to decide the end.
+    fn group_end(&self) -> Option<RowPosition> {
+        let (block_index, row_index) = self.position;
+        let current = self.rows[block_index].row_unchecked(row_index);
+        let mut position = self.next_position(self.position);
+        while position.0 < self.blocks.len() {
+            if self.rows[position.0].row_unchecked(position.1) != current {
+                return Some(position);
+            }
+            position = self.next_position(position);
+        }
+
+        if self.finished { Some(position) } else { None }
+    }
+
+    fn group_positions(&self, end: RowPosition) -> Vec<RowPosition> {
+        let mut positions = vec![];
+        let mut position = self.position;
+        while position < end {
+            positions.push(position);
+            position = self.next_position(position);
+        }
+        positions
+    }
+
+    /// Drop the blocks before current position.
+    fn compact(&mut self) {
+        let consumed = self.position.0;
+        if consumed > 0 {
+            self.blocks.drain(..consumed);
+            self.rows.drain(..consumed);
+            self.validities.drain(..consumed);
+            self.position.0 = 0;
+        }
+    }
+}
+
+/// Inner sort-merge join, the left input comes from input port and the right input
+/// comes from the receiver, both of them are sorted by the join keys.
+pub struct TransformMergeJoin {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    receiver: Receiver<DataBlock>,
+
+    func_ctx: FunctionContext,
+    block_size: usize,
+    converter: ArrowRowConverter,
+    other_predicate: Option<Expr>,
+
+    step: MergeJoinStep,
+    left: MergeJoinSide,
+    right: MergeJoinSide,
+    left_data: Option<DataBlock>,
+    right_data: Option<DataBlock>,
+
+    // Matched rows which are not output yet.
+    left_indices: Vec<BlockRowIndex>,
+    right_indices: Vec<BlockRowIndex>,
+    pending_rows: usize,
+    output_blocks: VecDeque<DataBlock>,
+}
+
+impl TransformMergeJoin {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        join: &MergeJoin,
+        receiver: Receiver<DataBlock>,
+    ) -> Result<Box<dyn Processor>> {
+        let left_keys = join
+            .left_keys
+            .iter()
+            .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+            .collect::<Vec<_>>();
+        let right_keys = join
+            .right_keys
+            .iter()
+            .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+            .collect::<Vec<_>>();
+
+        let key_schema = DataSchemaRefExt::create(
+            left_keys
+                .iter()
+                .enumerate()
+                .map(|(index, key)| {
+                    DataField::new(&index.to_string(), key.data_type().wrap_nullable())
+                })
+                .collect(),
+        );
+        let sort_desc = (0..left_keys.len())
+            .map(|offset| SortColumnDescription {
+                offset,
+                asc: true,
+                nulls_first: false,
+                is_nullable: true,
+            })
+            .collect();
+        let converter = ArrowRowConverter::create(sort_desc, key_schema)?;
+
+        let other_predicate = join
+            .non_equi_conditions
+            .iter()
+            .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+            .try_reduce(|lhs, rhs| {
+                check_function(None, "and_filters", &[], &[lhs, rhs], &BUILTIN_FUNCTIONS)
+            })?
+            .map(cast_expr_to_non_null_boolean)
+            .transpose()?;
+
+        Ok(Box::new(TransformMergeJoin {
+            input,
+            output,
+            receiver,
+            func_ctx: ctx.get_function_context()?,
+            block_size: ctx.get_settings().get_max_block_size()? as usize,
+            converter,
+            other_predicate,
+            step: MergeJoinStep::NeedLeft,
+            left: MergeJoinSide::create(left_keys),
+            right: MergeJoinSide::create(right_keys),
+            left_data: None,
+            right_data: None,
+            left_indices: vec![],
+            right_indices: vec![],
+            pending_rows: 0,
+            output_blocks: VecDeque::new(),
+        }))
+    }
+
+    fn merge(&mut self) -> Result<MergeJoinStep> {
+        loop {
+            if self.left.is_exhausted() {
+                return Ok(if self.left.finished {
+                    MergeJoinStep::Finished
+                } else {
+                    MergeJoinStep::NeedLeft
+                });
+            }
+            if self.right.is_exhausted() {
+                return Ok(if self.right.finished {
+                    MergeJoinStep::Finished
+                } else {
+                    MergeJoinStep::NeedRight
+                });
+            }
+            if !self.left.is_valid() {
+                self.left.advance();
+                continue;
+            }
+            if !self.right.is_valid() {
+                self.right.advance();
+                continue;
+            }
+
+            match self.left.compare(&self.right) {
+                Ordering::Less => self.left.advance(),
+                Ordering::Greater => self.right.advance(),
+                Ordering::Equal => {
+                    let left_end = match self.left.group_end() {
+                        Some(end) => end,
+                        None => return Ok(MergeJoinStep::NeedLeft),
+                    };
+                    let right_end = match self.right.group_end() {
+                        Some(end) => end,
+                        None => return Ok(MergeJoinStep::NeedRight),
+                    };
+                    self.join_groups(left_end, right_end)?;
+                }
+            }
+        }
+    }
+
+    fn join_groups(&mut self, left_end: RowPosition, right_end: RowPosition) -> Result<()> {
+        let left_positions = self.left.group_positions(left_end);
+        let right_positions = self.right.group_positions(right_end);
+        for (block_index, row_index) in left_positions {
+            self.left_indices
+                .push((block_index, row_index, right_positions.len()));
+            self.right_indices.extend(
+                right_positions
+                    .iter()
+                    .map(|(block_index, row_index)| (*block_index, *row_index, 1)),
+            );
+            self.pending_rows += right_positions.len();
+            if self.pending_rows >= self.block_size {
+                self.flush()?;
+            }
+        }
+        self.left.position = left_end;
+        self.right.position = right_end;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if self.pending_rows == 0 {
+            return Ok(());
+        }
+
+        let mut block =
+            DataBlock::take_blocks(&self.left.blocks, &self.left_indices, self.pending_rows);
+        let right_block =
+            DataBlock::take_blocks(&self.right.blocks, &self.right_indices, self.pending_rows);
+        for entry in right_block.columns() {
+            block.add_column(entry.clone());
+        }
+        self.left_indices.clear();
+        self.right_indices.clear();
+        self.pending_rows = 0;
+
+        if let Some(predicate) = &self.other_predicate {
+            let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let filter = evaluator
+                .run(predicate)?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            block = block.filter_boolean_value(&filter)?;
+        }
+
+        if !block.is_empty() {
+            self.output_blocks.push_back(block);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformMergeJoin {
+    fn name(&self) -> String {
+        "TransformMergeJoin".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(output_data) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(output_data));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.left_data.is_some() || self.right_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        match self.step {
+            MergeJoinStep::NeedLeft => {
+                if self.input.has_data() {
+                    self.left_data = Some(self.input.pull_data().unwrap()?);
+                    return Ok(Event::Sync);
+                }
+
+                if self.input.is_finished() {
+                    self.left.finished = true;
+                    return Ok(Event::Sync);
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            MergeJoinStep::NeedRight => {
+                // Merge again after the right input is finished.
+                if self.right.finished {
+                    return Ok(Event::Sync);
+                }
+
+                if let Ok(data) = self.receiver.try_recv() {
+                    self.right_data = Some(data);
+                    return Ok(Event::Sync);
+                }
+
+                Ok(Event::Async)
+            }
+            MergeJoinStep::Finished => {
+                self.input.finish();
+
+                // Drain the right input, so that the sender will not be blocked.
+                if !self.right.finished {
+                    return Ok(Event::Async);
+                }
+
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data) = self.left_data.take() {
+            self.left.push(data, &mut self.converter, &self.func_ctx)?;
+        }
+        if let Some(data) = self.right_data.take() {
+            self.right.push(data, &mut self.converter, &self.func_ctx)?;
+        }
+
+        self.step = self.merge()?;
+        self.flush()?;
+        self.left.compact();
+        self.right.compact();
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.step {
+            MergeJoinStep::NeedRight => match self.receiver.recv().await {
+                Ok(data) => self.right_data = Some(data),
+                Err(_) => self.right.finished = true,
+            },
+            MergeJoinStep::Finished => {
+                while self.receiver.recv().await.is_ok() {}
+                self.right.finished = true;
+            }
+            MergeJoinStep::NeedLeft => {}
+        }
+        Ok(())
+    }
+}
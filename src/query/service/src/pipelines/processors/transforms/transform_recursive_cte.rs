@@ -0,0 +1,267 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::Scalar;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_profile::ProfSpanSetRef;
+use common_sql::executor::PhysicalPlan;
+use common_sql::IndexType;
+use parking_lot::Mutex;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Rows of the working tables of the recursive CTEs, indexed by `cte_index`.
+pub type RecursiveCteStates = HashMap<IndexType, Arc<Mutex<VecDeque<DataBlock>>>>;
+
+/// Evaluates a recursive CTE.
+///
+/// The rows of the anchor term come from the input port. Once they are exhausted,
+/// the recursive term is executed in a separate pipeline on the rows produced by
+/// the previous iteration, until no new rows are produced.
+pub struct TransformRecursiveCte {
+    ctx: Arc<QueryContext>,
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    input_data: Option<DataBlock>,
+    output_data: Option<DataBlock>,
+    finished: bool,
+
+    anchor_schema: DataSchemaRef,
+    recursive: PhysicalPlan,
+    pairs: Vec<(String, String)>,
+    distinct: bool,
+    distinct_rows: HashSet<Vec<Scalar>>,
+
+    working_table: Arc<Mutex<VecDeque<DataBlock>>>,
+    next_working_table: Vec<DataBlock>,
+    recursive_cte_states: RecursiveCteStates,
+    iterations: u64,
+    max_iterations: u64,
+
+    enable_profiling: bool,
+    prof_span_set: ProfSpanSetRef,
+}
+
+impl TransformRecursiveCte {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        anchor_schema: DataSchemaRef,
+        recursive: PhysicalPlan,
+        pairs: Vec<(String, String)>,
+        distinct: bool,
+        working_table: Arc<Mutex<VecDeque<DataBlock>>>,
+        recursive_cte_states: RecursiveCteStates,
+        enable_profiling: bool,
+        prof_span_set: ProfSpanSetRef,
+    ) -> Result<Box<dyn Processor>> {
+        let max_iterations = ctx.get_settings().get_max_recursive_cte_iterations()?;
+        Ok(Box::new(TransformRecursiveCte {
+            ctx,
+            input,
+            output,
+            input_data: None,
+            output_data: None,
+            finished: false,
+            anchor_schema,
+            recursive,
+            pairs,
+            distinct,
+            distinct_rows: HashSet::new(),
+            working_table,
+            next_working_table: vec![],
+            recursive_cte_states,
+            iterations: 0,
+            max_iterations,
+            enable_profiling,
+            prof_span_set,
+        }))
+    }
+
+    fn project_block(&self, block: DataBlock, is_anchor: bool) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let schema = if is_anchor {
+            self.anchor_schema.clone()
+        } else {
+            self.recursive.output_schema()?
+        };
+        let columns = self
+            .pairs
+            .iter()
+            .map(|(left, right)| {
+                let name = if is_anchor { left } else { right };
+                Ok(block.get_by_offset(schema.index_of(name)?).clone())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataBlock::new(columns, num_rows))
+    }
+
+    // Add the new rows to the working table of the next iteration, and return them.
+    fn append_to_working_table(&mut self, block: DataBlock) -> Result<Option<DataBlock>> {
+        let block = if self.distinct {
+            let mut bitmap = MutableBitmap::from_len_set(block.num_rows());
+            for row in 0..block.num_rows() {
+                let values = block
+                    .columns()
+                    .iter()
+                    .map(|entry| entry.value.index(row).unwrap().to_owned())
+                    .collect::<Vec<_>>();
+                if !self.distinct_rows.insert(values) {
+                    bitmap.set(row, false);
+                }
+            }
+            block.filter_with_bitmap(&bitmap.into())?
+        } else {
+            block
+        };
+
+        if block.is_empty() {
+            return Ok(None);
+        }
+        self.next_working_table.push(block.clone());
+        Ok(Some(block))
+    }
+
+    fn execute_recursive_term(&mut self) -> Result<Vec<DataBlock>> {
+        {
+            let mut working_table = self.working_table.lock();
+            working_table.clear();
+            working_table.extend(self.next_working_table.drain(..));
+        }
+
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let mut pipeline_builder = PipelineBuilder::create(
+            ctx.clone(),
+            self.enable_profiling,
+            self.prof_span_set.clone(),
+        );
+        pipeline_builder.recursive_cte_states = self.recursive_cte_states.clone();
+        let mut build_res = pipeline_builder.finalize(&self.recursive)?;
+
+        let settings = ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+        let mut executor = PipelinePullingExecutor::from_pipelines(build_res, executor_settings)?;
+        executor.start();
+
+        let mut blocks = vec![];
+        while let Some(block) = executor.pull_data()? {
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformRecursiveCte {
+    fn name(&self) -> String {
+        "TransformRecursiveCte".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(output_data) = self.output_data.take() {
+            self.output.push_data(Ok(output_data));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if !self.input.is_finished() {
+            if self.input.has_data() {
+                self.input_data = Some(self.input.pull_data().unwrap()?);
+                return Ok(Event::Sync);
+            }
+            self.input.set_need_data();
+            return Ok(Event::NeedData);
+        }
+
+        // The anchor term is exhausted, run the recursive term until it produces no new rows.
+        if !self.finished {
+            return Ok(Event::Sync);
+        }
+
+        self.output.finish();
+        Ok(Event::Finished)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(input_data) = self.input_data.take() {
+            let block = self.project_block(input_data, true)?;
+            self.output_data = self.append_to_working_table(block)?;
+            return Ok(());
+        }
+
+        if self.next_working_table.is_empty() {
+            self.finished = true;
+            return Ok(());
+        }
+
+        if self.max_iterations != 0 && self.iterations >= self.max_iterations {
+            return Err(ErrorCode::RecursiveCteIterationsExceeded(format!(
+                "Recursive CTE exceeds the maximum number of iterations {}, which can be changed by setting `max_recursive_cte_iterations`",
+                self.max_iterations
+            )));
+        }
+        self.iterations += 1;
+
+        let mut blocks = vec![];
+        for block in self.execute_recursive_term()? {
+            let block = self.project_block(block, false)?;
+            if let Some(block) = self.append_to_working_table(block)? {
+                blocks.push(block);
+            }
+        }
+        if !blocks.is_empty() {
+            self.output_data = Some(DataBlock::concat(&blocks)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,434 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_arrow::arrow::compute::sort::row::RowConverter as ArrowRowConverter;
+use common_arrow::arrow::compute::sort::row::Rows as ArrowRows;
+use common_base::base::GlobalUniqName;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::sort::Cursor;
+use common_pipeline_transforms::processors::transforms::sort::RowConverter;
+use common_pipeline_transforms::processors::transforms::Compactor;
+use common_pipeline_transforms::processors::transforms::SortMergeCompactor;
+use futures_util::future::BoxFuture;
+use opendal::Operator;
+use tracing::error;
+use tracing::info;
+
+type SortRunCompactor = SortMergeCompactor<ArrowRows, ArrowRowConverter>;
+
+/// A sorted block of a run that has been written to storage.
+struct SpilledBlock {
+    location: String,
+    columns_layout: Vec<usize>,
+}
+
+/// The state used to merge the spilled runs back.
+struct SpilledRunsMerger {
+    /// The blocks of each run that have not been read back yet.
+    runs: Vec<VecDeque<SpilledBlock>>,
+    /// The block of each run currently being merged.
+    current_blocks: Vec<DataBlock>,
+    /// The runs whose next block needs to be read back.
+    loading_runs: Vec<usize>,
+    /// The blocks that have been read back but not added to the heap yet.
+    loaded_blocks: Vec<(usize, DataBlock)>,
+    /// Heap that yields [`Cursor`] in increasing order.
+    heap: BinaryHeap<Reverse<Cursor<ArrowRows>>>,
+    row_converter: ArrowRowConverter,
+}
+
+/// TransformSortSpill implements the external sort of a single stream.
+///
+/// The sorted blocks from the partial sort are buffered in memory. Once the buffered bytes
+/// exceed the threshold, they are merged into a sorted run and written to the storage.
+/// When the input is finished, the spilled runs are read back block by block and merged,
+/// so the output is a single sorted stream that can be consumed by the multi-way merge.
+pub struct TransformSortSpill {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    operator: Operator,
+    location_prefix: String,
+    schema: DataSchemaRef,
+    block_size: usize,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+    spilling_bytes_threshold: usize,
+    compactor: SortRunCompactor,
+
+    buffered_blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+    spilled_runs: Vec<VecDeque<SpilledBlock>>,
+    spilling_future: Option<BoxFuture<'static, Result<()>>>,
+    output_blocks: VecDeque<DataBlock>,
+    merger: Option<SpilledRunsMerger>,
+}
+
+impl TransformSortSpill {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        operator: Operator,
+        location_prefix: String,
+        schema: DataSchemaRef,
+        block_size: usize,
+        sort_columns_descriptions: Vec<SortColumnDescription>,
+        spilling_bytes_threshold: usize,
+    ) -> Result<Box<dyn Processor>> {
+        let compactor = SortRunCompactor::try_create(
+            schema.clone(),
+            block_size,
+            None,
+            sort_columns_descriptions.clone(),
+        )?;
+
+        Ok(Box::new(TransformSortSpill {
+            input,
+            output,
+            operator,
+            location_prefix,
+            schema,
+            block_size,
+            sort_columns_descriptions,
+            spilling_bytes_threshold,
+            compactor,
+            buffered_blocks: vec![],
+            buffered_bytes: 0,
+            spilled_runs: vec![],
+            spilling_future: None,
+            output_blocks: VecDeque::new(),
+            merger: None,
+        }))
+    }
+
+    fn spill_buffered_blocks(&mut self) -> Result<()> {
+        let blocks = std::mem::take(&mut self.buffered_blocks);
+        self.buffered_bytes = 0;
+
+        let run = self.compactor.compact_final(&blocks)?;
+        if run.is_empty() {
+            return Ok(());
+        }
+
+        let mut spilled_blocks = VecDeque::with_capacity(run.len());
+        let mut write_data = Vec::with_capacity(run.len());
+        for block in run {
+            let num_rows = block.num_rows();
+            let mut columns_layout = Vec::with_capacity(block.num_columns());
+            let mut data = vec![];
+            for entry in block.columns() {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                let column_data = serialize_column(&column);
+                columns_layout.push(column_data.len());
+                data.extend(column_data);
+            }
+
+            let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+            spilled_blocks.push_back(SpilledBlock {
+                location: location.clone(),
+                columns_layout,
+            });
+            write_data.push((location, data));
+        }
+        self.spilled_runs.push(spilled_blocks);
+
+        let operator = self.operator.clone();
+        self.spilling_future = Some(Box::pin(async move {
+            let instant = Instant::now();
+            let blocks_num = write_data.len();
+            for (location, data) in write_data {
+                operator.write(&location, data).await?;
+            }
+
+            info!(
+                "Write sort spill run of {} blocks successfully, elapsed: {:?}",
+                blocks_num,
+                instant.elapsed()
+            );
+
+            Ok(())
+        }));
+
+        Ok(())
+    }
+
+    fn start_merging(&mut self) -> Result<()> {
+        let runs = std::mem::take(&mut self.spilled_runs);
+        let runs_num = runs.len();
+        self.merger = Some(SpilledRunsMerger {
+            runs,
+            current_blocks: vec![DataBlock::empty_with_schema(self.schema.clone()); runs_num],
+            loading_runs: (0..runs_num).collect(),
+            loaded_blocks: Vec::with_capacity(runs_num),
+            heap: BinaryHeap::with_capacity(runs_num),
+            row_converter: ArrowRowConverter::create(
+                self.sort_columns_descriptions.clone(),
+                self.schema.clone(),
+            )?,
+        });
+        Ok(())
+    }
+
+    fn merge_event(&mut self) -> Result<Event> {
+        let merger = self.merger.as_ref().unwrap();
+
+        if !merger.loading_runs.is_empty() {
+            return Ok(Event::Async);
+        }
+
+        if !merger.loaded_blocks.is_empty() || !merger.heap.is_empty() {
+            return Ok(Event::Sync);
+        }
+
+        self.output.finish();
+        Ok(Event::Finished)
+    }
+
+    // Merge the current blocks of the runs until an output block is full,
+    // or the block of a run is drained and the next one needs to be read back.
+    fn merge_spilled_runs(&mut self) -> Result<()> {
+        let block_size = self.block_size;
+        let merger = self.merger.as_mut().unwrap();
+
+        for (run_index, block) in std::mem::take(&mut merger.loaded_blocks) {
+            let columns = self
+                .sort_columns_descriptions
+                .iter()
+                .map(|desc| block.get_by_offset(desc.offset).clone())
+                .collect::<Vec<_>>();
+            let rows = merger.row_converter.convert(&columns, block.num_rows())?;
+            merger
+                .heap
+                .push(Reverse(Cursor::try_create(run_index, rows)));
+            merger.current_blocks[run_index] = block;
+        }
+
+        let mut merged_rows = Vec::with_capacity(block_size);
+        while merged_rows.len() < block_size {
+            let mut cursor = match merger.heap.pop() {
+                Some(Reverse(cursor)) => cursor,
+                None => break,
+            };
+
+            let run_index = cursor.input_index;
+            match merger.heap.peek() {
+                Some(Reverse(next_cursor)) => {
+                    while !cursor.is_finished()
+                        && merged_rows.len() < block_size
+                        && cursor.le(next_cursor)
+                    {
+                        merged_rows.push((run_index, cursor.advance()));
+                    }
+                }
+                None => {
+                    while !cursor.is_finished() && merged_rows.len() < block_size {
+                        merged_rows.push((run_index, cursor.advance()));
+                    }
+                }
+            }
+
+            if !cursor.is_finished() {
+                merger.heap.push(Reverse(cursor));
+            } else if !merger.runs[run_index].is_empty() {
+                // The next block of this run may contain smaller rows than the other cursors.
+                merger.loading_runs.push(run_index);
+                break;
+            }
+        }
+
+        if merged_rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut merge_slices: Vec<(usize, usize, usize)> = Vec::new();
+        for (run_index, row_index) in merged_rows {
+            match merge_slices.last_mut() {
+                Some((last_run, last_row, len))
+                    if *last_run == run_index && *last_row + *len == row_index =>
+                {
+                    *len += 1;
+                }
+                _ => merge_slices.push((run_index, row_index, 1)),
+            }
+        }
+
+        self.output_blocks
+            .push_back(DataBlock::take_by_slices_limit_from_blocks(
+                &merger.current_blocks,
+                &merge_slices,
+                None,
+            ));
+        Ok(())
+    }
+
+    async fn read_spilled_blocks(&mut self) -> Result<()> {
+        let merger = self.merger.as_mut().unwrap();
+
+        let mut read_blocks = Vec::with_capacity(merger.loading_runs.len());
+        for run_index in std::mem::take(&mut merger.loading_runs) {
+            let spilled_block = merger.runs[run_index].pop_front().unwrap();
+            let operator = self.operator.clone();
+            read_blocks.push(async move {
+                let instant = Instant::now();
+                let data = operator.read(&spilled_block.location).await?;
+
+                if let Err(cause) = operator.delete(&spilled_block.location).await {
+                    error!(
+                        "Cannot delete spill file {}, cause: {:?}",
+                        &spilled_block.location, cause
+                    );
+                }
+
+                info!(
+                    "Read sort spill {} successfully, elapsed: {:?}",
+                    &spilled_block.location,
+                    instant.elapsed()
+                );
+
+                let mut begin = 0;
+                let mut columns = Vec::with_capacity(spilled_block.columns_layout.len());
+                for column_layout in spilled_block.columns_layout {
+                    let column = deserialize_column(&data[begin..begin + column_layout])
+                        .ok_or_else(|| {
+                            ErrorCode::Internal(format!(
+                                "Cannot deserialize sort spill file {}",
+                                spilled_block.location
+                            ))
+                        })?;
+                    columns.push(column);
+                    begin += column_layout;
+                }
+
+                Ok::<_, ErrorCode>((run_index, DataBlock::new_from_columns(columns)))
+            });
+        }
+
+        merger.loaded_blocks = futures::future::try_join_all(read_blocks).await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortSpill {
+    fn name(&self) -> String {
+        String::from("TransformSortSpill")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.spilling_future.is_some() {
+            self.input.set_not_need_data();
+            return Ok(Event::Async);
+        }
+
+        if let Some(data_block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.merger.is_some() {
+            return self.merge_event();
+        }
+
+        if self.input.has_data() {
+            let data_block = self.input.pull_data().unwrap()?;
+            self.buffered_bytes += data_block.memory_size();
+            self.buffered_blocks.push(data_block);
+
+            if self.buffered_bytes >= self.spilling_bytes_threshold {
+                self.input.set_not_need_data();
+                return Ok(Event::Sync);
+            }
+        }
+
+        if self.input.is_finished() {
+            if !self.buffered_blocks.is_empty() {
+                return Ok(Event::Sync);
+            }
+
+            if self.spilled_runs.is_empty() {
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+
+            self.start_merging()?;
+            return self.merge_event();
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if self.merger.is_some() {
+            return self.merge_spilled_runs();
+        }
+
+        if self.input.is_finished() && self.spilled_runs.is_empty() {
+            // Nothing has been spilled, merge the buffered blocks in memory.
+            let blocks = std::mem::take(&mut self.buffered_blocks);
+            self.buffered_bytes = 0;
+            self.output_blocks
+                .extend(self.compactor.compact_final(&blocks)?);
+            return Ok(());
+        }
+
+        self.spill_buffered_blocks()
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(spilling_future) = self.spilling_future.take() {
+            return spilling_future.await;
+        }
+
+        if self.merger.is_some() {
+            return self.read_spilled_blocks().await;
+        }
+
+        Ok(())
+    }
+}
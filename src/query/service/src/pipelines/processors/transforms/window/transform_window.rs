@@ -29,6 +29,7 @@ use common_expression::BlockEntry;
 use common_expression::Column;
 use common_expression::ColumnBuilder;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_expression::ScalarRef;
 use common_expression::SortColumnDescription;
 use common_expression::Value;
@@ -40,6 +41,8 @@ use common_sql::plans::WindowFuncFrameUnits;
 
 use super::frame_bound::FrameBound;
 use super::window_function::WindowFuncAggImpl;
+use super::window_function::WindowFuncLagLeadImpl;
+use super::window_function::WindowFuncNthValueImpl;
 use super::window_function::WindowFunctionImpl;
 use super::WindowFunctionInfo;
 
@@ -128,6 +131,8 @@ pub struct TransformWindow<T: Number> {
     current_rank: usize,
     current_rank_count: usize,
     current_dense_rank: usize,
+    // Used for cume_dist, the number of rows from the partition start to the end of current peer group.
+    current_peer_group_end: usize,
 
     // If `is_empty_frame`, the window function result of non-NULL rows will be NULL.
     is_empty_frame: bool,
@@ -340,6 +345,18 @@ impl<T: Number> TransformWindow<T> {
         row
     }
 
+    // Move the row back to the previous row, `row` should not be the first row of the buffered blocks.
+    fn goback_row(&self, mut row: RowPtr) -> RowPtr {
+        if row.row > 0 {
+            row.row -= 1;
+        } else {
+            debug_assert!(row.block > self.first_block);
+            row.block -= 1;
+            row.row = self.block_rows(&row) - 1;
+        }
+        row
+    }
+
     /// If the two rows are within the same peer group.
     fn are_peers(&self, lhs: &RowPtr, rhs: &RowPtr, for_computing_bound: bool) -> bool {
         if lhs == rhs {
@@ -450,8 +467,121 @@ impl<T: Number> TransformWindow<T> {
         Ok(())
     }
 
+    #[inline]
+    fn value_at(&self, row: &RowPtr, column_index: usize) -> Scalar {
+        let col = self.column_at(row, column_index);
+        unsafe { col.index_unchecked(row.row) }.to_owned()
+    }
+
+    /// Find the `n`-th (starting from 1) row with non-NULL value in the frame.
+    ///
+    /// If `forward` is true, the rows are counted from `frame_start` to `frame_end`, otherwise backward.
+    fn nth_non_null_row_in_frame(
+        &self,
+        column_index: usize,
+        n: usize,
+        forward: bool,
+    ) -> Option<RowPtr> {
+        let mut remain = n;
+        let mut row = if forward {
+            self.frame_start
+        } else {
+            self.frame_end
+        };
+        loop {
+            if forward {
+                if row >= self.frame_end {
+                    return None;
+                }
+            } else {
+                if row <= self.frame_start {
+                    return None;
+                }
+                row = self.goback_row(row);
+            }
+
+            let col = self.column_at(&row, column_index);
+            if unsafe { col.index_unchecked(row.row) } != ScalarRef::Null {
+                remain -= 1;
+                if remain == 0 {
+                    return Some(row);
+                }
+            }
+
+            if forward {
+                row = self.add_rows_within_partition(row, 1);
+            }
+        }
+    }
+
+    fn compute_lag_lead(&self, lag_lead: &WindowFuncLagLeadImpl) -> Scalar {
+        let row = if lag_lead.ignore_nulls && lag_lead.offset > 0 {
+            // The frame contains all the rows before (for LAG) or after (for LEAD) the current row.
+            self.nth_non_null_row_in_frame(lag_lead.arg, lag_lead.offset, !lag_lead.is_lag)
+        } else if self.frame_start < self.frame_end {
+            // The frame only contains the row at the offset.
+            Some(self.frame_start)
+        } else {
+            None
+        };
+
+        match (row, lag_lead.default) {
+            (Some(row), _) => self.value_at(&row, lag_lead.arg),
+            (None, Some(default)) => self.value_at(&self.current_row, default),
+            (None, None) => Scalar::Null,
+        }
+    }
+
+    fn compute_nth_value(&self, nth_value: &WindowFuncNthValueImpl) -> Scalar {
+        if (self.is_empty_frame && !self.is_null_frame) || self.frame_start >= self.frame_end {
+            return Scalar::Null;
+        }
+
+        let row = match (nth_value.n, nth_value.ignore_nulls) {
+            (Some(n), true) => self.nth_non_null_row_in_frame(nth_value.arg, n, true),
+            (None, true) => self.nth_non_null_row_in_frame(nth_value.arg, 1, false),
+            (Some(n), false) => {
+                let row = self.add_rows_within_partition(self.frame_start, n - 1);
+                if row < self.frame_end {
+                    Some(row)
+                } else {
+                    None
+                }
+            }
+            (None, false) => Some(self.goback_row(self.frame_end)),
+        };
+
+        match row {
+            Some(row) => self.value_at(&row, nth_value.arg),
+            None => Scalar::Null,
+        }
+    }
+
+    /// Find the end of current peer group, the rows of the partition should be all buffered.
+    fn advance_peer_group_end(&mut self) {
+        let mut row = self.advance_row(self.current_row);
+        let mut rows = self.current_row_in_partition;
+        while row < self.partition_end && self.are_peers(&self.current_row, &row, false) {
+            row = self.advance_row(row);
+            rows += 1;
+        }
+        self.current_peer_group_end = rows;
+    }
+
     #[inline]
     fn merge_result_of_current_row(&mut self) -> Result<()> {
+        if matches!(self.func, WindowFunctionImpl::CumeDist)
+            && self.current_row == self.peer_group_start
+        {
+            self.advance_peer_group_end();
+        }
+
+        let value = match &self.func {
+            WindowFunctionImpl::LagLead(lag_lead) => Some(self.compute_lag_lead(lag_lead)),
+            WindowFunctionImpl::NthValue(nth_value) => Some(self.compute_nth_value(nth_value)),
+            _ => None,
+        };
+
         let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
 
         match &self.func {
@@ -481,6 +611,18 @@ impl<T: Number> TransformWindow<T> {
                 };
                 builder.push(ScalarRef::Number(NumberScalar::Float64(percent.into())));
             }
+            WindowFunctionImpl::LagLead(_) | WindowFunctionImpl::NthValue(_) => {
+                builder.push(value.as_ref().unwrap().as_ref());
+            }
+            WindowFunctionImpl::Ntile(ntile) => {
+                let bucket =
+                    ntile.compute_bucket(self.current_row_in_partition - 1, self.partition_size);
+                builder.push(ScalarRef::Number(NumberScalar::UInt64(bucket as u64)));
+            }
+            WindowFunctionImpl::CumeDist => {
+                let cume_dist = (self.current_peer_group_end as f64) / (self.partition_size as f64);
+                builder.push(ScalarRef::Number(NumberScalar::Float64(cume_dist.into())));
+            }
         };
 
         Ok(())
@@ -558,6 +700,7 @@ impl TransformWindow<u64> {
             current_rank: 1,
             current_rank_count: 1,
             current_dense_rank: 1,
+            current_peer_group_end: 0,
             input_is_finished: false,
             is_empty_frame,
         })
@@ -623,6 +766,7 @@ where T: Number + ResultTypeOfUnary
             current_rank: 1,
             current_rank_count: 1,
             current_dense_rank: 1,
+            current_peer_group_end: 0,
             input_is_finished: false,
             is_empty_frame,
         })
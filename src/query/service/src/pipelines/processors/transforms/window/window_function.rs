@@ -36,6 +36,10 @@ pub enum WindowFunctionInfo {
     Rank,
     DenseRank,
     PercentRank,
+    LagLead(WindowFuncLagLeadImpl),
+    NthValue(WindowFuncNthValueImpl),
+    Ntile(WindowFuncNtileImpl),
+    CumeDist,
 }
 
 pub struct WindowFuncAggImpl {
@@ -87,12 +91,60 @@ impl Drop for WindowFuncAggImpl {
     }
 }
 
+#[derive(Clone)]
+pub struct WindowFuncLagLeadImpl {
+    pub is_lag: bool,
+    pub offset: usize,
+    pub ignore_nulls: bool,
+    pub arg: usize,
+    pub default: Option<usize>,
+    pub return_type: DataType,
+}
+
+#[derive(Clone)]
+pub struct WindowFuncNthValueImpl {
+    /// `None` for `LAST_VALUE`.
+    pub n: Option<usize>,
+    pub ignore_nulls: bool,
+    pub arg: usize,
+    pub return_type: DataType,
+}
+
+#[derive(Clone)]
+pub struct WindowFuncNtileImpl {
+    pub n: usize,
+    pub return_type: DataType,
+}
+
+impl WindowFuncNtileImpl {
+    /// Compute the bucket (starting from 1) of the row at `row_in_partition` (starting from 0).
+    ///
+    /// The rows are divided into `n` buckets as evenly as possible,
+    /// the first `partition_size % n` buckets have one more row than the others.
+    #[inline]
+    pub fn compute_bucket(&self, row_in_partition: usize, partition_size: usize) -> usize {
+        let num_buckets = self.n.min(partition_size);
+        let bucket_size = partition_size / num_buckets;
+        let num_large_buckets = partition_size % num_buckets;
+        let large_rows = num_large_buckets * (bucket_size + 1);
+        if row_in_partition < large_rows {
+            row_in_partition / (bucket_size + 1) + 1
+        } else {
+            num_large_buckets + (row_in_partition - large_rows) / bucket_size + 1
+        }
+    }
+}
+
 pub enum WindowFunctionImpl {
     Aggregate(WindowFuncAggImpl),
     RowNumber,
     Rank,
     DenseRank,
     PercentRank,
+    LagLead(WindowFuncLagLeadImpl),
+    NthValue(WindowFuncNthValueImpl),
+    Ntile(WindowFuncNtileImpl),
+    CumeDist,
 }
 
 impl WindowFunctionInfo {
@@ -118,6 +170,28 @@ impl WindowFunctionInfo {
             WindowFunction::Rank => Self::Rank,
             WindowFunction::DenseRank => Self::DenseRank,
             WindowFunction::PercentRank => Self::PercentRank,
+            WindowFunction::LagLead(lag_lead) => Self::LagLead(WindowFuncLagLeadImpl {
+                is_lag: lag_lead.is_lag,
+                offset: lag_lead.offset as usize,
+                ignore_nulls: lag_lead.ignore_nulls,
+                arg: schema.index_of(&lag_lead.arg.to_string())?,
+                default: lag_lead
+                    .default
+                    .map(|default| schema.index_of(&default.to_string()))
+                    .transpose()?,
+                return_type: lag_lead.return_type.clone(),
+            }),
+            WindowFunction::NthValue(nth_value) => Self::NthValue(WindowFuncNthValueImpl {
+                n: nth_value.n.map(|n| n as usize),
+                ignore_nulls: nth_value.ignore_nulls,
+                arg: schema.index_of(&nth_value.arg.to_string())?,
+                return_type: nth_value.return_type.clone(),
+            }),
+            WindowFunction::Ntile(ntile) => Self::Ntile(WindowFuncNtileImpl {
+                n: ntile.n as usize,
+                return_type: ntile.return_type.clone(),
+            }),
+            WindowFunction::CumeDist => Self::CumeDist,
         })
     }
 }
@@ -144,6 +218,10 @@ impl WindowFunctionImpl {
             WindowFunctionInfo::Rank => Self::Rank,
             WindowFunctionInfo::DenseRank => Self::DenseRank,
             WindowFunctionInfo::PercentRank => Self::PercentRank,
+            WindowFunctionInfo::LagLead(lag_lead) => Self::LagLead(lag_lead),
+            WindowFunctionInfo::NthValue(nth_value) => Self::NthValue(nth_value),
+            WindowFunctionInfo::Ntile(ntile) => Self::Ntile(ntile),
+            WindowFunctionInfo::CumeDist => Self::CumeDist,
         })
     }
 
@@ -153,7 +231,10 @@ impl WindowFunctionImpl {
             Self::RowNumber | Self::Rank | Self::DenseRank => {
                 DataType::Number(NumberDataType::UInt64)
             }
-            Self::PercentRank => DataType::Number(NumberDataType::Float64),
+            Self::PercentRank | Self::CumeDist => DataType::Number(NumberDataType::Float64),
+            Self::LagLead(lag_lead) => lag_lead.return_type.clone(),
+            Self::NthValue(nth_value) => nth_value.return_type.clone(),
+            Self::Ntile(ntile) => ntile.return_type.clone(),
         })
     }
 
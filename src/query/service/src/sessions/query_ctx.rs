@@ -37,6 +37,7 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
@@ -62,6 +63,7 @@ use common_storages_fuse::TableContext;
 use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultScan;
 use common_storages_stage::StageTable;
+use common_storages_stream::stream_table::STREAM_ENGINE;
 use common_users::UserApiProvider;
 use dashmap::mapref::multiple::RefMulti;
 use dashmap::DashMap;
@@ -197,6 +199,15 @@ impl QueryContext {
         self.shared.get_data_metrics()
     }
 
+    /// Get the streams read by this query.
+    pub fn get_consumed_streams(&self) -> Vec<Arc<dyn Table>> {
+        self.shared
+            .get_tables_refs()
+            .into_iter()
+            .filter(|table| table.engine() == STREAM_ENGINE)
+            .collect()
+    }
+
     pub fn set_affect(self: &Arc<Self>, affect: QueryAffect) {
         self.shared.set_affect(affect)
     }
@@ -603,6 +614,10 @@ impl TableContext for QueryContext {
         }
         Ok(results)
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
 }
 
 impl TrySpawn for QueryContext {
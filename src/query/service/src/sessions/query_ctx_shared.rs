@@ -256,7 +256,16 @@ impl QueryContextShared {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(catalog)?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let mut cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // The table updated in the explicit transaction is read at its uncommitted version.
+        let buffered = self
+            .session
+            .txn_mgr()
+            .lock()
+            .get_table_from_buffer(cache_table.get_id());
+        if let Some(table_info) = buffered {
+            cache_table = catalog.get_table_by_info(&table_info)?;
+        }
 
         let mut tables_refs = self.tables_refs.lock();
 
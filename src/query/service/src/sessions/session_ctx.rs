@@ -20,6 +20,8 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
@@ -56,6 +58,9 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // The explicit transaction started by `BEGIN`, the table metas updated by the queries
+    // of the session are buffered in it until `COMMIT`.
+    txn_mgr: TxnManagerRef,
 }
 
 impl SessionContext {
@@ -73,6 +78,7 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            txn_mgr: TxnManager::init(),
         }))
     }
 
@@ -276,4 +282,8 @@ impl SessionContext {
         let lock = self.query_ids_results.read();
         HashSet::from_iter(lock.iter().map(|result| result.clone().0))
     }
+
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
 }
@@ -151,6 +151,8 @@ fn test_format() {
             marker_index: None,
             from_correlated_subquery: false,
             contain_runtime_filter: false,
+            merge_join: false,
+            asof_condition: None,
         }
         .into(),
         SExpr::create_unary(
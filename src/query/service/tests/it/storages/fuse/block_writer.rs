@@ -88,6 +88,8 @@ impl<'a> BlockWriter<'a> {
             bloom_filter_index_location,
             bloom_filter_index_size,
             Compression::Lz4Raw,
+            None,
+            0,
         );
         Ok((block_meta, meta))
     }
@@ -103,8 +105,14 @@ impl<'a> BlockWriter<'a> {
             .location_generator
             .block_bloom_index_location(&block_id);
 
-        let maybe_bloom_index =
-            BloomIndex::try_create(FunctionContext::default(), schema, location.1, &[block])?;
+        let maybe_bloom_index = BloomIndex::try_create(
+            FunctionContext::default(),
+            schema,
+            location.1,
+            &[block],
+            None,
+            &[],
+        )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
             let filter_schema = bloom_index.filter_schema;
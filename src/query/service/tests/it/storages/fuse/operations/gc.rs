@@ -18,12 +18,15 @@ use std::sync::Arc;
 use chrono::Duration;
 use common_base::base::tokio;
 use common_catalog::table_context::TableContext;
+use common_config::GlobalConfig;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_meta_app::storage::StorageParams;
 use common_storages_fuse::io::MetaWriter;
 use common_storages_fuse::io::SegmentWriter;
 use common_storages_fuse::statistics::gen_columns_statistics;
 use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_TBL_AGG_INDEX_PREFIX;
 use futures_util::TryStreamExt;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
@@ -31,10 +34,13 @@ use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use uuid::Uuid;
+use walkdir::WalkDir;
 
 use crate::storages::fuse::block_writer::BlockWriter;
 use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::append_sample_data_overwrite;
 use crate::storages::fuse::table_test_fixture::check_data_dir;
+use crate::storages::fuse::table_test_fixture::execute_command;
 use crate::storages::fuse::table_test_fixture::TestFixture;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -243,7 +249,63 @@ async fn test_fuse_purge_orphan_retention() -> Result<()> {
     Ok(())
 }
 
-mod utils {
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_purge_agg_index() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let qry = format!(
+        "create aggregating index idx as select id, count(*) from {}.{} group by id",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    execute_command(ctx.clone(), &qry).await?;
+
+    // S_1 -> seg_1 -> block_1
+    append_sample_data(1, &fixture).await?;
+    // S_2 -> seg_2 -> block_2, block_1 is only referenced by S_1
+    append_sample_data_overwrite(1, true, &fixture).await?;
+    assert_eq!(count_agg_index_files(), 2);
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let keep_last_snapshot = true;
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    fuse_table.do_purge(&table_ctx, keep_last_snapshot).await?;
+
+    check_data_dir(
+        &fixture,
+        "do_gc: the aggregating index of block_1 is purged with it",
+        1,
+        0,
+        1,
+        1,
+        1,
+        Some(()),
+        None,
+    )
+    .await?;
+    assert_eq!(count_agg_index_files(), 1);
+    Ok(())
+}
+
+fn count_agg_index_files() -> usize {
+    let root = match &GlobalConfig::instance().storage.params {
+        StorageParams::Fs(v) => v.root.clone(),
+        _ => panic!("storage type is not fs"),
+    };
+    let dir = format!("/{}/", FUSE_TBL_AGG_INDEX_PREFIX);
+    WalkDir::new(root)
+        .into_iter()
+        .map(|entry| entry.unwrap())
+        .filter(|entry| {
+            entry.file_type().is_file() && entry.path().to_str().unwrap().contains(&dir)
+        })
+        .count()
+}
+
+pub mod utils {
     use std::sync::Arc;
 
     use chrono::DateTime;
@@ -96,6 +96,8 @@ fn test_to_partitions() -> Result<()> {
         bloom_filter_location,
         bloom_filter_size,
         meta::Compression::Lz4Raw,
+        None,
+        0,
     ));
 
     let blocks_metas = (0..num_of_block)
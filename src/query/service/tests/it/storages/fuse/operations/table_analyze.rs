@@ -20,6 +20,7 @@ use common_catalog::table::Table;
 use common_exception::Result;
 use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_expression::ScalarRef;
 use common_expression::SendableDataBlockStream;
 use databend_query::sessions::QueryContext;
@@ -87,6 +88,41 @@ async fn test_table_modify_column_ndv_statistics() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_histogram() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    execute_command(ctx.clone(), "create table t(c int)").await?;
+    execute_command(ctx.clone(), "insert into t values(1),(1),(1),(1),(2),(3)").await?;
+    execute_command(ctx.clone(), "analyze table default.t").await?;
+
+    let catalog = ctx.get_catalog("default")?;
+    let table = catalog
+        .get_table(ctx.get_tenant().as_str(), "default", "t")
+        .await?;
+    let provider = table.column_statistics_provider().await?;
+    let histogram = provider.column_statistics(0).unwrap().histogram.unwrap();
+
+    let upper_bounds = histogram
+        .buckets
+        .iter()
+        .map(|bucket| bucket.upper_bound.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(upper_bounds, vec![
+        Scalar::Number(NumberScalar::Int32(1)),
+        Scalar::Number(NumberScalar::Int32(2)),
+        Scalar::Number(NumberScalar::Int32(3)),
+    ]);
+    assert_eq!(histogram.buckets[0].num_values, 4.0);
+    assert_eq!(histogram.most_common_values, vec![(
+        Scalar::Number(NumberScalar::Int32(1)),
+        4
+    )]);
+
+    Ok(())
+}
+
 async fn check_column_ndv_statistics(
     table: Arc<dyn Table>,
     expected: HashMap<u32, u64>,
@@ -0,0 +1,99 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Duration;
+use common_base::base::tokio;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_storages_fuse::FuseTable;
+
+use crate::storages::fuse::operations::gc::utils;
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::append_sample_data_overwrite;
+use crate::storages::fuse::table_test_fixture::check_data_dir;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_vacuum_orphan_files() -> Result<()> {
+    // verifies that:
+    //
+    // - files referenced by the current snapshot, or by the snapshots of the
+    //   history, shall not be removed
+    // - the segment, block and bloom index that are not referenced by any
+    //   snapshot, shall be removed once they are beyond the retention period
+
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // S_1 -> seg_1 -> block_1
+    append_sample_data(1, &fixture).await?;
+    // S_2 -> seg_2 -> block_2, seg_1 is only referenced by S_1
+    append_sample_data_overwrite(1, true, &fixture).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+    // seg_orphan -> block_orphan, not referenced by any snapshot, like the files
+    // left behind by an aborted commit
+    let segments = utils::generate_segments(fuse_table, 1, 1).await?;
+    let orphan_segment_location = segments[0].0.0.clone();
+
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+
+    // the orphan files are within the retention period
+    let removed = fuse_table
+        .do_vacuum(&table_ctx, Duration::hours(1), false)
+        .await?;
+    assert!(removed.is_empty());
+
+    // dry run reports the orphan files, without removing them
+    let removed = fuse_table
+        .do_vacuum(&table_ctx, Duration::zero(), true)
+        .await?;
+    assert_eq!(removed.len(), 3);
+    assert!(
+        removed
+            .iter()
+            .any(|(location, _)| location == &orphan_segment_location)
+    );
+    check_data_dir(&fixture, "vacuum: dry run", 2, 0, 3, 3, 3, None, None).await?;
+
+    let removed = fuse_table
+        .do_vacuum(&table_ctx, Duration::zero(), false)
+        .await?;
+    assert_eq!(removed.len(), 3);
+    check_data_dir(
+        &fixture,
+        "vacuum: orphan files removed",
+        2,
+        0,
+        2,
+        2,
+        2,
+        None,
+        None,
+    )
+    .await?;
+
+    // nothing left to be removed
+    let removed = fuse_table
+        .do_vacuum(&table_ctx, Duration::zero(), false)
+        .await?;
+    assert!(removed.is_empty());
+
+    Ok(())
+}
@@ -43,6 +43,7 @@ use common_storages_fuse::statistics::STATS_REPLACEMENT_CHAR;
 use common_storages_fuse::statistics::STATS_STRING_PREFIX_LEN;
 use common_storages_fuse::FuseStorageFormat;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
+use databend_query::storages::fuse::statistics::build_column_histogram;
 use databend_query::storages::fuse::statistics::gen_columns_statistics;
 use databend_query::storages::fuse::statistics::reducers;
 use databend_query::storages::fuse::statistics::ClusterStatsGenerator;
@@ -539,6 +540,8 @@ fn test_reduce_block_meta() -> common_exception::Result<()> {
             None,
             bloom_filter_index_size,
             Compression::Lz4Raw,
+            None,
+            0,
         );
         blocks.push(block_meta);
     }
@@ -552,3 +555,39 @@ fn test_reduce_block_meta() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_build_column_histogram() -> common_exception::Result<()> {
+    // 1..=100 each once, with 7 repeated another 100 times.
+    let mut samples = (1..=100u64)
+        .map(|v| Scalar::Number(NumberScalar::UInt64(v)))
+        .collect::<Vec<_>>();
+    samples.extend((0..100).map(|_| Scalar::Number(NumberScalar::UInt64(7))));
+
+    // The whole column has twice the rows of the samples.
+    let histogram = build_column_histogram(samples, 400, 100, 10, 3).unwrap();
+
+    assert_eq!(histogram.most_common_values, vec![(
+        Scalar::Number(NumberScalar::UInt64(7)),
+        202
+    )]);
+
+    // The skewed value is kept in a single bucket.
+    assert_eq!(histogram.buckets.len(), 6);
+    assert_eq!(
+        histogram.buckets[0].upper_bound,
+        Scalar::Number(NumberScalar::UInt64(7))
+    );
+    assert_eq!(histogram.buckets[0].num_values, 214.0);
+    assert_eq!(histogram.buckets[0].num_distinct, 7.0);
+    assert_eq!(
+        histogram.buckets[5].upper_bound,
+        Scalar::Number(NumberScalar::UInt64(100))
+    );
+    let num_values: f64 = histogram.buckets.iter().map(|b| b.num_values).sum();
+    assert_eq!(num_values, 400.0);
+
+    assert!(build_column_histogram(vec![], 0, 0, 10, 3).is_none());
+
+    Ok(())
+}
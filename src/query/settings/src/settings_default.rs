@@ -130,6 +130,11 @@ impl DefaultSettings {
                     desc: "Enables dphyp join order algorithm.",
                     possible_values: None,
                 }),
+                ("enable_aggregating_index", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables rewriting aggregate queries to read the pre-aggregated states of aggregating indexes.",
+                    possible_values: None,
+                }),
                 ("enable_cbo", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables cost-based optimization.",
@@ -155,6 +160,11 @@ impl DefaultSettings {
                     desc: "Sets the maximum number of rows that can be returned in a query result when no specific row count is specified. Setting it to 0 means no limit.",
                     possible_values: None,
                 }),
+                ("max_recursive_cte_iterations", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the maximum number of iterations of a recursive CTE. Setting it to 0 means no limit.",
+                    possible_values: None,
+                }),
                 ("enable_distributed_eval_index", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables evaluated indexes to be created and maintained across multiple nodes.",
@@ -195,6 +205,11 @@ impl DefaultSettings {
                     desc: "Enables generating a bushy join plan with the optimizer.",
                     possible_values: None,
                 }),
+                ("enable_merge_join", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables sort-merge join, the optimizer picks it if the inputs are already ordered by the join keys.",
+                    possible_values: None,
+                }),
                 ("enable_query_result_cache", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables caching query results to improve performance for identical queries.",
@@ -233,6 +248,11 @@ impl DefaultSettings {
                     desc: "Sets the maximum amount of memory in bytes that an aggregator can use before spilling data to storage during query execution.",
                     possible_values: None,
                 }),
+                ("sort_spilling_bytes_threshold_per_proc", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum amount of memory in bytes that a sorter can use before spilling data to storage during query execution.",
+                    possible_values: None,
+                }),
                 ("group_by_shuffle_mode", DefaultSettingValue {
                     value: UserSettingValue::String(String::from("before_merge")),
                     desc: "Group by shuffle mode, 'before_partial' is more balanced, but more data needs to exchange.",
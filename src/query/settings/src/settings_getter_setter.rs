@@ -171,6 +171,10 @@ impl Settings {
         self.try_get_u64("enable_bushy_join")
     }
 
+    pub fn get_enable_merge_join(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_merge_join")? != 0)
+    }
+
     pub fn get_timezone(&self) -> Result<String> {
         self.try_get_string("timezone")
     }
@@ -213,6 +217,10 @@ impl Settings {
         self.try_get_u64("max_result_rows")
     }
 
+    pub fn get_max_recursive_cte_iterations(&self) -> Result<u64> {
+        self.try_get_u64("max_recursive_cte_iterations")
+    }
+
     pub fn set_enable_distributed_eval_index(&self, val: bool) -> Result<()> {
         self.try_set_u64("enable_distributed_eval_index", u64::from(val))
     }
@@ -225,6 +233,10 @@ impl Settings {
         self.try_set_u64("enable_dphyp", u64::from(val))
     }
 
+    pub fn get_enable_aggregating_index(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_aggregating_index")? != 0)
+    }
+
     pub fn get_enable_cbo(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_cbo")? != 0)
     }
@@ -312,6 +324,14 @@ impl Settings {
         self.try_set_u64("spilling_bytes_threshold_per_proc", value as u64)
     }
 
+    pub fn get_sort_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("sort_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
+    pub fn set_sort_spilling_bytes_threshold_per_proc(&self, value: usize) -> Result<()> {
+        self.try_set_u64("sort_spilling_bytes_threshold_per_proc", value as u64)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -28,6 +28,7 @@ use super::Exchange;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MergeJoin;
 use super::PhysicalPlan;
 use super::Project;
 use super::ProjectSet;
@@ -40,6 +41,8 @@ use crate::executor::DistributedInsertSelect;
 use crate::executor::ExchangeSink;
 use crate::executor::ExchangeSource;
 use crate::executor::FragmentKind;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Window;
 use crate::planner::MetadataRef;
@@ -89,6 +92,20 @@ impl PhysicalPlan {
                     children,
                 ))
             }
+            PhysicalPlan::MergeJoin(plan) => {
+                let left_child = plan.left.format_join(metadata)?;
+                let right_child = plan.right.format_join(metadata)?;
+
+                let children = vec![
+                    FormatTreeNode::with_children("Left".to_string(), vec![left_child]),
+                    FormatTreeNode::with_children("Right".to_string(), vec![right_child]),
+                ];
+
+                Ok(FormatTreeNode::with_children(
+                    format!("MergeJoin: {}", plan.join_type),
+                    children,
+                ))
+            }
             other => {
                 let children = other
                     .children()
@@ -131,6 +148,7 @@ fn to_format_tree(
         PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::MergeJoin(plan) => merge_join_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::ExchangeSource(plan) => exchange_source_to_format_tree(plan),
@@ -144,6 +162,12 @@ fn to_format_tree(
         PhysicalPlan::RuntimeFilterSource(plan) => {
             runtime_filter_source_to_format_tree(plan, metadata, prof_span_set)
         }
+        PhysicalPlan::RecursiveCte(plan) => {
+            recursive_cte_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::RecursiveCteScan(plan) => {
+            recursive_cte_scan_to_format_tree(plan, metadata, prof_span_set)
+        }
     }
 }
 
@@ -203,6 +227,18 @@ fn table_scan_to_format_tree(
     };
     children.push(FormatTreeNode::new(push_downs));
 
+    if let Some(agg_index) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.agg_index.as_ref())
+    {
+        children.push(FormatTreeNode::new(format!(
+            "aggregating index: [{}]",
+            agg_index.index_name
+        )));
+    }
+
     let output_columns = plan.source.output_schema.fields();
 
     // If output_columns contains all columns of the source,
@@ -639,6 +675,12 @@ fn hash_join_to_format_tree(
         FormatTreeNode::new(format!("filters: [{filters}]")),
     ];
 
+    if let Some(asof_condition) = &plan.asof_condition {
+        children.push(FormatTreeNode::new(format!(
+            "match condition: [{asof_condition}]"
+        )));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -660,6 +702,64 @@ fn hash_join_to_format_tree(
     ))
 }
 
+fn merge_join_to_format_tree(
+    plan: &MergeJoin,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let left_keys = plan
+        .left_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let right_keys = plan
+        .right_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let filters = plan
+        .non_equi_conditions
+        .iter()
+        .map(|filter| filter.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut left_child = to_format_tree(&plan.left, metadata, prof_span_set)?;
+    let mut right_child = to_format_tree(&plan.right, metadata, prof_span_set)?;
+
+    left_child.payload = format!("{}(Left)", left_child.payload);
+    right_child.payload = format!("{}(Right)", right_child.payload);
+
+    let mut children = vec![
+        FormatTreeNode::new(format!("join type: {}", plan.join_type)),
+        FormatTreeNode::new(format!("left keys: [{left_keys}]")),
+        FormatTreeNode::new(format!("right keys: [{right_keys}]")),
+        FormatTreeNode::new(format!("filters: [{filters}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.push(left_child);
+    children.push(right_child);
+
+    Ok(FormatTreeNode::with_children(
+        "MergeJoin".to_string(),
+        children,
+    ))
+}
+
 fn exchange_to_format_tree(
     plan: &Exchange,
     metadata: &MetadataRef,
@@ -713,6 +813,64 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("distinct: {}", plan.distinct))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.extend(vec![
+        to_format_tree(&plan.anchor, metadata, prof_span_set)?,
+        to_format_tree(&plan.recursive, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCte".to_string(),
+        children,
+    ))
+}
+
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "cte: {}",
+        metadata.read().recursive_cte_name(plan.cte_index)
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -32,6 +32,7 @@ use common_meta_app::schema::TableInfo;
 
 use crate::executor::explain::PlanStatsInfo;
 use crate::optimizer::ColumnSet;
+use crate::plans::ComparisonOp;
 use crate::plans::JoinType;
 use crate::plans::RuntimeFilterId;
 use crate::plans::WindowFuncFrame;
@@ -252,6 +253,27 @@ impl AggregatePartial {
         }
         Ok(DataSchemaRefExt::create(fields))
     }
+
+    /// Whether the input is the partial states read from an aggregating index,
+    /// which are merged instead of being accumulated from the arguments.
+    pub fn from_agg_index(&self) -> bool {
+        reads_agg_index(&self.input)
+    }
+}
+
+/// Whether the plan is a (filtered or projected) table scan which reads an aggregating index.
+pub fn reads_agg_index(plan: &PhysicalPlan) -> bool {
+    match plan {
+        PhysicalPlan::TableScan(scan) => scan
+            .source
+            .push_downs
+            .as_ref()
+            .map_or(false, |push_downs| push_downs.agg_index.is_some()),
+        PhysicalPlan::Filter(filter) => reads_agg_index(&filter.input),
+        PhysicalPlan::EvalScalar(eval_scalar) => reads_agg_index(&eval_scalar.input),
+        PhysicalPlan::Exchange(exchange) => reads_agg_index(&exchange.input),
+        _ => false,
+    }
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -296,6 +318,35 @@ pub enum WindowFunction {
     Rank,
     DenseRank,
     PercentRank,
+    LagLead(LagLeadFunctionDesc),
+    NthValue(NthValueFunctionDesc),
+    Ntile(NtileFunctionDesc),
+    CumeDist,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct LagLeadFunctionDesc {
+    pub is_lag: bool,
+    pub offset: u64,
+    pub ignore_nulls: bool,
+    pub arg: IndexType,
+    pub default: Option<IndexType>,
+    pub return_type: DataType,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NthValueFunctionDesc {
+    /// `None` for `LAST_VALUE`.
+    pub n: Option<u64>,
+    pub ignore_nulls: bool,
+    pub arg: IndexType,
+    pub return_type: DataType,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NtileFunctionDesc {
+    pub n: u64,
+    pub return_type: DataType,
 }
 
 impl WindowFunction {
@@ -305,7 +356,12 @@ impl WindowFunction {
             WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {
                 DataType::Number(NumberDataType::UInt64)
             }
-            WindowFunction::PercentRank => DataType::Number(NumberDataType::Float64),
+            WindowFunction::PercentRank | WindowFunction::CumeDist => {
+                DataType::Number(NumberDataType::Float64)
+            }
+            WindowFunction::LagLead(lag_lead) => lag_lead.return_type.clone(),
+            WindowFunction::NthValue(nth_value) => nth_value.return_type.clone(),
+            WindowFunction::Ntile(ntile) => ntile.return_type.clone(),
         }
     }
 }
@@ -318,6 +374,15 @@ impl Display for WindowFunction {
             WindowFunction::Rank => write!(f, "rank"),
             WindowFunction::DenseRank => write!(f, "dense_rank"),
             WindowFunction::PercentRank => write!(f, "percent_rank"),
+            WindowFunction::LagLead(lag_lead) if lag_lead.is_lag => write!(f, "lag"),
+            WindowFunction::LagLead(_) => write!(f, "lead"),
+            WindowFunction::NthValue(nth_value) => match nth_value.n {
+                Some(1) => write!(f, "first_value"),
+                Some(_) => write!(f, "nth_value"),
+                None => write!(f, "last_value"),
+            },
+            WindowFunction::Ntile(_) => write!(f, "ntile"),
+            WindowFunction::CumeDist => write!(f, "cume_dist"),
         }
     }
 }
@@ -387,6 +452,14 @@ impl Limit {
     }
 }
 
+/// Match condition of asof join, in the form of `probe_key op build_key`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AsofJoinCondition {
+    pub probe_key: RemoteExpr,
+    pub build_key: RemoteExpr,
+    pub op: ComparisonOp,
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct HashJoin {
     /// A unique id of operator in a `PhysicalPlan` tree.
@@ -401,6 +474,8 @@ pub struct HashJoin {
     pub join_type: JoinType,
     pub marker_index: Option<IndexType>,
     pub from_correlated_subquery: bool,
+    // asof_condition is for AsofJoin only.
+    pub asof_condition: Option<AsofJoinCondition>,
 
     // It means that join has a corresponding runtime filter
     pub contain_runtime_filter: bool,
@@ -413,7 +488,7 @@ impl HashJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.probe.output_schema()?.fields().clone();
         match self.join_type {
-            JoinType::Left | JoinType::Single => {
+            JoinType::Left | JoinType::Single | JoinType::LeftAsof => {
                 for field in self.build.output_schema()?.fields() {
                     fields.push(DataField::new(
                         field.name().as_str(),
@@ -490,6 +565,33 @@ impl HashJoin {
     }
 }
 
+/// Sort-merge join, both children are sorted by the join keys in ascending order
+/// with nulls last. Only inner join is supported for now.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MergeJoin {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub left: Box<PhysicalPlan>,
+    pub right: Box<PhysicalPlan>,
+    pub left_keys: Vec<RemoteExpr>,
+    pub right_keys: Vec<RemoteExpr>,
+    pub non_equi_conditions: Vec<RemoteExpr>,
+    pub join_type: JoinType,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl MergeJoin {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let mut fields = self.left.output_schema()?.fields().clone();
+        fields.extend(self.right.output_schema()?.fields().clone());
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Exchange {
     pub input: Box<PhysicalPlan>,
@@ -572,6 +674,48 @@ impl UnionAll {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCte {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_index: IndexType,
+    pub anchor: Box<PhysicalPlan>,
+    pub recursive: Box<PhysicalPlan>,
+    pub pairs: Vec<(String, String)>,
+    pub distinct: bool,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_index: IndexType,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct DistributedInsertSelect {
     pub input: Box<PhysicalPlan>,
@@ -627,9 +771,12 @@ pub enum PhysicalPlan {
     Sort(Sort),
     Limit(Limit),
     HashJoin(HashJoin),
+    MergeJoin(MergeJoin),
     Exchange(Exchange),
     UnionAll(UnionAll),
     RuntimeFilterSource(RuntimeFilterSource),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
@@ -661,6 +808,7 @@ impl PhysicalPlan {
             PhysicalPlan::Sort(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
             PhysicalPlan::HashJoin(plan) => plan.output_schema(),
+            PhysicalPlan::MergeJoin(plan) => plan.output_schema(),
             PhysicalPlan::Exchange(plan) => plan.output_schema(),
             PhysicalPlan::ExchangeSource(plan) => plan.output_schema(),
             PhysicalPlan::ExchangeSink(plan) => plan.output_schema(),
@@ -668,6 +816,8 @@ impl PhysicalPlan {
             PhysicalPlan::DistributedInsertSelect(plan) => plan.output_schema(),
             PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::RuntimeFilterSource(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
         }
     }
 
@@ -684,6 +834,7 @@ impl PhysicalPlan {
             PhysicalPlan::Sort(_) => "Sort".to_string(),
             PhysicalPlan::Limit(_) => "Limit".to_string(),
             PhysicalPlan::HashJoin(_) => "HashJoin".to_string(),
+            PhysicalPlan::MergeJoin(_) => "MergeJoin".to_string(),
             PhysicalPlan::Exchange(_) => "Exchange".to_string(),
             PhysicalPlan::UnionAll(_) => "UnionAll".to_string(),
             PhysicalPlan::DistributedInsertSelect(_) => "DistributedInsertSelect".to_string(),
@@ -691,6 +842,8 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSink(_) => "Exchange Sink".to_string(),
             PhysicalPlan::ProjectSet(_) => "Unnest".to_string(),
             PhysicalPlan::RuntimeFilterSource(_) => "RuntimeFilterSource".to_string(),
+            PhysicalPlan::RecursiveCte(_) => "RecursiveCte".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
         }
     }
 
@@ -709,6 +862,9 @@ impl PhysicalPlan {
             PhysicalPlan::HashJoin(plan) => Box::new(
                 std::iter::once(plan.probe.as_ref()).chain(std::iter::once(plan.build.as_ref())),
             ),
+            PhysicalPlan::MergeJoin(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
             PhysicalPlan::Exchange(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::ExchangeSource(_) => Box::new(std::iter::empty()),
             PhysicalPlan::ExchangeSink(plan) => Box::new(std::iter::once(plan.input.as_ref())),
@@ -723,6 +879,11 @@ impl PhysicalPlan {
                 std::iter::once(plan.left_side.as_ref())
                     .chain(std::iter::once(plan.right_side.as_ref())),
             ),
+            PhysicalPlan::RecursiveCte(plan) => Box::new(
+                std::iter::once(plan.anchor.as_ref())
+                    .chain(std::iter::once(plan.recursive.as_ref())),
+            ),
+            PhysicalPlan::RecursiveCteScan(_) => Box::new(std::iter::empty()),
         }
     }
 }
@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use common_catalog::catalog::CatalogManager;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::AggIndexInfo;
 use common_catalog::plan::PrewhereInfo;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
@@ -39,15 +40,21 @@ use common_expression::TableSchema;
 use common_functions::BUILTIN_FUNCTIONS;
 
 use super::cast_expr_to_non_null_boolean;
+use super::reads_agg_index;
 use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregateFunctionSignature;
 use super::AggregatePartial;
+use super::AsofJoinCondition;
 use super::Exchange as PhysicalExchange;
 use super::Filter;
 use super::HashJoin;
+use super::LagLeadFunctionDesc;
 use super::Limit;
+use super::MergeJoin;
+use super::NthValueFunctionDesc;
+use super::NtileFunctionDesc;
 use super::ProjectSet;
 use super::Sort;
 use super::TableScan;
@@ -58,6 +65,8 @@ use crate::executor::table_read_plan::ToReadDataSourcePlan;
 use crate::executor::EvalScalar;
 use crate::executor::FragmentKind;
 use crate::executor::PhysicalPlan;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::SortDesc;
 use crate::executor::UnionAll;
@@ -68,6 +77,7 @@ use crate::optimizer::SExpr;
 use crate::plans::AggregateMode;
 use crate::plans::Exchange;
 use crate::plans::FunctionCall;
+use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
@@ -76,6 +86,7 @@ use crate::plans::WindowFuncType;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
+use crate::IndexType;
 use crate::Metadata;
 use crate::MetadataRef;
 use crate::TableInternalColumn;
@@ -228,7 +239,12 @@ impl PhysicalPlanBuilder {
                         has_virtual_column = true;
                     }
 
-                    if let Some(prewhere) = &scan.prewhere {
+                    if let Some(agg_index) = &scan.agg_index {
+                        // Only the group columns are read from the aggregating index.
+                        if agg_index.info.group_by.iter().any(|c| c == column.name()) {
+                            name_mapping.insert(column.name().to_string(), *index);
+                        }
+                    } else if let Some(prewhere) = &scan.prewhere {
                         // if there is a prewhere optimization,
                         // we can prune `PhysicalScan`'s output schema.
                         if prewhere.output_columns.contains(index) {
@@ -239,6 +255,17 @@ impl PhysicalPlanBuilder {
                     }
                 }
 
+                if let Some(agg_index) = &scan.agg_index {
+                    for (offset, index) in agg_index
+                        .info
+                        .aggregates
+                        .iter()
+                        .zip(agg_index.state_columns.iter())
+                    {
+                        name_mapping.insert(AggIndexInfo::state_field_name(*offset), *index);
+                    }
+                }
+
                 let table_entry = metadata.table(scan.table_index);
                 let table = table_entry.table();
                 let mut table_schema = table.schema();
@@ -309,12 +336,15 @@ impl PhysicalPlanBuilder {
                     internal_column: None,
                 }))
             }
+            RelOperator::Join(join) if join.merge_join => {
+                self.build_merge_join(s_expr, join, stat_info).await
+            }
             RelOperator::Join(join) => {
                 let build_side = self.build(s_expr.child(1)?).await?;
                 let probe_side = self.build(s_expr.child(0)?).await?;
 
                 let build_schema = match join.join_type {
-                    JoinType::Left | JoinType::Full => {
+                    JoinType::Left | JoinType::Full | JoinType::LeftAsof => {
                         let build_schema = build_side.output_schema()?;
                         // Wrap nullable type for columns in build side.
                         let build_schema = DataSchemaRefExt::create(
@@ -390,6 +420,32 @@ impl PhysicalPlanBuilder {
                         right_join_conditions.push(right_join_condition.clone());
                     }
                 }
+                let asof_condition = match &join.asof_condition {
+                    Some(asof_condition) => {
+                        let probe_key = asof_condition
+                            .left
+                            .resolve_and_check(probe_schema.as_ref())?
+                            .project_column_ref(|index| {
+                                probe_schema.index_of(&index.to_string()).unwrap()
+                            });
+                        let build_key = asof_condition
+                            .right
+                            .resolve_and_check(build_schema.as_ref())?
+                            .project_column_ref(|index| {
+                                build_schema.index_of(&index.to_string()).unwrap()
+                            });
+                        let (probe_key, _) =
+                            ConstantFolder::fold(&probe_key, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                        let (build_key, _) =
+                            ConstantFolder::fold(&build_key, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                        Some(AsofJoinCondition {
+                            probe_key: probe_key.as_remote_expr(),
+                            build_key: build_key.as_remote_expr(),
+                            op: asof_condition.op,
+                        })
+                    }
+                    None => None,
+                };
                 Ok(PhysicalPlan::HashJoin(HashJoin {
                     plan_id: self.next_plan_id(),
                     build: Box::new(build_side),
@@ -437,6 +493,7 @@ impl PhysicalPlanBuilder {
                         .collect::<Result<_>>()?,
                     marker_index: join.marker_index,
                     from_correlated_subquery: join.from_correlated_subquery,
+                    asof_condition,
 
                     contain_runtime_filter: join.contain_runtime_filter,
                     stat_info: Some(stat_info),
@@ -502,6 +559,7 @@ impl PhysicalPlanBuilder {
 
                 let result = match &agg.mode {
                     AggregateMode::Partial => {
+                        let from_agg_index = reads_agg_index(&input);
                         let agg_funcs: Vec<AggregateFunctionDesc> = agg.aggregate_functions.iter().map(|v| {
                             if let ScalarExpr::AggregateFunction(agg) = &v.scalar {
                                 Ok(AggregateFunctionDesc {
@@ -514,15 +572,20 @@ impl PhysicalPlanBuilder {
                                         return_type: *agg.return_type.clone(),
                                     },
                                     output_column: v.index,
-                                    args: agg.args.iter().map(|arg| {
-                                        if let ScalarExpr::BoundColumnRef(col) = arg {
-                                            input_schema.index_of(&col.column.index.to_string())
-                                        } else {
-                                            Err(ErrorCode::Internal(
-                                                "Aggregate function argument must be a BoundColumnRef".to_string()
-                                            ))
-                                        }
-                                    }).collect::<Result<_>>()?,
+                                    args: if from_agg_index {
+                                        // The partial states are read from the aggregating index.
+                                        vec![input_schema.index_of(&v.index.to_string())?]
+                                    } else {
+                                        agg.args.iter().map(|arg| {
+                                            if let ScalarExpr::BoundColumnRef(col) = arg {
+                                                input_schema.index_of(&col.column.index.to_string())
+                                            } else {
+                                                Err(ErrorCode::Internal(
+                                                    "Aggregate function argument must be a BoundColumnRef".to_string()
+                                                ))
+                                            }
+                                        }).collect::<Result<_>>()?
+                                    },
                                     arg_indices: agg.args.iter().map(|arg| {
                                         if let ScalarExpr::BoundColumnRef(col) = arg {
                                             Ok(col.column.index)
@@ -627,13 +690,15 @@ impl PhysicalPlanBuilder {
 
                     // Hack to get before group by schema, we should refactor this
                     AggregateMode::Final => {
-                        let input_schema = match input {
-                            PhysicalPlan::AggregatePartial(ref agg) => agg.input.output_schema()?,
+                        let (input_schema, from_agg_index) = match input {
+                            PhysicalPlan::AggregatePartial(ref agg) => {
+                                (agg.input.output_schema()?, agg.from_agg_index())
+                            }
 
                             PhysicalPlan::Exchange(PhysicalExchange {
                                 input: box PhysicalPlan::AggregatePartial(ref agg),
                                 ..
-                            }) => agg.input.output_schema()?,
+                            }) => (agg.input.output_schema()?, agg.from_agg_index()),
 
                             _ => {
                                 return Err(ErrorCode::Internal(format!(
@@ -655,15 +720,20 @@ impl PhysicalPlanBuilder {
                                         return_type: *agg.return_type.clone(),
                                     },
                                     output_column: v.index,
-                                    args: agg.args.iter().map(|arg| {
-                                        if let ScalarExpr::BoundColumnRef(col) = arg {
-                                            input_schema.index_of(&col.column.index.to_string())
-                                        } else {
-                                            Err(ErrorCode::Internal(
-                                                "Aggregate function argument must be a BoundColumnRef".to_string()
-                                            ))
-                                        }
-                                    }).collect::<Result<_>>()?,
+                                    args: if from_agg_index {
+                                        // The partial states are read from the aggregating index.
+                                        vec![input_schema.index_of(&v.index.to_string())?]
+                                    } else {
+                                        agg.args.iter().map(|arg| {
+                                            if let ScalarExpr::BoundColumnRef(col) = arg {
+                                                input_schema.index_of(&col.column.index.to_string())
+                                            } else {
+                                                Err(ErrorCode::Internal(
+                                                    "Aggregate function argument must be a BoundColumnRef".to_string()
+                                                ))
+                                            }
+                                        }).collect::<Result<_>>()?
+                                    },
                                     arg_indices: agg.args.iter().map(|arg| {
                                         if let ScalarExpr::BoundColumnRef(col) = arg {
                                             Ok(col.column.index)
@@ -774,6 +844,33 @@ impl PhysicalPlanBuilder {
                     WindowFuncType::Rank => WindowFunction::Rank,
                     WindowFuncType::DenseRank => WindowFunction::DenseRank,
                     WindowFuncType::PercentRank => WindowFunction::PercentRank,
+                    WindowFuncType::LagLead(lag_lead) => {
+                        WindowFunction::LagLead(LagLeadFunctionDesc {
+                            is_lag: lag_lead.is_lag,
+                            offset: lag_lead.offset,
+                            ignore_nulls: lag_lead.ignore_nulls,
+                            arg: window_function_arg_index(&lag_lead.arg)?,
+                            default: lag_lead
+                                .default
+                                .as_ref()
+                                .map(|default| window_function_arg_index(default))
+                                .transpose()?,
+                            return_type: *lag_lead.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::NthValue(nth_value) => {
+                        WindowFunction::NthValue(NthValueFunctionDesc {
+                            n: nth_value.n,
+                            ignore_nulls: nth_value.ignore_nulls,
+                            arg: window_function_arg_index(&nth_value.arg)?,
+                            return_type: *nth_value.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::Ntile(ntile) => WindowFunction::Ntile(NtileFunctionDesc {
+                        n: ntile.n,
+                        return_type: *ntile.return_type.clone(),
+                    }),
+                    WindowFuncType::CumeDist => WindowFunction::CumeDist,
                 };
 
                 Ok(PhysicalPlan::Window(Window {
@@ -864,6 +961,49 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::RecursiveCte(op) => {
+                let anchor = self.build(s_expr.child(0)?).await?;
+                let anchor_schema = anchor.output_schema()?;
+                let pairs = op
+                    .pairs
+                    .iter()
+                    .map(|(l, r)| (l.to_string(), r.to_string()))
+                    .collect::<Vec<_>>();
+                let fields = pairs
+                    .iter()
+                    .map(|(left, _)| Ok(anchor_schema.field_with_name(left)?.clone()))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+                    plan_id: self.next_plan_id(),
+                    cte_index: op.cte_index,
+                    anchor: Box::new(anchor),
+                    recursive: Box::new(self.build(s_expr.child(1)?).await?),
+                    pairs,
+                    distinct: op.distinct,
+                    schema: DataSchemaRefExt::create(fields),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::RecursiveCteScan(scan) => {
+                let metadata = self.metadata.read().clone();
+                let fields = scan
+                    .columns
+                    .iter()
+                    .map(|index| {
+                        DataField::new(&index.to_string(), metadata.column(*index).data_type())
+                    })
+                    .collect::<Vec<_>>();
+                Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+                    plan_id: self.next_plan_id(),
+                    cte_index: scan.cte_index,
+                    schema: DataSchemaRefExt::create(fields),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             RelOperator::RuntimeFilterSource(op) => {
                 let left_side = Box::new(self.build(s_expr.child(0)?).await?);
                 let left_schema = left_side.output_schema()?;
@@ -1148,9 +1288,101 @@ impl PhysicalPlanBuilder {
             limit: scan.limit,
             order_by: order_by.unwrap_or_default(),
             virtual_columns,
+            agg_index: scan
+                .agg_index
+                .as_ref()
+                .map(|agg_index| agg_index.info.clone()),
         })
     }
 
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn build_merge_join(
+        &mut self,
+        s_expr: &SExpr,
+        join: &Join,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let plan_id = self.next_plan_id();
+        let mut children = Vec::with_capacity(2);
+        for (child_index, conditions) in [&join.left_conditions, &join.right_conditions]
+            .into_iter()
+            .enumerate()
+        {
+            let input = self.build(s_expr.child(child_index)?).await?;
+            let schema = input.output_schema()?;
+            let order_by = conditions
+                .iter()
+                .map(|condition| match condition {
+                    ScalarExpr::BoundColumnRef(column) => Ok(SortDesc {
+                        asc: true,
+                        nulls_first: false,
+                        order_by: column.column.index,
+                    }),
+                    _ => Err(ErrorCode::Internal(
+                        "Join keys of merge join must be columns",
+                    )),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let keys = conditions
+                .iter()
+                .map(|scalar| {
+                    let expr = scalar
+                        .resolve_and_check(schema.as_ref())?
+                        .project_column_ref(|index| schema.index_of(&index.to_string()).unwrap());
+                    Ok(expr.as_remote_expr())
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let sorted = if is_sorted_by(&input, &order_by) {
+                input
+            } else {
+                PhysicalPlan::Sort(Sort {
+                    plan_id: self.next_plan_id(),
+                    input: Box::new(input),
+                    order_by,
+                    limit: None,
+                    stat_info: None,
+                })
+            };
+            children.push((sorted, keys));
+        }
+        let (right, right_keys) = children.pop().unwrap();
+        let (left, left_keys) = children.pop().unwrap();
+
+        let merged_schema = DataSchemaRefExt::create(
+            left.output_schema()?
+                .fields()
+                .iter()
+                .chain(right.output_schema()?.fields())
+                .cloned()
+                .collect::<Vec<_>>(),
+        );
+        let non_equi_conditions = join
+            .non_equi_conditions
+            .iter()
+            .map(|scalar| {
+                let expr = scalar
+                    .resolve_and_check(merged_schema.as_ref())?
+                    .project_column_ref(|index| {
+                        merged_schema.index_of(&index.to_string()).unwrap()
+                    });
+                let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                Ok(expr.as_remote_expr())
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(PhysicalPlan::MergeJoin(MergeJoin {
+            plan_id,
+            left: Box::new(left),
+            right: Box::new(right),
+            left_keys,
+            right_keys,
+            non_equi_conditions,
+            join_type: join.join_type.clone(),
+            stat_info: Some(stat_info),
+        }))
+    }
+
     fn build_plan_stat_info(&self, s_expr: &SExpr) -> Result<PlanStatsInfo> {
         let rel_expr = RelExpr::with_s_expr(s_expr);
         let stat_info = rel_expr.derive_cardinality()?;
@@ -1160,3 +1392,35 @@ impl PhysicalPlanBuilder {
         })
     }
 }
+
+fn window_function_arg_index(arg: &ScalarExpr) -> Result<IndexType> {
+    if let ScalarExpr::BoundColumnRef(col) = arg {
+        Ok(col.column.index)
+    } else {
+        Err(ErrorCode::Internal(
+            "Window function argument must be a BoundColumnRef".to_string(),
+        ))
+    }
+}
+
+/// Check if the output of the plan is already sorted by `order_by`, so that
+/// the input of merge join doesn't need to be sorted again.
+///
+/// Only an explicit sort is trusted, the data of a clustered table is not
+/// globally ordered by the cluster keys.
+fn is_sorted_by(plan: &PhysicalPlan, order_by: &[SortDesc]) -> bool {
+    match plan {
+        PhysicalPlan::Sort(sort) => {
+            sort.order_by.len() >= order_by.len()
+                && sort.order_by.iter().zip(order_by.iter()).all(|(a, b)| {
+                    a.order_by == b.order_by && a.asc == b.asc && a.nulls_first == b.nulls_first
+                })
+        }
+        // These operators preserve the order of input.
+        PhysicalPlan::Filter(filter) => is_sorted_by(&filter.input, order_by),
+        PhysicalPlan::EvalScalar(eval_scalar) => is_sorted_by(&eval_scalar.input, order_by),
+        PhysicalPlan::Project(project) => is_sorted_by(&project.input, order_by),
+        PhysicalPlan::Limit(limit) => is_sorted_by(&limit.input, order_by),
+        _ => false,
+    }
+}
@@ -23,6 +23,7 @@ use super::DistributedInsertSelect;
 use super::ProjectSet;
 use crate::executor::AggregateFinal;
 use crate::executor::AggregatePartial;
+use crate::executor::AsofJoinCondition;
 use crate::executor::EvalScalar;
 use crate::executor::Exchange;
 use crate::executor::ExchangeSink;
@@ -30,13 +31,17 @@ use crate::executor::ExchangeSource;
 use crate::executor::Filter;
 use crate::executor::HashJoin;
 use crate::executor::Limit;
+use crate::executor::MergeJoin;
 use crate::executor::PhysicalPlan;
 use crate::executor::Project;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Sort;
 use crate::executor::TableScan;
 use crate::executor::UnionAll;
 use crate::executor::Window;
+use crate::plans::ComparisonOp;
 use crate::plans::JoinType;
 
 impl PhysicalPlan {
@@ -66,6 +71,7 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::Sort(sort) => write!(f, "{}", sort)?,
             PhysicalPlan::Limit(limit) => write!(f, "{}", limit)?,
             PhysicalPlan::HashJoin(join) => write!(f, "{}", join)?,
+            PhysicalPlan::MergeJoin(join) => write!(f, "{}", join)?,
             PhysicalPlan::Exchange(exchange) => write!(f, "{}", exchange)?,
             PhysicalPlan::ExchangeSource(source) => write!(f, "{}", source)?,
             PhysicalPlan::ExchangeSink(sink) => write!(f, "{}", sink)?,
@@ -73,6 +79,8 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::DistributedInsertSelect(insert_select) => write!(f, "{}", insert_select)?,
             PhysicalPlan::ProjectSet(unnest) => write!(f, "{}", unnest)?,
             PhysicalPlan::RuntimeFilterSource(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::RecursiveCte(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::RecursiveCteScan(plan) => write!(f, "{}", plan)?,
         }
 
         for node in self.node.children() {
@@ -280,12 +288,68 @@ impl Display for HashJoin {
                     f,
                     "HashJoin: {}, build keys: [{}], probe keys: [{}], join filters: [{}]",
                     &self.join_type, build_keys, probe_keys, join_filters,
-                )
+                )?;
+
+                if let Some(asof_condition) = &self.asof_condition {
+                    write!(f, ", match condition: [{}]", asof_condition)?;
+                }
+                Ok(())
             }
         }
     }
 }
 
+impl Display for AsofJoinCondition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let op = match self.op {
+            ComparisonOp::Equal => "=",
+            ComparisonOp::NotEqual => "<>",
+            ComparisonOp::GT => ">",
+            ComparisonOp::LT => "<",
+            ComparisonOp::GTE => ">=",
+            ComparisonOp::LTE => "<=",
+        };
+        write!(
+            f,
+            "{} {} {}",
+            self.probe_key.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+            op,
+            self.build_key.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+        )
+    }
+}
+
+impl Display for MergeJoin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let left_keys = self
+            .left_keys
+            .iter()
+            .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let right_keys = self
+            .right_keys
+            .iter()
+            .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let join_filters = self
+            .non_equi_conditions
+            .iter()
+            .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(
+            f,
+            "MergeJoin: {}, left keys: [{}], right keys: [{}], join filters: [{}]",
+            &self.join_type, left_keys, right_keys, join_filters,
+        )
+    }
+}
+
 impl Display for Exchange {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let keys = self
@@ -324,6 +388,18 @@ impl Display for UnionAll {
     }
 }
 
+impl Display for RecursiveCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCte")
+    }
+}
+
+impl Display for RecursiveCteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCteScan")
+    }
+}
+
 impl Display for DistributedInsertSelect {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DistributedInsertSelect")
@@ -25,11 +25,14 @@ use super::ExchangeSource;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MergeJoin;
 use super::PhysicalPlan;
 use super::Project;
 use super::ProjectSet;
 use super::Sort;
 use super::TableScan;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::UnionAll;
 use crate::executor::Window;
@@ -48,6 +51,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
             PhysicalPlan::HashJoin(plan) => self.replace_hash_join(plan),
+            PhysicalPlan::MergeJoin(plan) => self.replace_merge_join(plan),
             PhysicalPlan::Exchange(plan) => self.replace_exchange(plan),
             PhysicalPlan::ExchangeSource(plan) => self.replace_exchange_source(plan),
             PhysicalPlan::ExchangeSink(plan) => self.replace_exchange_sink(plan),
@@ -55,6 +59,8 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::DistributedInsertSelect(plan) => self.replace_insert_select(plan),
             PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
             PhysicalPlan::RuntimeFilterSource(plan) => self.replace_runtime_filter_source(plan),
+            PhysicalPlan::RecursiveCte(plan) => self.replace_recursive_cte(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
         }
     }
 
@@ -163,11 +169,28 @@ pub trait PhysicalPlanReplacer {
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
+            asof_condition: plan.asof_condition.clone(),
             contain_runtime_filter: plan.contain_runtime_filter,
             stat_info: plan.stat_info.clone(),
         }))
     }
 
+    fn replace_merge_join(&mut self, plan: &MergeJoin) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+
+        Ok(PhysicalPlan::MergeJoin(MergeJoin {
+            plan_id: plan.plan_id,
+            left: Box::new(left),
+            right: Box::new(right),
+            left_keys: plan.left_keys.clone(),
+            right_keys: plan.right_keys.clone(),
+            non_equi_conditions: plan.non_equi_conditions.clone(),
+            join_type: plan.join_type.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_sort(&mut self, plan: &Sort) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -273,6 +296,25 @@ pub trait PhysicalPlanReplacer {
             right_runtime_filters: plan.right_runtime_filters.clone(),
         }))
     }
+
+    fn replace_recursive_cte(&mut self, plan: &RecursiveCte) -> Result<PhysicalPlan> {
+        let anchor = self.replace(&plan.anchor)?;
+        let recursive = self.replace(&plan.recursive)?;
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            plan_id: plan.plan_id,
+            cte_index: plan.cte_index,
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive),
+            pairs: plan.pairs.clone(),
+            distinct: plan.distinct,
+            schema: plan.schema.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
 }
 
 impl PhysicalPlan {
@@ -317,6 +359,10 @@ impl PhysicalPlan {
                     Self::traverse(&plan.build, pre_visit, visit, post_visit);
                     Self::traverse(&plan.probe, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::MergeJoin(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Exchange(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -338,6 +384,11 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left_side, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right_side, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::RecursiveCte(plan) => {
+                    Self::traverse(&plan.anchor, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.recursive, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::RecursiveCteScan(_) => {}
             }
             post_visit(plan);
         }
@@ -118,6 +118,11 @@ impl ToReadDataSourcePlan for dyn Table {
             }
         }
 
+        if let Some(agg_index) = push_downs.as_ref().and_then(|p| p.agg_index.as_ref()) {
+            // The partial states are read from the aggregating index instead.
+            output_schema = Arc::new(agg_index.output_schema(schema)?);
+        }
+
         if let Some(ref internal_columns) = internal_columns {
             let mut schema = output_schema.as_ref().clone();
             for internal_column in internal_columns.values() {
@@ -40,6 +40,8 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::WindowFunc;
@@ -167,6 +169,30 @@ impl<'a> AggregateRewriter<'a> {
                             return_type: agg.return_type.clone(),
                         })
                     }
+                    WindowFuncType::LagLead(lag_lead) => {
+                        let new_arg = self.visit(&lag_lead.arg)?;
+                        let new_default = match &lag_lead.default {
+                            None => None,
+                            Some(d) => Some(Box::new(self.visit(d)?)),
+                        };
+                        WindowFuncType::LagLead(LagLeadFunction {
+                            is_lag: lag_lead.is_lag,
+                            arg: Box::new(new_arg),
+                            offset: lag_lead.offset,
+                            default: new_default,
+                            ignore_nulls: lag_lead.ignore_nulls,
+                            return_type: lag_lead.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::NthValue(nth_value) => {
+                        let new_arg = self.visit(&nth_value.arg)?;
+                        WindowFuncType::NthValue(NthValueFunction {
+                            n: nth_value.n,
+                            arg: Box::new(new_arg),
+                            ignore_nulls: nth_value.ignore_nulls,
+                            return_type: nth_value.return_type.clone(),
+                        })
+                    }
                     func => func.clone(),
                 };
 
@@ -170,6 +170,20 @@ pub struct BindContext {
 pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
+    /// Whether the CTE is declared in a `WITH RECURSIVE` clause.
+    pub recursive: bool,
+    /// If set, references to the CTE are resolved to the working table
+    /// of a recursive CTE, i.e. we are binding its recursive term.
+    pub working_table: Option<WorkingTableInfo>,
+}
+
+/// The working table of a recursive CTE, which holds the rows produced
+/// by the previous iteration.
+#[derive(Clone, Debug)]
+pub struct WorkingTableInfo {
+    pub cte_index: IndexType,
+    pub column_names: Vec<String>,
+    pub data_types: Vec<DataType>,
 }
 
 impl BindContext {
@@ -187,12 +187,29 @@ impl<'a> Binder {
             Statement::TruncateTable(stmt) => self.bind_truncate_table(stmt).await?,
             Statement::OptimizeTable(stmt) => self.bind_optimize_table(bind_context, stmt).await?,
             Statement::AnalyzeTable(stmt) => self.bind_analyze_table(stmt).await?,
+            Statement::VacuumTable(stmt) => self.bind_vacuum_table(stmt).await?,
             Statement::ExistsTable(stmt) => self.bind_exists_table(stmt).await?,
 
+            // Indexes
+            Statement::CreateIndex(stmt) => self.bind_create_index(stmt).await?,
+            Statement::CreateAggregatingIndex(stmt) => self.bind_create_aggregating_index(stmt).await?,
+            Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
+            Statement::RefreshIndex(stmt) => self.bind_refresh_index(stmt).await?,
+
             // Views
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(stmt).await?
+            }
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
+
+            // Streams
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
 
             // Users
             Statement::CreateUser(stmt) => self.bind_create_user(stmt).await?,
@@ -245,6 +262,7 @@ impl<'a> Binder {
                     .await?
             }
             Statement::Update(stmt) => self.bind_update(bind_context, stmt).await?,
+            Statement::MergeInto(stmt) => self.bind_merge_into(bind_context, stmt).await?,
 
             // Permissions
             Statement::Grant(stmt) => self.bind_grant(stmt).await?,
@@ -395,6 +413,11 @@ impl<'a> Binder {
             Statement::ShowGrantsOfShare(stmt) => {
                 self.bind_show_grants_of_share(stmt).await?
             }
+
+            // Transactions
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Rollback => Plan::Rollback,
         };
         Ok(plan)
     }
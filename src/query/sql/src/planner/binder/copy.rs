@@ -19,6 +19,7 @@ use std::time::Instant;
 
 use common_ast::ast::CopyStmt;
 use common_ast::ast::CopyUnit;
+use common_ast::ast::Expr;
 use common_ast::ast::FileLocation;
 use common_ast::ast::Query;
 use common_ast::ast::SelectTarget;
@@ -48,6 +49,7 @@ use tracing::info;
 
 use crate::binder::location::parse_uri_location;
 use crate::binder::Binder;
+use crate::normalize_identifier;
 use crate::plans::CopyPlan;
 use crate::plans::Plan;
 use crate::plans::ValidationMode;
@@ -60,6 +62,12 @@ impl<'a> Binder {
         bind_context: &mut BindContext,
         stmt: &CopyStmt,
     ) -> Result<Plan> {
+        if stmt.partition_by.is_some() && matches!(stmt.dst, CopyUnit::Table { .. }) {
+            return Err(ErrorCode::SyntaxException(
+                "PARTITION BY is only supported by COPY INTO <location>",
+            ));
+        }
+
         match (&stmt.src, &stmt.dst) {
             (
                 CopyUnit::StageLocation(stage_location),
@@ -351,20 +359,9 @@ impl<'a> Binder {
     ) -> Result<Plan> {
         let subquery =
             format!("SELECT * FROM {src_catalog_name}.{src_database_name}.{src_table_name}");
-        let tokens = tokenize_sql(&subquery)?;
-        let sub_stmt_msg = parse_sql(&tokens, Dialect::PostgreSQL)?;
-        let sub_stmt = sub_stmt_msg.0;
-        let query = match &sub_stmt {
-            Statement::Query(query) => {
-                self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                    .await?
-            }
-            _ => {
-                return Err(ErrorCode::SyntaxException(
-                    "COPY INTO <location> FROM <non-query> is invalid",
-                ));
-            }
-        };
+        let (query, partition_by) = self
+            .bind_unload_query(bind_context, stmt.partition_by.as_deref(), &subquery)
+            .await?;
 
         // Validation mode.
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
@@ -379,6 +376,7 @@ impl<'a> Binder {
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
         })))
     }
 
@@ -396,20 +394,9 @@ impl<'a> Binder {
     ) -> Result<Plan> {
         let subquery =
             format!("SELECT * FROM {src_catalog_name}.{src_database_name}.{src_table_name}");
-        let tokens = tokenize_sql(&subquery)?;
-        let sub_stmt_msg = parse_sql(&tokens, Dialect::PostgreSQL)?;
-        let sub_stmt = sub_stmt_msg.0;
-        let query = match &sub_stmt {
-            Statement::Query(query) => {
-                self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                    .await?
-            }
-            _ => {
-                return Err(ErrorCode::SyntaxException(
-                    "COPY INTO <location> FROM <non-query> is invalid",
-                ));
-            }
-        };
+        let (query, partition_by) = self
+            .bind_unload_query(bind_context, stmt.partition_by.as_deref(), &subquery)
+            .await?;
 
         // Validation mode.
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
@@ -430,6 +417,7 @@ impl<'a> Binder {
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
         })))
     }
 
@@ -443,8 +431,12 @@ impl<'a> Binder {
         dst_stage: &str,
         dst_path: &str,
     ) -> Result<Plan> {
-        let query = self
-            .bind_statement(bind_context, &Statement::Query(Box::new(src_query.clone())))
+        let (query, partition_by) = self
+            .bind_unload_query(
+                bind_context,
+                stmt.partition_by.as_deref(),
+                &src_query.to_string(),
+            )
             .await?;
 
         // Validation mode.
@@ -460,6 +452,7 @@ impl<'a> Binder {
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
         })))
     }
 
@@ -473,8 +466,12 @@ impl<'a> Binder {
         src_query: &Query,
         dst_uri_location: &mut UriLocation,
     ) -> Result<Plan> {
-        let query = self
-            .bind_statement(bind_context, &Statement::Query(Box::new(src_query.clone())))
+        let (query, partition_by) = self
+            .bind_unload_query(
+                bind_context,
+                stmt.partition_by.as_deref(),
+                &src_query.to_string(),
+            )
             .await?;
 
         // Validation mode.
@@ -496,6 +493,7 @@ impl<'a> Binder {
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
         })))
     }
 
@@ -648,6 +646,102 @@ impl<'a> Binder {
         })))
     }
 
+    /// Bind the query to unload into the partitions of `PARTITION BY`, the keys are
+    /// appended to the output columns of the query as strings. The columns used as the
+    /// keys directly are not unloaded, as they are in the paths of the partitions.
+    ///
+    /// Returns the names of the partition keys.
+    #[async_backtrace::framed]
+    async fn bind_partitioned_unload_query(
+        &mut self,
+        bind_context: &mut BindContext,
+        partition_by: &[SelectTarget],
+        src_query: &str,
+    ) -> Result<(Plan, Vec<String>)> {
+        let mut keys: Vec<String> = Vec::with_capacity(partition_by.len());
+        let mut excluded_columns = vec![];
+        let mut key_exprs = Vec::with_capacity(partition_by.len());
+        for (i, target) in partition_by.iter().enumerate() {
+            let (expr, alias) = match target {
+                SelectTarget::AliasedExpr { expr, alias } => (expr, alias),
+                SelectTarget::QualifiedName { .. } => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "invalid partition key {target} in PARTITION BY"
+                    )));
+                }
+            };
+            let column = match expr.as_ref() {
+                Expr::ColumnRef { column, .. } => {
+                    Some(normalize_identifier(column, &self.name_resolution_ctx).name)
+                }
+                _ => None,
+            };
+            let key = match (alias, &column) {
+                (Some(alias), _) => normalize_identifier(alias, &self.name_resolution_ctx).name,
+                (None, Some(column)) => column.clone(),
+                (None, None) => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "partition key {expr} in PARTITION BY must have an alias, e.g. `{expr} AS key`"
+                    )));
+                }
+            };
+            if keys.contains(&key) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated partition key {key} in PARTITION BY"
+                )));
+            }
+            if column.as_ref() == Some(&key) {
+                excluded_columns.push(format!("\"{key}\""));
+            }
+            key_exprs.push(format!("CAST(({expr}) AS STRING) AS _partition_key_{i}"));
+            keys.push(key);
+        }
+
+        let projection = if excluded_columns.is_empty() {
+            "*".to_string()
+        } else {
+            format!("* EXCLUDE ({})", excluded_columns.join(", "))
+        };
+        let subquery = format!(
+            "SELECT {projection}, {} FROM ({src_query}) AS _partitioned_unload",
+            key_exprs.join(", ")
+        );
+        let query = self.bind_query_text(bind_context, &subquery).await?;
+        Ok((query, keys))
+    }
+
+    /// Bind the query to unload, and the partition keys if there is `PARTITION BY`.
+    #[async_backtrace::framed]
+    async fn bind_unload_query(
+        &mut self,
+        bind_context: &mut BindContext,
+        partition_by: Option<&[SelectTarget]>,
+        src_query: &str,
+    ) -> Result<(Plan, Vec<String>)> {
+        match partition_by {
+            Some(partition_by) => {
+                self.bind_partitioned_unload_query(bind_context, partition_by, src_query)
+                    .await
+            }
+            None => Ok((self.bind_query_text(bind_context, src_query).await?, vec![])),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn bind_query_text(&mut self, bind_context: &mut BindContext, sql: &str) -> Result<Plan> {
+        let tokens = tokenize_sql(sql)?;
+        let (sub_stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        match &sub_stmt {
+            Statement::Query(query) => {
+                self.bind_statement(bind_context, &Statement::Query(query.clone()))
+                    .await
+            }
+            _ => Err(ErrorCode::SyntaxException(
+                "COPY INTO <location> FROM <non-query> is invalid",
+            )),
+        }
+    }
+
     #[async_backtrace::framed]
     async fn apply_stage_options(&mut self, stmt: &CopyStmt, stage: &mut StageInfo) -> Result<()> {
         if !stmt.file_format.is_empty() {
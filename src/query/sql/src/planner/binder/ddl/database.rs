@@ -24,6 +24,7 @@ use common_ast::ast::ShowCreateDatabaseStmt;
 use common_ast::ast::ShowDatabasesStmt;
 use common_ast::ast::ShowLimit;
 use common_ast::ast::UndropDatabaseStmt;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::DataField;
@@ -34,6 +35,7 @@ use tracing::debug;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
+use crate::plans::CloneDatabasePlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::Plan;
@@ -211,6 +213,7 @@ impl Binder {
             engine,
             options,
             from_share,
+            clone_from,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -220,6 +223,22 @@ impl Binder {
             .unwrap_or_else(|| self.ctx.get_current_catalog());
         let database = normalize_identifier(database, &self.name_resolution_ctx).name;
 
+        if let Some(clone_from) = clone_from {
+            if engine.is_some() || !options.is_empty() {
+                return Err(ErrorCode::BadArguments(
+                    "CLONE can not be used with ENGINE or options of database",
+                ));
+            }
+            let source_database = normalize_identifier(clone_from, &self.name_resolution_ctx).name;
+            return Ok(Plan::CloneDatabase(Box::new(CloneDatabasePlan {
+                if_not_exists: *if_not_exists,
+                tenant,
+                catalog,
+                database,
+                source_database,
+            })));
+        }
+
         // change the database engine to share if create from share
         let engine = if from_share.is_some() {
             &Some(DatabaseEngine::Share)
@@ -0,0 +1,217 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateAggregatingIndexStmt;
+use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::DropIndexStmt;
+use common_ast::ast::RefreshIndexStmt;
+use common_ast::ast::SetExpr;
+use common_ast::ast::TableIndexType as AstTableIndexType;
+use common_ast::ast::TableReference;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_meta_app::schema::TableIndex;
+use common_meta_app::schema::TableIndexType;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::parse_agg_index_query;
+use crate::plans::CreateIndexPlan;
+use crate::plans::DropIndexPlan;
+use crate::plans::Plan;
+use crate::plans::RefreshIndexPlan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_index(
+        &mut self,
+        stmt: &CreateIndexStmt,
+    ) -> Result<Plan> {
+        let CreateIndexStmt {
+            index_type,
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+        let index_type = match index_type {
+            AstTableIndexType::Bloom => TableIndexType::Bloom,
+            AstTableIndexType::Inverted => TableIndexType::Inverted,
+            AstTableIndexType::Ngram => TableIndexType::Ngram,
+        };
+
+        let schema = self
+            .ctx
+            .get_table(&catalog, &database, &table)
+            .await?
+            .schema();
+        let mut column_ids = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+            let field = schema.field_with_name(&column)?;
+            // Inverted and ngram filters are only built for string columns.
+            if index_type != TableIndexType::Bloom
+                && !matches!(field.data_type().remove_nullable(), TableDataType::String)
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "{} index is only supported on string columns, but column '{}' is {}",
+                    index_type,
+                    column,
+                    field.data_type()
+                )));
+            }
+            if !column_ids.contains(&field.column_id()) {
+                column_ids.push(field.column_id());
+            }
+        }
+
+        Ok(Plan::CreateIndex(Box::new(CreateIndexPlan {
+            if_not_exists: *if_not_exists,
+            catalog,
+            database,
+            table,
+            index: TableIndex {
+                name: index_name,
+                index_type,
+                column_ids,
+                query: "".to_string(),
+            },
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_aggregating_index(
+        &mut self,
+        stmt: &CreateAggregatingIndexStmt,
+    ) -> Result<Plan> {
+        let CreateAggregatingIndexStmt {
+            if_not_exists,
+            index_name,
+            query,
+        } = stmt;
+
+        let (catalog, database, table) = match &query.body {
+            SetExpr::Select(select) => match select.from.as_slice() {
+                [
+                    TableReference::Table {
+                        catalog,
+                        database,
+                        table,
+                        ..
+                    },
+                ] => self.normalize_object_identifier_triple(catalog, database, table),
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "The query of aggregating index must select from a single table",
+                    ));
+                }
+            },
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "The query of aggregating index must be a SELECT statement",
+                ));
+            }
+        };
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        // Check the query, the columns of the index are the group items
+        // and the arguments of the aggregates.
+        let table_meta = self.ctx.get_table(&catalog, &database, &table).await?;
+        let schema = table_meta.schema();
+        let query = query.to_string();
+        let definition = parse_agg_index_query(self.ctx.clone(), table_meta, &query)?;
+        let mut column_ids = Vec::new();
+        let columns = definition
+            .aggregates
+            .iter()
+            .flat_map(|agg| agg.args.iter())
+            .chain(definition.group_by.iter());
+        for column in columns {
+            let column_id = schema.field_with_name(column)?.column_id();
+            if !column_ids.contains(&column_id) {
+                column_ids.push(column_id);
+            }
+        }
+
+        Ok(Plan::CreateIndex(Box::new(CreateIndexPlan {
+            if_not_exists: *if_not_exists,
+            catalog,
+            database,
+            table,
+            index: TableIndex {
+                name: index_name,
+                index_type: TableIndexType::Aggregating,
+                column_ids,
+                query,
+            },
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_index(
+        &mut self,
+        stmt: &DropIndexStmt,
+    ) -> Result<Plan> {
+        let DropIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        Ok(Plan::DropIndex(Box::new(DropIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_index(
+        &mut self,
+        stmt: &RefreshIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshIndexStmt {
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        Ok(Plan::RefreshIndex(Box::new(RefreshIndexPlan {
+            catalog,
+            database,
+            table,
+            index_name,
+        })))
+    }
+}
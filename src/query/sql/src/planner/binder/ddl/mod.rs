@@ -16,8 +16,10 @@ mod account;
 mod catalog;
 mod column;
 mod database;
+mod index;
 mod role;
 mod share;
 mod stage;
+mod stream;
 mod table;
 mod view;
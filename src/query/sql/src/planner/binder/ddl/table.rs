@@ -32,6 +32,7 @@ use common_ast::ast::Identifier;
 use common_ast::ast::Literal;
 use common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use common_ast::ast::OptimizeTableStmt;
+use common_ast::ast::Query;
 use common_ast::ast::RenameTableStmt;
 use common_ast::ast::ShowCreateTableStmt;
 use common_ast::ast::ShowLimit;
@@ -42,6 +43,7 @@ use common_ast::ast::TableReference;
 use common_ast::ast::TruncateTableStmt;
 use common_ast::ast::UndropTableStmt;
 use common_ast::ast::UriLocation;
+use common_ast::ast::VacuumTableStmt;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
@@ -61,6 +63,7 @@ use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
+use common_storages_delta::DeltaTable;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_reserved_opt_key;
@@ -83,6 +86,7 @@ use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CastExpr;
+use crate::plans::CloneTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
@@ -99,6 +103,7 @@ use crate::plans::RewriteKind;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
+use crate::plans::VacuumTablePlan;
 use crate::BindContext;
 use crate::ColumnBinding;
 use crate::Planner;
@@ -325,6 +330,54 @@ impl Binder {
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        if let Some(CreateTableSource::Clone {
+            catalog: source_catalog,
+            database: source_database,
+            table: source_table,
+            travel_point,
+        }) = source
+        {
+            if engine.is_some()
+                || uri_location.is_some()
+                || !table_options.is_empty()
+                || !cluster_by.is_empty()
+                || as_query.is_some()
+                || *transient
+            {
+                return Err(ErrorCode::BadArguments(
+                    "CLONE can not be used with ENGINE, location, options, CLUSTER BY, AS SELECT or TRANSIENT of table",
+                ));
+            }
+            let (source_catalog, source_database, source_table) = self
+                .normalize_object_identifier_triple(source_catalog, source_database, source_table);
+            if source_catalog != catalog {
+                return Err(ErrorCode::BadArguments(format!(
+                    "can not clone table {}.{} of catalog {} into catalog {}",
+                    source_database, source_table, source_catalog, catalog
+                )));
+            }
+            let navigation = match travel_point {
+                Some(point) => {
+                    let mut bind_context = BindContext::new();
+                    Some(
+                        self.resolve_data_travel_point(&mut bind_context, point)
+                            .await?,
+                    )
+                }
+                None => None,
+            };
+            return Ok(Plan::CloneTable(Box::new(CloneTablePlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                catalog,
+                database,
+                table,
+                source_database,
+                source_table,
+                navigation,
+            })));
+        }
+
         // Take FUSE engine AS default engine
         let engine = engine.unwrap_or(Engine::Fuse);
         let mut options: BTreeMap<String, String> = BTreeMap::new();
@@ -369,6 +422,11 @@ impl Binder {
 
         // Build table schema
         let (schema, field_default_exprs, field_comments) = match (&source, &as_query) {
+            _ if engine == Engine::Delta => {
+                // The schema of a DELTA table is the one in its transaction log
+                self.analyze_delta_table_schema(source, as_query, storage_params.as_ref())
+                    .await?
+            }
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 self.analyze_create_table_schema(source).await?
@@ -419,61 +477,13 @@ impl Binder {
         };
 
         if engine == Engine::Fuse {
-            // Currently, [Table] can not accesses its database id yet, thus
-            // here we keep the db id AS an entry of `table_meta.options`.
-            //
-            // To make the unit/stateless test cases (`show create ..`) easier,
-            // here we care about the FUSE engine only.
-            //
-            // Later, when database id is kept, let say in `TableInfo`, we can
-            // safely eliminate this "FUSE" constant and the table meta option entry.
-            let catalog = self.ctx.get_catalog(&catalog)?;
-            let db = catalog
-                .get_database(&self.ctx.get_tenant(), &database)
-                .await?;
-            let db_id = db.get_db_info().ident.db_id;
-            options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
-
-            let config = GlobalConfig::instance();
-            let is_blocking_fs = matches!(
-                storage_params.as_ref().unwrap_or(&config.storage.params),
-                StorageParams::Fs(_)
-            );
-
-            // we should persist the storage format and compression type instead of using the default value in fuse table
-            if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
-                let default_storage_format = match config.query.default_storage_format.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "native"
-                        } else {
-                            "parquet"
-                        }
-                    }
-                    _ => config.query.default_storage_format.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_STORAGE_FORMAT.to_owned(),
-                    default_storage_format.to_owned(),
-                );
-            }
-
-            if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
-                let default_compression = match config.query.default_compression.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "lz4"
-                        } else {
-                            "zstd"
-                        }
-                    }
-                    _ => config.query.default_compression.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_TABLE_COMPRESSION.to_owned(),
-                    default_compression.to_owned(),
-                );
-            }
+            self.fill_fuse_table_options(
+                &catalog,
+                &database,
+                storage_params.as_ref(),
+                &mut options,
+            )
+            .await?;
         }
 
         let cluster_key = {
@@ -834,6 +844,31 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_vacuum_table(
+        &mut self,
+        stmt: &VacuumTableStmt,
+    ) -> Result<Plan> {
+        let VacuumTableStmt {
+            catalog,
+            database,
+            table,
+            retain_hours,
+            dry_run,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        Ok(Plan::VacuumTable(Box::new(VacuumTablePlan {
+            catalog,
+            database,
+            table,
+            retain_hours: *retain_hours,
+            dry_run: *dry_run,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_exists_table(
         &mut self,
@@ -944,11 +979,105 @@ impl Binder {
                     Ok((table.schema(), vec![], table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone { .. } => Err(ErrorCode::Internal(
+                "CLONE table should be bound as a clone plan",
+            )),
+        }
+    }
+
+    /// Load the schema of the delta lake table at the location of a DELTA table.
+    #[async_backtrace::framed]
+    async fn analyze_delta_table_schema(
+        &self,
+        source: &Option<CreateTableSource>,
+        as_query: &Option<Box<Query>>,
+        storage_params: Option<&StorageParams>,
+    ) -> Result<(TableSchemaRef, Vec<Option<String>>, Vec<String>)> {
+        if source.is_some() || as_query.is_some() {
+            return Err(ErrorCode::BadArguments(
+                "DELTA table can not be created with columns or AS SELECT, its schema is loaded from the delta transaction log",
+            ));
+        }
+        let storage_params = storage_params.ok_or_else(|| {
+            ErrorCode::BadArguments("DELTA table requires the LOCATION of the delta lake table")
+        })?;
+        let schema = Arc::new(DeltaTable::load_schema(storage_params).await?);
+        Self::validate_create_table_schema(&schema)?;
+        Ok((schema, vec![], vec![]))
+    }
+
+    /// Fill the default options of a fuse table to be created.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn fill_fuse_table_options(
+        &self,
+        catalog: &str,
+        database: &str,
+        storage_params: Option<&StorageParams>,
+        options: &mut BTreeMap<String, String>,
+    ) -> Result<()> {
+        // Currently, [Table] can not accesses its database id yet, thus
+        // here we keep the db id AS an entry of `table_meta.options`.
+        //
+        // To make the unit/stateless test cases (`show create ..`) easier,
+        // here we care about the FUSE engine only.
+        //
+        // Later, when database id is kept, let say in `TableInfo`, we can
+        // safely eliminate this "FUSE" constant and the table meta option entry.
+        let catalog = self.ctx.get_catalog(catalog)?;
+        let db = catalog
+            .get_database(&self.ctx.get_tenant(), database)
+            .await?;
+        let db_id = db.get_db_info().ident.db_id;
+        options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+
+        let config = GlobalConfig::instance();
+        let is_blocking_fs = matches!(
+            storage_params.unwrap_or(&config.storage.params),
+            StorageParams::Fs(_)
+        );
+
+        // we should persist the storage format and compression type instead of using the default value in fuse table
+        if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
+            let default_storage_format = match config.query.default_storage_format.as_str() {
+                "" | "auto" => {
+                    if is_blocking_fs {
+                        "native"
+                    } else {
+                        "parquet"
+                    }
+                }
+                _ => config.query.default_storage_format.as_str(),
+            };
+            options.insert(
+                OPT_KEY_STORAGE_FORMAT.to_owned(),
+                default_storage_format.to_owned(),
+            );
         }
+
+        if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
+            let default_compression = match config.query.default_compression.as_str() {
+                "" | "auto" => {
+                    if is_blocking_fs {
+                        "lz4"
+                    } else {
+                        "zstd"
+                    }
+                }
+                _ => config.query.default_compression.as_str(),
+            };
+            options.insert(
+                OPT_KEY_TABLE_COMPRESSION.to_owned(),
+                default_compression.to_owned(),
+            );
+        }
+
+        Ok(())
     }
 
     /// Validate the schema of the table to be created.
-    fn validate_create_table_schema(schema: &TableSchemaRef) -> Result<()> {
+    pub(in crate::planner::binder) fn validate_create_table_schema(
+        schema: &TableSchemaRef,
+    ) -> Result<()> {
         // Check if there are duplicated column names
         let mut name_set = HashSet::new();
         for field in schema.fields() {
@@ -12,17 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_ast::ast::AlterViewStmt;
+use common_ast::ast::CreateMaterializedViewStmt;
 use common_ast::ast::CreateViewStmt;
 use common_ast::ast::DropViewStmt;
+use common_ast::ast::RefreshMaterializedViewStmt;
+use common_ast::VisitorMut;
 use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::TableNameQualifier;
 use crate::plans::AlterViewPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::BindContext;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -117,4 +129,86 @@ impl Binder {
         };
         Ok(Plan::DropView(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            query,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        let mut init_bind_context = BindContext::new();
+        let (_, bind_context) = self.bind_query(&mut init_bind_context, query).await?;
+        let fields = bind_context
+            .columns
+            .iter()
+            .map(|column_binding| {
+                Ok(TableField::new(
+                    &column_binding.column_name,
+                    infer_schema_type(&column_binding.data_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = TableSchemaRefExt::create(fields);
+        Self::validate_create_table_schema(&schema)?;
+
+        let mut options = BTreeMap::new();
+        self.fill_fuse_table_options(&catalog, &database, None, &mut options)
+            .await?;
+
+        // The query is planned again on each refresh, maybe in another database.
+        let mut query = query.clone();
+        let mut qualifier = TableNameQualifier::new(
+            &self.name_resolution_ctx,
+            self.ctx.get_current_catalog(),
+            self.ctx.get_current_database(),
+        );
+        qualifier.visit_query(&mut query);
+        let subquery = format!("{}", query);
+
+        let plan = CreateMaterializedViewPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+            schema,
+            options,
+            subquery,
+        };
+        Ok(Plan::CreateMaterializedView(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::RefreshMaterializedView(Box::new(plan)))
+    }
 }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use async_recursion::async_recursion;
 use common_ast::ast::split_conjunctions_expr;
 use common_ast::ast::split_equivalent_predicate_expr;
+use common_ast::ast::BinaryOperator;
 use common_ast::ast::Expr;
 use common_ast::ast::JoinCondition;
 use common_ast::ast::JoinOperator;
@@ -37,7 +38,9 @@ use crate::planner::binder::scalar::ScalarBinder;
 use crate::planner::binder::wrap_cast;
 use crate::planner::binder::Binder;
 use crate::planner::semantic::NameResolutionContext;
+use crate::plans::AsofCondition;
 use crate::plans::BoundColumnRef;
+use crate::plans::ComparisonOp;
 use crate::plans::Filter;
 use crate::plans::Join;
 use crate::plans::JoinType;
@@ -50,6 +53,7 @@ pub struct JoinConditions {
     pub(crate) right_conditions: Vec<ScalarExpr>,
     pub(crate) non_equi_conditions: Vec<ScalarExpr>,
     pub(crate) other_conditions: Vec<ScalarExpr>,
+    pub(crate) asof_condition: Option<AsofCondition>,
 }
 
 impl Binder {
@@ -81,6 +85,11 @@ impl Binder {
                     "cross join should not contain join conditions".to_string(),
                 ));
             }
+            JoinOperator::Asof | JoinOperator::LeftAsof if join.match_condition.is_none() => {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should contain match condition".to_string(),
+                ));
+            }
             _ => (),
         };
 
@@ -98,6 +107,14 @@ impl Binder {
             &mut bind_context,
             &join.condition,
         );
+        let asof_condition = match &join.match_condition {
+            Some(match_condition) => Some(
+                join_condition_resolver
+                    .resolve_match_condition(match_condition)
+                    .await?,
+            ),
+            None => None,
+        };
         join_condition_resolver
             .resolve(
                 &mut left_join_conditions,
@@ -108,11 +125,23 @@ impl Binder {
             )
             .await?;
 
+        if asof_condition.is_some() && !non_equi_conditions.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "asof join only supports equi conditions in ON clause".to_string(),
+            ));
+        }
+        if asof_condition.is_some() && left_join_conditions.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "asof join should contain at least one equi condition in ON clause".to_string(),
+            ));
+        }
+
         let join_conditions = JoinConditions {
             left_conditions: left_join_conditions,
             right_conditions: right_join_conditions,
             non_equi_conditions,
             other_conditions,
+            asof_condition,
         };
         let s_expr = match &join.op {
             JoinOperator::Inner => {
@@ -166,6 +195,15 @@ impl Binder {
                     right_child,
                 )
             }
+            JoinOperator::Asof => {
+                self.bind_join_with_type(JoinType::Asof, join_conditions, left_child, right_child)
+            }
+            JoinOperator::LeftAsof => self.bind_join_with_type(
+                JoinType::LeftAsof,
+                join_conditions,
+                left_child,
+                right_child,
+            ),
         }?;
         Ok((s_expr, bind_context))
     }
@@ -181,6 +219,7 @@ impl Binder {
         let right_conditions = join_conditions.right_conditions;
         let mut non_equi_conditions = join_conditions.non_equi_conditions;
         let other_conditions = join_conditions.other_conditions;
+        let asof_condition = join_conditions.asof_condition;
         if join_type == JoinType::Cross
             && (!left_conditions.is_empty() || !right_conditions.is_empty())
         {
@@ -202,6 +241,8 @@ impl Binder {
             marker_index: None,
             from_correlated_subquery: false,
             contain_runtime_filter: false,
+            merge_join: false,
+            asof_condition,
         };
         Ok(SExpr::create_binary(
             logical_join.into(),
@@ -282,7 +323,7 @@ fn wrap_nullable_for_column(
     bind_context: &mut BindContext,
 ) {
     match join_type {
-        JoinOperator::LeftOuter => {
+        JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
             for column in left_context.all_column_bindings() {
                 bind_context.add_column_binding(column.clone());
             }
@@ -610,6 +651,91 @@ impl<'a> JoinConditionResolver<'a> {
         Ok(())
     }
 
+    #[async_backtrace::framed]
+    async fn resolve_match_condition(&self, match_condition: &Expr) -> Result<AsofCondition> {
+        let (left, right, op) = match match_condition {
+            Expr::BinaryOp {
+                op:
+                    op @ (BinaryOperator::Gt
+                    | BinaryOperator::Gte
+                    | BinaryOperator::Lt
+                    | BinaryOperator::Lte),
+                left,
+                right,
+                ..
+            } => (left, right, ComparisonOp::try_from(op)?),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "match condition of asof join must be one of comparisons: >, >=, <, <="
+                        .to_string(),
+                )
+                .set_span(match_condition.span()));
+            }
+        };
+
+        let mut join_context = (*self.join_context).clone();
+        wrap_nullable_for_column(
+            &self.join_op,
+            self.left_context,
+            self.right_context,
+            &mut join_context,
+        );
+        let mut scalar_binder = ScalarBinder::new(
+            &mut join_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (mut left, _) = scalar_binder.bind(left).await?;
+        let (mut right, _) = scalar_binder.bind(right).await?;
+
+        let left_used_columns = left.used_columns();
+        let right_used_columns = right.used_columns();
+        let (left_columns, right_columns) = self.left_right_columns()?;
+        let op = if left_used_columns.is_empty() || right_used_columns.is_empty() {
+            None
+        } else if left_used_columns.is_subset(&left_columns)
+            && right_used_columns.is_subset(&right_columns)
+        {
+            Some(op)
+        } else if left_used_columns.is_subset(&right_columns)
+            && right_used_columns.is_subset(&left_columns)
+        {
+            std::mem::swap(&mut left, &mut right);
+            Some(op.reverse())
+        } else {
+            None
+        };
+        let op = op.ok_or_else(|| {
+            ErrorCode::SemanticError(
+                "each side of match condition must only reference one table of asof join"
+                    .to_string(),
+            )
+            .set_span(match_condition.span())
+        })?;
+
+        let left_type = left.data_type()?;
+        let right_type = right.data_type()?;
+        if left_type != right_type {
+            let least_super_type = common_super_type(
+                left_type.clone(),
+                right_type.clone(),
+                &BUILTIN_FUNCTIONS.default_cast_rules,
+            )
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(format!(
+                    "cannot compare {left_type} with {right_type} in match condition"
+                ))
+                .set_span(match_condition.span())
+            })?;
+            left = wrap_cast(&left, &least_super_type);
+            right = wrap_cast(&right, &least_super_type);
+        }
+
+        Ok(AsofCondition { left, right, op })
+    }
+
     fn add_equi_conditions(
         &self,
         mut left: ScalarExpr,
@@ -683,7 +809,7 @@ impl<'a> JoinConditionResolver<'a> {
         let predicate_used_columns = predicate.used_columns();
         let (left_columns, right_columns) = self.left_right_columns()?;
         match self.join_op {
-            JoinOperator::LeftOuter => {
+            JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
                 if predicate_used_columns.is_subset(&right_columns) {
                     other_join_conditions.push(predicate);
                     return Ok(true);
@@ -695,7 +821,7 @@ impl<'a> JoinConditionResolver<'a> {
                     return Ok(true);
                 }
             }
-            JoinOperator::Inner => {
+            JoinOperator::Inner | JoinOperator::Asof => {
                 if predicate_used_columns.is_subset(&left_columns)
                     || predicate_used_columns.is_subset(&right_columns)
                 {
@@ -0,0 +1,381 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::MatchOperation;
+use common_ast::ast::MergeIntoStmt;
+use common_ast::ast::MergeOption;
+use common_ast::ast::TableReference;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::split_conjunctions;
+use crate::binder::wrap_cast;
+use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::binder::Visibility;
+use crate::field_default_value;
+use crate::normalize_identifier;
+use crate::optimizer::optimize;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::OptimizerConfig;
+use crate::optimizer::OptimizerContext;
+use crate::plans::ConstantExpr;
+use crate::plans::MatchedEvaluator;
+use crate::plans::MergeInto;
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::plans::UnmatchedEvaluator;
+use crate::BindContext;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_merge_into(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &MergeIntoStmt,
+    ) -> Result<Plan> {
+        let MergeIntoStmt {
+            catalog,
+            database,
+            table,
+            target_alias,
+            source,
+            join_expr,
+            merge_options,
+        } = stmt;
+
+        if merge_options.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "MERGE INTO requires at least one WHEN MATCHED or WHEN NOT MATCHED clause",
+            ));
+        }
+
+        let catalog_name = catalog.as_ref().map_or_else(
+            || self.ctx.get_current_catalog(),
+            |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
+        );
+        let database_name = database.as_ref().map_or_else(
+            || self.ctx.get_current_database(),
+            |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
+        );
+        let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let schema = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?
+            .schema();
+
+        // Bind the target table.
+        let target_reference = TableReference::Table {
+            span: None,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: table.clone(),
+            alias: target_alias.clone(),
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+        };
+        let (_, target_context) = self
+            .bind_table_reference(bind_context, &target_reference)
+            .await?;
+        let target_columns = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                target_context
+                    .columns
+                    .iter()
+                    .find(|column| {
+                        column.visibility == Visibility::Visible
+                            && column.table_index.is_some()
+                            && &column.column_name == field.name()
+                    })
+                    .map(|column| column.index)
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "column `{}` of table `{table_name}` not found",
+                            field.name()
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Bind the source, its output columns are the visible columns in order.
+        let (source_expr, mut source_context) =
+            self.bind_table_reference(bind_context, source).await?;
+        source_context
+            .columns
+            .retain(|column| column.visibility != Visibility::InVisible);
+        let source_columns = source_context
+            .columns
+            .iter()
+            .map(|column| column.index)
+            .collect::<Vec<_>>();
+        let source_plan = Plan::Query {
+            s_expr: Box::new(source_expr),
+            metadata: self.metadata.clone(),
+            bind_context: Box::new(source_context.clone()),
+            rewrite_kind: None,
+            formatted_ast: None,
+            ignore_result: false,
+        };
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: false,
+        }));
+        let source_plan = optimize(self.ctx.clone(), opt_ctx, source_plan)?;
+
+        // The ON condition and the WHEN MATCHED clauses can see the columns of both sides.
+        let mut join_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        for column in target_context
+            .columns
+            .iter()
+            .filter(|column| target_columns.contains(&column.index))
+            .chain(source_context.columns.iter())
+        {
+            join_context.add_column_binding(column.clone());
+        }
+        // The WHEN NOT MATCHED clauses can only see the columns of the source.
+        let mut source_only_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        for column in source_context.columns.iter() {
+            source_only_context.add_column_binding(column.clone());
+        }
+
+        let target_set = target_columns.iter().cloned().collect::<ColumnSet>();
+        let source_set = source_columns.iter().cloned().collect::<ColumnSet>();
+        let join_set = target_set
+            .union(&source_set)
+            .cloned()
+            .collect::<ColumnSet>();
+
+        let join_condition = self
+            .bind_merge_into_expr(&mut join_context, join_expr, &join_set)
+            .await?;
+        let join_keys = extract_join_keys(&join_condition, &target_set, &source_set)?;
+        if join_keys.is_empty() {
+            // without an equi-condition every target row would be joined with every source row
+            return Err(ErrorCode::SemanticError(format!(
+                "MERGE INTO requires an equality condition between the target table and the source in ON, got `{join_expr}`"
+            ))
+            .set_span(join_expr.span()));
+        }
+
+        let mut matched_evaluators = vec![];
+        let mut unmatched_evaluators = vec![];
+        for option in merge_options {
+            match option {
+                MergeOption::Match(clause) => {
+                    let condition = match &clause.selection {
+                        Some(expr) => Some(
+                            self.bind_merge_into_expr(&mut join_context, expr, &join_set)
+                                .await?,
+                        ),
+                        None => None,
+                    };
+                    let update = match &clause.operation {
+                        MatchOperation::Update { update_list } => {
+                            let mut update_columns = HashMap::with_capacity(update_list.len());
+                            for update_expr in update_list {
+                                let col_name = normalize_identifier(
+                                    &update_expr.name,
+                                    &self.name_resolution_ctx,
+                                )
+                                .name;
+                                let index = schema.index_of(&col_name)?;
+                                if update_columns.contains_key(&index) {
+                                    return Err(ErrorCode::BadArguments(format!(
+                                        "Multiple assignments in the single statement to column `{}`",
+                                        col_name
+                                    )));
+                                }
+                                let scalar = self
+                                    .bind_merge_into_expr(
+                                        &mut join_context,
+                                        &update_expr.expr,
+                                        &join_set,
+                                    )
+                                    .await?;
+                                let target_type = DataType::from(schema.field(index).data_type());
+                                update_columns.insert(index, wrap_cast(&scalar, &target_type));
+                            }
+                            Some(update_columns)
+                        }
+                        MatchOperation::Delete => None,
+                    };
+                    matched_evaluators.push(MatchedEvaluator { condition, update });
+                }
+                MergeOption::Unmatch(clause) => {
+                    let condition = match &clause.selection {
+                        Some(expr) => Some(
+                            self.bind_merge_into_expr(&mut source_only_context, expr, &source_set)
+                                .await?,
+                        ),
+                        None => None,
+                    };
+                    let column_indexes = if clause.columns.is_empty() {
+                        (0..schema.num_fields()).collect::<Vec<_>>()
+                    } else {
+                        clause
+                            .columns
+                            .iter()
+                            .map(|ident| {
+                                schema.index_of(
+                                    &normalize_identifier(ident, &self.name_resolution_ctx).name,
+                                )
+                            })
+                            .collect::<Result<Vec<_>>>()?
+                    };
+                    if column_indexes.len() != clause.values.len() {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "WHEN NOT MATCHED clause has {} columns but {} values",
+                            column_indexes.len(),
+                            clause.values.len()
+                        )));
+                    }
+                    let mut values = HashMap::with_capacity(column_indexes.len());
+                    for (index, expr) in column_indexes.into_iter().zip(clause.values.iter()) {
+                        let scalar = self
+                            .bind_merge_into_expr(&mut source_only_context, expr, &source_set)
+                            .await?;
+                        if values.insert(index, scalar).is_some() {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Column `{}` is specified more than once",
+                                schema.field(index).name()
+                            )));
+                        }
+                    }
+                    let values = self.fill_unmatched_values(&schema, values)?;
+                    unmatched_evaluators.push(UnmatchedEvaluator { condition, values });
+                }
+            }
+        }
+
+        let plan = MergeInto {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            source: Box::new(source_plan),
+            target_columns,
+            source_columns,
+            join_keys,
+            join_condition,
+            matched_evaluators,
+            unmatched_evaluators,
+        };
+        Ok(Plan::MergeInto(Box::new(plan)))
+    }
+
+    async fn bind_merge_into_expr(
+        &mut self,
+        bind_context: &mut BindContext,
+        expr: &Expr,
+        visible_columns: &ColumnSet,
+    ) -> Result<ScalarExpr> {
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, _) = scalar_binder.bind(expr).await?;
+        if matches!(scalar, ScalarExpr::SubqueryExpr(_)) {
+            return Err(ErrorCode::Internal(
+                "Merge into does not support subquery temporarily",
+            ));
+        }
+        if !scalar.used_columns().is_subset(visible_columns) {
+            return Err(ErrorCode::SemanticError(format!(
+                "expression `{expr}` references columns that are not available in this clause"
+            ))
+            .set_span(expr.span()));
+        }
+        Ok(scalar)
+    }
+
+    // Complete the values of a WHEN NOT MATCHED clause, the columns not listed take their default values.
+    fn fill_unmatched_values(
+        &self,
+        schema: &TableSchemaRef,
+        mut values: HashMap<usize, ScalarExpr>,
+    ) -> Result<Vec<ScalarExpr>> {
+        schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let scalar = match values.remove(&index) {
+                    Some(scalar) => scalar,
+                    None => ScalarExpr::ConstantExpr(ConstantExpr {
+                        span: None,
+                        value: field_default_value(self.ctx.clone(), field)?,
+                    }),
+                };
+                Ok(wrap_cast(&scalar, &DataType::from(field.data_type())))
+            })
+            .collect()
+    }
+}
+
+// Extract the equi-conditions of the form `target_expr = source_expr` from the ON condition,
+// casting both sides to their common super type.
+fn extract_join_keys(
+    join_condition: &ScalarExpr,
+    target_set: &ColumnSet,
+    source_set: &ColumnSet,
+) -> Result<Vec<(ScalarExpr, ScalarExpr)>> {
+    let uses_only = |scalar: &ScalarExpr, columns: &ColumnSet| {
+        let used_columns = scalar.used_columns();
+        !used_columns.is_empty() && used_columns.is_subset(columns)
+    };
+
+    let mut join_keys = vec![];
+    for conjunction in split_conjunctions(join_condition) {
+        let (left, right) = match &conjunction {
+            ScalarExpr::FunctionCall(func)
+                if func.func_name == "eq" && func.arguments.len() == 2 =>
+            {
+                (&func.arguments[0], &func.arguments[1])
+            }
+            _ => continue,
+        };
+        let (target_key, source_key) =
+            if uses_only(left, target_set) && uses_only(right, source_set) {
+                (left, right)
+            } else if uses_only(left, source_set) && uses_only(right, target_set) {
+                (right, left)
+            } else {
+                continue;
+            };
+        if let Some(common_type) = common_super_type(
+            target_key.data_type()?,
+            source_key.data_type()?,
+            &BUILTIN_FUNCTIONS.default_cast_rules,
+        ) {
+            join_keys.push((
+                wrap_cast(target_key, &common_type),
+                wrap_cast(source_key, &common_type),
+            ));
+        }
+    }
+    Ok(join_keys)
+}
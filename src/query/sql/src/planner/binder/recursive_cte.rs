@@ -0,0 +1,306 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::SelectStmt;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperator;
+use common_ast::ast::TableReference;
+use common_ast::walk_expr;
+use common_ast::walk_query;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::CteInfo;
+use crate::binder::Visibility;
+use crate::binder::WorkingTableInfo;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
+use crate::BindContext;
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::NameResolutionContext;
+
+/// The recursive term is bound with the types of the working table, and the types
+/// of its output may be wider than them, e.g. `SELECT n + 1 FROM t`. In that case we
+/// bind it again with the common super types, until the types don't change anymore,
+/// or fail if they still change after this number of times.
+const MAX_WORKING_TABLE_TYPE_COERCIONS: usize = 8;
+
+impl Binder {
+    /// Bind a CTE declared in `WITH RECURSIVE`, it must be of the form
+    /// `anchor_term UNION [ALL] recursive_term` if it references itself.
+    #[async_backtrace::framed]
+    pub(super) async fn bind_recursive_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        cte_name: &str,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        let mut counter = CteReferenceCounter::new(cte_name, &self.name_resolution_ctx);
+        walk_query(&mut counter, query);
+        if counter.count == 0 {
+            // Not actually recursive, bind it as a normal CTE.
+            return self.bind_query(bind_context, query).await;
+        }
+
+        let set_operation = match &query.body {
+            SetExpr::SetOperation(set_operation) if set_operation.op == SetOperator::Union => {
+                set_operation
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive CTE `{cte_name}` must be of the form `anchor_term UNION [ALL] recursive_term`"
+                ))
+                .set_span(span));
+            }
+        };
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "WITH, ORDER BY, LIMIT and OFFSET are not supported in recursive CTE `{cte_name}`"
+            ))
+            .set_span(span));
+        }
+        if counter.count > 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "recursive reference to CTE `{cte_name}` must not appear more than once"
+            ))
+            .set_span(span));
+        }
+        let mut counter = CteReferenceCounter::new(cte_name, &self.name_resolution_ctx);
+        counter.visit_set_expr(&set_operation.left);
+        if counter.count > 0 {
+            return Err(ErrorCode::SemanticError(format!(
+                "recursive reference to CTE `{cte_name}` must not appear within its anchor term"
+            ))
+            .set_span(set_operation.left.span()));
+        }
+
+        let (anchor_expr, anchor_context) = self
+            .bind_set_expr(bind_context, &set_operation.left, &[])
+            .await?;
+        let column_names = anchor_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| {
+                cte_info
+                    .columns_alias
+                    .get(idx)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone())
+            })
+            .collect::<Vec<_>>();
+        let cte_index = self
+            .metadata
+            .write()
+            .add_recursive_cte(cte_name.to_string());
+
+        let mut data_types = anchor_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect::<Vec<_>>();
+        let mut coercions = 0;
+        let (recursive_expr, recursive_context) = loop {
+            let working_table = WorkingTableInfo {
+                cte_index,
+                column_names: column_names.clone(),
+                data_types: data_types.clone(),
+            };
+            let mut recursive_context = BindContext::with_parent(Box::new(bind_context.clone()));
+            recursive_context
+                .ctes_map
+                .insert(cte_name.to_string(), CteInfo {
+                    columns_alias: cte_info.columns_alias.clone(),
+                    query: cte_info.query.clone(),
+                    recursive: true,
+                    working_table: Some(working_table),
+                });
+            let (recursive_expr, recursive_context) = self
+                .bind_set_expr(&mut recursive_context, &set_operation.right, &[])
+                .await?;
+
+            if recursive_context.columns.len() != data_types.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive CTE `{cte_name}` has {} columns in its anchor term, but {} columns in its recursive term",
+                    data_types.len(),
+                    recursive_context.columns.len()
+                ))
+                .set_span(set_operation.right.span()));
+            }
+            let mut super_types = Vec::with_capacity(data_types.len());
+            for (data_type, column) in data_types.iter().zip(recursive_context.columns.iter()) {
+                let super_type = common_super_type(
+                    data_type.clone(),
+                    *column.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                )
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "column `{}` of recursive CTE `{cte_name}` has type {} in its anchor term, but type {} in its recursive term",
+                        column.column_name, data_type, column.data_type
+                    ))
+                    .set_span(span)
+                })?;
+                super_types.push(super_type);
+            }
+
+            if super_types == data_types {
+                // The output of the recursive term will be casted to the types of the working table.
+                break (recursive_expr, recursive_context);
+            }
+            coercions += 1;
+            if coercions >= MAX_WORKING_TABLE_TYPE_COERCIONS {
+                let (index, super_type) = super_types
+                    .iter()
+                    .enumerate()
+                    .find(|(index, super_type)| *super_type != &data_types[*index])
+                    .unwrap();
+                return Err(ErrorCode::SemanticError(format!(
+                    "the type of column `{}` of recursive CTE `{cte_name}` keeps widening in its recursive term, from {} to {}, cast it to a wider type in the anchor term",
+                    recursive_context.columns[index].column_name, data_types[index], super_type
+                ))
+                .set_span(set_operation.right.span()));
+            }
+            data_types = super_types;
+        };
+
+        let (new_bind_context, pairs, anchor_expr, recursive_expr) = self.coercion_union_type(
+            set_operation.left.span(),
+            set_operation.right.span(),
+            anchor_context,
+            recursive_context,
+            anchor_expr,
+            recursive_expr,
+            data_types,
+        )?;
+
+        let recursive_cte = RecursiveCte {
+            cte_index,
+            pairs,
+            distinct: !set_operation.all,
+        };
+        let s_expr = SExpr::create_binary(recursive_cte.into(), anchor_expr, recursive_expr);
+        Ok((s_expr, new_bind_context))
+    }
+
+    /// Bind a reference to a recursive CTE within its recursive term, which reads
+    /// the working table.
+    pub(super) fn bind_recursive_cte_scan(
+        &mut self,
+        bind_context: &BindContext,
+        cte_name: &str,
+        working_table: &WorkingTableInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut columns = Vec::with_capacity(working_table.column_names.len());
+        for (column_name, data_type) in working_table
+            .column_names
+            .iter()
+            .zip(working_table.data_types.iter())
+        {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            new_bind_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: Some(cte_name.to_string()),
+                table_index: None,
+                column_name: column_name.clone(),
+                index,
+                data_type: Box::new(data_type.clone()),
+                visibility: Visibility::Visible,
+            });
+            columns.push(index);
+        }
+
+        let scan = RecursiveCteScan {
+            cte_index: working_table.cte_index,
+            columns,
+        };
+        Ok((SExpr::create_leaf(scan.into()), new_bind_context))
+    }
+}
+
+/// Count the references to a CTE in a query.
+struct CteReferenceCounter<'a> {
+    cte_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    count: usize,
+}
+
+impl<'a> CteReferenceCounter<'a> {
+    fn new(cte_name: &'a str, name_resolution_ctx: &'a NameResolutionContext) -> Self {
+        Self {
+            cte_name,
+            name_resolution_ctx,
+            count: 0,
+        }
+    }
+
+    fn count_table_reference(&mut self, table_ref: &TableReference) {
+        match table_ref {
+            TableReference::Table {
+                catalog: None,
+                database: None,
+                table,
+                ..
+            } => {
+                if normalize_identifier(table, self.name_resolution_ctx).name == self.cte_name {
+                    self.count += 1;
+                }
+            }
+            TableReference::Subquery { subquery, .. } => walk_query(self, subquery),
+            TableReference::Join { join, .. } => {
+                self.count_table_reference(&join.left);
+                self.count_table_reference(&join.right);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'ast> Visitor<'ast> for CteReferenceCounter<'a> {
+    fn visit_select_stmt(&mut self, stmt: &'ast SelectStmt) {
+        for target in stmt.select_list.iter() {
+            if let SelectTarget::AliasedExpr { expr, .. } = target {
+                walk_expr(self, expr);
+            }
+        }
+        for table_ref in stmt.from.iter() {
+            self.count_table_reference(table_ref);
+        }
+        if let Some(selection) = &stmt.selection {
+            walk_expr(self, selection);
+        }
+        if let Some(having) = &stmt.having {
+            walk_expr(self, having);
+        }
+    }
+}
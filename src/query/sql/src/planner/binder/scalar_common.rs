@@ -176,6 +176,14 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
                 WindowFuncType::Aggregate(agg) => {
                     agg.args.iter().all(|arg| prune_by_children(arg, columns))
                 }
+                WindowFuncType::LagLead(lag_lead) => {
+                    prune_by_children(&lag_lead.arg, columns)
+                        && lag_lead
+                            .default
+                            .as_ref()
+                            .map_or(true, |default| prune_by_children(default, columns))
+                }
+                WindowFuncType::NthValue(nth_value) => prune_by_children(&nth_value.arg, columns),
                 _ => false,
             };
             flag || scalar
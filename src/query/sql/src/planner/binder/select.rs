@@ -32,6 +32,7 @@ use common_ast::ast::SetExpr;
 use common_ast::ast::SetOperator;
 use common_ast::ast::TableReference;
 use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::ast::WindowSpec;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -94,6 +95,7 @@ impl Binder {
                     join: Join {
                         op: JoinOperator::CrossJoin,
                         condition: JoinCondition::None,
+                        match_condition: None,
                         left: Box::new(left),
                         right: Box::new(right),
                     },
@@ -266,6 +268,8 @@ impl Binder {
                 let cte_info = CteInfo {
                     columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
                     query: cte.query.clone(),
+                    recursive: with.recursive,
+                    working_table: None,
                 };
                 bind_context.ctes_map.insert(table_name, cte_info);
             }
@@ -557,6 +561,7 @@ impl Binder {
             right_conditions,
             non_equi_conditions: vec![],
             other_conditions: vec![],
+            asof_condition: None,
         };
         let s_expr = self.bind_join_with_type(join_type, join_conditions, left_expr, right_expr)?;
         Ok((s_expr, left_context))
@@ -564,7 +569,7 @@ impl Binder {
 
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
-    fn coercion_union_type(
+    pub(super) fn coercion_union_type(
         &self,
         left_span: Span,
         right_span: Span,
@@ -934,7 +939,7 @@ impl<'a> SelectRewriter<'a> {
             match target {
                 SelectTarget::AliasedExpr { expr, .. } => match expr {
                     box Expr::FunctionCall { window, .. } => {
-                        if let Some(window) = window {
+                        if let Some(WindowDesc { window, .. }) = window {
                             match window {
                                 Window::WindowReference(reference) => {
                                     let window_spec = window_definitions
@@ -964,7 +969,7 @@ impl<'a> SelectRewriter<'a> {
             for order in &mut new_order_by {
                 match &mut order.expr {
                     Expr::FunctionCall { window, .. } => {
-                        if let Some(window) = window {
+                        if let Some(WindowDesc { window, .. }) = window {
                             match window {
                                 Window::WindowReference(reference) => {
                                     let window_spec = window_definitions
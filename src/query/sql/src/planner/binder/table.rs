@@ -33,6 +33,7 @@ use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::StageTableInfo;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
@@ -49,6 +50,7 @@ use common_expression::Scalar;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageInfo;
+use common_pipeline_sources::input_formats::InputContext;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
@@ -56,6 +58,7 @@ use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultCacheMetaManager;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::ResultScan;
+use common_storages_stage::StageTable;
 use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
@@ -467,39 +470,59 @@ impl Binder {
         alias: &Option<TableAlias>,
         files_to_copy: Option<Vec<StageFileInfo>>,
     ) -> Result<(SExpr, BindContext)> {
-        if matches!(stage_info.file_format_params, FileFormatParams::Parquet(..)) {
-            let read_options = ParquetReadOptions::default();
-
-            let table =
+        let table = match &stage_info.file_format_params {
+            FileFormatParams::Parquet(..) => {
+                let read_options = ParquetReadOptions::default();
                 ParquetTable::create(stage_info.clone(), files_info, read_options, files_to_copy)
+                    .await?
+            }
+            FileFormatParams::Avro(..)
+            | FileFormatParams::Orc(..)
+            | FileFormatParams::Arrow(..) => {
+                // The schema is read from the metadata of the first file.
+                let operator = StageTable::get_op(&stage_info)?;
+                let first_file = match files_to_copy.as_ref().and_then(|files| files.first()) {
+                    Some(file) => file.clone(),
+                    None => files_info.first_file(&operator).await?,
+                };
+                let schema = InputContext::get_input_format(&stage_info.file_format_params)?
+                    .infer_schema(&first_file.path, &operator)
                     .await?;
-
-            let table_alias_name = if let Some(table_alias) = alias {
-                Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
-            } else {
-                None
-            };
-
-            let table_index = self.metadata.write().add_table(
-                CATALOG_DEFAULT.to_string(),
-                "system".to_string(),
-                table.clone(),
-                table_alias_name,
-                false,
-            );
-
-            let (s_expr, mut bind_context) = self
-                .bind_base_table(bind_context, "system", table_index)
-                .await?;
-            if let Some(alias) = alias {
-                bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+                StageTable::try_create(StageTableInfo {
+                    schema,
+                    files_info,
+                    stage_info,
+                    files_to_copy,
+                })?
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "stage table function only support parquet, avro, orc and arrow format for now",
+                ));
             }
-            Ok((s_expr, bind_context))
+        };
+
+        let table_alias_name = if let Some(table_alias) = alias {
+            Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
         } else {
-            Err(ErrorCode::Unimplemented(
-                "stage table function only support parquet format for now",
-            ))
+            None
+        };
+
+        let table_index = self.metadata.write().add_table(
+            CATALOG_DEFAULT.to_string(),
+            "system".to_string(),
+            table.clone(),
+            table_alias_name,
+            false,
+        );
+
+        let (s_expr, mut bind_context) = self
+            .bind_base_table(bind_context, "system", table_index)
+            .await?;
+        if let Some(alias) = alias {
+            bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
         }
+        Ok((s_expr, bind_context))
     }
 
     #[async_backtrace::framed]
@@ -597,9 +620,15 @@ impl Binder {
             srfs: Default::default(),
             expr_context: ExprContext::default(),
         };
-        let (s_expr, mut new_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        let (s_expr, mut new_bind_context) = if let Some(working_table) = &cte_info.working_table {
+            self.bind_recursive_cte_scan(&new_bind_context, table_name, working_table)?
+        } else if cte_info.recursive {
+            self.bind_recursive_cte(span, &mut new_bind_context, table_name, cte_info)
+                .await?
+        } else {
+            self.bind_query(&mut new_bind_context, &cte_info.query)
+                .await?
+        };
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -717,6 +746,7 @@ impl Binder {
                         col_stats,
                     },
                     prewhere: None,
+                    agg_index: None,
                 }
                 .into(),
             ),
@@ -24,6 +24,8 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Window;
@@ -203,6 +205,49 @@ impl<'a> WindowRewriter<'a> {
         }
     }
 
+    // Replace the argument of a window function with a column reference,
+    // a derived column is added if the argument is not a column.
+    fn replace_function_arg(
+        &mut self,
+        arg: &ScalarExpr,
+        name: String,
+        window_args: &mut Vec<ScalarItem>,
+    ) -> Result<ScalarExpr> {
+        let arg = self.visit(arg)?;
+        if let ScalarExpr::BoundColumnRef(column_ref) = &arg {
+            window_args.push(ScalarItem {
+                index: column_ref.column.index,
+                scalar: arg.clone(),
+            });
+            return Ok(column_ref.clone().into());
+        }
+
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column(name.clone(), arg.data_type()?);
+
+        // Generate a ColumnBinding for each argument of window function
+        let column_binding = ColumnBinding {
+            database_name: None,
+            table_name: None,
+            table_index: None,
+            column_name: name,
+            index,
+            data_type: Box::new(arg.data_type()?),
+            visibility: Visibility::Visible,
+        };
+        window_args.push(ScalarItem {
+            index,
+            scalar: arg.clone(),
+        });
+        Ok(BoundColumnRef {
+            span: arg.span(),
+            column: column_binding,
+        }
+        .into())
+    }
+
     fn replace_window_function(&mut self, window: &WindowFunc) -> Result<ScalarExpr> {
         let mut replaced_partition_items: Vec<ScalarExpr> =
             Vec::with_capacity(window.partition_by.len());
@@ -216,42 +261,9 @@ impl<'a> WindowRewriter<'a> {
                 // resolve aggregate function args in window function.
                 let mut replaced_args: Vec<ScalarExpr> = Vec::with_capacity(agg.args.len());
                 for (i, arg) in agg.args.iter().enumerate() {
-                    let arg = self.visit(arg)?;
                     let name = format!("{}_arg_{}", &window_func_name, i);
-                    if let ScalarExpr::BoundColumnRef(column_ref) = &arg {
-                        replaced_args.push(column_ref.clone().into());
-                        agg_args.push(ScalarItem {
-                            index: column_ref.column.index,
-                            scalar: arg.clone(),
-                        });
-                    } else {
-                        let index = self
-                            .metadata
-                            .write()
-                            .add_derived_column(name.clone(), arg.data_type()?);
-
-                        // Generate a ColumnBinding for each argument of aggregates
-                        let column_binding = ColumnBinding {
-                            database_name: None,
-                            table_name: None,
-                            table_index: None,
-                            column_name: name,
-                            index,
-                            data_type: Box::new(arg.data_type()?),
-                            visibility: Visibility::Visible,
-                        };
-                        replaced_args.push(
-                            BoundColumnRef {
-                                span: arg.span(),
-                                column: column_binding.clone(),
-                            }
-                            .into(),
-                        );
-                        agg_args.push(ScalarItem {
-                            index,
-                            scalar: arg.clone(),
-                        });
-                    }
+                    let replaced_arg = self.replace_function_arg(arg, name, &mut agg_args)?;
+                    replaced_args.push(replaced_arg);
                 }
                 WindowFuncType::Aggregate(AggregateFunction {
                     display_name: agg.display_name.clone(),
@@ -262,6 +274,40 @@ impl<'a> WindowRewriter<'a> {
                     return_type: agg.return_type.clone(),
                 })
             }
+            WindowFuncType::LagLead(lag_lead) => {
+                let name = format!("{}_arg", &window_func_name);
+                let replaced_arg = self.replace_function_arg(&lag_lead.arg, name, &mut agg_args)?;
+                let replaced_default = match &lag_lead.default {
+                    None => None,
+                    Some(default) => {
+                        let name = format!("{}_default", &window_func_name);
+                        Some(Box::new(self.replace_function_arg(
+                            default,
+                            name,
+                            &mut agg_args,
+                        )?))
+                    }
+                };
+                WindowFuncType::LagLead(LagLeadFunction {
+                    is_lag: lag_lead.is_lag,
+                    arg: Box::new(replaced_arg),
+                    offset: lag_lead.offset,
+                    default: replaced_default,
+                    ignore_nulls: lag_lead.ignore_nulls,
+                    return_type: lag_lead.return_type.clone(),
+                })
+            }
+            WindowFuncType::NthValue(nth_value) => {
+                let name = format!("{}_arg", &window_func_name);
+                let replaced_arg =
+                    self.replace_function_arg(&nth_value.arg, name, &mut agg_args)?;
+                WindowFuncType::NthValue(NthValueFunction {
+                    n: nth_value.n,
+                    arg: Box::new(replaced_arg),
+                    ignore_nulls: nth_value.ignore_nulls,
+                    return_type: nth_value.return_type.clone(),
+                })
+            }
             func => func.clone(),
         };
 
@@ -14,7 +14,14 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::Expr as AExpr;
+use common_ast::ast::GroupBy;
+use common_ast::ast::Query;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::Statement;
 use common_ast::parser::parse_comma_separated_exprs;
+use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_base::base::tokio::runtime::Handle;
@@ -32,6 +39,8 @@ use common_expression::FunctionContext;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableField;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableInfo;
 use common_settings::Settings;
@@ -44,14 +53,12 @@ use crate::BaseTableColumn;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::Metadata;
+use crate::MetadataRef;
+use crate::ScalarExpr;
 use crate::Visibility;
 
-pub fn parse_exprs(
-    ctx: Arc<dyn TableContext>,
-    table_meta: Arc<dyn Table>,
-    sql: &str,
-) -> Result<Vec<Expr>> {
-    let settings = Settings::create("".to_string());
+/// Bind the columns of the table, so that expressions on the table could be resolved.
+fn bind_one_table(table_meta: Arc<dyn Table>) -> Result<(BindContext, MetadataRef)> {
     let mut bind_context = BindContext::new();
     let metadata = Arc::new(RwLock::new(Metadata::default()));
     let table_index = metadata.write().add_table(
@@ -91,6 +98,16 @@ pub fn parse_exprs(
 
         bind_context.add_column_binding(column_binding);
     }
+    Ok((bind_context, metadata))
+}
+
+pub fn parse_exprs(
+    ctx: Arc<dyn TableContext>,
+    table_meta: Arc<dyn Table>,
+    sql: &str,
+) -> Result<Vec<Expr>> {
+    let settings = Settings::create("".to_string());
+    let (mut bind_context, metadata) = bind_one_table(table_meta)?;
 
     let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
     let mut type_checker =
@@ -137,6 +154,147 @@ pub fn parse_to_remote_string_expr(
     }
 }
 
+/// An aggregate function of an aggregating index.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggIndexFunction {
+    pub func_name: String,
+    pub params: Vec<Scalar>,
+    /// Names of the argument columns.
+    pub args: Vec<String>,
+    pub arg_types: Vec<DataType>,
+}
+
+impl AggIndexFunction {
+    pub fn create(&self) -> Result<AggregateFunctionRef> {
+        AggregateFunctionFactory::instance().get(
+            &self.func_name,
+            self.params.clone(),
+            self.arg_types.clone(),
+        )
+    }
+}
+
+/// The definition of an aggregating index, which is parsed from the defining query.
+///
+/// For each block of the table, the index stores the partial states of the `aggregates`
+/// grouped by the `group_by` columns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggIndexDefinition {
+    /// Names of the group columns.
+    pub group_by: Vec<String>,
+    pub aggregates: Vec<AggIndexFunction>,
+}
+
+/// Parse the defining query of an aggregating index on the table,
+/// which must be like `SELECT <column>, ..., <aggregate>(<column>, ...), ... FROM <table> GROUP BY <column>, ...`.
+pub fn parse_agg_index_query(
+    ctx: Arc<dyn TableContext>,
+    table_meta: Arc<dyn Table>,
+    sql: &str,
+) -> Result<AggIndexDefinition> {
+    let unsupported = |reason: &str| {
+        Err(ErrorCode::SemanticError(format!(
+            "Unsupported aggregating index query, {reason}: {sql}"
+        )))
+    };
+
+    let tokens = tokenize_sql(sql)?;
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+    let select = match &stmt {
+        Statement::Query(box Query {
+            with: None,
+            body: SetExpr::Select(select),
+            order_by,
+            limit,
+            offset: None,
+            ..
+        }) if order_by.is_empty() && limit.is_empty() => select,
+        _ => return unsupported("it must be a single SELECT statement"),
+    };
+    if select.distinct
+        || select.from.len() != 1
+        || select.selection.is_some()
+        || select.having.is_some()
+        || select.window_list.is_some()
+    {
+        return unsupported("only SELECT, FROM and GROUP BY clauses are allowed");
+    }
+    let group_by = match &select.group_by {
+        Some(GroupBy::Normal(exprs)) => exprs,
+        _ => return unsupported("it must have a GROUP BY clause"),
+    };
+
+    let settings = Settings::create("".to_string());
+    let (mut bind_context, metadata) = bind_one_table(table_meta)?;
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut type_checker =
+        TypeChecker::new(&mut bind_context, ctx, &name_resolution_ctx, metadata, &[]);
+    let mut resolve = |expr: &AExpr| -> Result<ScalarExpr> {
+        let (scalar, _) =
+            *block_in_place(|| Handle::current().block_on(type_checker.resolve(expr)))?;
+        Ok(scalar)
+    };
+
+    let mut definition = AggIndexDefinition {
+        group_by: Vec::with_capacity(group_by.len()),
+        aggregates: vec![],
+    };
+    for expr in group_by {
+        match resolve(expr)? {
+            ScalarExpr::BoundColumnRef(col) => {
+                if !definition.group_by.contains(&col.column.column_name) {
+                    definition.group_by.push(col.column.column_name);
+                }
+            }
+            _ => return unsupported("the group items must be columns"),
+        }
+    }
+
+    for target in select.select_list.iter() {
+        let expr = match target {
+            SelectTarget::AliasedExpr { expr, .. } => expr,
+            SelectTarget::QualifiedName { .. } => {
+                return unsupported("wildcard is not allowed");
+            }
+        };
+        match resolve(expr)? {
+            ScalarExpr::BoundColumnRef(col) => {
+                if !definition.group_by.contains(&col.column.column_name) {
+                    return unsupported("the selected columns must be group items");
+                }
+            }
+            ScalarExpr::AggregateFunction(agg) => {
+                let mut args = Vec::with_capacity(agg.args.len());
+                let mut arg_types = Vec::with_capacity(agg.args.len());
+                for arg in agg.args.iter() {
+                    match arg {
+                        ScalarExpr::BoundColumnRef(col) => {
+                            args.push(col.column.column_name.clone());
+                            arg_types.push(*col.column.data_type.clone());
+                        }
+                        _ => return unsupported("the arguments of aggregates must be columns"),
+                    }
+                }
+                let func = AggIndexFunction {
+                    func_name: agg.func_name,
+                    params: agg.params,
+                    args,
+                    arg_types,
+                };
+                if !definition.aggregates.contains(&func) {
+                    definition.aggregates.push(func);
+                }
+            }
+            _ => return unsupported("only group items and aggregates could be selected"),
+        }
+    }
+    if definition.aggregates.is_empty() {
+        return unsupported("it must select at least one aggregate");
+    }
+
+    Ok(definition)
+}
+
 #[derive(Default)]
 struct DummyTable {
     info: TableInfo,
@@ -46,6 +46,7 @@ impl Plan {
                 Ok(format!("{:?}", show_create_database))
             }
             Plan::CreateDatabase(create_database) => Ok(format!("{:?}", create_database)),
+            Plan::CloneDatabase(clone_database) => Ok(format!("{:?}", clone_database)),
             Plan::DropDatabase(drop_database) => Ok(format!("{:?}", drop_database)),
             Plan::UndropDatabase(undrop_database) => Ok(format!("{:?}", undrop_database)),
             Plan::RenameDatabase(rename_database) => Ok(format!("{:?}", rename_database)),
@@ -53,6 +54,7 @@ impl Plan {
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(format!("{:?}", show_create_table)),
             Plan::CreateTable(create_table) => Ok(format!("{:?}", create_table)),
+            Plan::CloneTable(clone_table) => Ok(format!("{:?}", clone_table)),
             Plan::DropTable(drop_table) => Ok(format!("{:?}", drop_table)),
             Plan::UndropTable(undrop_table) => Ok(format!("{:?}", undrop_table)),
             Plan::DescribeTable(describe_table) => Ok(format!("{:?}", describe_table)),
@@ -69,16 +71,33 @@ impl Plan {
             Plan::TruncateTable(truncate_table) => Ok(format!("{:?}", truncate_table)),
             Plan::OptimizeTable(optimize_table) => Ok(format!("{:?}", optimize_table)),
             Plan::AnalyzeTable(analyze_table) => Ok(format!("{:?}", analyze_table)),
+            Plan::VacuumTable(vacuum_table) => Ok(format!("{:?}", vacuum_table)),
             Plan::ExistsTable(exists_table) => Ok(format!("{:?}", exists_table)),
 
+            // Indexes
+            Plan::CreateIndex(create_index) => Ok(format!("{:?}", create_index)),
+            Plan::DropIndex(drop_index) => Ok(format!("{:?}", drop_index)),
+            Plan::RefreshIndex(refresh_index) => Ok(format!("{:?}", refresh_index)),
+
             // Views
             Plan::CreateView(create_view) => Ok(format!("{:?}", create_view)),
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(format!("{:?}", create_materialized_view))
+            }
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(format!("{:?}", refresh_materialized_view))
+            }
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
 
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
             Plan::Replace(replace) => Ok(format!("{:?}", replace)),
+            Plan::MergeInto(merge_into) => Ok(format!("{:?}", merge_into)),
             Plan::Delete(delete) => Ok(format!("{:?}", delete)),
             Plan::Update(update) => Ok(format!("{:?}", update)),
 
@@ -129,6 +148,10 @@ impl Plan {
             Plan::ShowObjectGrantPrivileges(p) => Ok(format!("{:?}", p)),
             Plan::ShowGrantTenantsOfShare(p) => Ok(format!("{:?}", p)),
             Plan::RevertTable(p) => Ok(format!("{:?}", p)),
+
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Rollback => Ok("Rollback".to_string()),
         }
     }
 }
@@ -76,6 +76,8 @@ impl Display for FormatContext {
                 RelOperator::RuntimeFilterSource(_) => write!(f, "RuntimeFilterSource"),
                 RelOperator::Window(_) => write!(f, "WindowFunc"),
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
+                RelOperator::RecursiveCte(_) => write!(f, "RecursiveCte"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -147,6 +149,9 @@ pub fn format_join(
         JoinType::Cross => {
             write!(f, "CrossJoin")
         }
+        _ if op.merge_join => {
+            write!(f, "MergeJoin: {}", &op.join_type)
+        }
         _ => {
             write!(f, "HashJoin: {}", &op.join_type)
         }
@@ -370,25 +375,35 @@ pub fn logical_join_to_format_tree(
         "".to_string()
     };
 
+    let mut conditions = vec![
+        FormatTreeNode::new(FormatContext::Text(format!(
+            "equi conditions: [{}]",
+            equi_conditions
+        ))),
+        FormatTreeNode::new(FormatContext::Text(format!(
+            "non-equi conditions: [{}]",
+            non_equi_conditions.join(", ")
+        ))),
+    ];
+    if let Some(asof_condition) = &op.asof_condition {
+        let match_condition = ScalarExpr::FunctionCall(FunctionCall {
+            span: None,
+            func_name: asof_condition.op.to_func_name().to_string(),
+            params: vec![],
+            arguments: vec![asof_condition.left.clone(), asof_condition.right.clone()],
+        });
+        conditions.push(FormatTreeNode::new(FormatContext::Text(format!(
+            "match condition: [{}]",
+            format_scalar(&metadata, &match_condition)
+        ))));
+    }
+
     FormatTreeNode::with_children(
         FormatContext::RelOp {
             metadata,
             rel_operator: Box::new(op.clone().into()),
         },
-        vec![
-            vec![
-                FormatTreeNode::new(FormatContext::Text(format!(
-                    "equi conditions: [{}]",
-                    equi_conditions
-                ))),
-                FormatTreeNode::new(FormatContext::Text(format!(
-                    "non-equi conditions: [{}]",
-                    non_equi_conditions.join(", ")
-                ))),
-            ],
-            children,
-        ]
-        .concat(),
+        vec![conditions, children].concat(),
     )
 }
 
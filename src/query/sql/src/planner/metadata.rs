@@ -52,6 +52,8 @@ pub type MetadataRef = Arc<RwLock<Metadata>>;
 pub struct Metadata {
     tables: Vec<TableEntry>,
     columns: Vec<ColumnEntry>,
+    /// Names of recursive CTEs, indexed by `cte_index`.
+    recursive_ctes: Vec<String>,
 }
 
 impl Metadata {
@@ -103,6 +105,18 @@ impl Metadata {
         self.columns.as_slice()
     }
 
+    pub fn recursive_cte_name(&self, cte_index: IndexType) -> &str {
+        self.recursive_ctes
+            .get(cte_index)
+            .expect("metadata must contain recursive cte")
+    }
+
+    pub fn add_recursive_cte(&mut self, name: String) -> IndexType {
+        let cte_index = self.recursive_ctes.len();
+        self.recursive_ctes.push(name);
+        cte_index
+    }
+
     pub fn columns_by_table_index(&self, index: IndexType) -> Vec<ColumnEntry> {
         self.columns
             .iter()
@@ -278,6 +292,16 @@ impl Metadata {
         table_index
     }
 
+    /// Replace the table to be read by the table entry, the table must have the same
+    /// schema, e.g. the table navigated to another snapshot.
+    pub fn replace_table(&mut self, index: IndexType, table: Arc<dyn Table>) {
+        let entry = self
+            .tables
+            .get_mut(index)
+            .expect("metadata must contain table");
+        entry.table = table;
+    }
+
     pub fn change_derived_column_alias(&mut self, index: IndexType, alias: String) {
         let derived_column = self
             .columns
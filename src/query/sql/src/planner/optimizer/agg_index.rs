@@ -0,0 +1,290 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::plan::AggIndexInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_meta_app::schema::TableIndexType;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::parse_agg_index_query;
+use crate::plans::AggIndexScan;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::AggIndexDefinition;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Rewrite the aggregations which could be answered by an aggregating index
+/// of the scanned table, so that the partial aggregation merges the states
+/// read from the index instead of accumulating the rows of the table.
+///
+/// The supported plans are like:
+///
+/// ```text
+/// Aggregate(Final)
+///     Aggregate(Partial)
+///         [EvalScalar]
+///             [Filter]
+///                 Scan
+/// ```
+///
+/// The group columns and the filtered columns must be group columns of the index,
+/// and each aggregate function must be one of the aggregates of the index.
+pub fn try_rewrite_agg_index(
+    ctx: Arc<dyn TableContext>,
+    metadata: &MetadataRef,
+    s_expr: &SExpr,
+) -> Result<SExpr> {
+    if let Some(result) = try_rewrite_aggregate(ctx.clone(), metadata, s_expr)? {
+        return Ok(result);
+    }
+
+    let children = s_expr
+        .children()
+        .iter()
+        .map(|child| try_rewrite_agg_index(ctx.clone(), metadata, child))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(s_expr.replace_children(children))
+}
+
+fn try_rewrite_aggregate(
+    ctx: Arc<dyn TableContext>,
+    metadata: &MetadataRef,
+    s_expr: &SExpr,
+) -> Result<Option<SExpr>> {
+    let partial_expr = match s_expr.plan() {
+        RelOperator::Aggregate(Aggregate {
+            mode: AggregateMode::Final,
+            ..
+        }) if s_expr.arity() == 1 => s_expr.child(0)?,
+        _ => return Ok(None),
+    };
+    let partial = match partial_expr.plan() {
+        RelOperator::Aggregate(
+            agg @ Aggregate {
+                mode: AggregateMode::Partial,
+                ..
+            },
+        ) if agg.grouping_sets.is_empty() => agg,
+        _ => return Ok(None),
+    };
+
+    // Collect the optional `EvalScalar` and `Filter` above the scan.
+    let mut eval_scalar: Option<&EvalScalar> = None;
+    let mut filter: Option<&Filter> = None;
+    let mut child = partial_expr.child(0)?;
+    if let RelOperator::EvalScalar(plan) = child.plan() {
+        eval_scalar = Some(plan);
+        child = child.child(0)?;
+    }
+    if let RelOperator::Filter(plan) = child.plan() {
+        filter = Some(plan);
+        child = child.child(0)?;
+    }
+    let scan = match child.plan() {
+        RelOperator::Scan(scan)
+            if scan.agg_index.is_none() && scan.limit.is_none() && scan.order_by.is_none() =>
+        {
+            scan
+        }
+        _ => return Ok(None),
+    };
+
+    let (table, column_names) = {
+        let metadata = metadata.read();
+        let table = metadata.table(scan.table_index).table();
+        // Only the columns of the scanned table itself could be read from the index.
+        let mut column_names = HashMap::with_capacity(scan.columns.len());
+        for index in scan.columns.iter() {
+            match metadata.column(*index) {
+                ColumnEntry::BaseTableColumn(column) if column.path_indices.is_none() => {
+                    column_names.insert(*index, column.column_name.clone());
+                }
+                _ => return Ok(None),
+            }
+        }
+        (table, column_names)
+    };
+    let column_name = |scalar: &ScalarExpr| match scalar {
+        ScalarExpr::BoundColumnRef(col) => column_names.get(&col.column.index).cloned(),
+        _ => None,
+    };
+
+    // The group columns and the filtered columns, which must be read from the index.
+    let mut group_columns = ColumnSet::new();
+    for item in partial.group_items.iter() {
+        match column_name(&item.scalar) {
+            Some(_) if item.index == scalar_column_index(&item.scalar) => {
+                group_columns.insert(item.index);
+            }
+            _ => return Ok(None),
+        }
+    }
+    let mut predicates = filter.map_or(vec![], |filter| filter.predicates.clone());
+    if let Some(prewhere) = &scan.prewhere {
+        predicates.extend(prewhere.predicates.iter().cloned());
+    }
+    for predicate in predicates.iter() {
+        for index in predicate.used_columns() {
+            if !column_names.contains_key(&index) {
+                return Ok(None);
+            }
+            group_columns.insert(index);
+        }
+    }
+    let group_by = group_columns
+        .iter()
+        .map(|index| column_names[index].clone())
+        .collect::<Vec<_>>();
+    if let Some(eval_scalar) = eval_scalar {
+        for item in eval_scalar.items.iter() {
+            if column_name(&item.scalar).is_none()
+                || item.index != scalar_column_index(&item.scalar)
+            {
+                return Ok(None);
+            }
+        }
+    }
+
+    // The aggregate functions, with the names of their argument columns.
+    let mut aggregates = Vec::with_capacity(partial.aggregate_functions.len());
+    for item in partial.aggregate_functions.iter() {
+        match &item.scalar {
+            ScalarExpr::AggregateFunction(agg) if !agg.distinct => {
+                let args = agg.args.iter().map(column_name).collect::<Option<Vec<_>>>();
+                match args {
+                    Some(args) => aggregates.push((agg, args, item.index)),
+                    None => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        }
+    }
+
+    for index in table.get_table_info().meta.indexes.values() {
+        if index.index_type != TableIndexType::Aggregating {
+            continue;
+        }
+        let definition = parse_agg_index_query(ctx.clone(), table.clone(), &index.query)?;
+        if let Some(offsets) = match_agg_index(&definition, &group_by, &aggregates) {
+            // Group columns are output in the order of the index definition.
+            let output_group_by = definition
+                .group_by
+                .iter()
+                .filter(|name| group_by.contains(name))
+                .cloned()
+                .collect();
+            let agg_index = AggIndexScan {
+                info: AggIndexInfo {
+                    index_name: index.name.clone(),
+                    query: index.query.clone(),
+                    group_by: output_group_by,
+                    aggregates: offsets,
+                },
+                state_columns: aggregates.iter().map(|(_, _, index)| *index).collect(),
+            };
+            let result = rewrite_scan(
+                s_expr,
+                eval_scalar,
+                scan,
+                predicates,
+                agg_index,
+                &group_columns,
+            );
+            return Ok(Some(result?));
+        }
+    }
+    Ok(None)
+}
+
+fn scalar_column_index(scalar: &ScalarExpr) -> IndexType {
+    match scalar {
+        ScalarExpr::BoundColumnRef(col) => col.column.index,
+        _ => unreachable!("expect a column reference"),
+    }
+}
+
+/// Returns the offsets of the query aggregates in the index definition, if the
+/// index could answer the query.
+fn match_agg_index(
+    definition: &AggIndexDefinition,
+    group_by: &[String],
+    aggregates: &[(&AggregateFunction, Vec<String>, IndexType)],
+) -> Option<Vec<usize>> {
+    if !group_by
+        .iter()
+        .all(|name| definition.group_by.contains(name))
+    {
+        return None;
+    }
+    aggregates
+        .iter()
+        .map(|(agg, args, _)| {
+            definition.aggregates.iter().position(|func| {
+                func.func_name == agg.func_name && func.params == agg.params && &func.args == args
+            })
+        })
+        .collect()
+}
+
+/// Make the scan read the aggregating index, the prewhere predicates are moved
+/// into the filter since the index is read as a whole.
+fn rewrite_scan(
+    s_expr: &SExpr,
+    eval_scalar: Option<&EvalScalar>,
+    scan: &Scan,
+    predicates: Vec<ScalarExpr>,
+    agg_index: AggIndexScan,
+    group_columns: &ColumnSet,
+) -> Result<SExpr> {
+    let mut new_scan = scan.clone();
+    new_scan.prewhere = None;
+    new_scan.agg_index = Some(agg_index);
+
+    let mut result = SExpr::create_leaf(new_scan.into());
+    if !predicates.is_empty() {
+        let filter = Filter {
+            predicates,
+            is_having: false,
+        };
+        result = SExpr::create_unary(filter.into(), result);
+    }
+    if let Some(eval_scalar) = eval_scalar {
+        // The arguments of the aggregate functions are not read from the index.
+        let items = eval_scalar
+            .items
+            .iter()
+            .filter(|item| group_columns.contains(&item.index))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !items.is_empty() {
+            result = SExpr::create_unary(EvalScalar { items }.into(), result);
+        }
+    }
+
+    let partial = s_expr.child(0)?.replace_children(vec![result]);
+    Ok(s_expr.replace_children(vec![partial]))
+}
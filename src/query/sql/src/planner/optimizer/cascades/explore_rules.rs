@@ -15,12 +15,16 @@
 use crate::optimizer::RuleID;
 use crate::optimizer::RuleSet;
 
-pub fn get_explore_rule_set(enable_bushy_join: bool) -> RuleSet {
-    if enable_bushy_join {
+pub fn get_explore_rule_set(enable_bushy_join: bool, enable_merge_join: bool) -> RuleSet {
+    let mut rule_set = if enable_bushy_join {
         rule_set_rs_b2()
     } else {
         rule_set_rs_l1()
+    };
+    if enable_merge_join {
+        rule_set.insert(RuleID::ImplementMergeJoin);
     }
+    rule_set
 }
 
 /// Get rule set of join order RS-B2, which may generate bushy trees.
@@ -22,27 +22,97 @@ use crate::optimizer::Memo;
 use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
 use crate::plans::Scan;
+use crate::IndexType;
 
 static COST_FACTOR_COMPUTE_PER_ROW: f64 = 1.0;
 static COST_FACTOR_HASH_TABLE_PER_ROW: f64 = 10.0;
 static COST_FACTOR_AGGREGATE_PER_ROW: f64 = 5.0;
+static COST_FACTOR_SORT_PER_ROW: f64 = 1.0;
 
 #[derive(Default)]
 pub struct DefaultCostModel;
 
+impl DefaultCostModel {
+    /// Check if the output of the group is sorted by the given columns, in ascending
+    /// order with nulls last, which is the order merge join sorts its inputs by.
+    ///
+    /// Only an explicit sort is trusted, the data of a clustered table is not
+    /// globally ordered by the cluster keys.
+    fn is_ordered_by(memo: &Memo, group_index: IndexType, columns: &[IndexType]) -> Result<bool> {
+        let group = memo.group(group_index)?;
+        for m_expr in group.m_exprs.iter() {
+            let ordered = match &m_expr.plan {
+                RelOperator::Sort(sort) => {
+                    sort.items.len() >= columns.len()
+                        && sort.items.iter().zip(columns.iter()).all(|(item, column)| {
+                            item.index == *column && item.asc && !item.nulls_first
+                        })
+                }
+                // These operators preserve the order of input.
+                RelOperator::EvalScalar(_) | RelOperator::Filter(_) | RelOperator::Limit(_) => {
+                    Self::is_ordered_by(memo, m_expr.children[0], columns)?
+                }
+                _ => false,
+            };
+            if ordered {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn compute_cost_merge_join(&self, memo: &Memo, m_expr: &MExpr, plan: &Join) -> Result<Cost> {
+        let mut cost = 0.0;
+        for (child_index, conditions) in [&plan.left_conditions, &plan.right_conditions]
+            .into_iter()
+            .enumerate()
+        {
+            let group = m_expr.child_group(memo, child_index)?;
+            let card = group.stat_info.cardinality;
+            cost += card * COST_FACTOR_COMPUTE_PER_ROW;
+
+            let columns = conditions
+                .iter()
+                .map(|condition| match condition {
+                    ScalarExpr::BoundColumnRef(column) => Some(column.column.index),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>();
+            let ordered = match columns {
+                Some(columns) => Self::is_ordered_by(memo, group.group_index, &columns)?,
+                None => false,
+            };
+            // The sort of an ordered input is skipped by the physical plan builder,
+            // otherwise the input is fully sorted before merging.
+            if !ordered {
+                cost += card * card.max(2.0).log2() * COST_FACTOR_SORT_PER_ROW;
+            }
+        }
+        Ok(Cost(cost))
+    }
+}
+
 impl CostModel for DefaultCostModel {
     fn compute_cost(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
-        compute_cost_impl(memo, m_expr)
+        match &m_expr.plan {
+            RelOperator::Join(plan) if plan.merge_join => {
+                self.compute_cost_merge_join(memo, m_expr, plan)
+            }
+            _ => compute_cost_impl(memo, m_expr),
+        }
     }
 }
 
 fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
     match &m_expr.plan {
         RelOperator::Scan(plan) => compute_cost_scan(memo, m_expr, plan),
-        RelOperator::DummyTableScan(_) => Ok(Cost(0.0)),
+        RelOperator::DummyTableScan(_) | RelOperator::RecursiveCteScan(_) => Ok(Cost(0.0)),
         RelOperator::Join(plan) => compute_cost_join(memo, m_expr, plan),
-        RelOperator::UnionAll(_) => compute_cost_union_all(memo, m_expr),
+        RelOperator::UnionAll(_) | RelOperator::RecursiveCte(_) => {
+            compute_cost_union_all(memo, m_expr)
+        }
         RelOperator::Aggregate(_) => compute_aggregate(memo, m_expr),
 
         RelOperator::EvalScalar(_)
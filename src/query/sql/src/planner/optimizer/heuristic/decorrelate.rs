@@ -202,6 +202,8 @@ impl SubqueryRewriter {
             marker_index: None,
             from_correlated_subquery: true,
             contain_runtime_filter: false,
+            merge_join: false,
+            asof_condition: None,
         };
 
         // Rewrite plan to semi-join.
@@ -270,6 +272,8 @@ impl SubqueryRewriter {
                     marker_index: None,
                     from_correlated_subquery: true,
                     contain_runtime_filter: false,
+                    merge_join: false,
+                    asof_condition: None,
                 };
                 let s_expr = SExpr::create_binary(join_plan.into(), left.clone(), flatten_plan);
                 Ok((s_expr, UnnestResult::SingleJoin))
@@ -309,6 +313,8 @@ impl SubqueryRewriter {
                     marker_index: Some(marker_index),
                     from_correlated_subquery: true,
                     contain_runtime_filter: false,
+                    merge_join: false,
+                    asof_condition: None,
                 };
                 let s_expr = SExpr::create_binary(join_plan.into(), left.clone(), flatten_plan);
                 Ok((s_expr, UnnestResult::MarkJoin { marker_index }))
@@ -368,6 +374,8 @@ impl SubqueryRewriter {
                     marker_index: Some(marker_index),
                     from_correlated_subquery: true,
                     contain_runtime_filter: false,
+                    merge_join: false,
+                    asof_condition: None,
                 }
                 .into();
                 Ok((
@@ -421,6 +429,7 @@ impl SubqueryRewriter {
                         col_stats: HashMap::new(),
                     },
                     prewhere: None,
+                    agg_index: None,
                 }
                 .into(),
             );
@@ -433,6 +442,8 @@ impl SubqueryRewriter {
                 marker_index: None,
                 from_correlated_subquery: false,
                 contain_runtime_filter: false,
+                merge_join: false,
+                asof_condition: None,
             }
             .into();
             return Ok(SExpr::create_binary(cross_join, logical_get, plan.clone()));
@@ -541,6 +552,8 @@ impl SubqueryRewriter {
                         marker_index: join.marker_index,
                         from_correlated_subquery: false,
                         contain_runtime_filter: false,
+                        merge_join: false,
+                        asof_condition: join.asof_condition.clone(),
                     }
                     .into(),
                     left_flatten_plan,
@@ -21,7 +21,6 @@ use crate::optimizer::SExpr;
 use crate::plans::Aggregate;
 use crate::plans::EvalScalar;
 use crate::plans::RelOperator;
-use crate::plans::WindowFuncType;
 use crate::ColumnEntry;
 use crate::MetadataRef;
 
@@ -89,13 +88,18 @@ impl UnusedColumnPruner {
             }
             RelOperator::Join(p) => {
                 // Include columns referenced in left conditions
-                let left = p.left_conditions.iter().fold(required.clone(), |acc, v| {
+                let mut left = p.left_conditions.iter().fold(required.clone(), |acc, v| {
                     acc.union(&v.used_columns()).cloned().collect()
                 });
                 // Include columns referenced in right conditions
-                let right = p.right_conditions.iter().fold(required.clone(), |acc, v| {
+                let mut right = p.right_conditions.iter().fold(required.clone(), |acc, v| {
                     acc.union(&v.used_columns()).cloned().collect()
                 });
+                // Include columns referenced in match condition of asof join
+                if let Some(asof_condition) = &p.asof_condition {
+                    left.extend(asof_condition.left.used_columns());
+                    right.extend(asof_condition.right.used_columns());
+                }
 
                 let others = p.non_equi_conditions.iter().fold(required, |acc, v| {
                     acc.union(&v.used_columns()).cloned().collect()
@@ -184,11 +188,7 @@ impl UnusedColumnPruner {
             }
             RelOperator::Window(p) => {
                 if required.contains(&p.index) {
-                    if let WindowFuncType::Aggregate(agg) = &p.function {
-                        agg.args.iter().for_each(|item| {
-                            required.extend(item.used_columns());
-                        });
-                    }
+                    required.extend(p.function.used_columns());
                     p.partition_by.iter().for_each(|item| {
                         required.insert(item.index);
                     });
@@ -232,6 +232,21 @@ impl UnusedColumnPruner {
                 ))
             }
 
+            RelOperator::RecursiveCte(p) => {
+                // The working table is accessed by position, so all the
+                // columns of both terms must be kept.
+                let anchor_used = p.pairs.iter().fold(required, |mut acc, v| {
+                    acc.insert(v.0);
+                    acc
+                });
+                let recursive_used = p.pairs.iter().map(|v| v.1).collect();
+                Ok(SExpr::create_binary(
+                    RelOperator::RecursiveCte(p.clone()),
+                    self.keep_required_columns(expr.child(0)?, anchor_used)?,
+                    self.keep_required_columns(expr.child(1)?, recursive_used)?,
+                ))
+            }
+
             RelOperator::ProjectSet(op) => {
                 // We can't prune SRFs because they may change the cardinality of result set,
                 // even if the result column of an SRF is not used by any following expression.
@@ -245,7 +260,7 @@ impl UnusedColumnPruner {
                 ))
             }
 
-            RelOperator::DummyTableScan(_) => Ok(expr.clone()),
+            RelOperator::DummyTableScan(_) | RelOperator::RecursiveCteScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
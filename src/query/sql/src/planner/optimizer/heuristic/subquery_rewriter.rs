@@ -131,29 +131,51 @@ impl SubqueryRewriter {
                     item.order_by_item.scalar = res.0;
                 }
 
-                if let WindowFuncType::Aggregate(agg) = &mut plan.function {
-                    for item in agg.args.iter_mut() {
-                        let res = self.try_rewrite_subquery(item, &input, false)?;
+                match &mut plan.function {
+                    WindowFuncType::Aggregate(agg) => {
+                        for item in agg.args.iter_mut() {
+                            let res = self.try_rewrite_subquery(item, &input, false)?;
+                            input = res.1;
+                            *item = res.0;
+                        }
+                    }
+                    WindowFuncType::LagLead(lag_lead) => {
+                        let res = self.try_rewrite_subquery(&lag_lead.arg, &input, false)?;
+                        input = res.1;
+                        lag_lead.arg = Box::new(res.0);
+                        if let Some(default) = &mut lag_lead.default {
+                            let res = self.try_rewrite_subquery(default, &input, false)?;
+                            input = res.1;
+                            *default = Box::new(res.0);
+                        }
+                    }
+                    WindowFuncType::NthValue(nth_value) => {
+                        let res = self.try_rewrite_subquery(&nth_value.arg, &input, false)?;
                         input = res.1;
-                        *item = res.0;
+                        nth_value.arg = Box::new(res.0);
                     }
+                    _ => {}
                 }
 
                 Ok(SExpr::create_unary(plan.into(), input))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) => Ok(SExpr::create_binary(
-                s_expr.plan().clone(),
-                self.rewrite(s_expr.child(0)?)?,
-                self.rewrite(s_expr.child(1)?)?,
-            )),
+            RelOperator::Join(_) | RelOperator::UnionAll(_) | RelOperator::RecursiveCte(_) => {
+                Ok(SExpr::create_binary(
+                    s_expr.plan().clone(),
+                    self.rewrite(s_expr.child(0)?)?,
+                    self.rewrite(s_expr.child(1)?)?,
+                ))
+            }
 
             RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
                 s_expr.plan().clone(),
                 self.rewrite(s_expr.child(0)?)?,
             )),
 
-            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) => Ok(s_expr.clone()),
+            RelOperator::DummyTableScan(_)
+            | RelOperator::Scan(_)
+            | RelOperator::RecursiveCteScan(_) => Ok(s_expr.clone()),
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
         }
@@ -341,6 +363,8 @@ impl SubqueryRewriter {
                     marker_index: None,
                     from_correlated_subquery: false,
                     contain_runtime_filter: false,
+                    merge_join: false,
+                    asof_condition: None,
                 }
                 .into();
                 let s_expr =
@@ -434,6 +458,8 @@ impl SubqueryRewriter {
                     marker_index: None,
                     from_correlated_subquery: false,
                     contain_runtime_filter: false,
+                    merge_join: false,
+                    asof_condition: None,
                 }
                 .into();
                 Ok((
@@ -499,6 +525,8 @@ impl SubqueryRewriter {
                     marker_index: Some(marker_index),
                     from_correlated_subquery: false,
                     contain_runtime_filter: false,
+                    merge_join: false,
+                    asof_condition: None,
                 }
                 .into();
                 let s_expr =
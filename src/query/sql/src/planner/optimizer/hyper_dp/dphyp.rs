@@ -223,7 +223,9 @@ impl DPhpy {
             RelOperator::Window(_)
             | RelOperator::UnionAll(_)
             | RelOperator::DummyTableScan(_)
-            | RelOperator::RuntimeFilterSource(_) => Ok(false),
+            | RelOperator::RuntimeFilterSource(_)
+            | RelOperator::RecursiveCte(_)
+            | RelOperator::RecursiveCteScan(_) => Ok(false),
         }
     }
 
@@ -87,6 +87,8 @@ impl JoinNode {
             marker_index: None,
             from_correlated_subquery: false,
             contain_runtime_filter: false,
+            merge_join: false,
+            asof_condition: None,
         });
         let children = self
             .children
@@ -23,11 +23,13 @@ use common_exception::Result;
 use super::cost::CostContext;
 use super::format::display_memo;
 use super::Memo;
+use crate::optimizer::agg_index::try_rewrite_agg_index;
 use crate::optimizer::cascades::CascadesOptimizer;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::runtime_filter::try_add_runtime_filter_nodes;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::util::contains_recursive_cte;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::SExpr;
 use crate::plans::CopyPlan;
@@ -122,6 +124,7 @@ pub fn optimize(
                     path,
                     validation_mode,
                     from,
+                    partition_by,
                 } => {
                     CopyPlan::IntoStage {
                         stage,
@@ -129,6 +132,7 @@ pub fn optimize(
                         validation_mode,
                         // Make sure the subquery has been optimized.
                         from: Box::new(optimize(ctx, opt_ctx, *from)?),
+                        partition_by,
                     }
                 }
                 into_table => into_table,
@@ -147,9 +151,13 @@ pub fn optimize_query(
     s_expr: SExpr,
 ) -> Result<SExpr> {
     let contains_local_table_scan = contains_local_table_scan(&s_expr, &metadata);
+    let contains_recursive_cte = contains_recursive_cte(&s_expr);
 
     let mut heuristic = HeuristicOptimizer::new(ctx.clone(), bind_context, metadata.clone());
     let mut result = heuristic.optimize(s_expr)?;
+    if ctx.get_settings().get_enable_aggregating_index()? {
+        result = try_rewrite_agg_index(ctx.clone(), &metadata, &result)?;
+    }
     if ctx.get_settings().get_enable_dphyp()? {
         let (dp_res, optimized) = DPhpy::new(metadata.clone()).optimize(result.clone())?;
         result = dp_res;
@@ -163,9 +171,11 @@ pub fn optimize_query(
         result = cascades.optimize(result)?;
     }
     // So far, we don't have ability to execute distributed query
-    // with reading data from local tales(e.g. system tables).
-    let enable_distributed_query =
-        opt_ctx.config.enable_distributed_optimization && !contains_local_table_scan;
+    // with reading data from local tales(e.g. system tables),
+    // or with the working table of recursive CTE.
+    let enable_distributed_query = opt_ctx.config.enable_distributed_optimization
+        && !contains_local_table_scan
+        && !contains_recursive_cte;
     // Add runtime filter related nodes after cbo
     // Because cbo may change join order and we don't want to
     // break optimizer due to new added nodes by runtime filter.
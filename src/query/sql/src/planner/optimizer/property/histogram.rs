@@ -16,6 +16,7 @@ use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Debug;
 
+use common_catalog::table::ColumnHistogram;
 use common_exception::Result;
 
 use crate::optimizer::property::datum::Datum;
@@ -33,18 +34,23 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
+/// The histogram is built from sampled data by `ANALYZE TABLE` if available,
+/// otherwise it is constructed from NDV(number of distinct values) and the
+/// total number of rows, which brings the assumption that the data is
+/// uniformly distributed.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
+    /// The most common values and their estimated number of rows.
+    pub most_common_values: Vec<(Datum, f64)>,
 }
 
 impl Histogram {
     pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+        Self {
+            buckets,
+            most_common_values: vec![],
+        }
     }
 
     /// Get number of buckets
@@ -73,6 +79,51 @@ impl Histogram {
     ) -> impl Iterator<Item = &HistogramBucket> + DoubleEndedIterator<Item = &HistogramBucket> {
         self.buckets.iter()
     }
+
+    /// Get estimated number of rows of the value if it is one of the most common values
+    pub fn most_common_value_count(&self, value: &Datum) -> Option<f64> {
+        self.most_common_values
+            .iter()
+            .find(|(v, _)| {
+                v.type_comparable(value) && matches!(v.compare(value), Ok(Ordering::Equal))
+            })
+            .map(|(_, count)| *count)
+    }
+}
+
+/// Construct a histogram from the one collected by `ANALYZE TABLE`.
+///
+/// Returns `None` if any of the values can't be represented as a `Datum`.
+pub fn histogram_from_column_histogram(
+    min: Datum,
+    histogram: &ColumnHistogram,
+) -> Option<Histogram> {
+    let mut buckets = Vec::with_capacity(histogram.buckets.len() + 1);
+    // The first bucket is a dummy bucket
+    // which is used to record the min value of the column
+    buckets.push(HistogramBucket {
+        upper_bound: min,
+        num_values: 1.0,
+        num_distinct: 1.0,
+    });
+    for bucket in histogram.buckets.iter() {
+        buckets.push(HistogramBucket {
+            upper_bound: Datum::from_scalar(&bucket.upper_bound)?,
+            num_values: bucket.num_values,
+            num_distinct: bucket.num_distinct,
+        });
+    }
+
+    let most_common_values = histogram
+        .most_common_values
+        .iter()
+        .map(|(value, count)| Some((Datum::from_scalar(value)?, *count as f64)))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Histogram {
+        buckets,
+        most_common_values,
+    })
 }
 
 /// Construct a histogram from NDV and total number of rows.
@@ -95,7 +146,7 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram::new(vec![]))
         };
     }
 
@@ -150,7 +201,7 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram::new(buckets))
 }
 
 #[derive(Debug, Clone)]
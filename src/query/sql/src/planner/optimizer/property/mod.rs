@@ -29,6 +29,7 @@ pub use column_stat::NewStatistic;
 pub use constraint::ConstraintSet;
 pub use datum::Datum;
 pub use enforcer::require_property;
+pub use histogram::histogram_from_column_histogram;
 pub use histogram::histogram_from_ndv;
 pub use histogram::Histogram;
 pub use histogram::HistogramBucket;
@@ -259,6 +259,12 @@ fn compare_equal(datum: &Option<Datum>, col_hist: &Histogram, column_stat: &Colu
                 return 0.0;
             }
         }
+
+        // The frequency of the most common values is collected by `ANALYZE TABLE`,
+        // so we don't need to assume a uniform distribution for them.
+        if let Some(count) = col_hist.most_common_value_count(constant_datum) {
+            return count / col_hist.num_values();
+        }
     }
 
     1.0 / col_hist.num_distinct_values()
@@ -26,6 +26,8 @@ use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -104,6 +106,30 @@ impl RulePushDownFilterEvalScalar {
                             display_name: agg.display_name.clone(),
                         })
                     }
+                    WindowFuncType::LagLead(lag_lead) => {
+                        let new_arg = Self::replace_predicate(&lag_lead.arg, items)?;
+                        let new_default = match &lag_lead.default {
+                            None => None,
+                            Some(d) => Some(Box::new(Self::replace_predicate(d, items)?)),
+                        };
+                        WindowFuncType::LagLead(LagLeadFunction {
+                            is_lag: lag_lead.is_lag,
+                            arg: Box::new(new_arg),
+                            offset: lag_lead.offset,
+                            default: new_default,
+                            ignore_nulls: lag_lead.ignore_nulls,
+                            return_type: lag_lead.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::NthValue(nth_value) => {
+                        let new_arg = Self::replace_predicate(&nth_value.arg, items)?;
+                        WindowFuncType::NthValue(NthValueFunction {
+                            n: nth_value.n,
+                            arg: Box::new(new_arg),
+                            ignore_nulls: nth_value.ignore_nulls,
+                            return_type: nth_value.return_type.clone(),
+                        })
+                    }
                     func => func.clone(),
                 };
 
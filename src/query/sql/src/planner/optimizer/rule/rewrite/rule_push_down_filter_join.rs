@@ -145,7 +145,10 @@ pub fn try_push_down_filter_join(
                 left_push_down.push(predicate);
             }
             JoinPredicate::Right(_) => {
-                if matches!(join.join_type, JoinType::Left) {
+                if matches!(
+                    join.join_type,
+                    JoinType::Left | JoinType::Asof | JoinType::LeftAsof
+                ) {
                     original_predicates.push(predicate);
                     continue;
                 }
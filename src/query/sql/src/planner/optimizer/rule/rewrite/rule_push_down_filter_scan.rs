@@ -23,6 +23,8 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::Scan;
@@ -127,6 +129,42 @@ impl RulePushDownFilterScan {
                             display_name: agg.display_name.clone(),
                         })
                     }
+                    WindowFuncType::LagLead(lag_lead) => {
+                        let new_arg = Self::replace_view_column(
+                            &lag_lead.arg,
+                            table_entries,
+                            column_entries,
+                        )?;
+                        let new_default = match &lag_lead.default {
+                            None => None,
+                            Some(d) => Some(Box::new(Self::replace_view_column(
+                                d,
+                                table_entries,
+                                column_entries,
+                            )?)),
+                        };
+                        WindowFuncType::LagLead(LagLeadFunction {
+                            is_lag: lag_lead.is_lag,
+                            arg: Box::new(new_arg),
+                            offset: lag_lead.offset,
+                            default: new_default,
+                            ignore_nulls: lag_lead.ignore_nulls,
+                            return_type: lag_lead.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::NthValue(nth_value) => {
+                        let new_arg = Self::replace_view_column(
+                            &nth_value.arg,
+                            table_entries,
+                            column_entries,
+                        )?;
+                        WindowFuncType::NthValue(NthValueFunction {
+                            n: nth_value.n,
+                            arg: Box::new(new_arg),
+                            ignore_nulls: nth_value.ignore_nulls,
+                            return_type: nth_value.return_type.clone(),
+                        })
+                    }
                     func => func.clone(),
                 };
 
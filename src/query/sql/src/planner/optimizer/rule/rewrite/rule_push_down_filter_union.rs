@@ -25,6 +25,8 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -168,6 +170,23 @@ fn replace_column_binding(
                         .collect::<Result<Vec<_>>>()?,
                     return_type: arg.return_type,
                 }),
+                WindowFuncType::LagLead(lag_lead) => WindowFuncType::LagLead(LagLeadFunction {
+                    is_lag: lag_lead.is_lag,
+                    arg: Box::new(replace_column_binding(index_pairs, *lag_lead.arg)?),
+                    offset: lag_lead.offset,
+                    default: match lag_lead.default {
+                        None => None,
+                        Some(d) => Some(Box::new(replace_column_binding(index_pairs, *d)?)),
+                    },
+                    ignore_nulls: lag_lead.ignore_nulls,
+                    return_type: lag_lead.return_type,
+                }),
+                WindowFuncType::NthValue(nth_value) => WindowFuncType::NthValue(NthValueFunction {
+                    n: nth_value.n,
+                    arg: Box::new(replace_column_binding(index_pairs, *nth_value.arg)?),
+                    ignore_nulls: nth_value.ignore_nulls,
+                    return_type: nth_value.return_type,
+                }),
                 t => t,
             },
             partition_by: expr
@@ -73,6 +73,7 @@ pub enum RuleID {
     LeftExchangeJoin,
     EagerAggregation,
     RightExchangeJoin,
+    ImplementMergeJoin,
 }
 
 impl Display for RuleID {
@@ -109,6 +110,7 @@ impl Display for RuleID {
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::RightExchangeJoin => write!(f, "RightExchangeJoin"),
             RuleID::ExchangeJoin => write!(f, "ExchangeJoin"),
+            RuleID::ImplementMergeJoin => write!(f, "ImplementMergeJoin"),
         }
     }
 }
@@ -0,0 +1,111 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+
+/// Rule to generate a sort-merge join alternative for an inner equi-join.
+/// Whether the merge join is picked or not is decided by the cost model,
+/// which takes the sortedness of the children into account.
+///
+/// Only the join keys which are plain columns with the same data type are
+/// supported, because both children will be sorted by the key columns.
+pub struct RuleImplementMergeJoin {
+    id: RuleID,
+    patterns: Vec<SExpr>,
+}
+
+impl RuleImplementMergeJoin {
+    pub fn new() -> Self {
+        Self {
+            id: RuleID::ImplementMergeJoin,
+
+            // LogicalJoin
+            // | \
+            // *  *
+            patterns: vec![SExpr::create_binary(
+                PatternPlan {
+                    plan_type: RelOp::Join,
+                }
+                .into(),
+                SExpr::create_pattern_leaf(),
+                SExpr::create_pattern_leaf(),
+            )],
+        }
+    }
+
+    fn is_mergeable(join: &Join) -> bool {
+        if join.merge_join
+            || join.join_type != JoinType::Inner
+            || join.left_conditions.is_empty()
+            || join.marker_index.is_some()
+            || join.from_correlated_subquery
+            || join.contain_runtime_filter
+        {
+            return false;
+        }
+
+        join.left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+            .all(|(left, right)| match (left, right) {
+                (ScalarExpr::BoundColumnRef(left), ScalarExpr::BoundColumnRef(right)) => {
+                    left.column.data_type.remove_nullable()
+                        == right.column.data_type.remove_nullable()
+                }
+                _ => false,
+            })
+    }
+}
+
+impl Rule for RuleImplementMergeJoin {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let mut join: Join = s_expr.plan().clone().try_into()?;
+        if !Self::is_mergeable(&join) {
+            return Ok(());
+        }
+
+        join.merge_join = true;
+        let mut result = SExpr::create_binary(
+            join.into(),
+            s_expr.child(0)?.clone(),
+            s_expr.child(1)?.clone(),
+        );
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+
+    fn transformation(&self) -> bool {
+        false
+    }
+}
@@ -89,7 +89,7 @@ pub fn try_add_runtime_filter_nodes(expr: &SExpr) -> Result<SExpr> {
 fn add_runtime_filter_nodes(expr: &SExpr) -> Result<SExpr> {
     assert_eq!(expr.plan.rel_op(), RelOp::Join);
     let join: Join = expr.plan().clone().try_into()?;
-    if join.join_type != JoinType::Inner {
+    if join.join_type != JoinType::Inner || join.merge_join {
         return Ok(expr.clone());
     }
     let runtime_filter_result = create_runtime_filters(&join)?;
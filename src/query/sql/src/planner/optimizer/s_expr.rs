@@ -241,6 +241,8 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::RuntimeFilterSource(_)
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::Pattern(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
@@ -271,6 +273,14 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
                     .any(|expr| find_subquery_in_expr(&expr.scalar))
                 || match &op.function {
                     WindowFuncType::Aggregate(agg) => agg.args.iter().any(find_subquery_in_expr),
+                    WindowFuncType::LagLead(lag_lead) => {
+                        find_subquery_in_expr(&lag_lead.arg)
+                            || lag_lead
+                                .default
+                                .as_ref()
+                                .map_or(false, |default| find_subquery_in_expr(default))
+                    }
+                    WindowFuncType::NthValue(nth_value) => find_subquery_in_expr(&nth_value.arg),
                     _ => false,
                 }
         }
@@ -289,6 +299,14 @@ fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
         ScalarExpr::WindowFunction(expr) => {
             let flag = match &expr.func {
                 WindowFuncType::Aggregate(agg) => agg.args.iter().any(find_subquery_in_expr),
+                WindowFuncType::LagLead(lag_lead) => {
+                    find_subquery_in_expr(&lag_lead.arg)
+                        || lag_lead
+                            .default
+                            .as_ref()
+                            .map_or(false, |default| find_subquery_in_expr(default))
+                }
+                WindowFuncType::NthValue(nth_value) => find_subquery_in_expr(&nth_value.arg),
                 _ => false,
             };
             flag || expr.partition_by.iter().any(find_subquery_in_expr)
@@ -43,3 +43,10 @@ pub fn contains_project_set(s_expr: &SExpr) -> bool {
 
     false
 }
+
+/// Check if a query contains recursive CTE, whose working table can only be
+/// accessed in the local node.
+pub fn contains_recursive_cte(s_expr: &SExpr) -> bool {
+    matches!(s_expr.plan(), RelOperator::RecursiveCte(_))
+        || s_expr.children().iter().any(contains_recursive_cte)
+}
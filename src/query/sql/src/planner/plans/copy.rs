@@ -83,6 +83,8 @@ pub enum CopyPlan {
         path: String,
         validation_mode: ValidationMode,
         from: Box<Plan>,
+        /// The names of the partition keys, which are the last columns of `from`.
+        partition_by: Vec<String>,
     },
 }
 
@@ -118,11 +120,15 @@ impl Debug for CopyPlan {
                 stage,
                 path,
                 validation_mode,
+                partition_by,
                 ..
             } => {
                 write!(f, "Copy into {stage:?}")?;
                 write!(f, ", path: {path:?}")?;
                 write!(f, ", validation_mode: {validation_mode:?}")?;
+                if !partition_by.is_empty() {
+                    write!(f, ", partition_by: {partition_by:?}")?;
+                }
             }
         }
         Ok(())
@@ -64,6 +64,22 @@ impl CreateDatabasePlan {
     }
 }
 
+/// Clone.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloneDatabasePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub source_database: String,
+}
+
+impl CloneDatabasePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Drop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropDatabasePlan {
@@ -15,7 +15,9 @@ mod account;
 mod catalog;
 mod database;
 mod file_format;
+mod index;
 mod stage;
+mod stream;
 mod table;
 mod udf;
 mod view;
@@ -24,7 +26,9 @@ pub use account::*;
 pub use catalog::*;
 pub use database::*;
 pub use file_format::*;
+pub use index::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
 pub use udf::*;
 pub use view::*;
@@ -57,6 +57,26 @@ impl CreateTablePlan {
     }
 }
 
+/// Clone.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloneTablePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub source_database: String,
+    pub source_table: String,
+    /// Clone the table as of this point, or the current snapshot if it is None.
+    pub navigation: Option<NavigationPoint>,
+}
+
+impl CloneTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -128,6 +148,25 @@ impl AnalyzeTablePlan {
     }
 }
 
+/// Vacuum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VacuumTablePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub retain_hours: Option<u64>,
+    pub dry_run: bool,
+}
+
+impl VacuumTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", DataType::String),
+            DataField::new("size", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+}
+
 /// Rename.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RenameTablePlan {
@@ -11,10 +11,12 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::TableSchemaRef;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateViewPlan {
@@ -63,3 +65,37 @@ impl DropViewPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    /// Schema of the fuse table which stores the result of the query.
+    pub schema: TableSchemaRef,
+    /// Options of the fuse table.
+    pub options: BTreeMap<String, String>,
+    pub subquery: String,
+}
+
+impl CreateMaterializedViewPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
+
+impl RefreshMaterializedViewPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
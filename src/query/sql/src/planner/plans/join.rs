@@ -34,6 +34,7 @@ use crate::optimizer::RequiredProperty;
 use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics;
 use crate::optimizer::UniformSampleSet;
+use crate::plans::ComparisonOp;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -57,6 +58,11 @@ pub enum JoinType {
     RightMark,
     /// Single Join is a special kind of join that is used to process correlated scalar subquery.
     Single,
+    /// Asof Join matches each row of left side with at most one row of right side,
+    /// which has the closest value satisfying the match condition.
+    Asof,
+    /// Left Asof Join is similar to Asof Join, but the unmatched rows of left side are kept.
+    LeftAsof,
 }
 
 impl JoinType {
@@ -81,6 +87,10 @@ impl JoinType {
     pub fn is_mark_join(&self) -> bool {
         matches!(self, JoinType::LeftMark | JoinType::RightMark)
     }
+
+    pub fn is_asof_join(&self) -> bool {
+        matches!(self, JoinType::Asof | JoinType::LeftAsof)
+    }
 }
 
 impl Display for JoinType {
@@ -122,10 +132,26 @@ impl Display for JoinType {
             JoinType::Single => {
                 write!(f, "SINGLE")
             }
+            JoinType::Asof => {
+                write!(f, "ASOF")
+            }
+            JoinType::LeftAsof => {
+                write!(f, "LEFT ASOF")
+            }
         }
     }
 }
 
+/// Match condition of Asof Join, in the form of `left op right`.
+/// `left` only uses the columns of left child, and `right` only
+/// uses the columns of right child.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AsofCondition {
+    pub left: ScalarExpr,
+    pub right: ScalarExpr,
+    pub op: ComparisonOp,
+}
+
 /// Join operator. We will choose hash join by default.
 /// In the case that using hash join, the right child
 /// is always the build side, and the left child is always
@@ -141,6 +167,11 @@ pub struct Join {
     pub from_correlated_subquery: bool,
     // It means that join has a corresponding runtime filter
     pub contain_runtime_filter: bool,
+    // It means that join is executed as a sort-merge join instead of hash join,
+    // both children will be sorted by the join keys.
+    pub merge_join: bool,
+    // asof_condition is for AsofJoin only.
+    pub asof_condition: Option<AsofCondition>,
 }
 
 impl Default for Join {
@@ -153,6 +184,8 @@ impl Default for Join {
             marker_index: Default::default(),
             from_correlated_subquery: Default::default(),
             contain_runtime_filter: false,
+            merge_join: false,
+            asof_condition: None,
         }
     }
 }
@@ -168,6 +201,10 @@ impl Join {
         {
             used_columns = used_columns.union(&cond.used_columns()).cloned().collect();
         }
+        if let Some(asof_condition) = &self.asof_condition {
+            used_columns.extend(asof_condition.left.used_columns());
+            used_columns.extend(asof_condition.right.used_columns());
+        }
         Ok(used_columns)
     }
 
@@ -359,6 +396,7 @@ impl Operator for Join {
         )?;
         let cardinality = match self.join_type {
             JoinType::Inner | JoinType::Cross => inner_join_cardinality,
+            JoinType::Asof => f64::min(left_cardinality, inner_join_cardinality),
             JoinType::Left => f64::max(left_cardinality, inner_join_cardinality),
             JoinType::Right => f64::max(right_cardinality, inner_join_cardinality),
             JoinType::Full => {
@@ -366,9 +404,11 @@ impl Operator for Join {
                     + f64::max(right_cardinality, inner_join_cardinality)
                     - inner_join_cardinality
             }
-            JoinType::LeftSemi | JoinType::LeftAnti | JoinType::LeftMark | JoinType::Single => {
-                left_cardinality
-            }
+            JoinType::LeftSemi
+            | JoinType::LeftAnti
+            | JoinType::LeftMark
+            | JoinType::Single
+            | JoinType::LeftAsof => left_cardinality,
             JoinType::RightSemi | JoinType::RightAnti | JoinType::RightMark => right_cardinality,
         };
         // Derive column statistics
@@ -401,8 +441,10 @@ impl Operator for Join {
         let probe_physical_prop = rel_expr.derive_physical_prop_child(0)?;
         let build_physical_prop = rel_expr.derive_physical_prop_child(1)?;
 
-        // if join/probe side is Serial or join key is empty, we use Serial distribution
-        if probe_physical_prop.distribution == Distribution::Serial
+        // if join/probe side is Serial or join key is empty, we use Serial distribution.
+        // Merge join needs the whole sorted inputs, so it's always executed in Serial.
+        if self.merge_join
+            || probe_physical_prop.distribution == Distribution::Serial
             || build_physical_prop.distribution == Distribution::Serial
         {
             // TODO(leiysky): we can enforce redistribution here
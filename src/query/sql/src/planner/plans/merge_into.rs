@@ -0,0 +1,62 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::FieldIndex;
+
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::IndexType;
+
+#[derive(Clone, Debug)]
+pub struct MatchedEvaluator {
+    pub condition: Option<ScalarExpr>,
+    // None means DELETE
+    pub update: Option<HashMap<FieldIndex, ScalarExpr>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UnmatchedEvaluator {
+    pub condition: Option<ScalarExpr>,
+    // One value for each field of the target table
+    pub values: Vec<ScalarExpr>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MergeInto {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    // The query plan of the source
+    pub source: Box<Plan>,
+    // Columns of the target table, in the order of the table schema
+    pub target_columns: Vec<IndexType>,
+    // Output columns of the source
+    pub source_columns: Vec<IndexType>,
+    // Equi-join keys of (target, source)
+    pub join_keys: Vec<(ScalarExpr, ScalarExpr)>,
+    pub join_condition: ScalarExpr,
+    pub matched_evaluators: Vec<MatchedEvaluator>,
+    pub unmatched_evaluators: Vec<UnmatchedEvaluator>,
+}
+
+impl MergeInto {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -25,12 +25,14 @@ pub mod insert;
 mod join;
 mod kill;
 mod limit;
+mod merge_into;
 mod operator;
 mod pattern;
 mod plan;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte;
 mod replace;
 mod revert_table;
 mod runtime_filter_source;
@@ -57,6 +59,9 @@ pub use insert::InsertInputSource;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
+pub use merge_into::MatchedEvaluator;
+pub use merge_into::MergeInto;
+pub use merge_into::UnmatchedEvaluator;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use plan::Plan::*;
@@ -65,6 +70,8 @@ pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte::RecursiveCte;
+pub use recursive_cte::RecursiveCteScan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
 pub use runtime_filter_source::RuntimeFilterId;
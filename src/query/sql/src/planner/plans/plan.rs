@@ -45,11 +45,16 @@ use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CallPlan;
+use crate::plans::CloneDatabasePlan;
+use crate::plans::CloneTablePlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateIndexPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
@@ -59,8 +64,10 @@ use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::DropFileFormatPlan;
+use crate::plans::DropIndexPlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
@@ -71,7 +78,10 @@ use crate::plans::ExistsTablePlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
+use crate::plans::MergeInto;
 use crate::plans::OptimizeTablePlan;
+use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTablePlan;
@@ -94,6 +104,7 @@ use crate::plans::UndropDatabasePlan;
 use crate::plans::UndropTablePlan;
 use crate::plans::UpdatePlan;
 use crate::plans::UseDatabasePlan;
+use crate::plans::VacuumTablePlan;
 use crate::BindContext;
 use crate::MetadataRef;
 
@@ -138,6 +149,7 @@ pub enum Plan {
     // Databases
     ShowCreateDatabase(Box<ShowCreateDatabasePlan>),
     CreateDatabase(Box<CreateDatabasePlan>),
+    CloneDatabase(Box<CloneDatabasePlan>),
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
@@ -147,6 +159,7 @@ pub enum Plan {
     ShowCreateTable(Box<ShowCreateTablePlan>),
     DescribeTable(Box<DescribeTablePlan>),
     CreateTable(Box<CreateTablePlan>),
+    CloneTable(Box<CloneTablePlan>),
     DropTable(Box<DropTablePlan>),
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
@@ -159,11 +172,18 @@ pub enum Plan {
     TruncateTable(Box<TruncateTablePlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
     AnalyzeTable(Box<AnalyzeTablePlan>),
+    VacuumTable(Box<VacuumTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
 
+    // Indexes
+    CreateIndex(Box<CreateIndexPlan>),
+    DropIndex(Box<DropIndexPlan>),
+    RefreshIndex(Box<RefreshIndexPlan>),
+
     // Insert
     Insert(Box<Insert>),
     Replace(Box<Replace>),
+    MergeInto(Box<MergeInto>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
 
@@ -171,6 +191,12 @@ pub enum Plan {
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
 
     // Account
     AlterUser(Box<AlterUserPlan>),
@@ -224,6 +250,11 @@ pub enum Plan {
     ShowShares(Box<ShowSharesPlan>),
     ShowObjectGrantPrivileges(Box<ShowObjectGrantPrivilegesPlan>),
     ShowGrantTenantsOfShare(Box<ShowGrantTenantsOfSharePlan>),
+
+    // Transactions
+    Begin,
+    Commit,
+    Rollback,
 }
 
 #[derive(Clone, Debug)]
@@ -261,6 +292,7 @@ impl Display for Plan {
             Plan::DropCatalog(_) => write!(f, "DropCatalog"),
             Plan::ShowCreateDatabase(_) => write!(f, "ShowCreateDatabase"),
             Plan::CreateDatabase(_) => write!(f, "CreateDatabase"),
+            Plan::CloneDatabase(_) => write!(f, "CloneDatabase"),
             Plan::DropDatabase(_) => write!(f, "DropDatabase"),
             Plan::UndropDatabase(_) => write!(f, "UndropDatabase"),
             Plan::UseDatabase(_) => write!(f, "UseDatabase"),
@@ -268,6 +300,7 @@ impl Display for Plan {
             Plan::ShowCreateTable(_) => write!(f, "ShowCreateTable"),
             Plan::DescribeTable(_) => write!(f, "DescribeTable"),
             Plan::CreateTable(_) => write!(f, "CreateTable"),
+            Plan::CloneTable(_) => write!(f, "CloneTable"),
             Plan::DropTable(_) => write!(f, "DropTable"),
             Plan::UndropTable(_) => write!(f, "UndropTable"),
             Plan::RenameTable(_) => write!(f, "RenameTable"),
@@ -279,10 +312,18 @@ impl Display for Plan {
             Plan::TruncateTable(_) => write!(f, "TruncateTable"),
             Plan::OptimizeTable(_) => write!(f, "OptimizeTable"),
             Plan::AnalyzeTable(_) => write!(f, "AnalyzeTable"),
+            Plan::VacuumTable(_) => write!(f, "VacuumTable"),
             Plan::ExistsTable(_) => write!(f, "ExistsTable"),
+            Plan::CreateIndex(_) => write!(f, "CreateIndex"),
+            Plan::DropIndex(_) => write!(f, "DropIndex"),
+            Plan::RefreshIndex(_) => write!(f, "RefreshIndex"),
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateMaterializedView(_) => write!(f, "CreateMaterializedView"),
+            Plan::RefreshMaterializedView(_) => write!(f, "RefreshMaterializedView"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
             Plan::AlterUser(_) => write!(f, "AlterUser"),
             Plan::CreateUser(_) => write!(f, "CreateUser"),
             Plan::DropUser(_) => write!(f, "DropUser"),
@@ -305,6 +346,7 @@ impl Display for Plan {
             Plan::DropUDF(_) => write!(f, "DropUDF"),
             Plan::Insert(_) => write!(f, "Insert"),
             Plan::Replace(_) => write!(f, "Replace"),
+            Plan::MergeInto(_) => write!(f, "MergeInto"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Update(_) => write!(f, "Update"),
             Plan::Call(_) => write!(f, "Call"),
@@ -328,6 +370,9 @@ impl Display for Plan {
             Plan::ExplainAst { .. } => write!(f, "ExplainAst"),
             Plan::ExplainSyntax { .. } => write!(f, "ExplainSyntax"),
             Plan::RevertTable(..) => write!(f, "RevertTable"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Rollback => write!(f, "Rollback"),
         }
     }
 }
@@ -355,6 +400,7 @@ impl Plan {
             Plan::DropCatalog(plan) => plan.schema(),
             Plan::ShowCreateDatabase(plan) => plan.schema(),
             Plan::CreateDatabase(plan) => plan.schema(),
+            Plan::CloneDatabase(plan) => plan.schema(),
             Plan::UseDatabase(_) => Arc::new(DataSchema::empty()),
             Plan::DropDatabase(plan) => plan.schema(),
             Plan::UndropDatabase(plan) => plan.schema(),
@@ -362,6 +408,7 @@ impl Plan {
             Plan::ShowCreateTable(plan) => plan.schema(),
             Plan::DescribeTable(plan) => plan.schema(),
             Plan::CreateTable(plan) => plan.schema(),
+            Plan::CloneTable(plan) => plan.schema(),
             Plan::DropTable(plan) => plan.schema(),
             Plan::UndropTable(plan) => plan.schema(),
             Plan::RenameTable(plan) => plan.schema(),
@@ -373,10 +420,18 @@ impl Plan {
             Plan::TruncateTable(plan) => plan.schema(),
             Plan::OptimizeTable(plan) => plan.schema(),
             Plan::AnalyzeTable(plan) => plan.schema(),
+            Plan::VacuumTable(plan) => plan.schema(),
             Plan::ExistsTable(plan) => plan.schema(),
+            Plan::CreateIndex(plan) => plan.schema(),
+            Plan::DropIndex(plan) => plan.schema(),
+            Plan::RefreshIndex(plan) => plan.schema(),
             Plan::CreateView(plan) => plan.schema(),
             Plan::AlterView(plan) => plan.schema(),
             Plan::DropView(plan) => plan.schema(),
+            Plan::CreateMaterializedView(plan) => plan.schema(),
+            Plan::RefreshMaterializedView(plan) => plan.schema(),
+            Plan::CreateStream(plan) => plan.schema(),
+            Plan::DropStream(plan) => plan.schema(),
             Plan::AlterUser(plan) => plan.schema(),
             Plan::CreateUser(plan) => plan.schema(),
             Plan::DropUser(plan) => plan.schema(),
@@ -399,6 +454,7 @@ impl Plan {
             Plan::DropUDF(_) => Arc::new(DataSchema::empty()),
             Plan::Insert(plan) => plan.schema(),
             Plan::Replace(plan) => plan.schema(),
+            Plan::MergeInto(plan) => plan.schema(),
             Plan::Delete(_) => Arc::new(DataSchema::empty()),
             Plan::Update(_) => Arc::new(DataSchema::empty()),
             Plan::Call(_) => Arc::new(DataSchema::empty()),
@@ -420,6 +476,9 @@ impl Plan {
             Plan::ShowObjectGrantPrivileges(plan) => plan.schema(),
             Plan::ShowGrantTenantsOfShare(plan) => plan.schema(),
             Plan::RevertTable(plan) => plan.schema(),
+            Plan::Begin => Arc::new(DataSchema::empty()),
+            Plan::Commit => Arc::new(DataSchema::empty()),
+            Plan::Rollback => Arc::new(DataSchema::empty()),
         }
     }
 
@@ -297,7 +297,7 @@ pub struct ConstantExpr {
     pub value: Scalar,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, serde::Serialize, serde::Deserialize)]
 pub enum ComparisonOp {
     Equal,
     NotEqual,
@@ -324,6 +324,17 @@ impl ComparisonOp {
         }
     }
 
+    /// Get the operator with swapped operands, e.g. `a < b` is equivalent to `b > a`.
+    pub fn reverse(&self) -> Self {
+        match self {
+            ComparisonOp::GT => ComparisonOp::LT,
+            ComparisonOp::LT => ComparisonOp::GT,
+            ComparisonOp::GTE => ComparisonOp::LTE,
+            ComparisonOp::LTE => ComparisonOp::GTE,
+            op => *op,
+        }
+    }
+
     pub fn to_func_name(&self) -> &'static str {
         match &self {
             ComparisonOp::Equal => "eq",
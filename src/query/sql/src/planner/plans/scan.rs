@@ -15,12 +15,14 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::plan::AggIndexInfo;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use itertools::Itertools;
 
+use crate::optimizer::histogram_from_column_histogram;
 use crate::optimizer::histogram_from_ndv;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
@@ -60,6 +62,15 @@ pub struct Statistics {
     pub col_stats: HashMap<IndexType, Option<ColumnStatistics>>,
 }
 
+/// The aggregating index read by the scan instead of the table data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggIndexScan {
+    pub info: AggIndexInfo,
+    /// Output columns of the aggregate functions whose partial states are read,
+    /// in the same order of `info.aggregates`.
+    pub state_columns: Vec<IndexType>,
+}
+
 #[derive(Clone, Debug)]
 pub struct Scan {
     pub table_index: IndexType,
@@ -68,6 +79,7 @@ pub struct Scan {
     pub limit: Option<usize>,
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
+    pub agg_index: Option<AggIndexScan>,
 
     pub statistics: Statistics,
 }
@@ -93,6 +105,7 @@ impl Scan {
                 col_stats,
             },
             prewhere,
+            agg_index: self.agg_index.clone(),
         }
     }
 
@@ -117,6 +130,7 @@ impl PartialEq for Scan {
         self.table_index == other.table_index
             && self.columns == other.columns
             && self.push_down_predicates == other.push_down_predicates
+            && self.agg_index == other.agg_index
     }
 }
 
@@ -173,13 +187,22 @@ impl Operator for Scan {
                 let min_datum = Datum::from_scalar(&min);
                 let max_datum = Datum::from_scalar(&max);
                 if let (Some(min), Some(max)) = (min_datum, max_datum) {
-                    let histogram = histogram_from_ndv(
-                        col_stat.number_of_distinct_values,
-                        num_rows,
-                        Some((min.clone(), max.clone())),
-                        DEFAULT_HISTOGRAM_BUCKETS,
-                    )
-                    .ok();
+                    // Prefer the histogram collected by `ANALYZE TABLE`.
+                    let histogram = col_stat
+                        .histogram
+                        .as_ref()
+                        .and_then(|histogram| {
+                            histogram_from_column_histogram(min.clone(), histogram)
+                        })
+                        .or_else(|| {
+                            histogram_from_ndv(
+                                col_stat.number_of_distinct_values,
+                                num_rows,
+                                Some((min.clone(), max.clone())),
+                                DEFAULT_HISTOGRAM_BUCKETS,
+                            )
+                            .ok()
+                        });
                     let column_stat = ColumnStat {
                         min,
                         max,
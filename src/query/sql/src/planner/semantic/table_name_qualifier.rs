@@ -0,0 +1,98 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::Identifier;
+use common_ast::ast::Query;
+use common_ast::ast::TableReference;
+use common_ast::ast::With;
+use common_ast::walk_cte_mut;
+use common_ast::walk_query_mut;
+use common_ast::walk_table_reference_mut;
+use common_ast::VisitorMut;
+
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::NameResolutionContext;
+
+/// Qualify the table references of a query with the catalog and the database
+/// they are resolved in, so the query reads the same tables wherever it is
+/// planned again, e.g. the stored query of a materialized view.
+///
+/// References to the CTEs in scope are left as is.
+pub struct TableNameQualifier<'a> {
+    name_resolution_ctx: &'a NameResolutionContext,
+    catalog: String,
+    database: String,
+    cte_names: Vec<String>,
+}
+
+impl<'a> TableNameQualifier<'a> {
+    pub fn new(
+        name_resolution_ctx: &'a NameResolutionContext,
+        catalog: String,
+        database: String,
+    ) -> Self {
+        Self {
+            name_resolution_ctx,
+            catalog,
+            database,
+            cte_names: vec![],
+        }
+    }
+
+    fn quoted(name: &str) -> Identifier {
+        Identifier {
+            name: name.to_string(),
+            quote: Some('`'),
+            span: None,
+        }
+    }
+}
+
+impl<'a> VisitorMut for TableNameQualifier<'a> {
+    fn visit_query(&mut self, query: &mut Query) {
+        // The CTEs of a query are only visible inside of it.
+        let num_cte_names = self.cte_names.len();
+        walk_query_mut(self, query);
+        self.cte_names.truncate(num_cte_names);
+    }
+
+    fn visit_with(&mut self, with: &mut With) {
+        for cte in with.ctes.iter() {
+            let name = normalize_identifier(&cte.alias.name, self.name_resolution_ctx).name;
+            self.cte_names.push(name);
+        }
+        for cte in with.ctes.iter_mut() {
+            walk_cte_mut(self, cte);
+        }
+    }
+
+    fn visit_table_reference(&mut self, table_ref: &mut TableReference) {
+        if let TableReference::Table {
+            catalog,
+            database,
+            table,
+            ..
+        } = table_ref
+        {
+            let table_name = normalize_identifier(table, self.name_resolution_ctx).name;
+            if database.is_none() && !self.cte_names.contains(&table_name) {
+                *database = Some(Self::quoted(&self.database));
+            }
+            if catalog.is_none() && database.is_some() {
+                *catalog = Some(Self::quoted(&self.catalog));
+            }
+        }
+        walk_table_reference_mut(self, table_ref);
+    }
+}
@@ -78,6 +78,9 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::NthValueFunction;
+use crate::plans::NtileFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
@@ -649,18 +652,14 @@ impl<'a> TypeChecker<'a> {
                             "window function {name} can only be used in window clause"
                         )));
                     }
-                    if !args.is_empty() {
-                        return Err(ErrorCode::SemanticError(format!(
-                            "window function {name} does not have any argument"
-                        )));
-                    }
                     let window = window.as_ref().unwrap();
+                    let func = self
+                        .resolve_general_window_function(*span, &name, &args, window.ignore_nulls)
+                        .await?;
                     // WindowReference already rewritten by `SelectRewriter` before.
-                    let window = window.as_window_spec().unwrap();
+                    let spec = window.window.as_window_spec().unwrap();
                     let display_name = format!("{:#}", expr);
-                    let func = WindowFuncType::from_name(&name)?;
-                    self.resolve_window(*span, display_name, window, func)
-                        .await?
+                    self.resolve_window(*span, display_name, spec, func).await?
                 } else if AggregateFunctionFactory::instance().contains(&name) {
                     let in_window = self.in_window_function;
                     self.in_window_function = self.in_window_function || window.is_some();
@@ -670,10 +669,16 @@ impl<'a> TypeChecker<'a> {
                     self.in_window_function = in_window;
                     if let Some(window) = window {
                         // aggregate window function
+                        if window.ignore_nulls.is_some() {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "IGNORE NULLS or RESPECT NULLS is not supported by aggregate function {name}"
+                            ))
+                            .set_span(*span));
+                        }
                         let display_name = format!("{:#}", expr);
                         let func = WindowFuncType::Aggregate(new_agg_func);
                         // WindowReference already rewritten by `SelectRewriter` before.
-                        let window = window.as_window_spec().unwrap();
+                        let window = window.window.as_window_spec().unwrap();
                         self.resolve_window(*span, display_name, window, func)
                             .await?
                     } else {
@@ -874,6 +879,137 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve the general window functions, which are not aggregate functions.
+    #[async_backtrace::framed]
+    async fn resolve_general_window_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[&Expr],
+        ignore_nulls: Option<bool>,
+    ) -> Result<WindowFuncType> {
+        match func_name {
+            "lag" | "lead" => {
+                if args.is_empty() || args.len() > 3 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} requires 1 to 3 arguments, but got {}",
+                        args.len()
+                    ))
+                    .set_span(span));
+                }
+                let box (arg, arg_type) = self.resolve_window_function_arg(args[0]).await?;
+                let offset = match args.get(1) {
+                    Some(expr) => self.resolve_window_function_offset(expr, false).await?,
+                    None => 1,
+                };
+                let return_type = arg_type.wrap_nullable();
+                let default = match args.get(2) {
+                    Some(expr) => {
+                        let box (default, _) = self.resolve_window_function_arg(expr).await?;
+                        Some(Box::new(wrap_cast(&default, &return_type)))
+                    }
+                    None => None,
+                };
+                Ok(WindowFuncType::LagLead(LagLeadFunction {
+                    is_lag: func_name == "lag",
+                    arg: Box::new(arg),
+                    offset,
+                    default,
+                    ignore_nulls: ignore_nulls.unwrap_or(false),
+                    return_type: Box::new(return_type),
+                }))
+            }
+            "first_value" | "last_value" | "nth_value" => {
+                let expected_args = if func_name == "nth_value" { 2 } else { 1 };
+                if args.len() != expected_args {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} requires {expected_args} arguments, but got {}",
+                        args.len()
+                    ))
+                    .set_span(span));
+                }
+                let box (arg, arg_type) = self.resolve_window_function_arg(args[0]).await?;
+                let n = match func_name {
+                    "first_value" => Some(1),
+                    "last_value" => None,
+                    _ => Some(self.resolve_window_function_offset(args[1], true).await?),
+                };
+                Ok(WindowFuncType::NthValue(NthValueFunction {
+                    n,
+                    arg: Box::new(arg),
+                    ignore_nulls: ignore_nulls.unwrap_or(false),
+                    return_type: Box::new(arg_type.wrap_nullable()),
+                }))
+            }
+            _ => {
+                if ignore_nulls.is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "IGNORE NULLS or RESPECT NULLS is not supported by window function {func_name}"
+                    ))
+                    .set_span(span));
+                }
+                if func_name == "ntile" {
+                    if args.len() != 1 {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "window function ntile requires 1 argument, but got {}",
+                            args.len()
+                        ))
+                        .set_span(span));
+                    }
+                    let n = self.resolve_window_function_offset(args[0], true).await?;
+                    return Ok(WindowFuncType::Ntile(NtileFunction {
+                        n,
+                        return_type: Box::new(DataType::Number(NumberDataType::UInt64)),
+                    }));
+                }
+                if !args.is_empty() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} does not have any argument"
+                    ))
+                    .set_span(span));
+                }
+                WindowFuncType::from_name(func_name)
+            }
+        }
+    }
+
+    // The arguments of general window functions can contain aggregate functions, but not window functions.
+    #[async_backtrace::framed]
+    async fn resolve_window_function_arg(
+        &mut self,
+        arg: &Expr,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let in_window = self.in_window_function;
+        self.in_window_function = true;
+        let res = self.resolve(arg).await;
+        self.in_window_function = in_window;
+        res
+    }
+
+    // Resolve the constant unsigned integer argument of window functions,
+    // such as the offset of LAG and LEAD, or the `n` of NTH_VALUE and NTILE.
+    #[async_backtrace::framed]
+    async fn resolve_window_function_offset(&mut self, arg: &Expr, positive: bool) -> Result<u64> {
+        let box (scalar, _) = self.resolve(arg).await?;
+        let scalar = wrap_cast(&scalar, &DataType::Number(NumberDataType::UInt64));
+        let expr = scalar.as_expr_with_col_index()?;
+        let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        if let common_expression::Expr::Constant {
+            scalar: Scalar::Number(NumberScalar::UInt64(n)),
+            ..
+        } = expr
+        {
+            if n > 0 || !positive {
+                return Ok(n);
+            }
+        }
+        let expected = if positive { "positive" } else { "non-negative" };
+        Err(ErrorCode::SemanticError(format!(
+            "The argument `{arg}` of window function must be a constant {expected} integer"
+        ))
+        .set_span(arg.span()))
+    }
+
     #[async_backtrace::framed]
     async fn resolve_window(
         &mut self,
@@ -1131,12 +1267,55 @@ impl<'a> TypeChecker<'a> {
         order_by: &mut [WindowOrderBy],
         window_frame: Option<WindowFrame>,
     ) -> Result<WindowFuncFrame> {
-        if matches!(func, WindowFuncType::PercentRank) {
-            return Ok(WindowFuncFrame {
-                units: WindowFuncFrameUnits::Rows,
-                start_bound: WindowFuncFrameBound::Preceding(None),
-                end_bound: WindowFuncFrameBound::Following(None),
-            });
+        match func {
+            WindowFuncType::PercentRank | WindowFuncType::Ntile(_) | WindowFuncType::CumeDist => {
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound: WindowFuncFrameBound::Preceding(None),
+                    end_bound: WindowFuncFrameBound::Following(None),
+                });
+            }
+            WindowFuncType::LagLead(lag_lead) => {
+                // The frame of LAG and LEAD only contains the offset row, or all the rows
+                // before or after the current row if nulls are ignored.
+                let offset = Scalar::Number(NumberScalar::UInt64(lag_lead.offset));
+                let (start_bound, end_bound) = if lag_lead.offset == 0 {
+                    (
+                        WindowFuncFrameBound::CurrentRow,
+                        WindowFuncFrameBound::CurrentRow,
+                    )
+                } else if lag_lead.ignore_nulls && lag_lead.is_lag {
+                    (
+                        WindowFuncFrameBound::Preceding(None),
+                        WindowFuncFrameBound::Preceding(Some(Scalar::Number(
+                            NumberScalar::UInt64(1),
+                        ))),
+                    )
+                } else if lag_lead.ignore_nulls {
+                    (
+                        WindowFuncFrameBound::Following(Some(Scalar::Number(
+                            NumberScalar::UInt64(1),
+                        ))),
+                        WindowFuncFrameBound::Following(None),
+                    )
+                } else if lag_lead.is_lag {
+                    (
+                        WindowFuncFrameBound::Preceding(Some(offset.clone())),
+                        WindowFuncFrameBound::Preceding(Some(offset)),
+                    )
+                } else {
+                    (
+                        WindowFuncFrameBound::Following(Some(offset.clone())),
+                        WindowFuncFrameBound::Following(Some(offset)),
+                    )
+                };
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound,
+                    end_bound,
+                });
+            }
+            _ => {}
         }
         if let Some(frame) = window_frame {
             if frame.units.is_range() {
@@ -2864,8 +3043,11 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
             })?)
         }
         TypeName::String => TableDataType::String,
+        TypeName::Binary => TableDataType::Binary,
+        TypeName::Bitmap => TableDataType::Bitmap,
         TypeName::Timestamp => TableDataType::Timestamp,
         TypeName::Date => TableDataType::Date,
+        TypeName::Interval => TableDataType::Interval,
         TypeName::Array(item_type) => TableDataType::Array(Box::new(resolve_type_name(item_type)?)),
         TypeName::Map { key_type, val_type } => {
             let key_type = resolve_type_name(key_type)?;
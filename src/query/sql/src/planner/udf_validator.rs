@@ -18,6 +18,7 @@ use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::Literal;
 use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::walk_expr;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
@@ -87,7 +88,7 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        over: &'ast Option<Window>,
+        over: &'ast Option<WindowDesc>,
     ) {
         let name = name.to_string();
         if !is_builtin_function(&name) && self.name.eq_ignore_ascii_case(&name) {
@@ -100,7 +101,7 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         }
 
         if let Some(over) = over {
-            match over {
+            match &over.window {
                 Window::WindowSpec(spec) => {
                     spec.partition_by
                         .iter()
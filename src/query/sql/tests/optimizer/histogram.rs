@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_catalog::table::ColumnHistogram;
+use common_catalog::table::HistogramBucket as ColumnHistogramBucket;
+use common_expression::types::number::NumberScalar;
+use common_expression::Scalar;
+use common_sql::optimizer::histogram_from_column_histogram;
 use common_sql::optimizer::Datum;
 use common_sql::optimizer::Histogram;
 use common_sql::optimizer::HistogramBucket;
@@ -28,3 +33,29 @@ fn test_histogram() {
     assert_eq!(histogram.num_values(), 4.0);
     assert_eq!(histogram.num_distinct_values(), 2.0);
 }
+
+#[test]
+fn test_histogram_from_column_histogram() {
+    let column_histogram = ColumnHistogram {
+        buckets: vec![
+            ColumnHistogramBucket {
+                upper_bound: Scalar::Number(NumberScalar::UInt64(1)),
+                num_values: 8.0,
+                num_distinct: 1.0,
+            },
+            ColumnHistogramBucket {
+                upper_bound: Scalar::Number(NumberScalar::UInt64(10)),
+                num_values: 2.0,
+                num_distinct: 2.0,
+            },
+        ],
+        most_common_values: vec![(Scalar::Number(NumberScalar::UInt64(1)), 8)],
+    };
+
+    let histogram = histogram_from_column_histogram(Datum::UInt(1), &column_histogram).unwrap();
+    // The first bucket is a dummy bucket of the min value.
+    assert_eq!(histogram.num_buckets(), 3);
+    assert_eq!(histogram.num_values(), 11.0);
+    assert_eq!(histogram.most_common_value_count(&Datum::Int(1)), Some(8.0));
+    assert_eq!(histogram.most_common_value_count(&Datum::UInt(2)), None);
+}
@@ -0,0 +1,141 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use bumpalo::Bump;
+use common_exception::Result;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
+
+/// An aggregate function of an aggregating index, with the offsets of its
+/// argument columns in the source block.
+#[derive(Clone)]
+pub struct AggregatingIndexFunction {
+    pub func: AggregateFunctionRef,
+    pub args: Vec<usize>,
+}
+
+/// AggregatingIndex pre-aggregates a data block by the group columns.
+///
+/// The index block has one row for each group: the values of the group columns,
+/// followed by the serialized partial state of each aggregate function, which
+/// could be merged by the final aggregation as if it was computed from the rows
+/// of the source block.
+pub struct AggregatingIndex;
+
+impl AggregatingIndex {
+    /// Build the index block of the source block, the group columns are the columns
+    /// at `group_by` offsets of the source block.
+    pub fn build(
+        block: &DataBlock,
+        group_by: &[usize],
+        aggregates: &[AggregatingIndexFunction],
+    ) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let full_column = |offset: usize| {
+            let entry = block.get_by_offset(offset);
+            entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows)
+        };
+
+        // Assign each row to its group, groups are numbered by their first rows.
+        let group_columns = group_by
+            .iter()
+            .map(|offset| full_column(*offset))
+            .collect::<Vec<_>>();
+        let mut groups: HashMap<Vec<ScalarRef>, usize> = HashMap::new();
+        let mut first_rows = Vec::new();
+        let mut row_groups = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let key = group_columns
+                .iter()
+                .map(|column| unsafe { column.index_unchecked(row) })
+                .collect::<Vec<_>>();
+            let group = *groups.entry(key).or_insert_with(|| {
+                first_rows.push(row as u32);
+                first_rows.len() - 1
+            });
+            row_groups.push(group);
+        }
+        let num_groups = first_rows.len();
+
+        let mut entries = group_columns
+            .iter()
+            .map(|column| {
+                let column = column.take(&first_rows);
+                BlockEntry {
+                    data_type: column.data_type(),
+                    value: Value::Column(column),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for aggregate in aggregates {
+            let func = &aggregate.func;
+            let args = aggregate
+                .args
+                .iter()
+                .map(|offset| full_column(*offset))
+                .collect::<Vec<_>>();
+
+            let arena = Bump::new();
+            let layout = func.state_layout();
+            let states: Vec<StateAddr> = (0..num_groups)
+                .map(|_| {
+                    let place: StateAddr = arena.alloc_layout(layout).into();
+                    func.init_state(place);
+                    place
+                })
+                .collect();
+            let places = row_groups
+                .iter()
+                .map(|group| states[*group])
+                .collect::<Vec<_>>();
+
+            let res = func
+                .accumulate_keys(&places, 0, &args, num_rows)
+                .and_then(|_| Self::serialize_states(func, &states));
+            if func.need_manual_drop_state() {
+                for place in states.iter() {
+                    unsafe { func.drop_state(*place) };
+                }
+            }
+
+            entries.push(BlockEntry {
+                data_type: DataType::String,
+                value: Value::Column(res?),
+            });
+        }
+
+        Ok(DataBlock::new(entries, num_groups))
+    }
+
+    fn serialize_states(func: &AggregateFunctionRef, states: &[StateAddr]) -> Result<Column> {
+        let mut builder = StringColumnBuilder::with_capacity(states.len(), 0);
+        for place in states {
+            func.serialize(*place, &mut builder.data)?;
+            builder.commit_row();
+        }
+        Ok(Column::String(builder.build()))
+    }
+}
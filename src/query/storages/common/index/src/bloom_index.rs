@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -22,22 +23,27 @@ use common_exception::Result;
 use common_exception::Span;
 use common_expression::converts::scalar_to_datavalue;
 use common_expression::eval_function;
+use common_expression::types::number::NumberScalar;
 use common_expression::types::AnyType;
 use common_expression::types::DataType;
 use common_expression::types::MapType;
 use common_expression::types::NullableType;
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
 use common_expression::Column;
+use common_expression::ColumnId;
 use common_expression::ConstantFolder;
 use common_expression::DataBlock;
 use common_expression::Expr;
 use common_expression::FieldIndex;
+use common_expression::FromData;
 use common_expression::FunctionContext;
 use common_expression::Scalar;
+use common_expression::ScalarRef;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
@@ -55,6 +61,9 @@ use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
 use crate::Index;
 
+/// Size of the ngrams in the ngram filters.
+pub const NGRAM_SIZE: usize = 3;
+
 #[derive(Clone)]
 pub struct BloomIndexMeta {
     pub columns: Vec<(String, SingleColumnMeta)>,
@@ -175,11 +184,19 @@ impl BloomIndex {
     /// Create a filter block from source data.
     ///
     /// All input blocks should belong to a Parquet file, e.g. the block array represents the parquet file in memory.
+    ///
+    /// The filters of the column values are created for the columns in `bloom_column_ids`,
+    /// or for all the supported columns if it's `None`.
+    ///
+    /// Besides the filters of the column values, ngram filters are created for the
+    /// string columns in `ngram_column_ids`, which are used to prune `LIKE` patterns.
     pub fn try_create(
         func_ctx: FunctionContext,
         source_schema: TableSchemaRef,
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
+        bloom_column_ids: Option<&[ColumnId]>,
+        ngram_column_ids: &[ColumnId],
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -193,6 +210,12 @@ impl BloomIndex {
         let mut fields = Vec::new();
         let mut columns = Vec::new();
         for i in 0..num_columns {
+            if let Some(column_ids) = bloom_column_ids {
+                if !column_ids.contains(&source_schema.field(i).column_id()) {
+                    continue;
+                }
+            }
+
             let data_type = &data_blocks_tobe_indexed[0].get_by_offset(i).data_type;
             match data_type {
                 DataType::Map(box inner_ty) => {
@@ -237,7 +260,7 @@ impl BloomIndex {
                 }
             };
         }
-        if columns.is_empty() {
+        if columns.is_empty() && ngram_column_ids.is_empty() {
             return Ok(None);
         }
 
@@ -296,6 +319,51 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for (i, field) in source_schema.fields().iter().enumerate() {
+            if !ngram_column_ids.contains(&field.column_id())
+                || field.data_type().remove_nullable() != TableDataType::String
+            {
+                continue;
+            }
+
+            let data_type = &data_blocks_tobe_indexed[0].get_by_offset(i).data_type;
+            let mut ngrams = HashSet::new();
+            for block in data_blocks_tobe_indexed {
+                let column = block
+                    .get_by_offset(i)
+                    .value
+                    .convert_to_full_column(data_type, block.num_rows());
+                for row in 0..column.len() {
+                    if let Some(ScalarRef::String(text)) = column.index(row) {
+                        ngrams.extend(Self::ngrams(text));
+                    }
+                }
+            }
+            if ngrams.is_empty() {
+                continue;
+            }
+
+            let column = StringType::from_data(ngrams.into_iter().collect::<Vec<_>>());
+            let col = Self::calculate_column_digest(
+                &func_ctx,
+                &column,
+                &DataType::String,
+                &DataType::Number(NumberDataType::UInt64),
+            )?;
+            let digests = UInt64Type::try_downcast_column(&col).unwrap();
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_digests(digests.deref());
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_ngram_filter_column_name(field);
+            filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+            filters.push(Arc::new(filter));
+        }
+
+        if filters.is_empty() {
+            return Ok(None);
+        }
+
         let filter_schema = Arc::new(TableSchema::new(filter_fields));
 
         Ok(Some(Self {
@@ -349,6 +417,10 @@ impl BloomIndex {
             },
         )?;
 
+        visit_expr_column_contains_constant(&mut expr, &mut |col_name, substrings| {
+            self.find_ngrams(col_name, substrings, scalar_map)
+        })?;
+
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -360,6 +432,12 @@ impl BloomIndex {
         }
     }
 
+    /// Split the text into overlapping byte ngrams of `NGRAM_SIZE`,
+    /// text shorter than `NGRAM_SIZE` has no ngram.
+    pub fn ngrams(text: &[u8]) -> impl Iterator<Item = Vec<u8>> + '_ {
+        text.windows(NGRAM_SIZE).map(|ngram| ngram.to_vec())
+    }
+
     /// calculate digest for column
     pub fn calculate_column_digest(
         func_ctx: &FunctionContext,
@@ -410,6 +488,28 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all string columns that must contain some constant substrings, i.e. `col LIKE <pattern>`,
+    /// `contains(col, <constant>)` and `position(<constant> IN col)`, with the ngrams of the substrings.
+    pub fn find_ngram_columns(expr: &Expr<String>) -> Result<Vec<(String, Vec<Vec<u8>>)>> {
+        let mut cols = Vec::new();
+        visit_expr_column_contains_constant(&mut expr.clone(), &mut |col_name, substrings| {
+            let ngrams = substrings
+                .iter()
+                .flat_map(|substring| Self::ngrams(substring))
+                .collect::<Vec<_>>();
+            if !ngrams.is_empty() {
+                cols.push((col_name.to_string(), ngrams));
+            }
+            Ok(FilterEvalResult::Uncertain)
+        })?;
+        Ok(cols)
+    }
+
+    /// The ngram filter of a column will be stored with field name 'Ngram(column_id)'
+    pub fn build_ngram_filter_column_name(field: &TableField) -> String {
+        format!("Ngram({})", field.column_id())
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -464,6 +564,130 @@ impl BloomIndex {
             Ok(FilterEvalResult::MustFalse)
         }
     }
+
+    fn find_ngrams(
+        &self,
+        column_name: &str,
+        substrings: &[Vec<u8>],
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        let filter_column =
+            Self::build_ngram_filter_column_name(self.source_schema.field_with_name(column_name)?);
+        if !self.filter_schema.has_field(&filter_column) {
+            // The column doesn't have a ngram filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(&filter_column)?;
+        let filter = &self.filters[idx];
+        for ngram in substrings
+            .iter()
+            .flat_map(|substring| Self::ngrams(substring))
+        {
+            if let Some(digest) = scalar_map.get(&Scalar::String(ngram)) {
+                if !filter.contains_digest(*digest) {
+                    return Ok(FilterEvalResult::MustFalse);
+                }
+            }
+        }
+        Ok(FilterEvalResult::Uncertain)
+    }
+}
+
+/// Extract the substrings which must be contained by any string matching the `LIKE` pattern.
+///
+/// Returns `None` if the pattern has escaped characters, which are not handled here.
+fn like_pattern_substrings(pattern: &[u8]) -> Option<Vec<Vec<u8>>> {
+    if pattern.contains(&b'\\') {
+        return None;
+    }
+    Some(
+        pattern
+            .split(|c| *c == b'%' || *c == b'_')
+            .filter(|substring| !substring.is_empty())
+            .map(|substring| substring.to_vec())
+            .collect(),
+    )
+}
+
+fn visit_expr_column_contains_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(&str, &[Vec<u8>]) -> Result<FilterEvalResult>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE <pattern>`, `contains(Column, <constant>)`,
+    // `instr(Column, <constant>)`, `position(<constant> IN Column)` or `locate(<constant>, Column)`
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let found = match (function.signature.name.as_str(), args.as_slice()) {
+            (
+                "like" | "contains" | "instr",
+                [
+                    Expr::ColumnRef { id, data_type, .. },
+                    Expr::Constant {
+                        scalar: Scalar::String(constant),
+                        ..
+                    },
+                ],
+            )
+            | (
+                "position" | "locate",
+                [
+                    Expr::Constant {
+                        scalar: Scalar::String(constant),
+                        ..
+                    },
+                    Expr::ColumnRef { id, data_type, .. },
+                ],
+            ) if data_type.remove_nullable() == DataType::String => {
+                let substrings = if function.signature.name == "like" {
+                    like_pattern_substrings(constant)
+                } else {
+                    Some(vec![constant.clone()])
+                };
+                substrings.map(|substrings| (id.clone(), substrings))
+            }
+            _ => None,
+        };
+
+        if let Some((col_name, substrings)) = found {
+            if visitor(&col_name, &substrings)? == FilterEvalResult::MustFalse {
+                // The column doesn't contain the substrings, `like` and `contains` must be false,
+                // and the position functions must return 0.
+                let scalar = match return_type.remove_nullable() {
+                    DataType::Boolean => Scalar::Boolean(false),
+                    _ => Scalar::Number(NumberScalar::UInt64(0)),
+                };
+                let new_expr = Expr::Constant {
+                    span: *span,
+                    scalar,
+                    data_type: return_type.clone(),
+                };
+                *expr = new_expr;
+            }
+            return Ok(());
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_contains_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_contains_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
 }
 
 fn visit_expr_column_eq_constant(
@@ -0,0 +1,243 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_expression::ColumnId;
+use common_expression::ConstantFolder;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_functions::scalars::tokenize;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_io::prelude::deserialize_from_slice;
+use common_io::prelude::serialize_into_buf;
+use serde::Deserialize;
+use serde::Serialize;
+use storages_common_table_meta::meta::Versioned;
+
+use crate::FilterEvalResult;
+
+/// InvertedIndex maps the terms of the indexed string columns of a data block
+/// to the rows they appear in.
+///
+/// The terms are produced by the same tokenizer as the `match` function, so a block
+/// could be skipped if no row contains all the terms of the `match` query.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct InvertedIndex {
+    /// Column id -> term -> sorted row offsets of the rows which contain the term.
+    pub columns: BTreeMap<ColumnId, BTreeMap<String, Vec<u32>>>,
+}
+
+impl Versioned<1> for InvertedIndex {}
+
+impl InvertedIndex {
+    /// Create the index of the given columns from source data.
+    ///
+    /// Columns which are not (nullable) string columns are ignored,
+    /// returns `None` if there is nothing to index.
+    pub fn try_create(
+        source_schema: &TableSchema,
+        column_ids: &[ColumnId],
+        block: &DataBlock,
+    ) -> Result<Option<Self>> {
+        let mut columns = BTreeMap::new();
+        for (idx, field) in source_schema.fields().iter().enumerate() {
+            if !column_ids.contains(&field.column_id())
+                || !Self::supported_type(field.data_type())
+                || idx >= block.num_columns()
+            {
+                continue;
+            }
+
+            let entry = block.get_by_offset(idx);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+            let mut terms: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+            for row in 0..column.len() {
+                if let Some(ScalarRef::String(text)) = column.index(row) {
+                    for term in tokenize(text) {
+                        let rows = terms.entry(term).or_default();
+                        if rows.last() != Some(&(row as u32)) {
+                            rows.push(row as u32);
+                        }
+                    }
+                }
+            }
+            columns.insert(field.column_id(), terms);
+        }
+
+        if columns.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self { columns }))
+    }
+
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        serialize_into_buf(&mut buf, self)?;
+        Ok(buf)
+    }
+
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self> {
+        deserialize_from_slice(&mut bytes)
+    }
+
+    /// Apply the predicate expression, return `MustFalse` if we are sure that
+    /// no row of the block matches the expression.
+    ///
+    /// Each `match(col, 'query')` whose terms are not all in the same row of the
+    /// column is rewritten to `false`, then the expression is folded.
+    pub fn apply(
+        &self,
+        mut expr: Expr<String>,
+        source_schema: &TableSchema,
+        func_ctx: &FunctionContext,
+    ) -> Result<FilterEvalResult> {
+        visit_expr_column_match_constant(&mut expr, &mut |span, col_name, query, return_type| {
+            let column_id = source_schema.field_with_name(col_name)?.column_id();
+            let contains = match self.columns.get(&column_id) {
+                Some(terms) => any_row_contains(terms, query),
+                // The column is not indexed.
+                None => true,
+            };
+            if contains {
+                Ok(None)
+            } else {
+                Ok(Some(Expr::Constant {
+                    span,
+                    scalar: Scalar::Boolean(false),
+                    data_type: return_type.clone(),
+                }))
+            }
+        })?;
+
+        let (new_expr, _) = ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS);
+
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => Ok(FilterEvalResult::MustFalse),
+            _ => Ok(FilterEvalResult::Uncertain),
+        }
+    }
+
+    /// Find all columns that match the pattern of `match(col, <constant>)` in the expression.
+    pub fn find_match_columns(expr: &Expr<String>) -> Result<Vec<String>> {
+        let mut cols = Vec::new();
+        visit_expr_column_match_constant(&mut expr.clone(), &mut |_, col_name, _, _| {
+            if !cols.iter().any(|c| c == col_name) {
+                cols.push(col_name.to_string());
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+}
+
+// Intersect the rows of the terms of the query, false if no row contains all of them.
+fn any_row_contains(terms: &BTreeMap<String, Vec<u32>>, query: &[u8]) -> bool {
+    let mut rows: Option<Vec<u32>> = None;
+    for term in tokenize(query) {
+        let term_rows = match terms.get(&term) {
+            Some(term_rows) => term_rows,
+            None => return false,
+        };
+        let new_rows = match rows {
+            None => term_rows.clone(),
+            Some(rows) => intersect_sorted(&rows, term_rows),
+        };
+        if new_rows.is_empty() {
+            return false;
+        }
+        rows = Some(new_rows);
+    }
+    true
+}
+
+fn intersect_sorted(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(left.len().min(right.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        match left[i].cmp(&right[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                result.push(left[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    result
+}
+
+fn visit_expr_column_match_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[u8], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `match(Column, <constant>)`
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if function.signature.name == "match" {
+            if let [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(query),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                if let Some(new_expr) = visitor(*span, id, query, return_type)? {
+                    *expr = new_expr;
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_match_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_match_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
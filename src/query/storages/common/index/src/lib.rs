@@ -15,15 +15,20 @@
 #![allow(clippy::uninlined_format_args)]
 #![feature(box_patterns)]
 
+mod aggregating_index;
 mod bloom_index;
 pub mod filters;
 mod index;
+mod inverted_index;
 mod page_index;
 mod range_index;
 
+pub use aggregating_index::AggregatingIndex;
+pub use aggregating_index::AggregatingIndexFunction;
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
+pub use inverted_index::InvertedIndex;
 pub use page_index::PageIndex;
 pub use range_index::RangeIndex;
@@ -0,0 +1,97 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bumpalo::Bump;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::Int32Type;
+use common_expression::types::Int64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Scalar;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::AggregateMergeStateCombinator;
+use common_functions::aggregates::StateAddr;
+use storages_common_index::AggregatingIndex;
+use storages_common_index::AggregatingIndexFunction;
+
+#[test]
+fn test_aggregating_index() -> Result<()> {
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![1, 2, 1, 2, 3]),
+        Int64Type::from_data(vec![10, 20, 30, 40, 50]),
+    ]);
+
+    let factory = AggregateFunctionFactory::instance();
+    let sum = factory.get("sum", vec![], vec![DataType::Number(NumberDataType::Int64)])?;
+    let count = factory.get("count", vec![], vec![])?;
+    let index = AggregatingIndex::build(&block, &[0], &[
+        AggregatingIndexFunction {
+            func: sum.clone(),
+            args: vec![1],
+        },
+        AggregatingIndexFunction {
+            func: count.clone(),
+            args: vec![],
+        },
+    ])?;
+
+    // one row for each group, in the order of their first rows
+    assert_eq!(index.num_rows(), 3);
+    assert_eq!(index.num_columns(), 3);
+    let groups = index.get_by_offset(0).value.as_column().unwrap();
+    assert_eq!(groups, &Int32Type::from_data(vec![1, 2, 3]));
+
+    let sum_states = index.get_by_offset(1).value.as_column().unwrap();
+    let count_states = index.get_by_offset(2).value.as_column().unwrap();
+    for (row, expected) in [40i64, 60, 50].into_iter().enumerate() {
+        let state = sum_states.slice(row..row + 1);
+        assert_eq!(
+            merge_states(&sum, &[state])?,
+            Scalar::Number(NumberScalar::Int64(expected))
+        );
+    }
+    assert_eq!(
+        merge_states(&count, &[count_states.slice(0..1)])?,
+        Scalar::Number(NumberScalar::UInt64(2))
+    );
+
+    // states of all the groups are merged
+    assert_eq!(
+        merge_states(&sum, &[sum_states.clone()])?,
+        Scalar::Number(NumberScalar::Int64(150))
+    );
+    assert_eq!(
+        merge_states(&count, &[count_states.clone()])?,
+        Scalar::Number(NumberScalar::UInt64(5))
+    );
+    Ok(())
+}
+
+fn merge_states(func: &AggregateFunctionRef, states: &[Column]) -> Result<Scalar> {
+    let func = AggregateMergeStateCombinator::create(func.clone());
+    let arena = Bump::new();
+    let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+    func.init_state(place);
+    func.accumulate(place, states, None, states[0].len())?;
+
+    let mut builder = ColumnBuilder::with_capacity(&func.return_type()?, 1);
+    func.merge_result(place, &mut builder)?;
+    Ok(builder.build_scalar())
+}
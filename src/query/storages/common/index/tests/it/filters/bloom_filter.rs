@@ -111,6 +111,8 @@ fn test_bloom_filter() -> Result<()> {
         schema,
         LatestBloom::VERSION,
         &blocks_ref,
+        None,
+        &[],
     )?
     .unwrap();
 
@@ -193,6 +195,141 @@ fn test_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::String),
+        TableField::new("1", TableDataType::String),
+    ]));
+    let block = DataBlock::new_from_columns(vec![
+        StringType::from_data(vec!["Connection refused", "disk full"]),
+        StringType::from_data(vec!["Connection refused", "disk full"]),
+    ]);
+
+    // only the column "0" has ngram filter
+    let ngram_column_ids = vec![schema.field(0).column_id()];
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema.clone(),
+        LatestBloom::VERSION,
+        &[&block],
+        None,
+        &ngram_column_ids,
+    )?
+    .unwrap();
+
+    let like = |col_name: &str, pattern: &str| {
+        eval_ngram_index(&index, "like", &[
+            column_ref(col_name),
+            string_constant(pattern),
+        ])
+    };
+    assert_eq!(FilterEvalResult::Uncertain, like("0", "%refused%"));
+    assert_eq!(FilterEvalResult::Uncertain, like("0", "Conn%full"));
+    assert_eq!(FilterEvalResult::MustFalse, like("0", "%timeout%"));
+    assert_eq!(FilterEvalResult::MustFalse, like("0", "disk_f%timeout"));
+    // too short to have any ngram
+    assert_eq!(FilterEvalResult::Uncertain, like("0", "%ti_me%"));
+    // patterns with escape characters are not handled
+    assert_eq!(FilterEvalResult::Uncertain, like("0", "%time\\_out%"));
+    assert_eq!(FilterEvalResult::Uncertain, like("1", "%timeout%"));
+
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_ngram_index(&index, "contains", &[
+            column_ref("0"),
+            string_constant("refused")
+        ])
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_ngram_index(&index, "contains", &[
+            column_ref("0"),
+            string_constant("timeout")
+        ])
+    );
+
+    // `position('timeout' IN col) > 0`
+    let position = check_function(
+        None,
+        "position",
+        &[],
+        &[string_constant("timeout"), column_ref("0")],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_ngram_index(&index, "gt", &[position, Expr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::UInt8(0)),
+            data_type: DataType::Number(NumberDataType::UInt8),
+        }])
+    );
+
+    // no filter of the column values if none of the columns is chosen
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema.clone(),
+        LatestBloom::VERSION,
+        &[&block],
+        Some(&[]),
+        &ngram_column_ids,
+    )?
+    .unwrap();
+    assert_eq!(index.filter_schema.fields().len(), 1);
+    assert!(
+        BloomIndex::try_create(
+            FunctionContext::default(),
+            schema,
+            LatestBloom::VERSION,
+            &[&block],
+            Some(&[]),
+            &[],
+        )?
+        .is_none()
+    );
+    Ok(())
+}
+
+fn column_ref(col_name: &str) -> Expr<String> {
+    Expr::ColumnRef {
+        span: None,
+        id: col_name.to_string(),
+        data_type: DataType::String,
+        display_name: col_name.to_string(),
+    }
+}
+
+fn string_constant(value: &str) -> Expr<String> {
+    Expr::Constant {
+        span: None,
+        scalar: Scalar::String(value.as_bytes().to_vec()),
+        data_type: DataType::String,
+    }
+}
+
+fn eval_ngram_index(
+    index: &BloomIndex,
+    func_name: &str,
+    args: &[Expr<String>],
+) -> FilterEvalResult {
+    let expr = check_function(None, func_name, &[], args, &BUILTIN_FUNCTIONS).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, ngrams) in BloomIndex::find_ngram_columns(&expr).unwrap() {
+        for ngram in ngrams {
+            let scalar = Scalar::String(ngram);
+            let digest =
+                BloomIndex::calculate_scalar_digest(&func_ctx, &scalar, &DataType::String).unwrap();
+            scalar_map.insert(scalar, digest);
+        }
+    }
+
+    index.apply(expr, &scalar_map).unwrap()
+}
+
 fn eval_index(index: &BloomIndex, col_name: &str, val: Scalar, ty: DataType) -> FilterEvalResult {
     let expr = check_function(
         None,
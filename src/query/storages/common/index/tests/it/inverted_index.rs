@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::types::Int32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+
+#[test]
+fn test_inverted_index() -> Result<()> {
+    let schema = TableSchema::new(vec![
+        TableField::new("id", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("msg", TableDataType::String),
+        TableField::new("tag", TableDataType::String),
+    ]);
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec!["Connection refused", "disk full", "connection RESET"]),
+        StringType::from_data(vec!["a", "b", "c"]),
+    ]);
+
+    // only the `msg` column is indexed, `id` is not a string column
+    let msg_id = schema.field_with_name("msg")?.column_id();
+    let id_id = schema.field_with_name("id")?.column_id();
+    let index = InvertedIndex::try_create(&schema, &[id_id, msg_id], &block)?.unwrap();
+    assert_eq!(index.columns.len(), 1);
+    let terms = index.columns.get(&msg_id).unwrap();
+    assert_eq!(terms.get("connection"), Some(&vec![0, 2]));
+    assert_eq!(terms.get("full"), Some(&vec![1]));
+    assert!(terms.get("Connection").is_none());
+
+    let index = InvertedIndex::from_bytes(&index.to_bytes()?)?;
+    assert_eq!(index.columns.get(&msg_id), Some(terms));
+
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_index(&index, &schema, "msg", "connection refused")
+    );
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_index(&index, &schema, "msg", "DISK")
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_index(&index, &schema, "msg", "connection timeout")
+    );
+    // all the terms are in the block, but not in the same row
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_index(&index, &schema, "msg", "connection full")
+    );
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_index(&index, &schema, "msg", "reset connection")
+    );
+    // a query without terms matches all the rows
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_index(&index, &schema, "msg", "--")
+    );
+    // the `tag` column is not indexed
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_index(&index, &schema, "tag", "x")
+    );
+
+    assert!(InvertedIndex::try_create(&schema, &[id_id], &block)?.is_none());
+    Ok(())
+}
+
+fn eval_index(
+    index: &InvertedIndex,
+    schema: &TableSchema,
+    col_name: &str,
+    query: &str,
+) -> FilterEvalResult {
+    let expr = check_function(
+        None,
+        "match",
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: DataType::String,
+                display_name: col_name.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(query.as_bytes().to_vec()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    assert_eq!(InvertedIndex::find_match_columns(&expr).unwrap(), vec![
+        col_name.to_string()
+    ]);
+
+    index
+        .apply(expr, schema, &FunctionContext::default())
+        .unwrap()
+}
@@ -14,4 +14,6 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod aggregating_index;
 mod filters;
+mod inverted_index;
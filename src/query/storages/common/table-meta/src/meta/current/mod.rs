@@ -13,13 +13,14 @@
 //  limitations under the License.
 
 pub use v0::ColumnMeta as SingleColumnMeta;
-pub use v1::TableSnapshotStatistics;
 pub use v2::BlockMeta;
+pub use v2::ColumnHistogram;
 pub use v2::ColumnMeta;
+pub use v2::HistogramBucket;
 pub use v2::SegmentInfo;
 pub use v2::TableSnapshot;
 pub use v2::TableSnapshotLite;
+pub use v2::TableSnapshotStatistics;
 
 use super::v0;
-use super::v1;
 use super::v2;
@@ -20,7 +20,6 @@ use serde::Serialize;
 
 use crate::meta::statistics::FormatVersion;
 use crate::meta::SnapshotId;
-use crate::meta::Versioned;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TableSnapshotStatistics {
@@ -32,21 +31,3 @@ pub struct TableSnapshotStatistics {
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
 }
-
-impl TableSnapshotStatistics {
-    pub fn new(column_distinct_values: HashMap<ColumnId, u64>) -> Self {
-        Self {
-            format_version: TableSnapshotStatistics::VERSION,
-            snapshot_id: SnapshotId::new_v4(),
-            column_distinct_values,
-        }
-    }
-
-    pub fn format_version(&self) -> u64 {
-        self.format_version
-    }
-
-    pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
-        &self.column_distinct_values
-    }
-}
@@ -14,9 +14,13 @@
 
 mod segment;
 mod snapshot;
+mod table_snapshot_statistics;
 
 pub use segment::BlockMeta;
 pub use segment::ColumnMeta;
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
 pub use snapshot::TableSnapshotLite;
+pub use table_snapshot_statistics::ColumnHistogram;
+pub use table_snapshot_statistics::HistogramBucket;
+pub use table_snapshot_statistics::TableSnapshotStatistics;
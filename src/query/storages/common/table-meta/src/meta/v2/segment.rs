@@ -67,6 +67,11 @@ pub struct BlockMeta {
     #[serde(default)]
     pub bloom_filter_index_size: u64,
     pub compression: Compression,
+    /// location of inverted index, only exists if the table has inverted indexed columns
+    #[serde(default)]
+    pub inverted_index_location: Option<Location>,
+    #[serde(default)]
+    pub inverted_index_size: u64,
 }
 
 impl BlockMeta {
@@ -82,6 +87,8 @@ impl BlockMeta {
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
         compression: Compression,
+        inverted_index_location: Option<Location>,
+        inverted_index_size: u64,
     ) -> Self {
         Self {
             row_count,
@@ -94,6 +101,8 @@ impl BlockMeta {
             bloom_filter_index_location,
             bloom_filter_index_size,
             compression,
+            inverted_index_location,
+            inverted_index_size,
         }
     }
 
@@ -234,6 +243,8 @@ impl BlockMeta {
             bloom_filter_index_location: None,
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
+            inverted_index_location: None,
+            inverted_index_size: 0,
         }
     }
 
@@ -264,6 +275,8 @@ impl BlockMeta {
             bloom_filter_index_location: s.bloom_filter_index_location.clone(),
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
+            inverted_index_location: None,
+            inverted_index_size: 0,
         }
     }
 }
@@ -0,0 +1,98 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+
+use common_expression::ColumnId;
+use common_expression::Scalar;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::meta::statistics::FormatVersion;
+use crate::meta::v1;
+use crate::meta::SnapshotId;
+use crate::meta::Versioned;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableSnapshotStatistics {
+    /// format version of snapshot
+    format_version: FormatVersion,
+
+    /// id of snapshot
+    pub snapshot_id: SnapshotId,
+
+    pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// Equi-depth histograms of the columns, built from sampled blocks
+    pub histograms: HashMap<ColumnId, ColumnHistogram>,
+}
+
+/// Equi-depth histogram of the non-null values of a column.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ColumnHistogram {
+    /// Buckets ordered by their upper bounds, the lower bound of the
+    /// first bucket is the min value of the column.
+    pub buckets: Vec<HistogramBucket>,
+
+    /// The most common values and their estimated number of rows,
+    /// ordered by the number of rows descending.
+    pub most_common_values: Vec<(Scalar, u64)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    /// Upper bound value of the bucket, inclusive.
+    pub upper_bound: Scalar,
+    /// Estimated number of values in the bucket.
+    pub num_values: f64,
+    /// Estimated number of distinct values in the bucket.
+    pub num_distinct: f64,
+}
+
+impl TableSnapshotStatistics {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        histograms: HashMap<ColumnId, ColumnHistogram>,
+    ) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: SnapshotId::new_v4(),
+            column_distinct_values,
+            histograms,
+        }
+    }
+
+    pub fn format_version(&self) -> u64 {
+        self.format_version
+    }
+
+    pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
+        &self.column_distinct_values
+    }
+
+    pub fn get_histograms(&self) -> &HashMap<ColumnId, ColumnHistogram> {
+        &self.histograms
+    }
+}
+
+impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
+    fn from(s: v1::TableSnapshotStatistics) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: s.snapshot_id,
+            column_distinct_values: s.column_distinct_values,
+            histograms: HashMap::new(),
+        }
+    }
+}
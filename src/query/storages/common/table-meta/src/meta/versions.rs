@@ -75,17 +75,20 @@ impl SnapshotVersion {
 }
 
 impl Versioned<0> for v1::TableSnapshotStatistics {}
+impl Versioned<1> for v2::TableSnapshotStatistics {}
 
 impl Versioned<2> for DataBlock {}
 
 pub enum TableSnapshotStatisticsVersion {
     V0(PhantomData<v1::TableSnapshotStatistics>),
+    V1(PhantomData<v2::TableSnapshotStatistics>),
 }
 
 impl TableSnapshotStatisticsVersion {
     pub fn version(&self) -> u64 {
         match self {
             TableSnapshotStatisticsVersion::V0(a) => Self::ver(a),
+            TableSnapshotStatisticsVersion::V1(a) => Self::ver(a),
         }
     }
 
@@ -144,8 +147,11 @@ mod converters {
                 0 => Ok(TableSnapshotStatisticsVersion::V0(testify_version::<_, 0>(
                     PhantomData,
                 ))),
+                1 => Ok(TableSnapshotStatisticsVersion::V1(testify_version::<_, 1>(
+                    PhantomData,
+                ))),
                 _ => Err(ErrorCode::Internal(format!(
-                    "unknown table snapshot statistics version {value}, versions supported: 0"
+                    "unknown table snapshot statistics version {value}, versions supported: 0, 1"
                 ))),
             }
         }
@@ -0,0 +1,57 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_exception::ErrorCode;
+
+/// The snapshot locations shared between cloned tables, keyed by the table ids.
+///
+/// It is stored in the table options as `<table_id>=<snapshot_location>,...`:
+/// - `cloned_from` of a clone, the snapshots of the tables it is cloned from
+/// - `clone_pinned_snapshots` of a table, its snapshots that are shared with the clones
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CloneSnapshots {
+    pub snapshots: BTreeMap<u64, String>,
+}
+
+impl TryFrom<&str> for CloneSnapshots {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut snapshots = BTreeMap::new();
+        for item in value.split(',').filter(|s| !s.is_empty()) {
+            let (table_id, location) = item
+                .split_once('=')
+                .ok_or_else(|| ErrorCode::Internal(format!("invalid cloned snapshot: {}", item)))?;
+            let table_id = table_id.parse::<u64>().map_err(|_| {
+                ErrorCode::Internal(format!("invalid table id of cloned snapshot: {}", table_id))
+            })?;
+            snapshots.insert(table_id, location.to_string());
+        }
+        Ok(CloneSnapshots { snapshots })
+    }
+}
+
+impl Display for CloneSnapshots {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let snapshots = self
+            .snapshots
+            .iter()
+            .map(|(table_id, location)| format!("{}={}", table_id, location))
+            .collect::<Vec<_>>();
+        write!(f, "{}", snapshots.join(","))
+    }
+}
@@ -0,0 +1,65 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_exception::ErrorCode;
+
+/// The snapshot locations of the source tables of a materialized view, keyed
+/// by the table ids, as of the last refresh of the view.
+///
+/// It is stored in the table option `materialized_view_sources` of the view as
+/// `<table_id>=<snapshot_location>,...`, the location is empty if the source
+/// table had no snapshot.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MaterializedViewSources {
+    pub snapshots: BTreeMap<u64, Option<String>>,
+}
+
+impl TryFrom<&str> for MaterializedViewSources {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut snapshots = BTreeMap::new();
+        for source in value.split(',').filter(|s| !s.is_empty()) {
+            let (table_id, location) = source.split_once('=').ok_or_else(|| {
+                ErrorCode::Internal(format!("invalid source of materialized view: {}", source))
+            })?;
+            let table_id = table_id.parse::<u64>().map_err(|_| {
+                ErrorCode::Internal(format!(
+                    "invalid source table id of materialized view: {}",
+                    table_id
+                ))
+            })?;
+            let location = (!location.is_empty()).then(|| location.to_string());
+            snapshots.insert(table_id, location);
+        }
+        Ok(MaterializedViewSources { snapshots })
+    }
+}
+
+impl Display for MaterializedViewSources {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sources = self
+            .snapshots
+            .iter()
+            .map(|(table_id, location)| {
+                format!("{}={}", table_id, location.as_deref().unwrap_or_default())
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", sources.join(","))
+    }
+}
@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod clone;
+mod materialized_view;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
 
+pub use clone::CloneSnapshots;
+pub use materialized_view::MaterializedViewSources;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
@@ -21,6 +21,28 @@ pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 pub const OPT_KEY_STORAGE_FORMAT: &str = "storage_format";
 pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 
+/// The defining query of a materialized view, which is stored in a fuse table.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+/// The snapshot locations of the source tables, as of the last refresh of a materialized view.
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCES: &str = "materialized_view_sources";
+/// The time of the last refresh of a materialized view.
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON: &str = "materialized_view_refreshed_on";
+/// The snapshot locations of the tables that this table is cloned from, which share
+/// their data files with this table.
+pub const OPT_KEY_CLONED_FROM: &str = "cloned_from";
+/// The snapshot locations of this table, which are shared with the tables cloned from it.
+pub const OPT_KEY_CLONE_PINNED_SNAPSHOTS: &str = "clone_pinned_snapshots";
+/// The source table of a stream.
+pub const OPT_KEY_STREAM_SOURCE_DATABASE: &str = "source_database";
+pub const OPT_KEY_STREAM_SOURCE_TABLE: &str = "source_table";
+pub const OPT_KEY_STREAM_SOURCE_TABLE_ID: &str = "source_table_id";
+/// The snapshot location of the source table that a stream has been consumed to, the
+/// changes made since then are read from the stream.
+pub const OPT_KEY_STREAM_OFFSET: &str = "stream_offset";
+/// Only the segments appended since this snapshot are read, set by the table navigated
+/// to the appended data, never persisted.
+pub const OPT_KEY_BASE_SNAPSHOT_LOCATION: &str = "base_snapshot_location";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -38,6 +60,16 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCES);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON);
+    r.insert(OPT_KEY_BASE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_CLONE_PINNED_SNAPSHOTS);
+    r.insert(OPT_KEY_STREAM_SOURCE_DATABASE);
+    r.insert(OPT_KEY_STREAM_SOURCE_TABLE);
+    r.insert(OPT_KEY_STREAM_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_STREAM_OFFSET);
     r
 });
 
@@ -46,6 +78,16 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCES);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON);
+    r.insert(OPT_KEY_BASE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_CLONE_PINNED_SNAPSHOTS);
+    r.insert(OPT_KEY_STREAM_SOURCE_DATABASE);
+    r.insert(OPT_KEY_STREAM_SOURCE_TABLE);
+    r.insert(OPT_KEY_STREAM_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_STREAM_OFFSET);
     r
 });
 
@@ -0,0 +1,255 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::io::Read;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+use roaring::RoaringTreemap;
+
+use crate::delta_log::DeletionVectorDescriptor;
+
+const Z85_ALPHABET: &[u8] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// The magic number at the beginning of a serialized deletion vector.
+const DELETION_VECTOR_MAGIC: u32 = 1681511377;
+
+/// Load the indexes of the rows deleted by the deletion vector.
+#[async_backtrace::framed]
+pub async fn load_deletion_vector(
+    operator: &Operator,
+    dv: &DeletionVectorDescriptor,
+) -> Result<RoaringTreemap> {
+    let data = match dv.storage_type.as_str() {
+        "u" => {
+            let path = relative_path(&dv.path_or_inline_dv)?;
+            let offset = dv.offset.unwrap_or(0) as u64;
+            // the data is prefixed with its size in 4 bytes
+            let data = operator
+                .range_read(&path, offset..offset + 4 + dv.size_in_bytes as u64)
+                .await?;
+            if data.len() < 4 {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid deletion vector in '{}'",
+                    path
+                )));
+            }
+            data[4..].to_vec()
+        }
+        "i" => {
+            let mut data = z85_decode(&dv.path_or_inline_dv)?;
+            data.truncate(dv.size_in_bytes as usize);
+            data
+        }
+        storage_type => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "deletion vectors of storage type '{}' are not supported",
+                storage_type
+            )));
+        }
+    };
+    deserialize_deletion_vector(&data)
+}
+
+// `<random prefix><z85 encoded uuid>` to `<random prefix>/deletion_vector_<uuid>.bin`
+fn relative_path(path_or_inline_dv: &str) -> Result<String> {
+    if path_or_inline_dv.len() < 20
+        || !path_or_inline_dv.is_char_boundary(path_or_inline_dv.len() - 20)
+    {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid deletion vector path '{}'",
+            path_or_inline_dv
+        )));
+    }
+    let (prefix, encoded) = path_or_inline_dv.split_at(path_or_inline_dv.len() - 20);
+    let uuid = z85_decode(encoded)?;
+    let uuid = format!(
+        "{}-{}-{}-{}-{}",
+        hex(&uuid[0..4]),
+        hex(&uuid[4..6]),
+        hex(&uuid[6..8]),
+        hex(&uuid[8..10]),
+        hex(&uuid[10..16])
+    );
+    if prefix.is_empty() {
+        Ok(format!("deletion_vector_{}.bin", uuid))
+    } else {
+        Ok(format!("{}/deletion_vector_{}.bin", prefix, uuid))
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decode the z85 encoded string, every 5 characters are decoded to 4 bytes.
+fn z85_decode(encoded: &str) -> Result<Vec<u8>> {
+    let invalid = || ErrorCode::BadBytes(format!("invalid z85 encoded string '{}'", encoded));
+    if encoded.len() % 5 != 0 {
+        return Err(invalid());
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.as_bytes().chunks(5) {
+        let mut value: u64 = 0;
+        for c in chunk {
+            let digit = Z85_ALPHABET
+                .iter()
+                .position(|a| a == c)
+                .ok_or_else(invalid)?;
+            value = value * 85 + digit as u64;
+        }
+        if value > u32::MAX as u64 {
+            return Err(invalid());
+        }
+        decoded.extend_from_slice(&(value as u32).to_be_bytes());
+    }
+    Ok(decoded)
+}
+
+// the magic number, followed by the bitmaps of the 64 bits row indexes, in the
+// portable format of the roaring bitmaps
+fn deserialize_deletion_vector(data: &[u8]) -> Result<RoaringTreemap> {
+    let invalid =
+        |e: std::io::Error| ErrorCode::BadBytes(format!("invalid deletion vector: {}", e));
+    let mut reader = Cursor::new(data);
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic).map_err(invalid)?;
+    if u32::from_le_bytes(magic) != DELETION_VECTOR_MAGIC {
+        return Err(ErrorCode::BadBytes(
+            "invalid magic number of deletion vector",
+        ));
+    }
+    let mut num_bitmaps = [0u8; 8];
+    reader.read_exact(&mut num_bitmaps).map_err(invalid)?;
+    let num_bitmaps = u64::from_le_bytes(num_bitmaps);
+
+    let mut bitmaps = Vec::with_capacity(num_bitmaps.min(1024) as usize);
+    for _ in 0..num_bitmaps {
+        let mut key = [0u8; 4];
+        reader.read_exact(&mut key).map_err(invalid)?;
+        let bitmap = RoaringBitmap::deserialize_from(&mut reader).map_err(invalid)?;
+        bitmaps.push((u32::from_le_bytes(key), bitmap));
+    }
+    Ok(RoaringTreemap::from_bitmaps(bitmaps))
+}
+
+#[cfg(test)]
+mod tests {
+    use common_exception::Result;
+    use roaring::RoaringTreemap;
+
+    use super::*;
+
+    fn z85_encode(data: &[u8]) -> String {
+        data.chunks(4)
+            .flat_map(|chunk| {
+                let mut value = u32::from_be_bytes(chunk.try_into().unwrap());
+                let mut encoded = [0u8; 5];
+                for c in encoded.iter_mut().rev() {
+                    *c = Z85_ALPHABET[(value % 85) as usize];
+                    value /= 85;
+                }
+                encoded
+            })
+            .map(char::from)
+            .collect()
+    }
+
+    fn serialize_deletion_vector(bitmap: &RoaringTreemap) -> Vec<u8> {
+        let mut data = DELETION_VECTOR_MAGIC.to_le_bytes().to_vec();
+        bitmap.serialize_into(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_z85_decode() -> Result<()> {
+        // the test vector of the z85 spec
+        assert_eq!(z85_decode("HelloWorld")?, vec![
+            0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B
+        ]);
+        assert_eq!(z85_decode("")?, Vec::<u8>::new());
+        assert_eq!(z85_decode("%nSc0")?, u32::MAX.to_be_bytes().to_vec());
+
+        // not a multiple of 5 characters
+        assert!(z85_decode("Hello1").is_err());
+        // not in the alphabet
+        assert!(z85_decode("Hell,").is_err());
+        // larger than u32::MAX
+        assert!(z85_decode("#####").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_relative_path() -> Result<()> {
+        let uuid = [
+            0x3c, 0x5a, 0x1f, 0x7e, 0x92, 0x4b, 0x4d, 0x0a, 0xb1, 0x6e, 0x2f, 0x8d, 0x4c, 0x9a,
+            0x05, 0x71,
+        ];
+        let encoded = z85_encode(&uuid);
+        assert_eq!(encoded.len(), 20);
+
+        assert_eq!(
+            relative_path(&encoded)?,
+            "deletion_vector_3c5a1f7e-924b-4d0a-b16e-2f8d4c9a0571.bin"
+        );
+        assert_eq!(
+            relative_path(&format!("ab{}", encoded))?,
+            "ab/deletion_vector_3c5a1f7e-924b-4d0a-b16e-2f8d4c9a0571.bin"
+        );
+
+        assert!(relative_path("too short").is_err());
+        assert!(relative_path("ab,,,,,,,,,,,,,,,,,,,,").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_deletion_vector() -> Result<()> {
+        let bitmap = [1, 3, (1 << 32) + 5]
+            .into_iter()
+            .collect::<RoaringTreemap>();
+        let data = serialize_deletion_vector(&bitmap);
+        assert_eq!(deserialize_deletion_vector(&data)?, bitmap);
+
+        let empty = RoaringTreemap::new();
+        let data = serialize_deletion_vector(&empty);
+        assert_eq!(deserialize_deletion_vector(&data)?, empty);
+
+        // the bad magic number
+        let mut data = serialize_deletion_vector(&bitmap);
+        data[0] ^= 0xFF;
+        assert!(deserialize_deletion_vector(&data).is_err());
+
+        // truncated
+        let data = serialize_deletion_vector(&bitmap);
+        assert!(deserialize_deletion_vector(&data[..data.len() - 1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_inline_deletion_vector() -> Result<()> {
+        let bitmap = [0, 7].into_iter().collect::<RoaringTreemap>();
+        let data = serialize_deletion_vector(&bitmap);
+        // the inline data is padded to a multiple of 4 bytes to be z85 encoded
+        let mut padded = data.clone();
+        padded.resize((data.len() + 3) / 4 * 4, 0);
+        let mut decoded = z85_decode(&z85_encode(&padded))?;
+        decoded.truncate(data.len());
+        assert_eq!(deserialize_deletion_vector(&decoded)?, bitmap);
+        Ok(())
+    }
+}
@@ -0,0 +1,679 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BooleanArray;
+use common_arrow::arrow::array::ListArray;
+use common_arrow::arrow::array::MapArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read as pread;
+use common_arrow::parquet::read::read_metadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use opendal::Operator;
+use serde_json::Map as JsonMap;
+use serde_json::Value as JsonValue;
+
+/// The directory of the transaction log, relative to the root of the table.
+pub const DELTA_LOG_DIR: &str = "_delta_log/";
+
+/// The max reader version of the protocol we can read.
+const MAX_READER_VERSION: i32 = 3;
+
+/// The reader features we can read, of the tables with reader version 3.
+const SUPPORTED_READER_FEATURES: &[&str] =
+    &["deletionVectors", "timestampNtz", "vacuumProtocolCheck"];
+
+/// Describes a deletion vector, which marks the rows deleted from a data file.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionVectorDescriptor {
+    /// `u` for a relative path, `i` for inline and `p` for an absolute path.
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    pub fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{}", self.storage_type, self.path_or_inline_dv, offset),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+}
+
+/// A data file added to the table.
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFile {
+    /// The path of the file relative to the root of the table, url encoded.
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: HashMap<String, Option<String>>,
+    pub size: i64,
+    /// The statistics of the file in json, e.g. `{"numRecords":1,"minValues":{..}}`.
+    #[serde(default)]
+    pub stats: Option<String>,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+/// A data file removed from the table.
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveFile {
+    pub path: String,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaMetadata {
+    /// The schema of the table in json.
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: HashMap<String, Option<String>>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaProtocol {
+    pub min_reader_version: i32,
+    #[serde(default)]
+    pub reader_features: Option<Vec<String>>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct CommitInfo {
+    #[serde(default)]
+    timestamp: Option<i64>,
+}
+
+/// An action of a commit, only one of the fields is set.
+#[derive(serde::Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    #[serde(default)]
+    add: Option<AddFile>,
+    #[serde(default)]
+    remove: Option<RemoveFile>,
+    #[serde(default)]
+    meta_data: Option<DeltaMetadata>,
+    #[serde(default)]
+    protocol: Option<DeltaProtocol>,
+    #[serde(default)]
+    commit_info: Option<CommitInfo>,
+}
+
+/// The state of the table at a version.
+#[derive(Clone, Debug)]
+pub struct DeltaSnapshot {
+    pub version: i64,
+    pub metadata: DeltaMetadata,
+    /// The active data files of the table.
+    pub files: Vec<AddFile>,
+}
+
+/// The files of the transaction log.
+#[derive(Default)]
+struct LogFiles {
+    commits: BTreeMap<i64, String>,
+    // version -> (number of parts, the files of the parts)
+    checkpoints: BTreeMap<i64, (u32, Vec<String>)>,
+}
+
+impl LogFiles {
+    fn latest_version(&self) -> Option<i64> {
+        let commit = self.commits.keys().next_back().copied();
+        let checkpoint = self.checkpoints.keys().next_back().copied();
+        commit.max(checkpoint)
+    }
+
+    // the latest complete checkpoint no later than the version
+    fn checkpoint_before(&self, version: i64) -> Option<(i64, Vec<String>)> {
+        self.checkpoints
+            .range(..=version)
+            .rev()
+            .find(|(_, (parts, files))| files.len() == *parts as usize)
+            .map(|(v, (_, files))| (*v, files.clone()))
+    }
+}
+
+/// Reads the `_delta_log` of a table, the state of the table at a version is replayed
+/// from the latest checkpoint before the version and the json commits after it.
+pub struct DeltaLog {
+    operator: Operator,
+}
+
+impl DeltaLog {
+    pub fn create(operator: Operator) -> Self {
+        Self { operator }
+    }
+
+    /// Load the snapshot of the table at the version, or the latest version if `None`.
+    #[async_backtrace::framed]
+    pub async fn snapshot(&self, version: Option<i64>) -> Result<DeltaSnapshot> {
+        let log_files = self.list_log_files().await?;
+        let latest = log_files.latest_version().ok_or_else(|| {
+            ErrorCode::TableInfoError(format!(
+                "no delta transaction log is found in {}",
+                DELTA_LOG_DIR
+            ))
+        })?;
+        let version = version.unwrap_or(latest);
+        if version < 0 || version > latest {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "version {} of the delta table is not found, the latest version is {}",
+                version, latest
+            )));
+        }
+
+        let mut files: HashMap<(String, Option<String>), AddFile> = HashMap::new();
+        let mut metadata = None;
+        let mut protocol = None;
+        let mut apply = |action: Action| {
+            if let Some(add) = action.add {
+                let key = (
+                    add.path.clone(),
+                    add.deletion_vector.as_ref().map(|dv| dv.unique_id()),
+                );
+                files.insert(key, add);
+            }
+            if let Some(remove) = action.remove {
+                let key = (
+                    remove.path,
+                    remove.deletion_vector.as_ref().map(|dv| dv.unique_id()),
+                );
+                files.remove(&key);
+            }
+            if action.meta_data.is_some() {
+                metadata = action.meta_data;
+            }
+            if action.protocol.is_some() {
+                protocol = action.protocol;
+            }
+        };
+
+        let start = match log_files.checkpoint_before(version) {
+            Some((checkpoint, parts)) => {
+                for part in parts {
+                    for action in self.read_checkpoint(&part).await? {
+                        apply(action);
+                    }
+                }
+                checkpoint + 1
+            }
+            None => 0,
+        };
+        for v in start..=version {
+            let commit = log_files.commits.get(&v).ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "the commit of version {} is missing in the delta transaction log",
+                    v
+                ))
+            })?;
+            for action in self.read_commit(commit).await? {
+                apply(action);
+            }
+        }
+
+        let protocol = protocol.ok_or_else(|| {
+            ErrorCode::TableInfoError("the protocol of the delta table is not found")
+        })?;
+        Self::check_protocol(&protocol)?;
+        let metadata = metadata.ok_or_else(|| {
+            ErrorCode::TableInfoError("the metadata of the delta table is not found")
+        })?;
+        if let Some(Some(mode)) = metadata.configuration.get("delta.columnMapping.mode") {
+            if mode != "none" {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "delta tables with column mapping mode '{}' are not supported",
+                    mode
+                )));
+            }
+        }
+
+        let mut files = files.into_values().collect::<Vec<_>>();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(DeltaSnapshot {
+            version,
+            metadata,
+            files,
+        })
+    }
+
+    /// The latest version committed no later than the timestamp in milliseconds.
+    ///
+    /// The timestamp of a version is the one in its `commitInfo`, or the last modified
+    /// time of the commit file.
+    #[async_backtrace::framed]
+    pub async fn version_at(&self, timestamp: i64) -> Result<i64> {
+        let log_files = self.list_log_files().await?;
+        let versions = log_files.commits.keys().copied().collect::<Vec<_>>();
+
+        // the commit timestamps are increasing with the versions
+        let (mut lo, mut hi) = (0, versions.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let commit = &log_files.commits[&versions[mid]];
+            if self.commit_timestamp(commit).await? <= timestamp {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        if lo == 0 {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "no version of the delta table is committed before timestamp {}",
+                timestamp
+            )));
+        }
+        Ok(versions[lo - 1])
+    }
+
+    fn check_protocol(protocol: &DeltaProtocol) -> Result<()> {
+        if protocol.min_reader_version > MAX_READER_VERSION {
+            return Err(ErrorCode::Unimplemented(format!(
+                "delta reader version {} is not supported",
+                protocol.min_reader_version
+            )));
+        }
+        if let Some(features) = &protocol.reader_features {
+            for feature in features {
+                if !SUPPORTED_READER_FEATURES.contains(&feature.as_str()) {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "delta reader feature '{}' is not supported",
+                        feature
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn list_log_files(&self) -> Result<LogFiles> {
+        let mut log_files = LogFiles::default();
+        let mut lister = self.operator.list(DELTA_LOG_DIR).await?;
+        while let Some(entry) = lister.try_next().await? {
+            let name = entry.name();
+            let path = format!("{}{}", DELTA_LOG_DIR, name);
+            // e.g. 00000000000000000010.json
+            // e.g. 00000000000000000010.checkpoint.parquet
+            // e.g. 00000000000000000010.checkpoint.0000000001.0000000002.parquet
+            let parts = name.split('.').collect::<Vec<_>>();
+            let version = match parts.first().and_then(|v| parse_number(v, 20)) {
+                Some(version) => version as i64,
+                None => continue,
+            };
+            match &parts[1..] {
+                ["json"] => {
+                    log_files.commits.insert(version, path);
+                }
+                ["checkpoint", "parquet"] => {
+                    log_files.checkpoints.insert(version, (1, vec![path]));
+                }
+                ["checkpoint", part, num_parts, "parquet"] => {
+                    if let (Some(_), Some(num_parts)) =
+                        (parse_number(part, 10), parse_number(num_parts, 10))
+                    {
+                        let checkpoint = log_files
+                            .checkpoints
+                            .entry(version)
+                            .or_insert_with(|| (num_parts as u32, vec![]));
+                        checkpoint.1.push(path);
+                        checkpoint.1.sort();
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(log_files)
+    }
+
+    #[async_backtrace::framed]
+    async fn read_commit(&self, path: &str) -> Result<Vec<Action>> {
+        let data = self.operator.read(path).await?;
+        let content = String::from_utf8(data).map_err(|e| {
+            ErrorCode::BadBytes(format!("invalid delta commit file '{}': {}", path, e))
+        })?;
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str::<Action>(line).map_err(|e| {
+                    ErrorCode::BadBytes(format!("invalid action in delta commit '{}': {}", path, e))
+                })
+            })
+            .collect()
+    }
+
+    #[async_backtrace::framed]
+    async fn commit_timestamp(&self, path: &str) -> Result<i64> {
+        for action in self.read_commit(path).await? {
+            if let Some(CommitInfo {
+                timestamp: Some(timestamp),
+            }) = action.commit_info
+            {
+                return Ok(timestamp);
+            }
+        }
+        let meta = self.operator.stat(path).await?;
+        meta.last_modified()
+            .map(|t| t.timestamp_millis())
+            .ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "the timestamp of delta commit '{}' is unknown",
+                    path
+                ))
+            })
+    }
+
+    /// Read the actions of a checkpoint, the rows of the checkpoint are converted to json,
+    /// to be deserialized the same as the actions of the commits.
+    #[async_backtrace::framed]
+    async fn read_checkpoint(&self, path: &str) -> Result<Vec<Action>> {
+        let data = self.operator.read(path).await?;
+        let mut reader = Cursor::new(data);
+        let meta = read_metadata(&mut reader)?;
+        let schema = pread::infer_schema(&meta)?;
+        let fields = schema
+            .fields
+            .into_iter()
+            .filter(|f| matches!(f.name.as_str(), "add" | "remove" | "metaData" | "protocol"))
+            .collect::<Vec<_>>();
+        let names = fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+
+        let mut actions = vec![];
+        let chunks = pread::FileReader::new(
+            reader,
+            meta.row_groups,
+            ArrowSchema::from(fields),
+            None,
+            None,
+            None,
+        );
+        for chunk in chunks {
+            let chunk = chunk?;
+            for row in 0..chunk.len() {
+                let mut object = JsonMap::new();
+                for (name, array) in names.iter().zip(chunk.arrays()) {
+                    if !array.is_null(row) {
+                        object.insert(name.clone(), array_value_to_json(array.as_ref(), row));
+                    }
+                }
+                if object.is_empty() {
+                    continue;
+                }
+                let action =
+                    serde_json::from_value::<Action>(JsonValue::Object(object)).map_err(|e| {
+                        ErrorCode::BadBytes(format!(
+                            "invalid action in delta checkpoint '{}': {}",
+                            path, e
+                        ))
+                    })?;
+                actions.push(action);
+            }
+        }
+        Ok(actions)
+    }
+}
+
+// parse the zero padded number with the given width.
+fn parse_number(s: &str, width: usize) -> Option<u64> {
+    if s.len() == width && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Convert a value of the checkpoint to json, the types not used by the actions are
+/// converted to null.
+fn array_value_to_json(array: &dyn Array, row: usize) -> JsonValue {
+    if array.is_null(row) {
+        return JsonValue::Null;
+    }
+    let any = array.as_any();
+    match array.data_type().to_logical_type() {
+        ArrowDataType::Boolean => {
+            JsonValue::from(any.downcast_ref::<BooleanArray>().unwrap().value(row))
+        }
+        ArrowDataType::Int8 => {
+            JsonValue::from(any.downcast_ref::<PrimitiveArray<i8>>().unwrap().value(row))
+        }
+        ArrowDataType::Int16 => JsonValue::from(
+            any.downcast_ref::<PrimitiveArray<i16>>()
+                .unwrap()
+                .value(row),
+        ),
+        ArrowDataType::Int32 => JsonValue::from(
+            any.downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap()
+                .value(row),
+        ),
+        ArrowDataType::Int64 => JsonValue::from(
+            any.downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap()
+                .value(row),
+        ),
+        ArrowDataType::Utf8 => {
+            JsonValue::from(any.downcast_ref::<Utf8Array<i32>>().unwrap().value(row))
+        }
+        ArrowDataType::LargeUtf8 => {
+            JsonValue::from(any.downcast_ref::<Utf8Array<i64>>().unwrap().value(row))
+        }
+        ArrowDataType::Struct(fields) => {
+            let array = any.downcast_ref::<StructArray>().unwrap();
+            let object = fields
+                .iter()
+                .zip(array.values())
+                .map(|(field, values)| {
+                    (
+                        field.name.clone(),
+                        array_value_to_json(values.as_ref(), row),
+                    )
+                })
+                .collect::<JsonMap<_, _>>();
+            JsonValue::Object(object)
+        }
+        ArrowDataType::Map(_, _) => {
+            // the entries of a map are the struct of the key and the value
+            let entries = any.downcast_ref::<MapArray>().unwrap().value(row);
+            let entries = entries.as_any().downcast_ref::<StructArray>().unwrap();
+            let (keys, values) = (&entries.values()[0], &entries.values()[1]);
+            let object = (0..entries.len())
+                .filter_map(|i| match array_value_to_json(keys.as_ref(), i) {
+                    JsonValue::String(key) => Some((key, array_value_to_json(values.as_ref(), i))),
+                    _ => None,
+                })
+                .collect::<JsonMap<_, _>>();
+            JsonValue::Object(object)
+        }
+        ArrowDataType::List(_) => {
+            let values = any.downcast_ref::<ListArray<i32>>().unwrap().value(row);
+            JsonValue::Array(
+                (0..values.len())
+                    .map(|i| array_value_to_json(values.as_ref(), i))
+                    .collect(),
+            )
+        }
+        ArrowDataType::LargeList(_) => {
+            let values = any.downcast_ref::<ListArray<i64>>().unwrap().value(row);
+            JsonValue::Array(
+                (0..values.len())
+                    .map(|i| array_value_to_json(values.as_ref(), i))
+                    .collect(),
+            )
+        }
+        _ => JsonValue::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_base::base::tokio;
+    use common_exception::Result;
+    use opendal::services::Memory;
+    use opendal::Operator;
+    use serde_json::json;
+
+    use super::*;
+
+    fn commit(actions: &[JsonValue]) -> Vec<u8> {
+        actions
+            .iter()
+            .map(|action| format!("{}\n", action))
+            .collect::<String>()
+            .into_bytes()
+    }
+
+    fn add(path: &str) -> JsonValue {
+        json!({"add": {"path": path, "partitionValues": {}, "size": 1, "dataChange": true}})
+    }
+
+    fn remove(path: &str) -> JsonValue {
+        json!({"remove": {"path": path, "dataChange": true}})
+    }
+
+    fn commit_info(timestamp: i64) -> JsonValue {
+        json!({"commitInfo": {"timestamp": timestamp}})
+    }
+
+    async fn create_log(files: &[(&str, Vec<u8>)]) -> Result<DeltaLog> {
+        let operator = Operator::new(Memory::default())?.finish();
+        for (name, content) in files {
+            operator
+                .write(&format!("{}{}", DELTA_LOG_DIR, name), content.clone())
+                .await?;
+        }
+        Ok(DeltaLog::create(operator))
+    }
+
+    #[tokio::test]
+    async fn test_list_log_files() -> Result<()> {
+        let log = create_log(&[
+            ("00000000000000000000.json", vec![]),
+            ("00000000000000000001.json", vec![]),
+            ("00000000000000000003.json", vec![]),
+            // the complete checkpoint of 2 parts at version 2
+            (
+                "00000000000000000002.checkpoint.0000000002.0000000002.parquet",
+                vec![],
+            ),
+            (
+                "00000000000000000002.checkpoint.0000000001.0000000002.parquet",
+                vec![],
+            ),
+            // the incomplete checkpoint at version 4, whose part 2 is not written yet
+            (
+                "00000000000000000004.checkpoint.0000000001.0000000002.parquet",
+                vec![],
+            ),
+            ("00000000000000000001.checkpoint.parquet", vec![]),
+            ("_last_checkpoint", vec![]),
+            ("00000000000000000003.crc", vec![]),
+            ("0000000000000000000a.json", vec![]),
+            ("00000000000000000005.checkpoint.1.2.parquet", vec![]),
+        ])
+        .await?;
+        let log_files = log.list_log_files().await?;
+
+        assert_eq!(log_files.commits.keys().copied().collect::<Vec<_>>(), vec![
+            0, 1, 3
+        ]);
+        assert_eq!(
+            log_files.checkpoints.keys().copied().collect::<Vec<_>>(),
+            vec![1, 2, 4]
+        );
+        assert_eq!(log_files.latest_version(), Some(4));
+
+        let parts = vec![
+            "_delta_log/00000000000000000002.checkpoint.0000000001.0000000002.parquet".to_string(),
+            "_delta_log/00000000000000000002.checkpoint.0000000002.0000000002.parquet".to_string(),
+        ];
+        assert_eq!(log_files.checkpoint_before(4), Some((2, parts.clone())));
+        assert_eq!(log_files.checkpoint_before(3), Some((2, parts)));
+        assert_eq!(
+            log_files.checkpoint_before(1),
+            Some((1, vec![
+                "_delta_log/00000000000000000001.checkpoint.parquet".to_string()
+            ]))
+        );
+        assert_eq!(log_files.checkpoint_before(0), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_and_version_at() -> Result<()> {
+        let protocol = json!({"protocol": {"minReaderVersion": 1, "minWriterVersion": 2}});
+        let metadata = json!({"metaData": {
+            "schemaString": r#"{"type":"struct","fields":[{"name":"a","type":"long","nullable":true,"metadata":{}}]}"#,
+            "partitionColumns": [],
+            "configuration": {}
+        }});
+        let log = create_log(&[
+            (
+                "00000000000000000000.json",
+                commit(&[commit_info(1000), protocol, metadata, add("f1"), add("f2")]),
+            ),
+            (
+                "00000000000000000001.json",
+                commit(&[commit_info(2000), remove("f1"), add("f3")]),
+            ),
+            (
+                "00000000000000000002.json",
+                commit(&[commit_info(3000), remove("f3")]),
+            ),
+        ])
+        .await?;
+
+        let paths = |snapshot: &DeltaSnapshot| {
+            snapshot
+                .files
+                .iter()
+                .map(|f| f.path.clone())
+                .collect::<Vec<_>>()
+        };
+        let latest = log.snapshot(None).await?;
+        assert_eq!(latest.version, 2);
+        assert_eq!(paths(&latest), vec!["f2"]);
+        assert_eq!(paths(&log.snapshot(Some(0)).await?), vec!["f1", "f2"]);
+        assert_eq!(paths(&log.snapshot(Some(1)).await?), vec!["f2", "f3"]);
+        assert!(log.snapshot(Some(3)).await.is_err());
+
+        assert!(log.version_at(999).await.is_err());
+        assert_eq!(log.version_at(1000).await?, 0);
+        assert_eq!(log.version_at(2500).await?, 1);
+        assert_eq!(log.version_at(3000).await?, 2);
+        assert_eq!(log.version_at(i64::MAX).await?, 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::delta_log::DeletionVectorDescriptor;
+
+/// An active data file of a delta table.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug)]
+pub struct DeltaPartInfo {
+    /// The decoded path of the file relative to the root of the table.
+    pub path: String,
+    pub size: u64,
+    /// The serialized values of the partition columns, keyed by the lowercased names.
+    pub partition_values: BTreeMap<String, Option<String>>,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[typetag::serde(name = "delta")]
+impl PartInfo for DeltaPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<DeltaPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.path.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl DeltaPartInfo {
+    pub fn create(
+        path: String,
+        size: u64,
+        partition_values: BTreeMap<String, Option<String>>,
+        deletion_vector: Option<DeletionVectorDescriptor>,
+    ) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(DeltaPartInfo {
+            path,
+            size,
+            partition_values,
+            deletion_vector,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&DeltaPartInfo> {
+        match info.as_any().downcast_ref::<DeltaPartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to DeltaPartInfo.",
+            )),
+        }
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde_json::Value as JsonValue;
+
+/// Convert the `schemaString` of the delta metadata to the table schema, the names of
+/// the top level columns are lowercased.
+pub fn parse_schema(schema_string: &str) -> Result<TableSchema> {
+    let schema = serde_json::from_str::<JsonValue>(schema_string)
+        .map_err(|e| ErrorCode::TableInfoError(format!("invalid delta schema: {}", e)))?;
+    let fields = struct_fields(&schema)?
+        .into_iter()
+        .map(|(name, ty)| TableField::new(&name.to_lowercase(), ty))
+        .collect();
+    Ok(TableSchema::new(fields))
+}
+
+// the fields of a delta struct type, e.g. `{"type":"struct","fields":[..]}`
+fn struct_fields(ty: &JsonValue) -> Result<Vec<(String, TableDataType)>> {
+    let fields = ty
+        .get("fields")
+        .and_then(|fields| fields.as_array())
+        .ok_or_else(|| ErrorCode::TableInfoError(format!("invalid delta struct type: {}", ty)))?;
+    fields
+        .iter()
+        .map(|field| {
+            let name = field.get("name").and_then(|name| name.as_str());
+            let ty = field.get("type");
+            match (name, ty) {
+                (Some(name), Some(ty)) => {
+                    let nullable = field
+                        .get("nullable")
+                        .and_then(|nullable| nullable.as_bool())
+                        .unwrap_or(true);
+                    Ok((name.to_string(), convert_type(ty, nullable)?))
+                }
+                _ => Err(ErrorCode::TableInfoError(format!(
+                    "invalid delta struct field: {}",
+                    field
+                ))),
+            }
+        })
+        .collect()
+}
+
+fn convert_type(ty: &JsonValue, nullable: bool) -> Result<TableDataType> {
+    let data_type = match ty {
+        JsonValue::String(name) => convert_primitive_type(name)?,
+        JsonValue::Object(object) => match object.get("type").and_then(|ty| ty.as_str()) {
+            Some("struct") => {
+                let (fields_name, fields_type) = struct_fields(ty)?.into_iter().unzip();
+                TableDataType::Tuple {
+                    fields_name,
+                    fields_type,
+                }
+            }
+            Some("array") => {
+                let element = object.get("elementType").ok_or_else(|| {
+                    ErrorCode::TableInfoError(format!("invalid delta array type: {}", ty))
+                })?;
+                let contains_null = object
+                    .get("containsNull")
+                    .and_then(|contains_null| contains_null.as_bool())
+                    .unwrap_or(true);
+                TableDataType::Array(Box::new(convert_type(element, contains_null)?))
+            }
+            Some("map") => {
+                let (key, value) = match (object.get("keyType"), object.get("valueType")) {
+                    (Some(key), Some(value)) => (key, value),
+                    _ => {
+                        return Err(ErrorCode::TableInfoError(format!(
+                            "invalid delta map type: {}",
+                            ty
+                        )));
+                    }
+                };
+                let value_contains_null = object
+                    .get("valueContainsNull")
+                    .and_then(|contains_null| contains_null.as_bool())
+                    .unwrap_or(true);
+                TableDataType::Map(Box::new(TableDataType::Tuple {
+                    fields_name: vec!["key".to_string(), "value".to_string()],
+                    fields_type: vec![
+                        convert_type(key, false)?,
+                        convert_type(value, value_contains_null)?,
+                    ],
+                }))
+            }
+            _ => {
+                return Err(ErrorCode::TableInfoError(format!(
+                    "unsupported delta type: {}",
+                    ty
+                )));
+            }
+        },
+        _ => {
+            return Err(ErrorCode::TableInfoError(format!(
+                "unsupported delta type: {}",
+                ty
+            )));
+        }
+    };
+    if nullable {
+        Ok(data_type.wrap_nullable())
+    } else {
+        Ok(data_type)
+    }
+}
+
+fn convert_primitive_type(name: &str) -> Result<TableDataType> {
+    let data_type = match name {
+        "string" => TableDataType::String,
+        "long" => TableDataType::Number(NumberDataType::Int64),
+        "integer" => TableDataType::Number(NumberDataType::Int32),
+        "short" => TableDataType::Number(NumberDataType::Int16),
+        "byte" => TableDataType::Number(NumberDataType::Int8),
+        "float" => TableDataType::Number(NumberDataType::Float32),
+        "double" => TableDataType::Number(NumberDataType::Float64),
+        "boolean" => TableDataType::Boolean,
+        "binary" => TableDataType::Binary,
+        "date" => TableDataType::Date,
+        "timestamp" | "timestamp_ntz" => TableDataType::Timestamp,
+        _ => match name
+            .strip_prefix("decimal(")
+            .and_then(|s| s.strip_suffix(')'))
+            .and_then(|s| s.split_once(','))
+        {
+            Some((precision, scale)) => {
+                let size = match (precision.trim().parse(), scale.trim().parse()) {
+                    (Ok(precision), Ok(scale)) => DecimalSize { precision, scale },
+                    _ => {
+                        return Err(ErrorCode::TableInfoError(format!(
+                            "invalid delta decimal type: {}",
+                            name
+                        )));
+                    }
+                };
+                TableDataType::Decimal(DecimalDataType::from_size(size)?)
+            }
+            None => {
+                return Err(ErrorCode::TableInfoError(format!(
+                    "unsupported delta type: {}",
+                    name
+                )));
+            }
+        },
+    };
+    Ok(data_type)
+}
+
+/// Parse the serialized value of a partition column, or a value of the file statistics
+/// which is not a json number.
+pub fn parse_scalar(value: &str, ty: &TableDataType) -> Result<Scalar> {
+    let invalid = || ErrorCode::BadBytes(format!("invalid delta value '{}' of type {}", value, ty));
+    let scalar = match ty {
+        TableDataType::Nullable(inner) => parse_scalar(value, inner)?,
+        TableDataType::String => Scalar::String(value.as_bytes().to_vec()),
+        TableDataType::Binary => Scalar::Binary(value.as_bytes().to_vec()),
+        TableDataType::Boolean => Scalar::Boolean(value.parse().map_err(|_| invalid())?),
+        TableDataType::Number(NumberDataType::Int8) => {
+            Scalar::Number(NumberScalar::Int8(value.parse().map_err(|_| invalid())?))
+        }
+        TableDataType::Number(NumberDataType::Int16) => {
+            Scalar::Number(NumberScalar::Int16(value.parse().map_err(|_| invalid())?))
+        }
+        TableDataType::Number(NumberDataType::Int32) => {
+            Scalar::Number(NumberScalar::Int32(value.parse().map_err(|_| invalid())?))
+        }
+        TableDataType::Number(NumberDataType::Int64) => {
+            Scalar::Number(NumberScalar::Int64(value.parse().map_err(|_| invalid())?))
+        }
+        TableDataType::Number(NumberDataType::Float32) => Scalar::Number(NumberScalar::Float32(
+            F32::from(value.parse::<f32>().map_err(|_| invalid())?),
+        )),
+        TableDataType::Number(NumberDataType::Float64) => Scalar::Number(NumberScalar::Float64(
+            F64::from(value.parse::<f64>().map_err(|_| invalid())?),
+        )),
+        TableDataType::Date => {
+            let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| invalid())?;
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+            Scalar::Date(date.signed_duration_since(epoch).num_days() as i32)
+        }
+        TableDataType::Timestamp => {
+            // e.g. `2023-01-01 00:00:00` in partition values,
+            // e.g. `2023-01-01T00:00:00.000Z` in statistics
+            let micros = match DateTime::parse_from_rfc3339(value) {
+                Ok(ts) => ts.timestamp_micros(),
+                Err(_) => NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+                    .map_err(|_| invalid())?
+                    .timestamp_micros(),
+            };
+            Scalar::Timestamp(micros)
+        }
+        TableDataType::Decimal(DecimalDataType::Decimal128(size)) => Scalar::Decimal(
+            DecimalScalar::Decimal128(parse_decimal(value, size.scale).ok_or_else(invalid)?, *size),
+        ),
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "delta values of type {} are not supported",
+                ty
+            )));
+        }
+    };
+    Ok(scalar)
+}
+
+// parse a decimal like `-12.30` to the integer scaled by `scale`
+fn parse_decimal(value: &str, scale: u8) -> Option<i128> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if fraction.len() > scale as usize
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let digits = format!(
+        "{}{}{}",
+        integer,
+        fraction,
+        "0".repeat(scale as usize - fraction.len())
+    );
+    let value = if digits.is_empty() {
+        0
+    } else {
+        digits.parse::<i128>().ok()?
+    };
+    Some(if negative { -value } else { value })
+}
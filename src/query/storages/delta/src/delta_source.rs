@@ -0,0 +1,221 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read as pread;
+use common_arrow::parquet::read::read_metadata;
+use common_base::base::Progress;
+use common_base::base::ProgressValues;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataType;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use opendal::Operator;
+use roaring::RoaringTreemap;
+
+use crate::deletion_vector::load_deletion_vector;
+use crate::delta_part::DeltaPartInfo;
+use crate::delta_schema::parse_scalar;
+
+/// How a column of the output is filled.
+enum ColumnSource {
+    // the index of the column read from the file
+    File(usize),
+    // the value of a partition column, or null if the column is missing in the file
+    Const(Scalar),
+}
+
+/// Reads the data files of a delta table, the rows marked in the deletion vectors are
+/// filtered out, and the partition columns are filled with the values in the log.
+pub struct DeltaSource {
+    ctx: Arc<dyn TableContext>,
+    operator: Operator,
+    output_schema: TableSchemaRef,
+    scan_progress: Arc<Progress>,
+    blocks: VecDeque<DataBlock>,
+}
+
+impl DeltaSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        operator: Operator,
+        output_schema: TableSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        AsyncSourcer::create(ctx.clone(), output, DeltaSource {
+            ctx,
+            operator,
+            output_schema,
+            scan_progress,
+            blocks: VecDeque::new(),
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_part(&mut self, part: &DeltaPartInfo) -> Result<()> {
+        let deleted = match &part.deletion_vector {
+            Some(dv) => Some(load_deletion_vector(&self.operator, dv).await?),
+            None => None,
+        };
+
+        let data = self.operator.read(&part.path).await?;
+        let mut reader = Cursor::new(data);
+        let meta = read_metadata(&mut reader)?;
+        let file_schema = pread::infer_schema(&meta)?;
+
+        // the fields read from the file, and how each output column is filled
+        let mut read_fields = vec![];
+        let mut read_types = vec![];
+        let mut sources = Vec::with_capacity(self.output_schema.num_fields());
+        for field in self.output_schema.fields() {
+            if let Some(value) = part.partition_values.get(field.name()) {
+                let scalar = match value {
+                    Some(value) => parse_scalar(value, field.data_type())?,
+                    None => Scalar::Null,
+                };
+                sources.push(ColumnSource::Const(scalar));
+                continue;
+            }
+            match file_schema
+                .fields
+                .iter()
+                .find(|f| f.name.eq_ignore_ascii_case(field.name()))
+            {
+                Some(file_field) => {
+                    sources.push(ColumnSource::File(read_fields.len()));
+                    read_fields.push(file_field.clone());
+                    read_types.push(DataType::from(field.data_type()));
+                }
+                None if field.is_nullable() => sources.push(ColumnSource::Const(Scalar::Null)),
+                None => {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "couldn't find column:{} in delta data file '{}'",
+                        field.name(),
+                        part.path
+                    )));
+                }
+            }
+        }
+
+        let mut offset = 0;
+        if read_fields.is_empty() {
+            for row_group in meta.row_groups.iter() {
+                let num_rows = row_group.num_rows();
+                let block = DataBlock::new(vec![], num_rows);
+                self.push_block(block, &sources, &deleted, offset)?;
+                offset += num_rows;
+            }
+        } else {
+            let chunks = pread::FileReader::new(
+                reader,
+                meta.row_groups,
+                ArrowSchema::from(read_fields),
+                None,
+                None,
+                None,
+            );
+            for chunk in chunks {
+                let chunk = chunk.map_err(|e| {
+                    ErrorCode::BadBytes(format!(
+                        "fail to read delta data file '{}': {}",
+                        part.path, e
+                    ))
+                })?;
+                let block = DataBlock::from_arrow_chunk_with_types(&chunk, &read_types)?;
+                let num_rows = block.num_rows();
+                self.push_block(block, &sources, &deleted, offset)?;
+                offset += num_rows;
+            }
+        }
+        Ok(())
+    }
+
+    // fill the output columns of the rows read from the file, whose first row is the
+    // `offset`th row of the file
+    fn push_block(
+        &mut self,
+        block: DataBlock,
+        sources: &[ColumnSource],
+        deleted: &Option<RoaringTreemap>,
+        offset: usize,
+    ) -> Result<()> {
+        let num_rows = block.num_rows();
+        let columns = self
+            .output_schema
+            .fields()
+            .iter()
+            .zip(sources.iter())
+            .map(|(field, source)| match source {
+                ColumnSource::File(index) => block.get_by_offset(*index).clone(),
+                ColumnSource::Const(scalar) => BlockEntry {
+                    data_type: DataType::from(field.data_type()),
+                    value: Value::Scalar(scalar.clone()),
+                },
+            })
+            .collect();
+        let mut block = DataBlock::new(columns, num_rows);
+
+        if let Some(deleted) = deleted {
+            let bitmap = (offset..offset + num_rows)
+                .map(|row| !deleted.contains(row as u64))
+                .collect::<MutableBitmap>();
+            block = block.filter_with_bitmap(&bitmap.into())?;
+        }
+
+        let progress_values = ProgressValues {
+            rows: block.num_rows(),
+            bytes: block.memory_size(),
+        };
+        self.scan_progress.incr(&progress_values);
+        self.blocks.push_back(block);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for DeltaSource {
+    const NAME: &'static str = "DeltaSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.blocks.pop_front() {
+                return Ok(Some(block));
+            }
+            match self.ctx.get_partition() {
+                None => return Ok(None),
+                Some(part) => {
+                    let part = DeltaPartInfo::from_part(&part)?.clone();
+                    self.read_part(&part).await?;
+                }
+            }
+        }
+    }
+}
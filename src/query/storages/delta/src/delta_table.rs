@@ -0,0 +1,371 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::NavigationPoint;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_core::Pipeline;
+use common_storage::init_operator;
+use opendal::Operator;
+use percent_encoding::percent_decode_str;
+use serde_json::Value as JsonValue;
+use storages_common_index::RangeIndex;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::delta_log::AddFile;
+use crate::delta_log::DeltaLog;
+use crate::delta_schema::parse_scalar;
+use crate::delta_schema::parse_schema;
+use crate::delta_source::DeltaSource;
+use crate::DeltaPartInfo;
+
+pub const DELTA_ENGINE: &str = "DELTA";
+
+/// The string statistics are truncated to this number of characters by the writers.
+const STRING_STATS_PREFIX_LENGTH: usize = 32;
+
+/// A read-only table over the files of a delta lake table at its `LOCATION`.
+///
+/// The active files are replayed from the `_delta_log` at each query, of the latest
+/// version, or the version navigated to by time travel.
+#[derive(Clone)]
+pub struct DeltaTable {
+    table_info: TableInfo,
+    operator: Operator,
+    // the version to read, the latest one if `None`
+    version: Option<i64>,
+}
+
+impl DeltaTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let operator = match &table_info.meta.storage_params {
+            Some(sp) => init_operator(sp)?,
+            None => {
+                return Err(ErrorCode::Internal(
+                    "Need the location of the delta table when creating DeltaTable",
+                ));
+            }
+        };
+        Ok(Box::new(DeltaTable {
+            table_info,
+            operator,
+            version: None,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: DELTA_ENGINE.to_string(),
+            comment: "DELTA STORAGE (READ ONLY DELTA LAKE TABLE)".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Load the schema of the latest version of the delta table at the location.
+    #[async_backtrace::framed]
+    pub async fn load_schema(sp: &StorageParams) -> Result<TableSchema> {
+        let operator = init_operator(sp)?;
+        let snapshot = DeltaLog::create(operator).snapshot(None).await?;
+        parse_schema(&snapshot.metadata.schema_string)
+    }
+
+    fn delta_log(&self) -> DeltaLog {
+        DeltaLog::create(self.operator.clone())
+    }
+
+    /// The statistics of the file to prune it, the partition columns are
+    /// taken as the columns whose min and max are the partition value.
+    fn file_statistics(
+        schema: &TableSchema,
+        file: &AddFile,
+        partition_values: &BTreeMap<String, Option<String>>,
+    ) -> StatisticsOfColumns {
+        let stats = file
+            .stats
+            .as_ref()
+            .and_then(|stats| serde_json::from_str::<JsonValue>(stats).ok())
+            .unwrap_or(JsonValue::Null);
+        let num_records = stats.get("numRecords").and_then(|n| n.as_u64());
+
+        let mut statistics = StatisticsOfColumns::new();
+        for field in schema.fields() {
+            let data_type = field.data_type();
+            if data_type.num_leaf_columns() != 1 {
+                continue;
+            }
+            let column_statistics = match partition_values.get(field.name()) {
+                Some(Some(value)) => match parse_scalar(value, data_type) {
+                    Ok(value) => Some(ColumnStatistics {
+                        min: value.clone(),
+                        max: value,
+                        null_count: 0,
+                        in_memory_size: 0,
+                        distinct_of_values: Some(1),
+                    }),
+                    Err(_) => None,
+                },
+                Some(None) => Some(ColumnStatistics {
+                    min: Scalar::Null,
+                    max: Scalar::Null,
+                    null_count: num_records.unwrap_or(0),
+                    in_memory_size: 0,
+                    distinct_of_values: Some(0),
+                }),
+                None => Self::column_statistics(&stats, field.name(), data_type, num_records),
+            };
+            if let Some(column_statistics) = column_statistics {
+                statistics.insert(field.column_id(), column_statistics);
+            }
+        }
+        statistics
+    }
+
+    // the statistics of a column in the `stats` of the file, `None` if unknown
+    fn column_statistics(
+        stats: &JsonValue,
+        name: &str,
+        data_type: &TableDataType,
+        num_records: Option<u64>,
+    ) -> Option<ColumnStatistics> {
+        let get = |key: &str| {
+            stats
+                .get(key)
+                .and_then(|values| values.as_object())
+                .and_then(|values| {
+                    values
+                        .iter()
+                        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                        .map(|(_, v)| v)
+                })
+        };
+        let null_count = get("nullCount").and_then(|n| n.as_u64())?;
+        // the min and max are absent if all the values are null
+        if num_records == Some(null_count) && data_type.is_nullable() {
+            return Some(ColumnStatistics {
+                min: Scalar::Null,
+                max: Scalar::Null,
+                null_count,
+                in_memory_size: 0,
+                distinct_of_values: Some(0),
+            });
+        }
+
+        let min = Self::stats_value(get("minValues")?, data_type, false)?;
+        let max = Self::stats_value(get("maxValues")?, data_type, true)?;
+        Some(ColumnStatistics {
+            min,
+            max,
+            null_count,
+            in_memory_size: 0,
+            distinct_of_values: None,
+        })
+    }
+
+    fn stats_value(value: &JsonValue, data_type: &TableDataType, is_max: bool) -> Option<Scalar> {
+        let scalar = match value {
+            JsonValue::String(s) => {
+                // a truncated max is not the upper bound of the values
+                if is_max
+                    && matches!(data_type.remove_nullable(), TableDataType::String)
+                    && s.chars().count() >= STRING_STATS_PREFIX_LENGTH
+                {
+                    return None;
+                }
+                parse_scalar(s, data_type).ok()?
+            }
+            JsonValue::Number(n) => parse_scalar(&n.to_string(), data_type).ok()?,
+            _ => return None,
+        };
+        match scalar {
+            // the timestamps are truncated to milliseconds
+            Scalar::Timestamp(ts) if is_max => Some(Scalar::Timestamp(ts + 999)),
+            scalar => Some(scalar),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for DeltaTable {
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let snapshot = self.delta_log().snapshot(self.version).await?;
+        let schema = self.table_info.schema();
+
+        let filter_expression = push_downs.as_ref().and_then(|extra| {
+            extra
+                .filter
+                .as_ref()
+                .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+        });
+        let range_index = match filter_expression {
+            Some(expr) => Some(RangeIndex::try_create(
+                ctx.get_function_context()?,
+                &expr,
+                schema.clone(),
+            )?),
+            None => None,
+        };
+
+        let partitions_total = snapshot.files.len();
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let mut parts = vec![];
+        for file in snapshot.files.iter() {
+            let partition_values = file
+                .partition_values
+                .iter()
+                .map(|(k, v)| (k.to_lowercase(), v.clone()))
+                .collect::<BTreeMap<_, _>>();
+            if let Some(range_index) = &range_index {
+                let statistics = Self::file_statistics(&schema, file, &partition_values);
+                if !range_index.apply(&statistics)? {
+                    continue;
+                }
+            }
+
+            // the paths are url encoded, and may be absolute for shallow clones
+            let path = percent_decode_str(&file.path)
+                .decode_utf8()
+                .map_err(|e| {
+                    ErrorCode::BadBytes(format!("invalid delta file path '{}': {}", file.path, e))
+                })?
+                .to_string();
+            if path.contains("://") || path.starts_with('/') {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "delta files out of the table location are not supported: {}",
+                    path
+                )));
+            }
+
+            let num_records = file
+                .stats
+                .as_ref()
+                .and_then(|stats| serde_json::from_str::<JsonValue>(stats).ok())
+                .and_then(|stats| stats.get("numRecords").and_then(|n| n.as_u64()));
+            if let Some(num_records) = num_records {
+                let deleted = file
+                    .deletion_vector
+                    .as_ref()
+                    .map(|dv| dv.cardinality as u64)
+                    .unwrap_or(0);
+                read_rows += num_records.saturating_sub(deleted) as usize;
+            }
+            read_bytes += file.size as usize;
+            parts.push(DeltaPartInfo::create(
+                path,
+                file.size as u64,
+                partition_values,
+                file.deletion_vector.clone(),
+            ));
+        }
+
+        let statistics =
+            PartStatistics::new_estimated(read_rows, read_bytes, parts.len(), partitions_total);
+        Ok((
+            statistics,
+            Partitions::create_nolazy(PartitionsShuffleKind::Seq, parts),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(plan.parts.len(), max_threads);
+        let output_schema = plan.schema();
+        pipeline.add_source(
+            |output| {
+                DeltaSource::create(
+                    ctx.clone(),
+                    output,
+                    self.operator.clone(),
+                    output_schema.clone(),
+                )
+            },
+            std::cmp::max(1, max_threads),
+        )
+    }
+
+    /// A snapshot id is taken as the delta version, and a time point is mapped to the
+    /// latest version committed no later than it.
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, instant: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let version = match instant {
+            NavigationPoint::SnapshotID(version) => version.parse::<i64>().map_err(|_| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "invalid version '{}' of delta table, expects an integer",
+                    version
+                ))
+            })?,
+            NavigationPoint::TimePoint(time_point) => {
+                self.delta_log()
+                    .version_at(time_point.timestamp_millis())
+                    .await?
+            }
+        };
+        let mut table = self.clone();
+        table.version = Some(version);
+        Ok(Arc::new(table))
+    }
+
+    #[async_backtrace::framed]
+    async fn truncate(&self, _ctx: Arc<dyn TableContext>, _: bool) -> Result<()> {
+        Err(ErrorCode::Unimplemented(format!(
+            "truncate for delta table {} is not supported, delta tables are read only",
+            self.name()
+        )))
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::uninlined_format_args)]
+#![deny(unused_crate_dependencies)]
+
+mod deletion_vector;
+mod delta_log;
+mod delta_part;
+mod delta_schema;
+mod delta_source;
+mod delta_table;
+
+pub use delta_part::DeltaPartInfo;
+pub use delta_table::DeltaTable;
+pub use delta_table::DELTA_ENGINE;
@@ -17,10 +17,14 @@ pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+pub const FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS: &str = "inverted_index_columns";
+pub const FUSE_OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
 pub const FUSE_TBL_XOR_BLOOM_INDEX_PREFIX: &str = "_i_b_v2";
+pub const FUSE_TBL_INVERTED_INDEX_PREFIX: &str = "_i_inv";
+pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_agg";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
 pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 pub const FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX: &str = "_ts";
@@ -31,3 +35,9 @@ pub const DEFAULT_ROW_PER_PAGE: usize = 8192;
 pub const DEFAULT_ROW_PER_PAGE_FOR_BLOCKING: usize = 2048;
 
 pub const DEFAULT_AVG_DEPTH_THRESHOLD: f64 = 0.01;
+
+/// The metadata columns of the rows changed since a snapshot of the table.
+pub const CHANGE_ACTION_COL_NAME: &str = "change$action";
+pub const CHANGE_IS_UPDATE_COL_NAME: &str = "change$is_update";
+pub const CHANGE_ACTION_INSERT: &str = "INSERT";
+pub const CHANGE_ACTION_DELETE: &str = "DELETE";
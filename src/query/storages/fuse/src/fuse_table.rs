@@ -21,13 +21,16 @@ use std::sync::Arc;
 
 use common_catalog::catalog::StorageDescription;
 use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::MergeIntoDesc;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::AppendMode;
+use common_catalog::table::ColumnHistogram;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_catalog::table::CompactTarget;
+use common_catalog::table::HistogramBucket;
 use common_catalog::table::NavigationDescriptor;
 use common_catalog::table_context::TableContext;
 use common_catalog::table_mutator::TableMutator;
@@ -42,6 +45,7 @@ use common_expression::TableField;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableIndexType;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_sharing::create_share_table_operator;
@@ -53,7 +57,9 @@ use common_storage::StorageMetrics;
 use common_storage::StorageMetricsLayer;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::ClusterKey;
+use storages_common_table_meta::meta::ColumnHistogram as FuseColumnHistogram;
 use storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 use storages_common_table_meta::meta::Statistics as FuseStatistics;
 use storages_common_table_meta::meta::TableSnapshot;
@@ -61,6 +67,7 @@ use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::table_storage_prefix;
 use storages_common_table_meta::table::TableCompression;
+use storages_common_table_meta::table::OPT_KEY_BASE_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
@@ -83,6 +90,8 @@ use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS;
+use crate::FUSE_OPT_KEY_NGRAM_INDEX_COLUMNS;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -188,9 +197,49 @@ impl FuseTable {
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            bloom_index_columns: self.table_info.meta.index_column_ids(TableIndexType::Bloom),
+            inverted_index_columns: self.get_string_index_column_ids(
+                TableIndexType::Inverted,
+                FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS,
+            ),
+            ngram_index_columns: self.get_string_index_column_ids(
+                TableIndexType::Ngram,
+                FUSE_OPT_KEY_NGRAM_INDEX_COLUMNS,
+            ),
         }
     }
 
+    /// Ids of the string columns covered by the indexes of the given type, which
+    /// are defined by `CREATE INDEX` or listed in the table option, e.g. `'c1,c2'`.
+    /// Unknown columns and non-string columns are ignored.
+    pub fn get_string_index_column_ids(
+        &self,
+        index_type: TableIndexType,
+        opt_key: &str,
+    ) -> Vec<ColumnId> {
+        let schema = self.table_info.schema();
+        let mut column_ids = match self.table_info.options().get(opt_key) {
+            Some(columns) => columns
+                .split(',')
+                .filter_map(|name| schema.field_with_name(name.trim()).ok())
+                .filter(|field| InvertedIndex::supported_type(field.data_type()))
+                .map(|field| field.column_id())
+                .collect(),
+            None => vec![],
+        };
+        for column_id in self
+            .table_info
+            .meta
+            .index_column_ids(index_type)
+            .unwrap_or_default()
+        {
+            if !column_ids.contains(&column_id) {
+                column_ids.push(column_id);
+            }
+        }
+        column_ids
+    }
+
     /// Get max page size.
     /// For native storage format.
     pub fn get_max_page_size(&self) -> Option<usize> {
@@ -215,7 +264,7 @@ impl FuseTable {
     }
 
     pub fn table_snapshot_statistics_format_version(&self, location: &String) -> u64 {
-        TableMetaLocationGenerator::snapshot_version(location)
+        TableMetaLocationGenerator::snapshot_statistics_version(location)
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
@@ -264,6 +313,29 @@ impl FuseTable {
         }
     }
 
+    /// Read the base snapshot of the table navigated by `navigate_to_appended_since`,
+    /// the segments of the base snapshot are excluded from the read of the table.
+    #[async_backtrace::framed]
+    pub async fn read_base_snapshot(&self) -> Result<Option<Arc<TableSnapshot>>> {
+        match self
+            .table_info
+            .options()
+            .get(OPT_KEY_BASE_SNAPSHOT_LOCATION)
+        {
+            Some(loc) => {
+                let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+                let params = LoadParams {
+                    location: loc.clone(),
+                    len_hint: None,
+                    ver: TableMetaLocationGenerator::snapshot_version(loc),
+                    put_cache: true,
+                };
+                Ok(Some(reader.read(&params).await?))
+            }
+            None => Ok(None),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn snapshot_format_version(&self) -> Result<u64> {
         match self.snapshot_loc().await? {
@@ -520,6 +592,17 @@ impl Table for FuseTable {
             .await
     }
 
+    #[async_backtrace::framed]
+    async fn merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        merge_into: MergeIntoDesc,
+    ) -> Result<()> {
+        self.build_merge_into_pipeline(ctx, merge_into, pipeline)
+            .await
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_commit_insertion", skip(self, ctx, operations), fields(ctx.id = ctx.get_id().as_str()))]
     #[async_backtrace::framed]
     async fn commit_insertion(
@@ -577,12 +660,14 @@ impl Table for FuseTable {
                     row_count: snapshot.summary.row_count,
                     // save row count first
                     column_distinct_values: Some(table_statistics.column_distinct_values.clone()),
+                    histograms: table_statistics.histograms.clone(),
                 }
             } else {
                 FuseTableColumnStatisticsProvider {
                     column_stats: stats.clone(),
                     row_count: snapshot.summary.row_count,
                     column_distinct_values: None,
+                    histograms: HashMap::new(),
                 }
             }
         } else {
@@ -669,6 +754,11 @@ impl Table for FuseTable {
         self.do_revert_to(ctx.as_ref(), point).await
     }
 
+    #[async_backtrace::framed]
+    async fn refresh_index(&self, ctx: Arc<dyn TableContext>, index_name: &str) -> Result<()> {
+        self.do_refresh_index(&ctx, index_name).await
+    }
+
     fn support_prewhere(&self) -> bool {
         matches!(self.storage_format, FuseStorageFormat::Native)
     }
@@ -704,6 +794,7 @@ struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, FuseColumnStatistics>,
     pub column_distinct_values: Option<HashMap<ColumnId, u64>>,
     pub row_count: u64,
+    histograms: HashMap<ColumnId, FuseColumnHistogram>,
 }
 
 impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
@@ -717,6 +808,21 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
                 .column_distinct_values
                 .as_ref()
                 .map_or(self.row_count, |map| map.get(&column_id).map_or(0, |v| *v)),
+            histogram: self
+                .histograms
+                .get(&column_id)
+                .map(|histogram| ColumnHistogram {
+                    buckets: histogram
+                        .buckets
+                        .iter()
+                        .map(|bucket| HistogramBucket {
+                            upper_bound: bucket.upper_bound.clone(),
+                            num_values: bucket.num_values,
+                            num_distinct: bucket.num_distinct,
+                        })
+                        .collect(),
+                    most_common_values: histogram.most_common_values.clone(),
+                }),
         })
     }
 }
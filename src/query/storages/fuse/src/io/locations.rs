@@ -16,6 +16,8 @@ use std::marker::PhantomData;
 
 use common_exception::Result;
 use common_expression::DataBlock;
+use sha2::Digest;
+use sha2::Sha256;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::SnapshotVersion;
@@ -28,6 +30,9 @@ use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::index::filters::BlockFilter;
+use crate::index::InvertedIndex;
+use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
@@ -36,6 +41,8 @@ static SNAPSHOT_V1: SnapshotVersion = SnapshotVersion::V1(PhantomData);
 static SNAPSHOT_V2: SnapshotVersion = SnapshotVersion::V2(PhantomData);
 static SNAPSHOT_STATISTICS_V0: TableSnapshotStatisticsVersion =
     TableSnapshotStatisticsVersion::V0(PhantomData);
+static SNAPSHOT_STATISTICS_V1: TableSnapshotStatisticsVersion =
+    TableSnapshotStatisticsVersion::V1(PhantomData);
 
 #[derive(Clone)]
 pub struct TableMetaLocationGenerator {
@@ -91,6 +98,36 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn block_inverted_index_location(&self, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_INVERTED_INDEX_PREFIX,
+                block_id.as_simple(),
+                InvertedIndex::VERSION,
+            ),
+            InvertedIndex::VERSION,
+        )
+    }
+
+    /// The aggregating index files of a block are named after the block, and put
+    /// in a directory per defining query, so that the files of a dropped index are
+    /// never read by a recreated index of the same name.
+    pub fn block_agg_index_location(&self, index_query: &str, block_location: &str) -> String {
+        let query_hash = format!("{:x}", Sha256::digest(index_query.as_bytes()));
+        let block_name = block_location
+            .rsplit_once('/')
+            .map_or(block_location, |(_, name)| name);
+        format!(
+            "{}/{}/{}/{}",
+            &self.prefix,
+            FUSE_TBL_AGG_INDEX_PREFIX,
+            &query_hash[..16],
+            block_name,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
@@ -126,8 +163,12 @@ impl TableMetaLocationGenerator {
         Ok(statistics_version.create(id, &self.prefix))
     }
 
-    pub fn snapshot_statistics_version(_location: impl AsRef<str>) -> u64 {
-        SNAPSHOT_STATISTICS_V0.version()
+    pub fn snapshot_statistics_version(location: impl AsRef<str>) -> u64 {
+        if location.as_ref().ends_with(SNAPSHOT_STATISTICS_V1.suffix()) {
+            SNAPSHOT_STATISTICS_V1.version()
+        } else {
+            SNAPSHOT_STATISTICS_V0.version()
+        }
     }
 
     pub fn gen_last_snapshot_hint_location(&self) -> String {
@@ -174,6 +215,7 @@ impl SnapshotLocationCreator for TableSnapshotStatisticsVersion {
     fn suffix(&self) -> &'static str {
         match self {
             TableSnapshotStatisticsVersion::V0(_) => "_ts_v0.json",
+            TableSnapshotStatisticsVersion::V1(_) => "_ts_v1.json",
         }
     }
 }
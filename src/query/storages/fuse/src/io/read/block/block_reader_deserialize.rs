@@ -28,6 +28,7 @@ use super::BlockReader;
 use crate::io::read::block::block_reader_merge_io::DataItem;
 use crate::io::ReadSettings;
 use crate::io::UncompressedBuffer;
+use crate::FusePartInfo;
 use crate::FuseStorageFormat;
 
 pub enum DeserializedArray<'a> {
@@ -66,31 +67,68 @@ impl BlockReader {
         meta: &BlockMeta,
         storage_format: &FuseStorageFormat,
     ) -> Result<DataBlock> {
-        let columns_meta = &meta.col_metas;
+        self.read_block(
+            settings,
+            &meta.location.0,
+            meta.row_count as usize,
+            &meta.compression,
+            &meta.col_metas,
+            storage_format,
+        )
+        .await
+    }
 
+    /// Read all the rows of the block of the part, the page ranges of the part are ignored.
+    #[tracing::instrument(level = "debug", skip_all)]
+    #[async_backtrace::framed]
+    pub async fn read_by_part(
+        &self,
+        settings: &ReadSettings,
+        part: &FusePartInfo,
+        storage_format: &FuseStorageFormat,
+    ) -> Result<DataBlock> {
+        self.read_block(
+            settings,
+            &part.location,
+            part.nums_rows,
+            &part.compression,
+            &part.columns_meta,
+            storage_format,
+        )
+        .await
+    }
+
+    #[async_backtrace::framed]
+    async fn read_block(
+        &self,
+        settings: &ReadSettings,
+        location: &str,
+        num_rows: usize,
+        compression: &Compression,
+        columns_meta: &HashMap<ColumnId, ColumnMeta>,
+        storage_format: &FuseStorageFormat,
+    ) -> Result<DataBlock> {
         // Get the merged IO read result.
         let merge_io_read_result = self
-            .read_columns_data_by_merge_io(settings, &meta.location.0, columns_meta)
+            .read_columns_data_by_merge_io(settings, location, columns_meta)
             .await?;
 
         // Get the columns chunk.
         let column_chunks = merge_io_read_result.columns_chunks()?;
 
-        let num_rows = meta.row_count as usize;
-
         match storage_format {
             FuseStorageFormat::Parquet => self.deserialize_parquet_chunks_with_buffer(
-                &meta.location.0,
+                location,
                 num_rows,
-                &meta.compression,
+                compression,
                 columns_meta,
                 column_chunks,
                 None,
             ),
             FuseStorageFormat::Native => self.deserialize_native_chunks_with_buffer(
-                &meta.location.0,
+                location,
                 num_rows,
-                &meta.compression,
+                compression,
                 columns_meta,
                 column_chunks,
                 None,
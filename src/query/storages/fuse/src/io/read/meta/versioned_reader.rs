@@ -58,7 +58,8 @@ impl VersionedReader<TableSnapshotStatistics> for TableSnapshotStatisticsVersion
     async fn read<R>(&self, reader: R) -> Result<TableSnapshotStatistics>
     where R: AsyncRead + Unpin + Send {
         let r = match self {
-            TableSnapshotStatisticsVersion::V0(v) => load_by_version(reader, v).await?,
+            TableSnapshotStatisticsVersion::V1(v) => load_by_version(reader, v).await?,
+            TableSnapshotStatisticsVersion::V0(v) => load_by_version(reader, v).await?.into(),
         };
         Ok(r)
     }
@@ -33,6 +33,7 @@ use crate::io::write::WriteSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::util;
 use crate::operations::BloomIndexState;
+use crate::operations::InvertedIndexState;
 use crate::statistics::gen_columns_statistics;
 
 // TODO rename this, it is serialization, or pass in a writer(if not rename)
@@ -92,6 +93,7 @@ pub struct BlockSerialization {
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub inverted_index_state: Option<InvertedIndexState>,
 }
 
 #[derive(Clone)]
@@ -115,11 +117,21 @@ impl BlockBuilder {
             self.source_schema.clone(),
             &data_block,
             bloom_index_location,
+            self.write_settings.bloom_index_columns.as_deref(),
+            &self.write_settings.ngram_index_columns,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let inverted_index_location = self.meta_locations.block_inverted_index_location(&block_id);
+        let inverted_index_state = InvertedIndexState::try_create(
+            &self.source_schema,
+            &self.write_settings.inverted_index_columns,
+            &data_block,
+            inverted_index_location,
+        )?;
+
         // TODO, generate the cluster stats
         let cluster_stats = None;
 
@@ -150,6 +162,11 @@ impl BlockBuilder {
                 .map(|v| v.size)
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
+            inverted_index_location: inverted_index_state.as_ref().map(|v| v.location.clone()),
+            inverted_index_size: inverted_index_state
+                .as_ref()
+                .map(|v| v.size)
+                .unwrap_or_default(),
         };
 
         let serialized = BlockSerialization {
@@ -157,6 +174,7 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            inverted_index_state,
         };
         Ok(serialized)
     }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::ColumnId;
 use storages_common_table_meta::table::TableCompression;
 
 use crate::FuseStorageFormat;
@@ -26,6 +27,13 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+
+    // columns to build bloom filter for, all the supported columns if None
+    pub bloom_index_columns: Option<Vec<ColumnId>>,
+    // columns to build inverted index for, see `FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS`
+    pub inverted_index_columns: Vec<ColumnId>,
+    // columns to build ngram bloom filter for, see `FUSE_OPT_KEY_NGRAM_INDEX_COLUMNS`
+    pub ngram_index_columns: Vec<ColumnId>,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +43,9 @@ impl Default for WriteSettings {
             table_compression: TableCompression::default(),
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            bloom_index_columns: None,
+            inverted_index_columns: vec![],
+            ngram_index_columns: vec![],
         }
     }
 }
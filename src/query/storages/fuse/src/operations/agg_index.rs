@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::AggIndexInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::FieldIndex;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use common_meta_app::schema::TableIndexType;
+use common_sql::parse_agg_index_query;
+use common_sql::AggIndexDefinition;
+use storages_common_blocks::blocks_to_parquet;
+use storages_common_index::AggregatingIndex;
+use storages_common_index::AggregatingIndexFunction;
+use storages_common_table_meta::table::TableCompression;
+
+use crate::FuseTable;
+
+/// AggIndexBuilder pre-aggregates the data blocks of a table into the blocks
+/// of an aggregating index.
+#[derive(Clone)]
+pub struct AggIndexBuilder {
+    /// The defining query of the index.
+    pub query: String,
+    /// Offsets of the group columns in the source block.
+    group_by: Vec<FieldIndex>,
+    aggregates: Vec<AggregatingIndexFunction>,
+    /// Schema of the index block.
+    schema: TableSchemaRef,
+}
+
+impl AggIndexBuilder {
+    /// Create the builder of the group columns and the aggregates selected by `info`,
+    /// over the source blocks of `source_schema`.
+    pub fn try_create(
+        definition: &AggIndexDefinition,
+        info: &AggIndexInfo,
+        source_schema: &TableSchema,
+    ) -> Result<Self> {
+        let group_by = info
+            .group_by
+            .iter()
+            .map(|name| source_schema.index_of(name))
+            .collect::<Result<Vec<_>>>()?;
+        let aggregates = info
+            .aggregates
+            .iter()
+            .map(|offset| {
+                let func = &definition.aggregates[*offset];
+                Ok(AggregatingIndexFunction {
+                    func: func.create()?,
+                    args: func
+                        .args
+                        .iter()
+                        .map(|name| source_schema.index_of(name))
+                        .collect::<Result<Vec<_>>>()?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            query: info.query.clone(),
+            group_by,
+            aggregates,
+            schema: Arc::new(info.output_schema(source_schema)?),
+        })
+    }
+
+    pub fn build(&self, block: &DataBlock) -> Result<DataBlock> {
+        AggregatingIndex::build(block, &self.group_by, &self.aggregates)
+    }
+
+    /// Build the index block and serialize it into a parquet file.
+    pub fn serialize(&self, block: &DataBlock) -> Result<Vec<u8>> {
+        let index_block = self.build(block)?;
+        let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+        blocks_to_parquet(
+            &self.schema,
+            vec![index_block],
+            &mut data,
+            TableCompression::None,
+        )?;
+        Ok(data)
+    }
+}
+
+impl FuseTable {
+    /// The builders of all the aggregating indexes of the table, which build
+    /// the index files of the table blocks.
+    pub fn agg_index_builders(&self, ctx: Arc<dyn TableContext>) -> Result<Vec<AggIndexBuilder>> {
+        let schema = self.table_info.schema();
+        let mut builders = vec![];
+        for index in self.table_info.meta.indexes.values() {
+            if index.index_type == TableIndexType::Aggregating {
+                builders.push(self.agg_index_builder(ctx.clone(), &index.name, &schema)?);
+            }
+        }
+        Ok(builders)
+    }
+
+    pub fn agg_index_builder(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        index_name: &str,
+        schema: &TableSchema,
+    ) -> Result<AggIndexBuilder> {
+        let query = &self.table_info.meta.indexes[index_name].query;
+        let definition = parse_agg_index_query(ctx, Arc::new(self.clone()), query)?;
+        let info = AggIndexInfo {
+            index_name: index_name.to_string(),
+            query: query.clone(),
+            group_by: definition.group_by.clone(),
+            aggregates: (0..definition.aggregates.len()).collect(),
+        };
+        AggIndexBuilder::try_create(&definition, &info, schema)
+    }
+}
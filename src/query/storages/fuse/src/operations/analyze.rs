@@ -15,17 +15,31 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use tracing::warn;
 
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
+use crate::statistics::build_column_histogram;
+use crate::statistics::HISTOGRAM_BUCKETS;
+use crate::statistics::HISTOGRAM_MOST_COMMON_VALUES;
 use crate::FuseTable;
 
+/// Max number of blocks read by `ANALYZE TABLE` to build histograms.
+const ANALYZE_SAMPLE_BLOCKS: usize = 16;
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
@@ -50,15 +64,16 @@ impl FuseTable {
             let mut sum_map = HashMap::new();
             let mut row_count_sum = 0;
             let mut block_count_sum: u64 = 0;
+            let mut blocks = Vec::new();
 
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
             let segments = segments_io.read_segments(&snapshot.segments, true).await?;
             for segment in segments {
                 let segment = segment?;
                 segment.blocks.iter().for_each(|block| {
-                    let block = block.as_ref();
                     let row_count = block.row_count;
                     if row_count != 0 {
+                        blocks.push(block.clone());
                         block_count_sum += 1;
                         row_count_sum += row_count;
                         for (i, col_stat) in block.col_stats.iter() {
@@ -85,8 +100,13 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 3. Build histograms from sampled blocks.
+            let histograms = self
+                .build_histograms(ctx, &snapshot.summary, &ndv_map, blocks)
+                .await?;
+
+            // 4. Generate new table statistics
+            let table_statistics = TableSnapshotStatistics::new(ndv_map, histograms);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -94,7 +114,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 5. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.table_statistics_location = Some(table_statistics_location);
             FuseTable::commit_to_meta_server(
@@ -111,4 +131,80 @@ impl FuseTable {
 
         Ok(())
     }
+
+    /// Read a sample of the blocks evenly spread over the table, and build
+    /// equi-depth histograms for the columns whose values can be ordered.
+    #[async_backtrace::framed]
+    async fn build_histograms(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        summary: &Statistics,
+        ndv_map: &HashMap<ColumnId, u64>,
+        blocks: Vec<Arc<BlockMeta>>,
+    ) -> Result<HashMap<ColumnId, ColumnHistogram>> {
+        let schema = self.schema();
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| {
+                matches!(
+                    f.data_type().remove_nullable(),
+                    TableDataType::Number(_)
+                        | TableDataType::String
+                        | TableDataType::Boolean
+                        | TableDataType::Date
+                )
+            })
+            .collect::<Vec<_>>();
+        if fields.is_empty() || blocks.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let step = (blocks.len() + ANALYZE_SAMPLE_BLOCKS - 1) / ANALYZE_SAMPLE_BLOCKS;
+        let sampled_blocks = blocks.iter().step_by(step).collect::<Vec<_>>();
+
+        let projection = Projection::Columns(fields.iter().map(|(i, _)| *i).collect());
+        let block_reader = self.create_block_reader(projection, false, ctx.clone())?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+        let mut samples = vec![Vec::new(); fields.len()];
+        for block in sampled_blocks {
+            let data_block = block_reader
+                .read_by_meta(&settings, block.as_ref(), &self.storage_format)
+                .await?;
+            let num_rows = data_block.num_rows();
+            for (offset, entry) in data_block.columns().iter().enumerate() {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                samples[offset].extend(
+                    column
+                        .iter()
+                        .filter(|v| !matches!(v, ScalarRef::Null))
+                        .map(|v| v.to_owned()),
+                );
+            }
+        }
+
+        let mut histograms = HashMap::new();
+        for ((_, field), samples) in fields.into_iter().zip(samples.into_iter()) {
+            let column_id = field.column_id();
+            let null_count = summary
+                .col_stats
+                .get(&column_id)
+                .map_or(0, |stat| stat.null_count);
+            let num_values = summary.row_count.saturating_sub(null_count);
+            let ndv = ndv_map.get(&column_id).cloned().unwrap_or(num_values);
+            if let Some(histogram) = build_column_histogram(
+                samples,
+                num_values,
+                ndv,
+                HISTOGRAM_BUCKETS,
+                HISTOGRAM_MOST_COMMON_VALUES,
+            ) {
+                histograms.insert(column_id, histogram);
+            }
+        }
+        Ok(histograms)
+    }
 }
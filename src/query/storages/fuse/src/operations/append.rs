@@ -47,6 +47,7 @@ impl FuseTable {
     ) -> Result<()> {
         let block_compact_thresholds = self.get_block_compact_thresholds();
         let write_settings = self.get_write_settings();
+        let agg_indexes = self.agg_index_builders(ctx.clone())?;
 
         match append_mode {
             AppendMode::Normal => {
@@ -114,6 +115,7 @@ impl FuseTable {
                     cluster_stats_gen.clone(),
                     block_compact_thresholds,
                     self.table_info.schema(),
+                    agg_indexes.clone(),
                     Some(transform_output_port),
                 )
             })?;
@@ -128,6 +130,7 @@ impl FuseTable {
                     cluster_stats_gen.clone(),
                     block_compact_thresholds,
                     self.table_info.schema(),
+                    agg_indexes.clone(),
                     None,
                 )
             })?;
@@ -0,0 +1,587 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::Value;
+use futures::TryStreamExt;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::SegmentsIO;
+use crate::io::SnapshotHistoryReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::CHANGE_ACTION_COL_NAME;
+use crate::CHANGE_ACTION_DELETE;
+use crate::CHANGE_ACTION_INSERT;
+use crate::CHANGE_IS_UPDATE_COL_NAME;
+
+/// The blocks changed together between two snapshots of a table, e.g. the blocks
+/// appended, or a block and the block it is rewritten to by a mutation.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct TableChanges {
+    /// The blocks of the current snapshot, which are not in the base snapshot.
+    pub inserted: Vec<Arc<BlockMeta>>,
+    /// The blocks of the base snapshot, which are not in the current snapshot.
+    pub deleted: Vec<Arc<BlockMeta>>,
+}
+
+impl TableChanges {
+    pub fn is_empty(&self) -> bool {
+        self.inserted.is_empty() && self.deleted.is_empty()
+    }
+
+    pub fn row_count(&self) -> u64 {
+        self.inserted
+            .iter()
+            .chain(self.deleted.iter())
+            .map(|block| block.row_count)
+            .sum()
+    }
+
+    pub fn block_size(&self) -> u64 {
+        self.inserted
+            .iter()
+            .chain(self.deleted.iter())
+            .map(|block| block.block_size)
+            .sum()
+    }
+
+    /// Whether the changes are blocks rewritten, whose rows may be unchanged.
+    pub fn is_rewrite(&self) -> bool {
+        !self.inserted.is_empty() && !self.deleted.is_empty()
+    }
+}
+
+impl FuseTable {
+    /// The schema of the changes of the table, the columns of the table followed by
+    /// the action and the update flag of the changed rows.
+    pub fn changes_schema(schema: &TableSchema) -> TableSchema {
+        let mut fields = schema.fields().clone();
+        fields.push(TableField::new(
+            CHANGE_ACTION_COL_NAME,
+            TableDataType::String,
+        ));
+        fields.push(TableField::new(
+            CHANGE_IS_UPDATE_COL_NAME,
+            TableDataType::Boolean,
+        ));
+        TableSchema::new(fields)
+    }
+
+    /// Collect the blocks changed since the snapshot at `base_location`, all the blocks
+    /// of the current snapshot are inserted if there is no base snapshot.
+    ///
+    /// The segments shared by the two snapshots are skipped, then the blocks of the
+    /// remaining segments are diffed by their locations, so the blocks only moved to
+    /// another segment, e.g. by segment compaction, are not changed.
+    ///
+    /// The changed blocks are grouped by the rewrites they come from, which are found
+    /// by diffing each snapshot since the base one with its previous snapshot, see
+    /// `rewritten_blocks`. The other blocks are changed on their own.
+    ///
+    /// The base snapshot and its blocks must not be purged yet.
+    #[async_backtrace::framed]
+    pub async fn collect_changes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        base_location: Option<&str>,
+    ) -> Result<Vec<TableChanges>> {
+        let history = self.segments_history(base_location).await?;
+
+        // Read the blocks of the segments changed by any snapshot.
+        let mut changed_segments = vec![];
+        let mut seen = HashSet::new();
+        for step in history.windows(2) {
+            let (prev, next) = (&step[0], &step[1]);
+            for location in segments_only_in(prev, next)
+                .into_iter()
+                .chain(segments_only_in(next, prev))
+            {
+                if seen.insert(location) {
+                    changed_segments.push(location.clone());
+                }
+            }
+        }
+        let segment_blocks = changed_segments
+            .iter()
+            .cloned()
+            .zip(self.segment_blocks(ctx, &changed_segments).await?)
+            .collect::<HashMap<_, _>>();
+
+        // Union the blocks rewritten together by any snapshot, a block rewritten again
+        // by a later snapshot chains the rewrites.
+        let mut block_ids = HashMap::new();
+        for block in segment_blocks.values().flatten() {
+            let id = block_ids.len();
+            block_ids.entry(&block.location).or_insert(id);
+        }
+        let mut parents = (0..block_ids.len()).collect::<Vec<_>>();
+        for step in history.windows(2) {
+            for group in rewritten_blocks(&step[0], &step[1], &segment_blocks) {
+                let mut ids = group
+                    .deleted
+                    .iter()
+                    .chain(group.inserted.iter())
+                    .map(|block| block_ids[&block.location]);
+                if let Some(first) = ids.next() {
+                    for id in ids {
+                        let (root, other) =
+                            (find_root(&mut parents, first), find_root(&mut parents, id));
+                        parents[other] = root;
+                    }
+                }
+            }
+        }
+
+        let (base_segments, latest_segments) = match (history.first(), history.last()) {
+            (Some(base), Some(latest)) => (base.as_slice(), latest.as_slice()),
+            _ => (&[][..], &[][..]),
+        };
+        let blocks_of = |segments: Vec<&Location>| {
+            segments
+                .into_iter()
+                .flat_map(|location| segment_blocks[location].iter())
+                .collect::<Vec<_>>()
+        };
+        let added_blocks = blocks_of(segments_only_in(latest_segments, base_segments));
+        let removed_blocks = blocks_of(segments_only_in(base_segments, latest_segments));
+        let added_set = added_blocks
+            .iter()
+            .map(|block| &block.location)
+            .collect::<HashSet<_>>();
+        let removed_set = removed_blocks
+            .iter()
+            .map(|block| &block.location)
+            .collect::<HashSet<_>>();
+
+        let mut groups: BTreeMap<usize, TableChanges> = BTreeMap::new();
+        for block in removed_blocks
+            .iter()
+            .filter(|block| !added_set.contains(&block.location))
+        {
+            let root = find_root(&mut parents, block_ids[&block.location]);
+            groups
+                .entry(root)
+                .or_default()
+                .deleted
+                .push((*block).clone());
+        }
+        for block in added_blocks
+            .iter()
+            .filter(|block| !removed_set.contains(&block.location))
+        {
+            let root = find_root(&mut parents, block_ids[&block.location]);
+            groups
+                .entry(root)
+                .or_default()
+                .inserted
+                .push((*block).clone());
+        }
+
+        let mut changes = vec![];
+        for group in groups.into_values() {
+            if group.is_rewrite() {
+                changes.push(group);
+            } else {
+                changes.extend(group.deleted.into_iter().map(|block| TableChanges {
+                    inserted: vec![],
+                    deleted: vec![block],
+                }));
+                changes.extend(group.inserted.into_iter().map(|block| TableChanges {
+                    inserted: vec![block],
+                    deleted: vec![],
+                }));
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Read the rows of the changed blocks, the columns of the table at `projection`
+    /// followed by the action and the update flag of the rows.
+    ///
+    /// The blocks of a rewrite, e.g. by a mutation or a compaction, are both deleted and
+    /// inserted. The rows deleted and inserted with the same values are unchanged, they
+    /// are netted out, so all the columns are read to compare the rows. The remaining
+    /// rows are flagged as updates if the rewrite leaves both deleted and inserted rows,
+    /// i.e. the old and the new values of the updated rows.
+    #[async_backtrace::framed]
+    pub async fn read_changes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        changes: &TableChanges,
+        projection: &[usize],
+    ) -> Result<Vec<DataBlock>> {
+        let is_rewrite = changes.is_rewrite();
+        let read_projection = if is_rewrite || projection.is_empty() {
+            (0..self.schema().num_fields()).collect::<Vec<_>>()
+        } else {
+            projection.to_vec()
+        };
+        let need_projection = read_projection != projection;
+        let block_reader =
+            self.create_block_reader(Projection::Columns(read_projection), false, ctx.clone())?;
+        let read_settings = ReadSettings::from_ctx(&ctx)?;
+
+        let mut deleted_blocks = Vec::with_capacity(changes.deleted.len());
+        for block_meta in &changes.deleted {
+            let block = block_reader
+                .read_by_meta(&read_settings, block_meta, &self.storage_format)
+                .await?;
+            deleted_blocks.push(block);
+        }
+        let mut inserted_blocks = Vec::with_capacity(changes.inserted.len());
+        for block_meta in &changes.inserted {
+            let block = block_reader
+                .read_by_meta(&read_settings, block_meta, &self.storage_format)
+                .await?;
+            inserted_blocks.push(block);
+        }
+
+        let mut deleted_kept = deleted_blocks
+            .iter()
+            .map(|block| MutableBitmap::from_len_set(block.num_rows()))
+            .collect::<Vec<_>>();
+        let mut inserted_kept = inserted_blocks
+            .iter()
+            .map(|block| MutableBitmap::from_len_set(block.num_rows()))
+            .collect::<Vec<_>>();
+
+        // Net out the rows deleted and inserted with the same values.
+        if is_rewrite {
+            let mut deleted_rows: HashMap<Vec<ScalarRef>, Vec<(usize, usize)>> = HashMap::new();
+            for (i, block) in deleted_blocks.iter().enumerate() {
+                for row in 0..block.num_rows() {
+                    deleted_rows
+                        .entry(row_values(block, row))
+                        .or_default()
+                        .push((i, row));
+                }
+            }
+            for (i, block) in inserted_blocks.iter().enumerate() {
+                for row in 0..block.num_rows() {
+                    if let Some(rows) = deleted_rows.get_mut(&row_values(block, row)) {
+                        if let Some((j, deleted_row)) = rows.pop() {
+                            inserted_kept[i].set(row, false);
+                            deleted_kept[j].set(deleted_row, false);
+                        }
+                    }
+                }
+            }
+        }
+
+        let has_rows =
+            |kept: &[MutableBitmap]| kept.iter().any(|kept| kept.unset_bits() < kept.len());
+        let is_update = is_rewrite && has_rows(&deleted_kept) && has_rows(&inserted_kept);
+
+        let mut blocks = Vec::with_capacity(deleted_blocks.len() + inserted_blocks.len());
+        let deleted = deleted_blocks
+            .into_iter()
+            .zip(deleted_kept)
+            .map(|(block, kept)| (CHANGE_ACTION_DELETE, block, kept));
+        let inserted = inserted_blocks
+            .into_iter()
+            .zip(inserted_kept)
+            .map(|(block, kept)| (CHANGE_ACTION_INSERT, block, kept));
+        for (action, block, kept) in deleted.chain(inserted) {
+            let block = block.filter_with_bitmap(&kept.into())?;
+            let num_rows = block.num_rows();
+            if num_rows == 0 {
+                continue;
+            }
+            let mut block = if need_projection {
+                let columns = projection
+                    .iter()
+                    .map(|index| block.get_by_offset(*index).clone())
+                    .collect();
+                DataBlock::new(columns, num_rows)
+            } else {
+                block
+            };
+            block.add_column(BlockEntry {
+                data_type: DataType::String,
+                value: Value::Column(StringType::from_data(vec![action; num_rows])),
+            });
+            block.add_column(BlockEntry {
+                data_type: DataType::Boolean,
+                value: Value::Column(BooleanType::from_data(vec![is_update; num_rows])),
+            });
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+
+    /// The segments of the snapshots from the base snapshot to the latest one. Only the
+    /// base and the latest snapshots are returned if the base snapshot is not found in
+    /// the history of the latest one.
+    #[async_backtrace::framed]
+    async fn segments_history(&self, base_location: Option<&str>) -> Result<Vec<Vec<Location>>> {
+        let latest_segments = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.segments.clone(),
+            None => vec![],
+        };
+        let (base_location, snapshot_location) = match (base_location, self.snapshot_loc().await?) {
+            (Some(base_location), Some(snapshot_location)) => (base_location, snapshot_location),
+            (Some(base_location), None) => {
+                let base_segments = self.read_snapshot_at(base_location).await?.segments.clone();
+                return Ok(vec![base_segments, latest_segments]);
+            }
+            (None, _) => return Ok(vec![vec![], latest_segments]),
+        };
+
+        let snapshot_version = self.snapshot_format_version().await?;
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut snapshot_stream = reader.snapshot_history(
+            snapshot_location,
+            snapshot_version,
+            self.meta_location_generator().clone(),
+        );
+        let mut history = vec![];
+        while let Some(snapshot) = snapshot_stream.try_next().await? {
+            history.push(snapshot.segments.clone());
+            let location = self
+                .meta_location_generator
+                .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version())?;
+            if location == base_location {
+                history.reverse();
+                return Ok(history);
+            }
+        }
+
+        let base_segments = self.read_snapshot_at(base_location).await?.segments.clone();
+        Ok(vec![base_segments, latest_segments])
+    }
+
+    #[async_backtrace::framed]
+    async fn read_snapshot_at(&self, location: &str) -> Result<Arc<TableSnapshot>> {
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: location.to_string(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(location),
+            put_cache: true,
+        };
+        reader.read(&params).await
+    }
+
+    #[async_backtrace::framed]
+    async fn segment_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        segment_locations: &[Location],
+    ) -> Result<Vec<Vec<Arc<BlockMeta>>>> {
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        let segments = segments_io.read_segments(segment_locations, true).await?;
+        segments
+            .into_iter()
+            .map(|segment| Ok(segment?.blocks.clone()))
+            .collect()
+    }
+}
+
+fn row_values(block: &DataBlock, row: usize) -> Vec<ScalarRef> {
+    block
+        .columns()
+        .iter()
+        .map(|entry| entry.value.index(row).unwrap_or_default())
+        .collect()
+}
+
+fn segments_only_in<'a>(segments: &'a [Location], other: &[Location]) -> Vec<&'a Location> {
+    let other = other.iter().collect::<HashSet<_>>();
+    segments
+        .iter()
+        .filter(|location| !other.contains(location))
+        .collect()
+}
+
+fn find_root(parents: &mut [usize], id: usize) -> usize {
+    let mut root = id;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    parents[id] = root;
+    root
+}
+
+/// The blocks rewritten together by the snapshot whose previous snapshot has the
+/// segments `prev`, e.g. by a mutation or a compaction.
+///
+/// A mutation replaces each segment it changes with a new one at the same place,
+/// which keeps the unchanged blocks and has the rewritten blocks at the places of the
+/// old ones, and a compaction merges the adjacent segments or blocks. So the segments
+/// between the same unchanged segments are rewritten together, one by one if their
+/// numbers are equal, and so are the blocks of them between the same unchanged blocks.
+fn rewritten_blocks(
+    prev: &[Location],
+    next: &[Location],
+    segment_blocks: &HashMap<Location, Vec<Arc<BlockMeta>>>,
+) -> Vec<TableChanges> {
+    let blocks_of = |segments: &[&Location]| {
+        segments
+            .iter()
+            .flat_map(|location| segment_blocks[*location].iter().cloned())
+            .collect::<Vec<_>>()
+    };
+    let mut groups = vec![];
+    match align_runs(prev, next, |location| location) {
+        Some(runs) => {
+            for (old, new) in runs {
+                if old.len() == new.len() {
+                    for (old, new) in old.iter().zip(new.iter()) {
+                        groups.extend(diff_blocks(&segment_blocks[*old], &segment_blocks[*new]));
+                    }
+                } else {
+                    groups.extend(diff_blocks(&blocks_of(&old), &blocks_of(&new)));
+                }
+            }
+        }
+        None => groups.extend(diff_blocks(
+            &blocks_of(&segments_only_in(prev, next)),
+            &blocks_of(&segments_only_in(next, prev)),
+        )),
+    }
+
+    // The blocks moved to another segment are not rewritten.
+    let locations = |segments: Vec<&Location>| {
+        segments
+            .into_iter()
+            .flat_map(|location| segment_blocks[location].iter().map(|block| &block.location))
+            .collect::<HashSet<_>>()
+    };
+    let removed = locations(segments_only_in(prev, next));
+    let added = locations(segments_only_in(next, prev));
+    for group in groups.iter_mut() {
+        group
+            .deleted
+            .retain(|block| !added.contains(&block.location));
+        group
+            .inserted
+            .retain(|block| !removed.contains(&block.location));
+    }
+    groups.retain(|group| group.is_rewrite());
+    groups
+}
+
+// Diff the blocks of the old segments and the new segments replacing them.
+fn diff_blocks(old: &[Arc<BlockMeta>], new: &[Arc<BlockMeta>]) -> Vec<TableChanges> {
+    match align_runs(old, new, |block| &block.location) {
+        Some(runs) => runs
+            .into_iter()
+            .flat_map(|(old, new)| {
+                if old.len() == new.len() {
+                    old.into_iter()
+                        .zip(new)
+                        .map(|(old, new)| TableChanges {
+                            inserted: vec![new.clone()],
+                            deleted: vec![old.clone()],
+                        })
+                        .collect()
+                } else {
+                    vec![TableChanges {
+                        inserted: new.into_iter().cloned().collect(),
+                        deleted: old.into_iter().cloned().collect(),
+                    }]
+                }
+            })
+            .collect(),
+        None => {
+            let old_locations = old
+                .iter()
+                .map(|block| &block.location)
+                .collect::<HashSet<_>>();
+            let new_locations = new
+                .iter()
+                .map(|block| &block.location)
+                .collect::<HashSet<_>>();
+            vec![TableChanges {
+                inserted: new
+                    .iter()
+                    .filter(|block| !old_locations.contains(&block.location))
+                    .cloned()
+                    .collect(),
+                deleted: old
+                    .iter()
+                    .filter(|block| !new_locations.contains(&block.location))
+                    .cloned()
+                    .collect(),
+            }]
+        }
+    }
+}
+
+/// Align the old and the new items by the items kept in both, the items between the
+/// same kept items are the runs of the old items replaced by the new ones. `None` if
+/// the kept items are reordered.
+fn align_runs<'a, T, K, F>(
+    old: &'a [T],
+    new: &'a [T],
+    key: F,
+) -> Option<Vec<(Vec<&'a T>, Vec<&'a T>)>>
+where
+    K: Eq + Hash + ?Sized,
+    F: Fn(&T) -> &K,
+{
+    let old_keys = old.iter().map(&key).collect::<HashSet<_>>();
+    let new_keys = new.iter().map(&key).collect::<HashSet<_>>();
+    let kept_old = old.iter().map(&key).filter(|k| new_keys.contains(k));
+    let kept_new = new.iter().map(&key).filter(|k| old_keys.contains(k));
+    if !kept_old.eq(kept_new) {
+        return None;
+    }
+
+    let mut runs = vec![];
+    let mut run = (vec![], vec![]);
+    let mut new_items = new.iter();
+    for item in old {
+        if new_keys.contains(key(item)) {
+            for new_item in new_items.by_ref() {
+                if key(new_item) == key(item) {
+                    break;
+                }
+                run.1.push(new_item);
+            }
+            runs.push(std::mem::take(&mut run));
+        } else {
+            run.0.push(item);
+        }
+    }
+    run.1.extend(new_items);
+    runs.push(run);
+    runs.retain(|(old, new)| !old.is_empty() || !new.is_empty());
+    Some(runs)
+}
@@ -0,0 +1,330 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_catalog::catalog::Catalog;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::CloneSnapshots;
+use storages_common_table_meta::table::OPT_KEY_BASE_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_CLONED_FROM;
+use storages_common_table_meta::table::OPT_KEY_CLONE_PINNED_SNAPSHOTS;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Build the meta of a table cloned from this table, in the database of `db_id`.
+    ///
+    /// The clone starts from the current snapshot of this table, which is recorded in
+    /// the option `cloned_from` of the clone, along with the snapshots this table
+    /// shares with the tables it is cloned from.
+    #[async_backtrace::framed]
+    pub async fn build_clone_table_meta(&self, db_id: u64) -> Result<TableMeta> {
+        let mut cloned_from = self.clone_snapshots(OPT_KEY_CLONED_FROM)?;
+        if let Some(location) = self.snapshot_loc().await? {
+            cloned_from
+                .snapshots
+                .insert(self.table_info.ident.table_id, location);
+        }
+
+        let mut meta = self.table_info.meta.clone();
+        meta.options.retain(|key, _| {
+            !matches!(
+                key.as_str(),
+                OPT_KEY_SNAPSHOT_LOCATION
+                    | OPT_KEY_LEGACY_SNAPSHOT_LOC
+                    | OPT_KEY_BASE_SNAPSHOT_LOCATION
+                    | OPT_KEY_CLONED_FROM
+                    | OPT_KEY_CLONE_PINNED_SNAPSHOTS
+            )
+        });
+        meta.options
+            .insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+        if !cloned_from.snapshots.is_empty() {
+            meta.options
+                .insert(OPT_KEY_CLONED_FROM.to_owned(), cloned_from.to_string());
+        }
+        meta.created_on = Utc::now();
+        meta.updated_on = Utc::now();
+        meta.drop_on = None;
+        meta.shared_by = BTreeSet::new();
+        // set while the first snapshot of the clone is committed
+        meta.statistics = Default::default();
+        Ok(meta)
+    }
+
+    /// Make the newly created `clone_table` start from the snapshot of this table
+    /// recorded in its `cloned_from`, without copying any data file.
+    ///
+    /// The shared snapshots are pinned on this table and the tables it is cloned from
+    /// first, so that the files shared with the clone are kept by their purge. As the
+    /// shared snapshot might be purged before it is pinned, it is checked again under
+    /// the pin. Then the first snapshot of the clone is committed, which references the
+    /// segments of the shared snapshot.
+    #[async_backtrace::framed]
+    pub async fn do_clone(&self, ctx: &dyn TableContext, clone_table: &FuseTable) -> Result<()> {
+        let cloned_from = clone_table.clone_snapshots(OPT_KEY_CLONED_FROM)?;
+        let source_snapshot_location =
+            match cloned_from.snapshots.get(&self.table_info.ident.table_id) {
+                Some(location) => location.clone(),
+                // this table has no data, nothing to share
+                None => return Ok(()),
+            };
+
+        let clone_table_id = clone_table.table_info.ident.table_id;
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog)?;
+        for (table_id, location) in &cloned_from.snapshots {
+            Self::pin_clone_snapshot(catalog.as_ref(), *table_id, clone_table_id, location).await?;
+        }
+        if let Err(e) = self.check_clone_snapshot(&source_snapshot_location).await {
+            for table_id in cloned_from.snapshots.keys() {
+                Self::unpin_clone_snapshots(catalog.as_ref(), *table_id, &[clone_table_id]).await?;
+            }
+            return Err(e);
+        }
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            ver: TableMetaLocationGenerator::snapshot_version(&source_snapshot_location),
+            location: source_snapshot_location,
+            len_hint: None,
+            put_cache: true,
+        };
+        let source_snapshot = reader.read(&params).await?;
+        let snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            source_snapshot.schema.clone(),
+            source_snapshot.summary.clone(),
+            source_snapshot.segments.clone(),
+            source_snapshot.cluster_key_meta.clone(),
+            None,
+        );
+
+        FuseTable::commit_to_meta_server(
+            ctx,
+            &clone_table.table_info,
+            &clone_table.meta_location_generator,
+            snapshot,
+            None,
+            &None,
+            &clone_table.operator,
+        )
+        .await
+    }
+
+    /// Read the snapshots of this table that are shared with the existing clones.
+    ///
+    /// A snapshot is unpinned once the clone is gone from the meta, the dropped but
+    /// not yet collected clones might still be undropped.
+    #[async_backtrace::framed]
+    pub(super) async fn read_clone_pinned_snapshots(
+        &self,
+        ctx: &dyn TableContext,
+    ) -> Result<Vec<Arc<TableSnapshot>>> {
+        let pinned = self.clone_snapshots(OPT_KEY_CLONE_PINNED_SNAPSHOTS)?;
+        if pinned.snapshots.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog)?;
+        let mut locations = HashSet::new();
+        let mut gone_clone_table_ids = vec![];
+        for (clone_table_id, location) in pinned.snapshots {
+            match catalog.get_table_meta_by_id(clone_table_id).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => {
+                    gone_clone_table_ids.push(clone_table_id);
+                }
+                Err(e) => return Err(e),
+                Ok(_) => {
+                    locations.insert(location);
+                }
+            }
+        }
+        if !gone_clone_table_ids.is_empty() {
+            Self::unpin_clone_snapshots(
+                catalog.as_ref(),
+                self.table_info.ident.table_id,
+                &gone_clone_table_ids,
+            )
+            .await?;
+        }
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut snapshots = Vec::with_capacity(locations.len());
+        for location in locations {
+            let params = LoadParams {
+                ver: TableMetaLocationGenerator::snapshot_version(&location),
+                location,
+                len_hint: None,
+                put_cache: false,
+            };
+            snapshots.push(reader.read(&params).await?);
+        }
+        Ok(snapshots)
+    }
+
+    fn clone_snapshots(&self, key: &str) -> Result<CloneSnapshots> {
+        match self.table_info.options().get(key) {
+            Some(snapshots) => CloneSnapshots::try_from(snapshots.as_str()),
+            None => Ok(CloneSnapshots::default()),
+        }
+    }
+
+    // Check that the shared snapshot of `location` and its segments are not purged.
+    //
+    // The purge removes the blocks and the segments before the snapshots, the blocks
+    // of the snapshot are kept if the snapshot and its segments are there.
+    #[async_backtrace::framed]
+    async fn check_clone_snapshot(&self, location: &str) -> Result<()> {
+        let purged = || {
+            ErrorCode::StorageNotFound(format!(
+                "snapshot {} of table {} is purged while being cloned",
+                location, self.table_info.desc
+            ))
+        };
+        if !self.operator.is_exist(location).await? {
+            return Err(purged());
+        }
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            ver: TableMetaLocationGenerator::snapshot_version(location),
+            location: location.to_string(),
+            len_hint: None,
+            put_cache: false,
+        };
+        let snapshot = reader.read(&params).await?;
+        for (segment_location, _) in &snapshot.segments {
+            if !self.operator.is_exist(segment_location).await? {
+                return Err(purged());
+            }
+        }
+        Ok(())
+    }
+
+    // Record on the table of `table_id` that its snapshot of `location` is shared with
+    // the clone of `clone_table_id`.
+    #[async_backtrace::framed]
+    async fn pin_clone_snapshot(
+        catalog: &dyn Catalog,
+        table_id: u64,
+        clone_table_id: u64,
+        location: &str,
+    ) -> Result<()> {
+        Self::update_clone_pinned_snapshots(catalog, table_id, |pinned| {
+            pinned
+                .snapshots
+                .insert(clone_table_id, location.to_string());
+        })
+        .await
+    }
+
+    // Remove the snapshots of the table of `table_id` pinned by the clones of
+    // `clone_table_ids`.
+    #[async_backtrace::framed]
+    async fn unpin_clone_snapshots(
+        catalog: &dyn Catalog,
+        table_id: u64,
+        clone_table_ids: &[u64],
+    ) -> Result<()> {
+        Self::update_clone_pinned_snapshots(catalog, table_id, |pinned| {
+            for clone_table_id in clone_table_ids {
+                pinned.snapshots.remove(clone_table_id);
+            }
+        })
+        .await
+    }
+
+    #[async_backtrace::framed]
+    async fn update_clone_pinned_snapshots<F>(
+        catalog: &dyn Catalog,
+        table_id: u64,
+        update: F,
+    ) -> Result<()>
+    where
+        F: Fn(&mut CloneSnapshots),
+    {
+        loop {
+            let (ident, meta) = match catalog.get_table_meta_by_id(table_id).await {
+                // the table and all its files are gone, nothing is pinned
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => return Ok(()),
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+
+            let mut new_table_meta = meta.as_ref().clone();
+            let mut pinned = match new_table_meta.options.get(OPT_KEY_CLONE_PINNED_SNAPSHOTS) {
+                Some(snapshots) => CloneSnapshots::try_from(snapshots.as_str())?,
+                None => CloneSnapshots::default(),
+            };
+            update(&mut pinned);
+            if pinned.snapshots.is_empty() {
+                new_table_meta
+                    .options
+                    .remove(OPT_KEY_CLONE_PINNED_SNAPSHOTS);
+            } else {
+                new_table_meta.options.insert(
+                    OPT_KEY_CLONE_PINNED_SNAPSHOTS.to_owned(),
+                    pinned.to_string(),
+                );
+            }
+            if new_table_meta.options == meta.options {
+                return Ok(());
+            }
+
+            let table_info = TableInfo {
+                ident: ident.clone(),
+                desc: format!("table id {}", table_id),
+                meta: meta.as_ref().clone(),
+                ..Default::default()
+            };
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(ident.seq),
+                new_table_meta,
+                copied_files: None,
+            };
+            match catalog.update_table_meta(&table_info, req).await {
+                Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                    info!(
+                        "table {} changed while updating the snapshots pinned by its clones, retry",
+                        table_id
+                    );
+                    continue;
+                }
+                Err(e) => return Err(e),
+                Ok(_) => return Ok(()),
+            }
+        }
+    }
+}
@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::collections::BTreeMap;
 use std::ops::Range;
 use std::sync::Arc;
 use std::time::Duration;
@@ -199,6 +200,31 @@ impl FuseTable {
         }
     }
 
+    /// Commit the appended data along with the new table options, which must be
+    /// updated atomically with the data, e.g. the refresh state of a materialized view.
+    ///
+    /// Unlike `do_commit`, the commit is not retried on conflicts, since the new
+    /// options are derived from the version of the table being committed to.
+    #[async_backtrace::framed]
+    pub async fn commit_with_options(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        operation_log: TableOperationLog,
+        options: BTreeMap<String, String>,
+        overwrite: bool,
+    ) -> Result<()> {
+        let mut tbl = self.clone();
+        tbl.table_info.meta.options.extend(options);
+        match tbl.try_commit(ctx, &operation_log, &None, overwrite).await {
+            Err(e) if no_side_effects_in_meta_store(&e) => {
+                info!("aborting operations");
+                let _ = utils::abort_operations(self.get_operator(), operation_log).await;
+                Err(e)
+            }
+            res => res,
+        }
+    }
+
     #[inline]
     #[async_backtrace::framed]
     pub async fn try_commit<'a>(
@@ -408,7 +434,23 @@ impl FuseTable {
             copied_files: copied_files.clone(),
         };
 
-        // 3. let's roll
+        // 3. in an explicit transaction, the request is buffered and committed together
+        // with the other tables updated in the transaction. the last snapshot hint is not
+        // written, since the snapshot is not committed yet.
+        let txn_mgr = ctx.txn_mgr();
+        if txn_mgr.lock().is_active() {
+            txn_mgr.lock().update_table_meta(table_info, req)?;
+            if let Some(snapshot_statistics) = table_statistics {
+                if let Some(location) = &snapshot.table_statistics_location {
+                    TableSnapshotStatistics::cache()
+                        .put(location.clone(), Arc::new(snapshot_statistics));
+                }
+            }
+            TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+            return Ok(());
+        }
+
+        // 4. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
@@ -729,6 +771,9 @@ mod utils {
                 if let Some(index) = &block.bloom_filter_index_location {
                     let _ = operator.delete(&index.0).await;
                 }
+                if let Some(index) = &block.inverted_index_location {
+                    let _ = operator.delete(&index.0).await;
+                }
             }
             let _ = operator.delete(&entry.segment_location).await;
         }
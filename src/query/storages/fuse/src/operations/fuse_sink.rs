@@ -40,6 +40,7 @@ use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::table::TableCompression;
 
+use super::AggIndexBuilder;
 use super::AppendOperationLogEntry;
 use crate::io;
 use crate::io::TableMetaLocationGenerator;
@@ -71,12 +72,18 @@ impl BloomIndexState {
         source_schema: TableSchemaRef,
         block: &DataBlock,
         location: Location,
+        bloom_column_ids: Option<&[ColumnId]>,
+        ngram_column_ids: &[ColumnId],
     ) -> Result<Option<Self>> {
         // write index
-        let maybe_bloom_index =
-            BloomIndex::try_create(ctx.get_function_context()?, source_schema, location.1, &[
-                block,
-            ])?;
+        let maybe_bloom_index = BloomIndex::try_create(
+            ctx.get_function_context()?,
+            source_schema,
+            location.1,
+            &[block],
+            bloom_column_ids,
+            ngram_column_ids,
+        )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
             let filter_schema = bloom_index.filter_schema;
@@ -101,6 +108,35 @@ impl BloomIndexState {
     }
 }
 
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        source_schema: &TableSchemaRef,
+        column_ids: &[ColumnId],
+        block: &DataBlock,
+        location: Location,
+    ) -> Result<Option<Self>> {
+        if column_ids.is_empty() {
+            return Ok(None);
+        }
+        if let Some(inverted_index) = InvertedIndex::try_create(source_schema, column_ids, block)? {
+            let data = inverted_index.to_bytes()?;
+            Ok(Some(Self {
+                size: data.len() as u64,
+                data,
+                location,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 enum State {
     None,
     NeedSerialize(DataBlock),
@@ -110,6 +146,9 @@ enum State {
         meta_data: HashMap<ColumnId, ColumnMeta>,
         block_statistics: BlockStatistics,
         bloom_index_state: Option<BloomIndexState>,
+        inverted_index_state: Option<InvertedIndexState>,
+        // (location, data) of the aggregating index files
+        agg_index_files: Vec<(String, Vec<u8>)>,
     },
     GenerateSegment,
     SerializedSegment {
@@ -135,6 +174,7 @@ pub struct FuseTableSink {
 
     source_schema: TableSchemaRef,
     write_settings: WriteSettings,
+    agg_indexes: Vec<AggIndexBuilder>,
     // A dummy output port for distributed insert select to connect Exchange Sink.
     output: Option<Arc<OutputPort>>,
 }
@@ -150,6 +190,7 @@ impl FuseTableSink {
         cluster_stats_gen: ClusterStatsGenerator,
         thresholds: BlockThresholds,
         source_schema: TableSchemaRef,
+        agg_indexes: Vec<AggIndexBuilder>,
         output: Option<Arc<OutputPort>>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(FuseTableSink {
@@ -162,6 +203,7 @@ impl FuseTableSink {
             write_settings,
             cluster_stats_gen,
             source_schema,
+            agg_indexes,
             output,
         })))
     }
@@ -227,10 +269,29 @@ impl Processor for FuseTableSink {
                     self.source_schema.clone(),
                     &block,
                     location,
+                    self.write_settings.bloom_index_columns.as_deref(),
+                    &self.write_settings.ngram_index_columns,
                 )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
                     .map(|i| i.column_distinct_count.clone());
+                let location = self.meta_locations.block_inverted_index_location(&block_id);
+                let inverted_index_state = InvertedIndexState::try_create(
+                    &self.source_schema,
+                    &self.write_settings.inverted_index_columns,
+                    &block,
+                    location,
+                )?;
+                let agg_index_files = self
+                    .agg_indexes
+                    .iter()
+                    .map(|index| {
+                        let location = self
+                            .meta_locations
+                            .block_agg_index_location(&index.query, &block_location.0);
+                        Ok((location, index.serialize(&block)?))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
                 let block_statistics = BlockStatistics::from(
                     &block,
                     block_location.0,
@@ -254,6 +315,8 @@ impl Processor for FuseTableSink {
                     block_statistics,
                     meta_data,
                     bloom_index_state,
+                    inverted_index_state,
+                    agg_index_files,
                 };
             }
             State::GenerateSegment => {
@@ -303,6 +366,8 @@ impl Processor for FuseTableSink {
                 meta_data,
                 block_statistics,
                 bloom_index_state,
+                inverted_index_state,
+                agg_index_files,
             } => {
                 let start = Instant::now();
 
@@ -341,6 +406,21 @@ impl Processor for FuseTableSink {
                     }
                 }
 
+                // write inverted index
+                if let Some(ref inverted_index_state) = inverted_index_state {
+                    io::write_data(
+                        inverted_index_state.data.clone(),
+                        &self.data_accessor,
+                        &inverted_index_state.location.0,
+                    )
+                    .await?;
+                }
+
+                // write aggregating indexes
+                for (location, data) in agg_index_files {
+                    io::write_data(data, &self.data_accessor, &location).await?;
+                }
+
                 let (bloom_index_location, bloom_index_size) =
                     if let Some(bloom_index_state) = bloom_index_state {
                         (
@@ -351,6 +431,11 @@ impl Processor for FuseTableSink {
                         (None, 0u64)
                     };
 
+                let (inverted_index_location, inverted_index_size) = match inverted_index_state {
+                    Some(state) => (Some(state.location), state.size),
+                    None => (None, 0u64),
+                };
+
                 self.accumulator.add_block(
                     size,
                     meta_data,
@@ -358,6 +443,8 @@ impl Processor for FuseTableSink {
                     bloom_index_location,
                     bloom_index_size,
                     self.write_settings.table_compression.into(),
+                    inverted_index_location,
+                    inverted_index_size,
                 )?;
 
                 if self.accumulator.summary_block_count >= self.write_settings.block_per_seg as u64
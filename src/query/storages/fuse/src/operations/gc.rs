@@ -24,6 +24,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::TableIndexType;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
 use storages_common_index::BloomIndexMeta;
@@ -43,24 +44,30 @@ use crate::io::SnapshotsIO;
 use crate::FuseTable;
 
 #[derive(Default)]
-struct LocationTuple {
-    block_location: HashSet<String>,
-    bloom_location: HashSet<String>,
+pub(super) struct LocationTuple {
+    pub(super) block_location: HashSet<String>,
+    pub(super) bloom_location: HashSet<String>,
+    pub(super) inverted_index_location: HashSet<String>,
 }
 
 impl From<Arc<SegmentInfo>> for LocationTuple {
     fn from(value: Arc<SegmentInfo>) -> Self {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut inverted_index_location = HashSet::new();
         for block_meta in &value.blocks {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(inverted_index_loc) = &block_meta.inverted_index_location {
+                inverted_index_location.insert(inverted_index_loc.0.clone());
+            }
         }
         Self {
             block_location,
             bloom_location,
+            inverted_index_location,
         }
     }
 }
@@ -106,6 +113,25 @@ impl FuseTable {
                 (SnapshotId::new_v4(), None, None)
             };
 
+        // The snapshots shared with the clones of this table, and the files referenced by
+        // them, are kept.
+        let pinned_snapshots = self.read_clone_pinned_snapshots(ctx.as_ref()).await?;
+        let pinned_snapshot_ids: HashSet<SnapshotId> = pinned_snapshots
+            .iter()
+            .map(|snapshot| snapshot.snapshot_id)
+            .collect();
+        let segments_pinned: HashSet<Location> = pinned_snapshots
+            .iter()
+            .flat_map(|snapshot| snapshot.segments.iter().cloned())
+            .collect();
+        let locations_pinned = self
+            .get_block_locations(
+                ctx.clone(),
+                &Vec::from_iter(segments_pinned.iter().cloned()),
+                false,
+            )
+            .await?;
+
         // 2. Get all snapshot(including root snapshot).
         let mut chained_snapshots = vec![];
         let mut all_segment_locations = HashSet::new();
@@ -186,6 +212,9 @@ impl FuseTable {
                 if keep_last_snapshot && snapshot.snapshot_id == root_snapshot_id {
                     continue;
                 }
+                if pinned_snapshot_ids.contains(&snapshot.snapshot_id) {
+                    continue;
+                }
                 snapshots_to_be_purged.insert((snapshot.snapshot_id, snapshot.format_version));
             }
         }
@@ -198,6 +227,9 @@ impl FuseTable {
                         continue;
                     }
                 }
+                if segments_pinned.contains(segment) {
+                    continue;
+                }
                 segments_to_be_purged.insert(segment.clone());
             }
         }
@@ -240,24 +272,30 @@ impl FuseTable {
                 {
                     let mut block_locations_to_be_purged = HashSet::new();
                     for loc in &locations.block_location {
-                        if keep_last_snapshot
-                            && locations_referenced_by_root.block_location.contains(loc)
+                        if (keep_last_snapshot
+                            && locations_referenced_by_root.block_location.contains(loc))
+                            || locations_pinned.block_location.contains(loc)
                         {
                             continue;
                         }
                         block_locations_to_be_purged.insert(loc.to_string());
                     }
                     status_block_to_be_purged_count += block_locations_to_be_purged.len();
+                    let agg_index_locations_to_be_purged =
+                        self.agg_index_locations(&block_locations_to_be_purged);
                     self.try_purge_location_files(ctx.clone(), block_locations_to_be_purged)
                         .await?;
+                    self.try_purge_location_files(ctx.clone(), agg_index_locations_to_be_purged)
+                        .await?;
                 }
 
                 // 2. Try to purge bloom index file chunks.
                 {
                     let mut bloom_locations_to_be_purged = HashSet::new();
                     for loc in &locations.bloom_location {
-                        if keep_last_snapshot
-                            && locations_referenced_by_root.bloom_location.contains(loc)
+                        if (keep_last_snapshot
+                            && locations_referenced_by_root.bloom_location.contains(loc))
+                            || locations_pinned.bloom_location.contains(loc)
                         {
                             continue;
                         }
@@ -271,7 +309,28 @@ impl FuseTable {
                     .await?;
                 }
 
-                // 3. Try to purge segment file chunks.
+                // 3. Try to purge inverted index file chunks.
+                {
+                    let mut inverted_index_locations_to_be_purged = HashSet::new();
+                    for loc in &locations.inverted_index_location {
+                        if (keep_last_snapshot
+                            && locations_referenced_by_root
+                                .inverted_index_location
+                                .contains(loc))
+                            || locations_pinned.inverted_index_location.contains(loc)
+                        {
+                            continue;
+                        }
+                        inverted_index_locations_to_be_purged.insert(loc.to_string());
+                    }
+                    self.try_purge_location_files(
+                        ctx.clone(),
+                        inverted_index_locations_to_be_purged,
+                    )
+                    .await?;
+                }
+
+                // 4. Try to purge segment file chunks.
                 {
                     let segment_locations_to_be_purged = HashSet::from_iter(
                         chunk
@@ -313,6 +372,7 @@ impl FuseTable {
                 snapshots_to_be_purged.into_iter().chain(
                     orphan_snapshots
                         .into_iter()
+                        .filter(|lite| !pinned_snapshot_ids.contains(&lite.snapshot_id))
                         .map(|lite| (lite.snapshot_id, lite.format_version)),
                 ),
             );
@@ -412,6 +472,23 @@ impl FuseTable {
         segment_with_refer_index.into_keys().collect()
     }
 
+    // The aggregating index files of the blocks, the files of the dropped indexes are
+    // left to VACUUM.
+    fn agg_index_locations(&self, block_locations: &HashSet<String>) -> HashSet<String> {
+        self.table_info
+            .meta
+            .indexes
+            .values()
+            .filter(|index| index.index_type == TableIndexType::Aggregating)
+            .flat_map(|index| {
+                block_locations.iter().map(|location| {
+                    self.meta_location_generator
+                        .block_agg_index_location(&index.query, location)
+                })
+            })
+            .collect()
+    }
+
     // Purge file by location chunks.
     #[async_backtrace::framed]
     async fn try_purge_location_files(
@@ -419,8 +496,15 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         locations_to_be_purged: HashSet<String>,
     ) -> Result<()> {
+        // A clone references the files of the tables it is cloned from, which are left
+        // to the purge of their own tables.
+        let prefix = format!("{}/", self.meta_location_generator.prefix());
         let fuse_file = Files::create(ctx.clone(), self.operator.clone());
-        let locations = Vec::from_iter(locations_to_be_purged);
+        let locations = Vec::from_iter(
+            locations_to_be_purged
+                .into_iter()
+                .filter(|location| location.starts_with(&prefix)),
+        );
         fuse_file.remove_file_in_batch(&locations).await
     }
 
@@ -444,7 +528,7 @@ impl FuseTable {
     }
 
     #[async_backtrace::framed]
-    async fn get_block_locations(
+    pub(super) async fn get_block_locations(
         &self,
         ctx: Arc<dyn TableContext>,
         segment_locations: &[Location],
@@ -452,6 +536,7 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut inverted_indexes = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let results = fuse_segments
@@ -473,11 +558,13 @@ impl FuseTable {
             };
             blocks.extend(location_tuple.block_location.into_iter());
             blooms.extend(location_tuple.bloom_location.into_iter());
+            inverted_indexes.extend(location_tuple.inverted_index_location.into_iter());
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            inverted_index_location: inverted_indexes,
         })
     }
 }
@@ -0,0 +1,68 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::MergeIntoDesc;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_pipeline_core::pipe::Pipe;
+
+use crate::io::ReadSettings;
+use crate::operations::merge_into::MergeMutator;
+use crate::pipelines::Pipeline;
+use crate::FuseTable;
+
+impl FuseTable {
+    // The pipeline going to be constructed
+    //
+    // ┌─────────────┐      ┌───────────────────┐      ┌──────────────┐      ┌───────────────────────┐      ┌──────────────┐
+    // │ MergeSource ├─────►│ResizeProcessor(1) ├─────►│ MergeMutator ├─────►│TableMutationAggregator├─────►│  CommitSink  │
+    // └─────────────┘      └───────────────────┘      └──────────────┘      └───────────────────────┘      └──────────────┘
+    //
+    // The MergeMutator joins all the rows of the source with the blocks of the base snapshot,
+    // replaces the blocks changed by the WHEN MATCHED clauses, and appends the rows inserted
+    // by the WHEN NOT MATCHED clauses.
+    #[async_backtrace::framed]
+    pub async fn build_merge_into_pipeline<'a>(
+        &'a self,
+        ctx: Arc<dyn TableContext>,
+        merge_into: MergeIntoDesc,
+        pipeline: &'a mut Pipeline,
+    ) -> Result<()> {
+        // 1. resize input to 1, since the source rows need to be joined with the table "globally"
+        pipeline.resize(1)?;
+
+        // 2. connect with MergeMutator
+        let base_snapshot = self
+            .read_table_snapshot()
+            .await?
+            .unwrap_or_else(|| Arc::new(self.new_empty_snapshot()));
+        let merge_mutator = MergeMutator::try_create(
+            ctx.clone(),
+            merge_into,
+            base_snapshot.clone(),
+            self.operator.clone(),
+            self.table_info.schema(),
+            self.get_write_settings(),
+            ReadSettings::from_ctx(&ctx)?,
+            self.create_append_transform(ctx.clone()),
+        )?;
+        pipeline.add_pipe(Pipe::create(1, 1, vec![merge_mutator.into_pipe_item()]));
+
+        // 3. connect with mutation pipes, the TableMutationAggregator, then CommitSink
+        self.chain_mutation_pipes(&ctx, pipeline, base_snapshot)
+            .await
+    }
+}
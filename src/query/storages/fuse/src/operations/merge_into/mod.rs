@@ -24,5 +24,6 @@ pub use processors::AppendTransform;
 pub use processors::BroadcastProcessor;
 pub use processors::CommitSink;
 pub use processors::MergeIntoOperationAggregator;
+pub use processors::MergeMutator;
 pub use processors::OnConflictField;
 pub use processors::TableMutationAggregator;
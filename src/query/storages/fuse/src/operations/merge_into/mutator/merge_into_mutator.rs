@@ -303,6 +303,9 @@ impl MergeIntoOperationAggregator {
         if let Some(index_state) = serialized.bloom_index_state {
             write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
         }
+        if let Some(index_state) = serialized.inverted_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
+        }
 
         // generate log
         let mutation = ReplacementLogEntry {
@@ -0,0 +1,703 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_base::base::ProgressValues;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::plan::MergeIntoDesc;
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::filter_helper::FilterHelpers;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableSchema;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransform;
+use opendal::Operator;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::meta::TableSnapshot;
+use tracing::info;
+
+use crate::io::write_data;
+use crate::io::BlockReader;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::SegmentInfoReader;
+use crate::io::WriteSettings;
+use crate::operations::merge_into::mutation_meta::mutation_log::BlockMetaIndex;
+use crate::operations::merge_into::mutation_meta::mutation_log::MutationLogEntry;
+use crate::operations::merge_into::mutation_meta::mutation_log::MutationLogs;
+use crate::operations::merge_into::mutation_meta::mutation_log::Replacement;
+use crate::operations::merge_into::mutation_meta::mutation_log::ReplacementLogEntry;
+use crate::operations::merge_into::AppendTransform;
+use crate::operations::mutation::base_mutator::BlockIndex;
+use crate::operations::mutation::base_mutator::SegmentIndex;
+
+struct MatchedAction {
+    condition: Option<Expr>,
+    // None means DELETE
+    update_list: Option<Vec<(usize, Expr)>>,
+}
+
+struct UnmatchedAction {
+    condition: Option<Expr>,
+    values: Vec<Expr>,
+}
+
+// The (target row, source row) pairs of a block which satisfy the join condition.
+struct MatchedBlock {
+    segment_index: SegmentIndex,
+    block_index: BlockIndex,
+    block_meta: Arc<BlockMeta>,
+    target_indexes: Vec<u32>,
+    source_indexes: Vec<u32>,
+}
+
+// Applies a MERGE INTO statement to the blocks of a snapshot.
+//
+// The rows of the source are accumulated and hashed by the join keys, then each
+// block of the target table is joined with them. The blocks whose key statistics are
+// out of the range of the source keys are skipped. All the blocks are joined before
+// any of them is rewritten, so that a target row matching more than one source row
+// fails the statement without writing anything. The matched rows are updated or
+// deleted by the WHEN MATCHED clauses, and the source rows that matched nothing are
+// inserted by the WHEN NOT MATCHED clauses.
+pub struct MergeMutator {
+    func_ctx: FunctionContext,
+    target_keys: Vec<Expr>,
+    source_keys: Vec<Expr>,
+    // The column id of each target key which is a column of the table, used to prune the blocks.
+    key_column_ids: Vec<Option<ColumnId>>,
+    join_condition: Expr,
+    matched: Vec<MatchedAction>,
+    unmatched: Vec<UnmatchedAction>,
+
+    base_snapshot: Arc<TableSnapshot>,
+    field_types: Vec<DataType>,
+    block_reader: Arc<BlockReader>,
+    segment_reader: SegmentInfoReader,
+    data_accessor: Operator,
+    write_settings: WriteSettings,
+    read_settings: ReadSettings,
+    append_transform: AppendTransform,
+
+    source_blocks: Vec<DataBlock>,
+}
+
+impl MergeMutator {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        merge_into: MergeIntoDesc,
+        base_snapshot: Arc<TableSnapshot>,
+        data_accessor: Operator,
+        table_schema: Arc<TableSchema>,
+        write_settings: WriteSettings,
+        read_settings: ReadSettings,
+        append_transform: AppendTransform,
+    ) -> Result<Self> {
+        if merge_into.target_keys.is_empty() {
+            return Err(ErrorCode::Internal(
+                "MERGE INTO must have at least one equi-join key",
+            ));
+        }
+        let func_ctx = ctx.get_function_context()?;
+        let as_expr = |expr: &RemoteExpr| expr.as_expr(&BUILTIN_FUNCTIONS);
+        let target_keys: Vec<Expr> = merge_into.target_keys.iter().map(as_expr).collect();
+        let source_keys: Vec<Expr> = merge_into.source_keys.iter().map(as_expr).collect();
+        let key_column_ids = target_keys
+            .iter()
+            .zip(source_keys.iter())
+            .map(|(target_key, source_key)| match target_key {
+                Expr::ColumnRef { id, data_type, .. }
+                    if data_type.remove_nullable() == source_key.data_type().remove_nullable() =>
+                {
+                    // the statistics of nested types are kept by their leaf columns
+                    let column_ids = table_schema.fields()[*id].leaf_column_ids();
+                    (column_ids.len() == 1).then(|| column_ids[0])
+                }
+                _ => None,
+            })
+            .collect();
+        let matched = merge_into
+            .matched
+            .iter()
+            .map(|action| MatchedAction {
+                condition: action.condition.as_ref().map(as_expr),
+                update_list: action.update_list.as_ref().map(|update_list| {
+                    update_list
+                        .iter()
+                        .map(|(field_index, expr)| (*field_index, as_expr(expr)))
+                        .collect()
+                }),
+            })
+            .collect();
+        let unmatched = merge_into
+            .unmatched
+            .iter()
+            .map(|action| UnmatchedAction {
+                condition: action.condition.as_ref().map(as_expr),
+                values: action.values.iter().map(as_expr).collect(),
+            })
+            .collect();
+
+        let segment_reader =
+            MetaReaders::segment_info_reader(data_accessor.clone(), table_schema.clone());
+        let field_types = table_schema
+            .fields()
+            .iter()
+            .map(|field| DataType::from(field.data_type()))
+            .collect();
+        let projection = Projection::Columns((0..table_schema.num_fields()).collect());
+        let block_reader =
+            BlockReader::create(data_accessor.clone(), table_schema, projection, ctx, false)?;
+
+        Ok(Self {
+            func_ctx,
+            target_keys,
+            source_keys,
+            key_column_ids,
+            join_condition: as_expr(&merge_into.join_condition),
+            matched,
+            unmatched,
+            base_snapshot,
+            field_types,
+            block_reader,
+            segment_reader,
+            data_accessor,
+            write_settings,
+            read_settings,
+            append_transform,
+            source_blocks: vec![],
+        })
+    }
+
+    pub fn accumulate(&mut self, data_block: DataBlock) {
+        if !data_block.is_empty() {
+            self.source_blocks.push(data_block);
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn apply(&mut self) -> Result<Option<MutationLogs>> {
+        if self.source_blocks.is_empty() {
+            return Ok(None);
+        }
+        let source = DataBlock::concat(&std::mem::take(&mut self.source_blocks))?;
+
+        let mut matched_source = MutableBitmap::from_len_zeroed(source.num_rows());
+        let source_hash_table = self.build_source_hash_table(&source)?;
+        let source_key_ranges = source_key_ranges(&source_hash_table, self.source_keys.len());
+
+        // 1. join all the blocks, nothing is written until every target row is known to
+        // match at most one source row
+        let mut matched_blocks = vec![];
+        if !source_hash_table.is_empty() {
+            let segments = self.base_snapshot.segments.clone();
+            for (segment_idx, (location, ver)) in segments.iter().enumerate() {
+                let load_param = LoadParams {
+                    location: location.clone(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: true,
+                };
+                let segment_info = self.segment_reader.read(&load_param).await?;
+                if self.is_pruned(&segment_info.summary.col_stats, &source_key_ranges) {
+                    continue;
+                }
+                for (block_idx, block_meta) in segment_info.blocks.iter().enumerate() {
+                    if block_meta.row_count == 0
+                        || self.is_pruned(&block_meta.col_stats, &source_key_ranges)
+                    {
+                        continue;
+                    }
+                    if let Some(matched_block) = self
+                        .match_data_block(
+                            segment_idx,
+                            block_idx,
+                            block_meta.clone(),
+                            &source,
+                            &source_hash_table,
+                            &mut matched_source,
+                        )
+                        .await?
+                    {
+                        matched_blocks.push(matched_block);
+                    }
+                }
+            }
+        }
+
+        // 2. rewrite the matched blocks
+        let mut entries = vec![];
+        for matched_block in matched_blocks {
+            if let Some(entry) = self.apply_to_data_block(matched_block, &source).await? {
+                entries.push(MutationLogEntry::Replacement(entry));
+            }
+        }
+
+        if !self.unmatched.is_empty() {
+            let unmatched_rows = matched_source
+                .iter()
+                .map(|matched| !matched)
+                .collect::<MutableBitmap>();
+            let unmatched_source = source.filter_with_bitmap(&unmatched_rows.into())?;
+            if let Some(block) = self.build_insert_block(unmatched_source)? {
+                if let Some(logs) = self.append_transform.transform(block).await? {
+                    entries.extend(MutationLogs::try_from(logs)?.entries);
+                }
+            }
+        }
+        if let Some(logs) = self.append_transform.on_finish(true).await? {
+            entries.extend(MutationLogs::try_from(logs)?.entries);
+        }
+
+        Ok(Some(MutationLogs { entries }))
+    }
+
+    // Map the join keys of the source rows to their row indexes, rows with NULL keys never match.
+    fn build_source_hash_table(
+        &self,
+        source: &DataBlock,
+    ) -> Result<HashMap<Vec<Scalar>, Vec<u32>>> {
+        let mut hash_table: HashMap<Vec<Scalar>, Vec<u32>> = HashMap::new();
+        let key_columns = self.evaluate_columns(source, &self.source_keys)?;
+        for row in 0..source.num_rows() {
+            if let Some(key) = row_key(&key_columns, row) {
+                hash_table.entry(key).or_default().push(row as u32);
+            }
+        }
+        Ok(hash_table)
+    }
+
+    // Whether the statistics of a key column show that no source key can match.
+    fn is_pruned(
+        &self,
+        col_stats: &StatisticsOfColumns,
+        source_key_ranges: &[Option<(Scalar, Scalar)>],
+    ) -> bool {
+        self.key_column_ids
+            .iter()
+            .zip(source_key_ranges.iter())
+            .any(|(column_id, range)| {
+                let stats = column_id.and_then(|column_id| col_stats.get(&column_id));
+                // NULL statistics are not comparable with the keys, and never prune
+                match (stats, range) {
+                    (Some(stats), Some((min, max))) => {
+                        stats.max.partial_cmp(min) == Some(Ordering::Less)
+                            || stats.min.partial_cmp(max) == Some(Ordering::Greater)
+                    }
+                    _ => false,
+                }
+            })
+    }
+
+    // Join a block with the source, and mark the matched source rows.
+    #[async_backtrace::framed]
+    async fn match_data_block(
+        &self,
+        segment_index: SegmentIndex,
+        block_index: BlockIndex,
+        block_meta: Arc<BlockMeta>,
+        source: &DataBlock,
+        source_hash_table: &HashMap<Vec<Scalar>, Vec<u32>>,
+        matched_source: &mut MutableBitmap,
+    ) -> Result<Option<MatchedBlock>> {
+        let data_block = self.read_block(&block_meta).await?;
+        let num_rows = data_block.num_rows();
+
+        // 1. find the candidate pairs of (target row, source row)
+        let mut target_indexes = vec![];
+        let mut source_indexes = vec![];
+        let key_columns = self.evaluate_columns(&data_block, &self.target_keys)?;
+        for target_row in 0..num_rows {
+            let source_rows =
+                row_key(&key_columns, target_row).and_then(|key| source_hash_table.get(&key));
+            if let Some(source_rows) = source_rows {
+                for source_row in source_rows {
+                    target_indexes.push(target_row as u32);
+                    source_indexes.push(*source_row);
+                }
+            }
+        }
+        if target_indexes.is_empty() {
+            return Ok(None);
+        }
+
+        // 2. evaluate the join condition on the candidate pairs
+        let pair_block =
+            self.build_pair_block(&data_block, &target_indexes, source, &source_indexes)?;
+        let filter = self.evaluate_predicate(&pair_block, &self.join_condition)?;
+        let (target_indexes, source_indexes): (Vec<u32>, Vec<u32>) = target_indexes
+            .into_iter()
+            .zip(source_indexes)
+            .zip(filter.iter())
+            .filter_map(|(pair, matched)| matched.then_some(pair))
+            .unzip();
+        if target_indexes.is_empty() {
+            return Ok(None);
+        }
+
+        let mut matched_target = MutableBitmap::from_len_zeroed(num_rows);
+        for (target_row, source_row) in target_indexes.iter().zip(source_indexes.iter()) {
+            if matched_target.get(*target_row as usize) && !self.matched.is_empty() {
+                return Err(ErrorCode::BadArguments(
+                    "MERGE INTO cannot update or delete a target row which matches more than one source row",
+                ));
+            }
+            matched_target.set(*target_row as usize, true);
+            matched_source.set(*source_row as usize, true);
+        }
+        if self.matched.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(MatchedBlock {
+            segment_index,
+            block_index,
+            block_meta,
+            target_indexes,
+            source_indexes,
+        }))
+    }
+
+    // The block is read again rather than kept since the join, only the matched blocks are
+    // read twice.
+    #[async_backtrace::framed]
+    async fn apply_to_data_block(
+        &self,
+        matched_block: MatchedBlock,
+        source: &DataBlock,
+    ) -> Result<Option<ReplacementLogEntry>> {
+        let MatchedBlock {
+            segment_index,
+            block_index,
+            block_meta,
+            target_indexes,
+            source_indexes,
+        } = matched_block;
+        let data_block = self.read_block(&block_meta).await?;
+        let num_rows = data_block.num_rows();
+        let pair_block =
+            self.build_pair_block(&data_block, &target_indexes, source, &source_indexes)?;
+        let mut matched_target = vec![None; num_rows];
+        for (pair_index, target_row) in target_indexes.iter().enumerate() {
+            matched_target[*target_row as usize] = Some(pair_index);
+        }
+
+        // 1. each matched pair is handled by the first WHEN MATCHED clause whose condition is true
+        let num_pairs = pair_block.num_rows();
+        let mut pair_actions = vec![None; num_pairs];
+        let mut remaining = MutableBitmap::from_len_set(num_pairs);
+        for (action_index, action) in self.matched.iter().enumerate() {
+            let condition = match &action.condition {
+                Some(condition) => self.evaluate_predicate(&pair_block, condition)?,
+                None => MutableBitmap::from_len_set(num_pairs),
+            };
+            for (pair_index, pair_action) in pair_actions.iter_mut().enumerate() {
+                if remaining.get(pair_index) && condition.get(pair_index) {
+                    *pair_action = Some(action_index);
+                    remaining.set(pair_index, false);
+                }
+            }
+        }
+        if pair_actions.iter().all(|action| action.is_none()) {
+            return Ok(None);
+        }
+
+        // 2. build the new block
+        let mut updated_values = HashMap::new();
+        for (action_index, action) in self.matched.iter().enumerate() {
+            if let Some(update_list) = &action.update_list {
+                if !pair_actions.contains(&Some(action_index)) {
+                    continue;
+                }
+                let evaluator = Evaluator::new(&pair_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                for (field_index, expr) in update_list {
+                    let value = evaluator.run(expr)?;
+                    let column =
+                        value.convert_to_full_column(&self.field_types[*field_index], num_pairs);
+                    updated_values.insert((action_index, *field_index), column);
+                }
+            }
+        }
+
+        let mut keep = MutableBitmap::from_len_set(num_rows);
+        let mut affected_rows = 0;
+        for (target_row, pair_index) in matched_target.iter().enumerate() {
+            if let Some(action_index) = pair_index.and_then(|pair_index| pair_actions[pair_index]) {
+                affected_rows += 1;
+                if self.matched[action_index].update_list.is_none() {
+                    keep.set(target_row, false);
+                }
+            }
+        }
+
+        let mut columns = Vec::with_capacity(self.field_types.len());
+        for (field_index, data_type) in self.field_types.iter().enumerate() {
+            let entry = data_block.get_by_offset(field_index);
+            let is_updated = updated_values
+                .keys()
+                .any(|(_, updated_field)| *updated_field == field_index);
+            if !is_updated {
+                columns.push(entry.clone());
+                continue;
+            }
+            let original = entry.value.convert_to_full_column(data_type, num_rows);
+            let mut builder = ColumnBuilder::with_capacity(data_type, num_rows);
+            for (target_row, pair_index) in matched_target.iter().enumerate() {
+                let updated = pair_index.and_then(|pair_index| {
+                    pair_actions[pair_index].and_then(|action_index| {
+                        updated_values
+                            .get(&(action_index, field_index))
+                            .map(|column| (column, pair_index))
+                    })
+                });
+                match updated {
+                    Some((column, pair_index)) => builder.push(column.index(pair_index).unwrap()),
+                    None => builder.push(original.index(target_row).unwrap()),
+                }
+            }
+            columns.push(BlockEntry {
+                data_type: data_type.clone(),
+                value: Value::Column(builder.build()),
+            });
+        }
+
+        let progress_values = ProgressValues {
+            rows: affected_rows,
+            // ignore bytes.
+            bytes: 0,
+        };
+        self.append_transform
+            .get_block_builder()
+            .ctx
+            .get_write_progress()
+            .incr(&progress_values);
+
+        let deleted_rows = keep.unset_bits();
+        if deleted_rows == num_rows {
+            info!(
+                "merge into deletes the whole block, segment idx {}, block idx {}",
+                segment_index, block_index
+            );
+            return Ok(Some(ReplacementLogEntry {
+                index: BlockMetaIndex {
+                    segment_idx: segment_index,
+                    block_idx: block_index,
+                    range: None,
+                },
+                op: Replacement::Deleted,
+            }));
+        }
+
+        let mut new_block = DataBlock::new(columns, num_rows);
+        if deleted_rows > 0 {
+            new_block = new_block.filter_with_bitmap(&keep.into())?;
+        }
+
+        // serialization and compression is cpu intensive, send them to dedicated thread pool
+        // and wait (asyncly, which will NOT block the executor thread)
+        let block_builder = self.append_transform.get_block_builder();
+        let serialized = GlobalIORuntime::instance()
+            .spawn_blocking(move || block_builder.build(new_block))
+            .await?;
+
+        // persistent data
+        let new_block_meta = serialized.block_meta;
+        let new_block_location = new_block_meta.location.0.clone();
+        write_data(
+            serialized.block_raw_data,
+            &self.data_accessor,
+            &new_block_location,
+        )
+        .await?;
+        if let Some(index_state) = serialized.bloom_index_state {
+            write_data(
+                index_state.data,
+                &self.data_accessor,
+                &index_state.location.0,
+            )
+            .await?;
+        }
+        if let Some(index_state) = serialized.inverted_index_state {
+            write_data(
+                index_state.data,
+                &self.data_accessor,
+                &index_state.location.0,
+            )
+            .await?;
+        }
+
+        Ok(Some(ReplacementLogEntry {
+            index: BlockMetaIndex {
+                segment_idx: segment_index,
+                block_idx: block_index,
+                range: None,
+            },
+            op: Replacement::Replaced(Arc::new(new_block_meta)),
+        }))
+    }
+
+    // Each unmatched source row is inserted by the first WHEN NOT MATCHED clause whose condition is true.
+    fn build_insert_block(&self, source: DataBlock) -> Result<Option<DataBlock>> {
+        let num_rows = source.num_rows();
+        let mut remaining = MutableBitmap::from_len_set(num_rows);
+        let mut blocks = vec![];
+        for action in self.unmatched.iter() {
+            if remaining.unset_bits() == num_rows {
+                break;
+            }
+            let mut selected = match &action.condition {
+                Some(condition) => self.evaluate_predicate(&source, condition)?,
+                None => MutableBitmap::from_len_set(num_rows),
+            };
+            for row in 0..num_rows {
+                let is_selected = selected.get(row) && remaining.get(row);
+                selected.set(row, is_selected);
+                if is_selected {
+                    remaining.set(row, false);
+                }
+            }
+            if selected.unset_bits() == num_rows {
+                continue;
+            }
+            let rows = source.clone().filter_with_bitmap(&selected.into())?;
+            let columns = self.evaluate_columns(&rows, &action.values)?;
+            let columns = columns
+                .into_iter()
+                .zip(self.field_types.iter())
+                .map(|(column, data_type)| BlockEntry {
+                    data_type: data_type.clone(),
+                    value: Value::Column(column),
+                })
+                .collect();
+            blocks.push(DataBlock::new(columns, rows.num_rows()));
+        }
+
+        if blocks.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(DataBlock::concat(&blocks)?))
+    }
+
+    // The columns of the target rows followed by the columns of the source rows.
+    fn build_pair_block(
+        &self,
+        target: &DataBlock,
+        target_indexes: &[u32],
+        source: &DataBlock,
+        source_indexes: &[u32],
+    ) -> Result<DataBlock> {
+        let target = target.take(target_indexes)?;
+        let source = source.take(source_indexes)?;
+        let num_rows = target_indexes.len();
+        let columns = target
+            .columns()
+            .iter()
+            .chain(source.columns().iter())
+            .cloned()
+            .collect();
+        Ok(DataBlock::new(columns, num_rows))
+    }
+
+    #[async_backtrace::framed]
+    async fn read_block(&self, block_meta: &BlockMeta) -> Result<DataBlock> {
+        self.block_reader
+            .read_by_meta(
+                &self.read_settings,
+                block_meta,
+                &self.write_settings.storage_format,
+            )
+            .await
+    }
+
+    fn evaluate_columns(&self, data_block: &DataBlock, exprs: &[Expr]) -> Result<Vec<Column>> {
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        exprs
+            .iter()
+            .map(|expr| {
+                let value = evaluator.run(expr)?;
+                Ok(value.convert_to_full_column(expr.data_type(), data_block.num_rows()))
+            })
+            .collect()
+    }
+
+    fn evaluate_predicate(&self, data_block: &DataBlock, expr: &Expr) -> Result<MutableBitmap> {
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let predicate = evaluator
+            .run(expr)?
+            .try_downcast::<BooleanType>()
+            .ok_or_else(|| ErrorCode::Internal("MERGE INTO predicate must be boolean"))?;
+        Ok(FilterHelpers::filter_to_bitmap(
+            predicate,
+            data_block.num_rows(),
+        ))
+    }
+}
+
+fn row_key(key_columns: &[Column], row: usize) -> Option<Vec<Scalar>> {
+    let mut key = Vec::with_capacity(key_columns.len());
+    for column in key_columns {
+        let value = column.index(row).unwrap();
+        if value == ScalarRef::Null {
+            return None;
+        }
+        key.push(value.to_owned());
+    }
+    Some(key)
+}
+
+// The min and max of each join key of the source, None if they can't be compared.
+fn source_key_ranges(
+    source_hash_table: &HashMap<Vec<Scalar>, Vec<u32>>,
+    num_keys: usize,
+) -> Vec<Option<(Scalar, Scalar)>> {
+    (0..num_keys)
+        .map(|key_index| {
+            let mut keys = source_hash_table.keys().map(|key| &key[key_index]);
+            let first = keys.next()?;
+            let (mut min, mut max) = (first, first);
+            for key in keys {
+                match (key.partial_cmp(min), key.partial_cmp(max)) {
+                    (Some(Ordering::Less), _) => min = key,
+                    (_, Some(Ordering::Greater)) => max = key,
+                    (None, _) | (_, None) => return None,
+                    _ => {}
+                }
+            }
+            Some((min.clone(), max.clone()))
+        })
+        .collect()
+}
@@ -17,6 +17,7 @@
 mod processor_broadcast;
 mod sink_commit;
 mod transform_append;
+mod transform_merge;
 mod transform_merge_into_mutation_aggregator;
 mod transform_mutation_aggregator;
 
@@ -25,6 +26,7 @@ use common_expression::TableField;
 pub use processor_broadcast::*;
 pub use sink_commit::CommitSink;
 pub use transform_append::AppendTransform;
+pub use transform_merge::*;
 pub use transform_merge_into_mutation_aggregator::*;
 pub use transform_mutation_aggregator::*;
 
@@ -0,0 +1,57 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_transforms::processors::transforms::transform_accumulating_async::AsyncAccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransformer;
+
+pub use crate::operations::merge_into::mutator::merge_mutator::MergeMutator;
+
+/// Takes the rows of the MERGE INTO source in, and merges them with the table
+/// in the `final` stage.
+/// Outputs [MutationLogs] logs(to be committed).
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for MergeMutator {
+    const NAME: &'static str = "MergeMutator";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        self.accumulate(data);
+        // no partial output
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        let mutation_logs = self.apply().await?;
+        Ok(mutation_logs.map(|logs| logs.into()))
+    }
+}
+
+impl MergeMutator {
+    pub fn into_pipe_item(self) -> PipeItem {
+        let input = InputPort::create();
+        let output = OutputPort::create();
+        let processor_ptr =
+            AsyncAccumulatingTransformer::create(input.clone(), output.clone(), self);
+        PipeItem::create(ProcessorPtr::create(processor_ptr), vec![input], vec![
+            output,
+        ])
+    }
+}
@@ -12,13 +12,17 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod agg_index;
 mod analyze;
 mod append;
+mod changes;
+mod clone;
 mod commit;
 mod compact;
 mod delete;
 mod fuse_sink;
 mod gc;
+mod merge;
 mod merge_into;
 mod mutation;
 mod navigate;
@@ -26,19 +30,24 @@ mod operation_log;
 mod read_data;
 mod read_partitions;
 mod recluster;
+mod refresh_index;
 mod replace;
 mod replace_into;
 mod truncate;
 mod update;
+mod vacuum;
 
 mod fuse_source;
 mod read;
 mod revert;
 pub mod util;
 
+pub use agg_index::AggIndexBuilder;
+pub use changes::TableChanges;
 pub use compact::CompactOptions;
 pub use fuse_sink::BloomIndexState;
 pub use fuse_sink::FuseTableSink;
+pub use fuse_sink::InvertedIndexState;
 pub use mutation::BlockCompactMutator;
 pub use mutation::CompactPartInfo;
 pub use mutation::FillInternalColumnProcessor;
@@ -38,6 +38,7 @@ use crate::operations::mutation::Mutation;
 use crate::operations::mutation::MutationTransformMeta;
 use crate::operations::mutation::SerializeDataMeta;
 use crate::operations::BloomIndexState;
+use crate::operations::InvertedIndexState;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::Processor;
@@ -50,6 +51,8 @@ pub struct SerializeState {
     pub block_location: String,
     pub index_data: Option<Vec<u8>>,
     pub index_location: Option<String>,
+    pub inverted_index_data: Option<Vec<u8>>,
+    pub inverted_index_location: Option<String>,
 }
 
 enum State {
@@ -180,10 +183,19 @@ impl Processor for SerializeDataTransform {
                     self.schema.clone(),
                     &block,
                     location,
+                    self.write_settings.bloom_index_columns.as_deref(),
+                    &self.write_settings.ngram_index_columns,
                 )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
                     .map(|i| i.column_distinct_count.clone());
+                let location = self.location_gen.block_inverted_index_location(&block_id);
+                let inverted_index_state = InvertedIndexState::try_create(
+                    &self.schema,
+                    &self.write_settings.inverted_index_columns,
+                    &block,
+                    location,
+                )?;
                 let col_stats =
                     gen_columns_statistics(&block, column_distinct_count, &self.schema)?;
 
@@ -204,6 +216,11 @@ impl Processor for SerializeDataTransform {
                     } else {
                         (None, None, 0u64)
                     };
+                let (inverted_index_data, inverted_index_location, inverted_index_size) =
+                    match inverted_index_state {
+                        Some(state) => (Some(state.data), Some(state.location), state.size),
+                        None => (None, None, 0u64),
+                    };
 
                 // new block meta.
                 let new_meta = Arc::new(BlockMeta::new(
@@ -217,6 +234,8 @@ impl Processor for SerializeDataTransform {
                     index_location.clone(),
                     index_size,
                     self.table_compression.into(),
+                    inverted_index_location.clone(),
+                    inverted_index_size,
                 ));
 
                 self.state = State::Serialized(
@@ -225,6 +244,8 @@ impl Processor for SerializeDataTransform {
                         block_location: block_location.0,
                         index_data,
                         index_location: index_location.map(|l| l.0),
+                        inverted_index_data,
+                        inverted_index_location: inverted_index_location.map(|l| l.0),
                     },
                     new_meta,
                 );
@@ -255,6 +276,12 @@ impl Processor for SerializeDataTransform {
                 {
                     write_data(index_data, &self.dal, &index_location).await?;
                 }
+                if let (Some(index_data), Some(index_location)) = (
+                    serialize_state.inverted_index_data,
+                    serialize_state.inverted_index_location,
+                ) {
+                    write_data(index_data, &self.dal, &index_location).await?;
+                }
 
                 self.state = State::Output(Mutation::Replaced(block_meta));
             }
@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_meta_app::schema::TableStatistics;
 use futures::TryStreamExt;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_BASE_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::io::MetaReaders;
@@ -55,6 +56,78 @@ impl FuseTable {
         .await
     }
 
+    /// Navigate to the rows appended since the snapshot at `base_location`.
+    ///
+    /// The returned table reads only the segments appended after the base snapshot.
+    /// It is `None` if the base snapshot is not in the history of the table, or any
+    /// snapshot since then is not made by appending segments, e.g. by deletion,
+    /// compaction or overwrite, so the rows of the base snapshot may have changed.
+    #[async_backtrace::framed]
+    pub async fn navigate_to_appended_since(
+        &self,
+        base_location: &str,
+    ) -> Result<Option<Arc<FuseTable>>> {
+        let snapshot_location = match self.snapshot_loc().await? {
+            Some(loc) => loc,
+            None => return Ok(None),
+        };
+
+        let snapshot_version = self.snapshot_format_version().await?;
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut snapshot_stream = reader.snapshot_history(
+            snapshot_location,
+            snapshot_version,
+            self.meta_location_generator().clone(),
+        );
+
+        // Walk back the history, each snapshot must be a suffix of the newer one,
+        // since the appended segments are put in front of the segments.
+        let mut latest: Option<Arc<TableSnapshot>> = None;
+        let mut newer: Option<Arc<TableSnapshot>> = None;
+        while let Some(snapshot) = snapshot_stream.try_next().await? {
+            if let Some(newer) = &newer {
+                if !newer.segments.ends_with(&snapshot.segments) {
+                    return Ok(None);
+                }
+            }
+            let location = self
+                .meta_location_generator
+                .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version())?;
+            if location == base_location {
+                let latest = latest.as_ref().unwrap_or(&snapshot);
+                let mut table_info = self.table_info.clone();
+                table_info
+                    .meta
+                    .options
+                    .insert(OPT_KEY_BASE_SNAPSHOT_LOCATION.to_owned(), location);
+
+                // The statistics of the appended rows.
+                let summary = &latest.summary;
+                let base_summary = &snapshot.summary;
+                table_info.meta.statistics = TableStatistics {
+                    number_of_rows: summary.row_count.saturating_sub(base_summary.row_count),
+                    data_bytes: summary
+                        .uncompressed_byte_size
+                        .saturating_sub(base_summary.uncompressed_byte_size),
+                    compressed_data_bytes: summary
+                        .compressed_byte_size
+                        .saturating_sub(base_summary.compressed_byte_size),
+                    index_data_bytes: summary.index_size.saturating_sub(base_summary.index_size),
+                };
+
+                let table = FuseTable::do_create(table_info)?;
+                return Ok(Some(table.into()));
+            }
+            if latest.is_none() {
+                latest = Some(snapshot.clone());
+            }
+            newer = Some(snapshot);
+        }
+
+        // The base snapshot is purged, or not of this table.
+        Ok(None)
+    }
+
     #[async_backtrace::framed]
     pub async fn find<P>(&self, mut pred: P) -> Result<Arc<FuseTable>>
     where P: FnMut(&TableSnapshot) -> bool {
@@ -0,0 +1,170 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::arrow::io::parquet::read::{self as pread};
+use common_arrow::parquet::read::read_metadata;
+use common_catalog::plan::AggIndexInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::StealablePartitions;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::Pipeline;
+use common_pipeline_core::SourcePipeBuilder;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_sql::parse_agg_index_query;
+use opendal::Operator;
+
+use crate::fuse_part::FusePartInfo;
+use crate::io::BlockReader;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::read::fuse_source::dispatch_partitions;
+use crate::operations::AggIndexBuilder;
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+
+/// ReadAggIndexSource reads the pre-aggregated blocks of an aggregating index
+/// instead of the table blocks.
+///
+/// If the index file of a block is missing, e.g. the block is written by
+/// compaction or before the index is created, the block is read and
+/// aggregated on the fly.
+pub struct ReadAggIndexSource {
+    id: usize,
+    partitions: StealablePartitions,
+    operator: Operator,
+    location_generator: TableMetaLocationGenerator,
+    read_settings: ReadSettings,
+    storage_format: FuseStorageFormat,
+
+    /// Names of the output columns.
+    output_names: Vec<String>,
+    /// Reads the group columns and the aggregate arguments of the block.
+    block_reader: Arc<BlockReader>,
+    /// Builds the output of the index from the block read by `block_reader`.
+    builder: AggIndexBuilder,
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for ReadAggIndexSource {
+    const NAME: &'static str = "ReadAggIndexSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let part = match self.partitions.steal_one(self.id) {
+            Some(part) => part,
+            None => return Ok(None),
+        };
+        let part = FusePartInfo::from_part(&part)?;
+
+        // Aggregations are only pushed down if the filters are on the group columns,
+        // so the groups of the whole block are read even if the part is a page range.
+        let location = self
+            .location_generator
+            .block_agg_index_location(&self.builder.query, &part.location);
+        match self.operator.read(&location).await {
+            Ok(data) => Ok(Some(self.deserialize_index(data)?)),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => {
+                let block = self
+                    .block_reader
+                    .read_by_part(&self.read_settings, part, &self.storage_format)
+                    .await?;
+                Ok(Some(self.builder.build(&block)?))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl ReadAggIndexSource {
+    fn deserialize_index(&self, data: Vec<u8>) -> Result<DataBlock> {
+        let mut reader = Cursor::new(data);
+        let meta = read_metadata(&mut reader)?;
+        let arrow_schema = infer_schema(&meta)?;
+        let schema = DataSchema::from(&TableSchema::from(&arrow_schema));
+
+        let chunks_iter =
+            pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
+        let mut blocks = Vec::with_capacity(1);
+        for chunk in chunks_iter {
+            blocks.push(DataBlock::from_arrow_chunk(&chunk?, &schema)?);
+        }
+        let block = DataBlock::concat(&blocks)?;
+
+        // Project the columns of the index to the output columns.
+        let columns = self
+            .output_names
+            .iter()
+            .map(|name| Ok(block.get_by_offset(schema.index_of(name)?).clone()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataBlock::new(columns, block.num_rows()))
+    }
+}
+
+impl FuseTable {
+    pub(crate) fn build_agg_index_source_pipeline(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        agg_index: &AggIndexInfo,
+        block_reader: Arc<BlockReader>,
+        max_io_requests: usize,
+    ) -> Result<()> {
+        let definition =
+            parse_agg_index_query(ctx.clone(), Arc::new(self.clone()), &agg_index.query)?;
+        let builder = AggIndexBuilder::try_create(&definition, agg_index, &block_reader.schema())?;
+        let output_names = plan
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+        let read_settings = ReadSettings::from_ctx(&ctx)?;
+
+        let partitions = dispatch_partitions(ctx.clone(), plan, max_io_requests);
+        let partitions = StealablePartitions::new(partitions, ctx.clone());
+
+        let mut source_builder = SourcePipeBuilder::create();
+        for id in 0..max_io_requests {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                AsyncSourcer::create(ctx.clone(), output, ReadAggIndexSource {
+                    id,
+                    partitions: partitions.clone(),
+                    operator: self.operator.clone(),
+                    location_generator: self.meta_location_generator.clone(),
+                    read_settings: read_settings.clone(),
+                    storage_format: self.storage_format,
+                    output_names: output_names.clone(),
+                    block_reader: block_reader.clone(),
+                    builder: builder.clone(),
+                })?,
+            );
+        }
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+}
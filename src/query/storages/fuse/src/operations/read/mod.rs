@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod agg_index_source;
 pub mod fuse_source;
 mod native_data_source;
 mod native_data_source_deserializer;
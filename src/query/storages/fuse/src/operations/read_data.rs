@@ -147,6 +147,17 @@ impl FuseTable {
         let block_reader = self.build_block_reader(plan, ctx.clone())?;
         let max_io_requests = self.adjust_io_request(&ctx)?;
 
+        if let Some(agg_index) = plan.push_downs.as_ref().and_then(|p| p.agg_index.as_ref()) {
+            return self.build_agg_index_source_pipeline(
+                ctx,
+                plan,
+                pipeline,
+                agg_index,
+                block_reader,
+                max_io_requests,
+            );
+        }
+
         let topk = plan.push_downs.as_ref().and_then(|x| {
             x.top_k(
                 plan.schema().as_ref(),
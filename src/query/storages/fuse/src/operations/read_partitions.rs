@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -60,10 +61,24 @@ impl FuseTable {
         let snapshot = self.read_table_snapshot().await?;
         match snapshot {
             Some(snapshot) => {
+                // Only the segments appended since the base snapshot are read, if any.
+                let segments_location = match self.read_base_snapshot().await? {
+                    Some(base) => {
+                        let base_segments = base.segments.iter().collect::<HashSet<_>>();
+                        snapshot
+                            .segments
+                            .iter()
+                            .filter(|location| !base_segments.contains(location))
+                            .cloned()
+                            .collect::<Vec<_>>()
+                    }
+                    None => snapshot.segments.clone(),
+                };
+
                 let settings = ctx.get_settings();
                 if settings.get_enable_distributed_eval_index()? && !ctx.get_cluster().is_empty() {
-                    let mut segments = Vec::with_capacity(snapshot.segments.len());
-                    for segment_location in &snapshot.segments {
+                    let mut segments = Vec::with_capacity(segments_location.len());
+                    for segment_location in &segments_location {
                         segments.push(FuseLazyPartInfo::create(segment_location.clone()))
                     }
 
@@ -71,15 +86,14 @@ impl FuseTable {
                         PartStatistics::new_estimated(
                             snapshot.summary.row_count as usize,
                             snapshot.summary.compressed_byte_size as usize,
-                            snapshot.segments.len(),
-                            snapshot.segments.len(),
+                            segments_location.len(),
+                            segments_location.len(),
                         ),
                         Partitions::create(PartitionsShuffleKind::Mod, segments, true),
                     ));
                 }
 
                 let table_info = self.table_info.clone();
-                let segments_location = snapshot.segments.clone();
                 let summary = snapshot.summary.block_count as usize;
 
                 self.prune_snapshot_blocks(
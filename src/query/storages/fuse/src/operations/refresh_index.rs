@@ -0,0 +1,181 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableIndexType;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+use uuid::Uuid;
+
+use crate::io::write_data;
+use crate::io::ReadSettings;
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::operations::BloomIndexState;
+use crate::operations::InvertedIndexState;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Rebuild the index files of the given index for all the blocks of the
+    /// current snapshot, the data blocks are read but never rewritten.
+    ///
+    /// Ngram filters are stored in the bloom index file, so refreshing a bloom or
+    /// an ngram index rebuilds the whole bloom index file of each block.
+    ///
+    /// Aggregating index files are located by the blocks, they are overwritten
+    /// and the block metas are kept as they are.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_index(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        index_name: &str,
+    ) -> Result<()> {
+        let index_type = match self.table_info.meta.indexes.get(index_name) {
+            Some(index) => index.index_type,
+            None => {
+                return Err(ErrorCode::UnknownIndex(format!(
+                    "Unknown index '{}' of table '{}'",
+                    index_name, self.table_info.desc
+                )));
+            }
+        };
+
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let schema = self.schema();
+        let write_settings = self.get_write_settings();
+        let projection = Projection::Columns((0..schema.num_fields()).collect());
+        let block_reader = self.create_block_reader(projection, false, ctx.clone())?;
+        let read_settings = ReadSettings::from_ctx(ctx)?;
+        let agg_index_builder = match index_type {
+            TableIndexType::Aggregating => {
+                Some(self.agg_index_builder(ctx.clone(), index_name, &schema)?)
+            }
+            _ => None,
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), schema.clone());
+        let segments = segments_io.read_segments(&snapshot.segments, true).await?;
+        let segment_writer = SegmentWriter::new(&self.operator, &self.meta_location_generator);
+
+        let mut new_summary = snapshot.summary.clone();
+        let mut new_segments = Vec::with_capacity(snapshot.segments.len());
+        for segment in segments {
+            let segment = segment?;
+            let mut new_segment_summary = segment.summary.clone();
+            let mut new_blocks = Vec::with_capacity(segment.blocks.len());
+            for block_meta in segment.blocks.iter() {
+                let data_block = block_reader
+                    .read_by_meta(&read_settings, block_meta, &self.storage_format)
+                    .await?;
+
+                // A new index file is written, the old one may still be referenced by
+                // the previous snapshots.
+                let index_id = Uuid::new_v4();
+                let mut new_block_meta = BlockMeta::clone(block_meta);
+                match index_type {
+                    TableIndexType::Bloom | TableIndexType::Ngram => {
+                        let location = self
+                            .meta_location_generator
+                            .block_bloom_index_location(&index_id);
+                        let state = BloomIndexState::try_create(
+                            ctx.clone(),
+                            schema.clone(),
+                            &data_block,
+                            location,
+                            write_settings.bloom_index_columns.as_deref(),
+                            &write_settings.ngram_index_columns,
+                        )?;
+                        let (location, size) = match state {
+                            Some(state) => {
+                                write_data(state.data, &self.operator, &state.location.0).await?;
+                                (Some(state.location), state.size)
+                            }
+                            None => (None, 0),
+                        };
+                        new_block_meta.bloom_filter_index_location = location;
+                        new_block_meta.bloom_filter_index_size = size;
+                    }
+                    TableIndexType::Inverted => {
+                        let location = self
+                            .meta_location_generator
+                            .block_inverted_index_location(&index_id);
+                        let state = InvertedIndexState::try_create(
+                            &schema,
+                            &write_settings.inverted_index_columns,
+                            &data_block,
+                            location,
+                        )?;
+                        let (location, size) = match state {
+                            Some(state) => {
+                                write_data(state.data, &self.operator, &state.location.0).await?;
+                                (Some(state.location), state.size)
+                            }
+                            None => (None, 0),
+                        };
+                        new_block_meta.inverted_index_location = location;
+                        new_block_meta.inverted_index_size = size;
+                    }
+                    TableIndexType::Aggregating => {
+                        if let Some(builder) = &agg_index_builder {
+                            let location = self
+                                .meta_location_generator
+                                .block_agg_index_location(&builder.query, &block_meta.location.0);
+                            write_data(builder.serialize(&data_block)?, &self.operator, &location)
+                                .await?;
+                        }
+                    }
+                }
+
+                let old_index_size =
+                    block_meta.bloom_filter_index_size + block_meta.inverted_index_size;
+                let new_index_size =
+                    new_block_meta.bloom_filter_index_size + new_block_meta.inverted_index_size;
+                // the summaries of old versions may not include the sizes of the indexes
+                new_segment_summary.index_size = (new_segment_summary.index_size + new_index_size)
+                    .saturating_sub(old_index_size);
+                new_summary.index_size =
+                    (new_summary.index_size + new_index_size).saturating_sub(old_index_size);
+                new_blocks.push(Arc::new(new_block_meta));
+            }
+
+            let new_segment = SegmentInfo::new(new_blocks, new_segment_summary);
+            new_segments.push(segment_writer.write_segment(new_segment).await?);
+        }
+
+        let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
+        new_snapshot.segments = new_segments;
+        new_snapshot.summary = new_summary;
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &self.table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+}
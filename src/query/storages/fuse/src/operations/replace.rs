@@ -304,7 +304,7 @@ impl FuseTable {
         chunks
     }
 
-    fn create_append_transform(&self, ctx: Arc<dyn TableContext>) -> AppendTransform {
+    pub(crate) fn create_append_transform(&self, ctx: Arc<dyn TableContext>) -> AppendTransform {
         AppendTransform::try_create(
             ctx,
             self.get_write_settings(),
@@ -316,7 +316,7 @@ impl FuseTable {
     }
 
     #[async_backtrace::framed]
-    async fn chain_mutation_pipes(
+    pub(crate) async fn chain_mutation_pipes(
         &self,
         ctx: &Arc<dyn TableContext>,
         pipeline: &mut Pipeline,
@@ -355,7 +355,7 @@ impl FuseTable {
         Ok(())
     }
 
-    fn new_empty_snapshot(&self) -> TableSnapshot {
+    pub(crate) fn new_empty_snapshot(&self) -> TableSnapshot {
         TableSnapshot::new(
             Uuid::new_v4(),
             &None,
@@ -0,0 +1,174 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::Duration;
+use chrono::Utc;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use futures_util::TryStreamExt;
+use opendal::EntryMode;
+use opendal::Metakey;
+use storages_common_table_meta::meta::Location;
+use tracing::info;
+
+use crate::io::Files;
+use crate::io::ListSnapshotLiteOption;
+use crate::io::SnapshotsIO;
+use crate::FuseTable;
+use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_BLOCK_PREFIX;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
+use crate::FUSE_TBL_SEGMENT_PREFIX;
+use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
+
+impl FuseTable {
+    /// Remove the data files of the table that are not referenced by any snapshot,
+    /// such as the blocks, segments and index files left behind by aborted commits.
+    ///
+    /// Every snapshot still kept in storage is regarded as retained. Only the files
+    /// last modified before the `retention` window are removed, so the files being
+    /// written by ongoing transactions are never collected. Snapshots and table
+    /// statistics files are left to `do_purge`.
+    ///
+    /// Returns the location and size of the files removed, or of the files that would
+    /// be removed if `dry_run` is true.
+    #[async_backtrace::framed]
+    pub async fn do_vacuum(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        retention: Duration,
+        dry_run: bool,
+    ) -> Result<Vec<(String, u64)>> {
+        // Take the retention point before the live set is computed, any file written
+        // after the live set is computed is newer than it.
+        let retention_point = Utc::now() - retention;
+        let start = Instant::now();
+
+        // 1. Collect the segments referenced by all the retained snapshots.
+        let mut live_segments: HashSet<Location> = HashSet::new();
+        if let Some(root_snapshot) = self.read_table_snapshot().await? {
+            live_segments.extend(root_snapshot.segments.iter().cloned());
+            if let Some(root_snapshot_location) = self.snapshot_loc().await? {
+                let snapshots_io = SnapshotsIO::create(ctx.clone(), self.operator.clone());
+                let snapshot_lites_extended = snapshots_io
+                    .read_snapshot_lites_ext(
+                        root_snapshot_location,
+                        None,
+                        &ListSnapshotLiteOption::NeedSegmentsWithExclusion(None),
+                        root_snapshot.timestamp,
+                        |status| {
+                            ctx.set_status_info(&status);
+                        },
+                    )
+                    .await?;
+                live_segments.extend(snapshot_lites_extended.segment_locations.into_keys());
+            }
+        }
+
+        // 2. Collect the blocks and index files referenced by the live segments.
+        let live_segments = Vec::from_iter(live_segments);
+        let live_locations = self
+            .get_block_locations(ctx.clone(), &live_segments, false)
+            .await?;
+        let live_segments: HashSet<String> =
+            live_segments.into_iter().map(|(path, _)| path).collect();
+        // The aggregating index files are named after the blocks they are built from.
+        let live_block_names: HashSet<&str> = live_locations
+            .block_location
+            .iter()
+            .map(|path| {
+                path.rsplit_once('/')
+                    .map_or(path.as_str(), |(_, name)| name)
+            })
+            .collect();
+
+        // 3. List the data files of the table, and find the unreferenced ones.
+        let prefix = format!("{}/", self.meta_location_generator.prefix());
+        let mut status_scan_count = 0;
+        let mut files_to_be_removed = vec![];
+        let mut lister = self.operator.scan(&prefix).await?;
+        while let Some(entry) = lister.try_next().await? {
+            let meta = self
+                .operator
+                .metadata(
+                    &entry,
+                    Metakey::Mode | Metakey::LastModified | Metakey::ContentLength,
+                )
+                .await?;
+            if meta.mode() != EntryMode::FILE {
+                continue;
+            }
+            status_scan_count += 1;
+
+            let path = entry.path();
+            let relative_path = match path.strip_prefix(prefix.as_str()) {
+                Some(relative_path) => relative_path,
+                None => continue,
+            };
+            let live = match relative_path.split_once('/') {
+                Some((FUSE_TBL_BLOCK_PREFIX, _)) => live_locations.block_location.contains(path),
+                Some((FUSE_TBL_SEGMENT_PREFIX, _)) => live_segments.contains(path),
+                Some((FUSE_TBL_XOR_BLOOM_INDEX_PREFIX, _)) => {
+                    live_locations.bloom_location.contains(path)
+                }
+                Some((FUSE_TBL_INVERTED_INDEX_PREFIX, _)) => {
+                    live_locations.inverted_index_location.contains(path)
+                }
+                Some((FUSE_TBL_AGG_INDEX_PREFIX, index_path)) => {
+                    let block_name = index_path
+                        .rsplit_once('/')
+                        .map_or(index_path, |(_, name)| name);
+                    live_block_names.contains(block_name)
+                }
+                // Snapshots, table statistics and the other files are not vacuumed.
+                _ => true,
+            };
+            // Files without modification time are kept, they might be written recently.
+            let expired =
+                matches!(meta.last_modified(), Some(modified) if modified < retention_point);
+            if !live && expired {
+                files_to_be_removed.push((path.to_string(), meta.content_length()));
+            }
+        }
+
+        let status = format!(
+            "vacuum: scan files:{}, unreferenced files:{}, takes:{} sec",
+            status_scan_count,
+            files_to_be_removed.len(),
+            start.elapsed().as_secs()
+        );
+        ctx.set_status_info(&status);
+        info!(status);
+
+        // 4. Remove the unreferenced files.
+        if !dry_run && !files_to_be_removed.is_empty() {
+            let fuse_file = Files::create(ctx.clone(), self.operator.clone());
+            fuse_file
+                .remove_file_in_batch(files_to_be_removed.iter().map(|(path, _)| path))
+                .await?;
+            info!(
+                "vacuum: removed {} files of table {}, ident {}",
+                files_to_be_removed.len(),
+                self.table_info.desc,
+                self.table_info.ident,
+            );
+        }
+
+        Ok(files_to_be_removed)
+    }
+}
@@ -28,7 +28,6 @@ use storages_common_table_meta::meta::SegmentInfo;
 
 use super::SegmentLocation;
 use crate::metrics::*;
-use crate::pruning::BloomPruner;
 use crate::pruning::PruningContext;
 
 pub struct BlockPruner {
@@ -47,8 +46,10 @@ impl BlockPruner {
         segment_location: SegmentLocation,
         segment_info: &SegmentInfo,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
-        if let Some(bloom_pruner) = &self.pruning_ctx.bloom_pruner {
-            self.block_pruning(bloom_pruner, segment_idx, segment_location, segment_info)
+        if self.pruning_ctx.bloom_pruner.is_some()
+            || self.pruning_ctx.inverted_index_pruner.is_some()
+        {
+            self.block_pruning(segment_idx, segment_location, segment_info)
                 .await
         } else {
             // if no available filter pruners, just prune the blocks by
@@ -57,11 +58,10 @@ impl BlockPruner {
         }
     }
 
-    // async pruning with bloom index.
+    // async pruning with bloom index and inverted index.
     #[async_backtrace::framed]
     async fn block_pruning(
         &self,
-        bloom_pruner: &Arc<dyn BloomPruner + Send + Sync>,
         segment_idx: usize,
         segment_location: SegmentLocation,
         segment_info: &SegmentInfo,
@@ -72,6 +72,8 @@ impl BlockPruner {
         let limit_pruner = self.pruning_ctx.limit_pruner.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
+        let bloom_pruner = self.pruning_ctx.bloom_pruner.clone();
+        let inverted_index_pruner = self.pruning_ctx.inverted_index_pruner.clone();
 
         let block_num = segment_info.blocks.len();
         let mut blocks = segment_info.blocks.iter().enumerate();
@@ -109,10 +111,12 @@ impl BlockPruner {
 
                     // not pruned by block zone map index,
                     let bloom_pruner = bloom_pruner.clone();
+                    let inverted_index_pruner = inverted_index_pruner.clone();
                     let limit_pruner = limit_pruner.clone();
                     let page_pruner = page_pruner.clone();
                     let index_location = block_meta.bloom_filter_index_location.clone();
                     let index_size = block_meta.bloom_filter_index_size;
+                    let inverted_index_location = block_meta.inverted_index_location.clone();
                     let column_ids = block_meta.col_metas.keys().cloned().collect::<Vec<_>>();
 
                     let v: BlockPruningFuture = Box::new(move |permit: OwnedSemaphorePermit| {
@@ -126,10 +130,22 @@ impl BlockPruner {
                             }
 
                             let _permit = permit;
-                            let keep = bloom_pruner
-                                .should_keep(&index_location, index_size, column_ids)
-                                .await
-                                && limit_pruner.within_limit(row_count);
+                            let mut keep = match &bloom_pruner {
+                                Some(bloom_pruner) => {
+                                    bloom_pruner
+                                        .should_keep(&index_location, index_size, column_ids)
+                                        .await
+                                }
+                                None => true,
+                            };
+                            if keep {
+                                if let Some(inverted_index_pruner) = &inverted_index_pruner {
+                                    keep = inverted_index_pruner
+                                        .should_keep(&inverted_index_location)
+                                        .await;
+                                }
+                            }
+                            let keep = keep && limit_pruner.within_limit(row_count);
 
                             if keep {
                                 // Perf.
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::Expr;
 use common_expression::FunctionContext;
@@ -47,6 +48,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// ngram indices that should be loaded from filter block
+    ngram_fields: Vec<TableField>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -69,10 +73,12 @@ impl BloomPrunerCreator {
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let point_query_cols = BloomIndex::find_eq_columns(expr)?;
+            let ngram_query_cols = BloomIndex::find_ngram_columns(expr)?;
 
-            if !point_query_cols.is_empty() {
+            if !point_query_cols.is_empty() || !ngram_query_cols.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
+                let mut ngram_fields = Vec::with_capacity(ngram_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
                 for (col_name, scalar, ty) in point_query_cols.iter() {
                     if let Ok(field) = schema.field_with_name(col_name) {
@@ -84,10 +90,27 @@ impl BloomPrunerCreator {
                         }
                     }
                 }
+                for (col_name, ngrams) in ngram_query_cols.into_iter() {
+                    if let Ok(field) = schema.field_with_name(&col_name) {
+                        ngram_fields.push(field.clone());
+                        for ngram in ngrams.into_iter() {
+                            let scalar = Scalar::String(ngram);
+                            if !scalar_map.contains_key(&scalar) {
+                                let digest = BloomIndex::calculate_scalar_digest(
+                                    &func_ctx,
+                                    &scalar,
+                                    &DataType::String,
+                                )?;
+                                scalar_map.insert(scalar, digest);
+                            }
+                        }
+                    }
+                }
 
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_fields,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -110,8 +133,8 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
-            Vec::with_capacity(self.index_fields.len()),
+        let mut index_columns = self.index_fields.iter().try_fold(
+            Vec::with_capacity(self.index_fields.len() + self.ngram_fields.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
                     acc.push(BloomIndex::build_filter_column_name(version, field)?);
@@ -119,6 +142,11 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        for field in self.ngram_fields.iter() {
+            if column_ids_of_indexed_block.contains(&field.column_id()) {
+                index_columns.push(BloomIndex::build_ngram_filter_column_name(field));
+            }
+        }
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
@@ -41,6 +41,8 @@ use super::create_segment_location_vector;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
+use crate::pruning::InvertedIndexPrunerCreator;
 use crate::pruning::SegmentPruner;
 
 pub struct PruningContext {
@@ -52,6 +54,7 @@ pub struct PruningContext {
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
+    pub inverted_index_pruner: Option<Arc<dyn InvertedIndexPruner + Send + Sync>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
@@ -112,6 +115,15 @@ impl FusePruner {
             filter_expr.as_ref(),
         )?;
 
+        // Inverted index pruner.
+        // None will be returned, if there is no `match` predicate in the filter
+        let inverted_index_pruner = InvertedIndexPrunerCreator::create(
+            func_ctx.clone(),
+            &table_schema,
+            dal.clone(),
+            filter_expr.as_ref(),
+        )?;
+
         // Page pruner, used in native format
         let page_pruner = PagePrunerCreator::try_create(
             func_ctx,
@@ -152,6 +164,7 @@ impl FusePruner {
             limit_pruner,
             range_pruner,
             bloom_pruner,
+            inverted_index_pruner,
             page_pruner,
             pruning_stats,
         });
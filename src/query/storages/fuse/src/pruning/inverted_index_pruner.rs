@@ -0,0 +1,106 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
+use opendal::Operator;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+use storages_common_table_meta::meta::Location;
+
+use crate::metrics::metrics_inc_block_index_read_bytes;
+
+#[async_trait::async_trait]
+pub trait InvertedIndexPruner {
+    // returns true, if target should NOT be pruned (false positive allowed)
+    async fn should_keep(&self, index_location: &Option<Location>) -> bool;
+}
+
+pub struct InvertedIndexPrunerCreator {
+    func_ctx: FunctionContext,
+
+    /// the expression that would be evaluate
+    filter_expression: Expr<String>,
+
+    /// the data accessor
+    dal: Operator,
+
+    /// the schema of data being indexed
+    data_schema: TableSchemaRef,
+}
+
+impl InvertedIndexPrunerCreator {
+    pub fn create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        dal: Operator,
+        filter_expr: Option<&Expr<String>>,
+    ) -> Result<Option<Arc<dyn InvertedIndexPruner + Send + Sync>>> {
+        if let Some(expr) = filter_expr {
+            let match_cols = InvertedIndex::find_match_columns(expr)?;
+            if match_cols
+                .iter()
+                .any(|col_name| schema.field_with_name(col_name).is_ok())
+            {
+                let creator = InvertedIndexPrunerCreator {
+                    func_ctx,
+                    filter_expression: expr.clone(),
+                    dal,
+                    data_schema: schema.clone(),
+                };
+                return Ok(Some(Arc::new(creator)));
+            }
+        }
+        Ok(None)
+    }
+
+    // Check a location file is hit or not by inverted index.
+    #[async_backtrace::framed]
+    pub async fn apply(&self, index_location: &Location) -> Result<bool> {
+        let data = self.dal.read(&index_location.0).await?;
+        metrics_inc_block_index_read_bytes(data.len() as u64);
+        let index = InvertedIndex::from_bytes(&data)?;
+        Ok(index.apply(
+            self.filter_expression.clone(),
+            &self.data_schema,
+            &self.func_ctx,
+        )? != FilterEvalResult::MustFalse)
+    }
+}
+
+#[async_trait::async_trait]
+impl InvertedIndexPruner for InvertedIndexPrunerCreator {
+    #[async_backtrace::framed]
+    async fn should_keep(&self, index_location: &Option<Location>) -> bool {
+        if let Some(loc) = index_location {
+            match self.apply(loc).await {
+                Ok(v) => v,
+                Err(e) => {
+                    // swallow exceptions intentionally, corrupted index should not prevent execution
+                    tracing::warn!(
+                        "failed to apply inverted index pruner, returning true. {}",
+                        e
+                    );
+                    true
+                }
+            }
+        } else {
+            true
+        }
+    }
+}
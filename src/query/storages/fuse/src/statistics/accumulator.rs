@@ -50,6 +50,7 @@ impl StatisticsAccumulator {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn add_block(
         &mut self,
         file_size: u64,
@@ -58,9 +59,11 @@ impl StatisticsAccumulator {
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
         block_compression: meta::Compression,
+        inverted_index_location: Option<Location>,
+        inverted_index_size: u64,
     ) -> Result<()> {
         self.file_size += file_size;
-        self.index_size += bloom_filter_index_size;
+        self.index_size += bloom_filter_index_size + inverted_index_size;
         self.summary_block_count += 1;
         self.in_memory_size += block_statistics.block_bytes_size;
         self.summary_row_count += block_statistics.block_rows_size;
@@ -91,6 +94,8 @@ impl StatisticsAccumulator {
             bloom_filter_index_location,
             bloom_filter_index_size,
             block_compression,
+            inverted_index_location,
+            inverted_index_size,
         )));
 
         Ok(())
@@ -101,7 +106,7 @@ impl StatisticsAccumulator {
         self.summary_block_count += 1;
         self.in_memory_size += block_meta.block_size;
         self.file_size += block_meta.file_size;
-        self.index_size += block_meta.bloom_filter_index_size;
+        self.index_size += block_meta.bloom_filter_index_size + block_meta.inverted_index_size;
         self.blocks_statistics.push(block_meta.col_stats.clone());
 
         if self.thresholds.check_large_enough(
@@ -0,0 +1,96 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_expression::Scalar;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::HistogramBucket;
+
+pub const HISTOGRAM_BUCKETS: usize = 100;
+pub const HISTOGRAM_MOST_COMMON_VALUES: usize = 10;
+
+/// Build an equi-depth histogram from the sampled non-null values of a column.
+///
+/// The counts of the sample are scaled up to `num_values`, the number of
+/// non-null values of the whole column, and the distinct counts are scaled up
+/// to `ndv`, the estimated number of distinct values of the whole column.
+pub fn build_column_histogram(
+    mut samples: Vec<Scalar>,
+    num_values: u64,
+    ndv: u64,
+    num_buckets: usize,
+    num_most_common_values: usize,
+) -> Option<ColumnHistogram> {
+    if samples.is_empty() || num_buckets == 0 {
+        return None;
+    }
+
+    let num_samples = samples.len();
+    samples.sort();
+
+    // Group the sorted samples into runs of equal values.
+    let mut groups: Vec<(Scalar, usize)> = Vec::new();
+    for value in samples {
+        match groups.last_mut() {
+            Some((last, count)) if *last == value => *count += 1,
+            _ => groups.push((value, 1)),
+        }
+    }
+
+    let values_scale = num_values as f64 / num_samples as f64;
+    let distinct_scale = if ndv as usize > groups.len() {
+        ndv as f64 / groups.len() as f64
+    } else {
+        1.0
+    };
+
+    // A value is common if it appears more often than the average value does.
+    let avg_count = num_samples as f64 / groups.len() as f64;
+    let mut most_common_values = groups
+        .iter()
+        .filter(|(_, count)| *count > 1 && *count as f64 > avg_count)
+        .collect::<Vec<_>>();
+    most_common_values.sort_by(|a, b| b.1.cmp(&a.1));
+    let most_common_values = most_common_values
+        .into_iter()
+        .take(num_most_common_values)
+        .map(|(value, count)| (value.clone(), (*count as f64 * values_scale) as u64))
+        .collect();
+
+    // Values equal to each other are always kept in the same bucket,
+    // so a single frequent value may make its bucket deeper than others.
+    let depth = (num_samples + num_buckets - 1) / num_buckets;
+    let mut buckets = Vec::with_capacity(num_buckets);
+    let mut bucket_values = 0;
+    let mut bucket_distinct = 0;
+    let num_groups = groups.len();
+    for (idx, (value, count)) in groups.into_iter().enumerate() {
+        bucket_values += count;
+        bucket_distinct += 1;
+        if bucket_values >= depth || idx == num_groups - 1 {
+            let num_values = bucket_values as f64 * values_scale;
+            buckets.push(HistogramBucket {
+                upper_bound: value,
+                num_values,
+                num_distinct: (bucket_distinct as f64 * distinct_scale).min(num_values),
+            });
+            bucket_values = 0;
+            bucket_distinct = 0;
+        }
+    }
+
+    Some(ColumnHistogram {
+        buckets,
+        most_common_values,
+    })
+}
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_arrow::arrow::io::orc::format::proto::ColumnStatistics as OrcColumnStatistics;
 use common_arrow::parquet::metadata::RowGroupMetaData;
 use common_arrow::parquet::statistics::BinaryStatistics;
 use common_arrow::parquet::statistics::BooleanStatistics;
@@ -23,6 +24,7 @@ use common_arrow::parquet::statistics::Statistics;
 use common_expression::types::number::F32;
 use common_expression::types::number::F64;
 use common_expression::types::BooleanType;
+use common_expression::types::DateType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -31,6 +33,7 @@ use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
+use common_storage::OrcFileMetadata;
 use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
@@ -95,36 +98,147 @@ impl HiveBlockFilter {
                 }
             }
 
-            for (p_key, p_value) in part_columns {
-                if let Ok(idx) = self.data_schema.index_of(&p_key) {
-                    let mut null_count = 0;
-                    let v = if p_value == HIVE_DEFAULT_PARTITION {
-                        null_count = row_group.num_rows();
-                        Scalar::Null
-                    } else {
-                        Scalar::String(p_value.as_bytes().to_vec())
-                    };
+            return self.filter_by_statistics(
+                filter,
+                statistics,
+                part_columns,
+                row_group.num_rows(),
+            );
+        }
+        false
+    }
 
+    // true: stripe of orc file is filtered by predict
+    pub fn filter_orc_stripe(
+        &self,
+        metadata: &OrcFileMetadata,
+        stripe: usize,
+        part_columns: HashMap<String, String>,
+    ) -> bool {
+        if let Some(filter) = &self.range_filter {
+            // the statistics of stripe are optional in orc files
+            let stripe_stats = match metadata.metadata.stripe_stats.get(stripe) {
+                Some(stripe_stats) => stripe_stats,
+                None => return false,
+            };
+            let root = match metadata.footer.types.first() {
+                Some(root) => root,
+                None => return false,
+            };
+            let num_rows = metadata.footer.stripes[stripe].number_of_rows();
+
+            let mut statistics = StatisticsOfColumns::new();
+            for col in self.projections.iter() {
+                // the top level fields of orc file are the subtypes of the root struct
+                let stats = root
+                    .field_names
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(col.name()))
+                    .and_then(|i| root.subtypes.get(i))
+                    .and_then(|column_id| stripe_stats.col_stats.get(*column_id as usize));
+                let stats = match stats {
+                    Some(stats) => stats,
+                    None => continue,
+                };
+                if let Some((max, min)) = Self::get_orc_max_min_stats(col.data_type(), stats) {
                     let col_stats = ColumnStatistics {
-                        min: v.clone(),
-                        max: v,
-                        null_count: null_count as u64,
+                        min,
+                        max,
+                        null_count: num_rows.saturating_sub(stats.number_of_values()),
                         in_memory_size: 0,
                         distinct_of_values: None,
                     };
-                    statistics.insert(idx as u32, col_stats);
+                    if let Ok(idx) = self.data_schema.index_of(col.name()) {
+                        statistics.insert(idx as u32, col_stats);
+                    }
                 }
             }
 
-            if let Ok(ret) = filter.apply(&statistics) {
-                if !ret {
-                    return true;
-                }
+            return self.filter_by_statistics(filter, statistics, part_columns, num_rows as usize);
+        }
+        false
+    }
+
+    // true: the data of statistics is filtered by predict, the partition columns are
+    // added to the statistics with the partition values
+    fn filter_by_statistics(
+        &self,
+        filter: &RangeIndex,
+        mut statistics: StatisticsOfColumns,
+        part_columns: HashMap<String, String>,
+        num_rows: usize,
+    ) -> bool {
+        for (p_key, p_value) in part_columns {
+            if let Ok(idx) = self.data_schema.index_of(&p_key) {
+                let mut null_count = 0;
+                let v = if p_value == HIVE_DEFAULT_PARTITION {
+                    null_count = num_rows;
+                    Scalar::Null
+                } else {
+                    Scalar::String(p_value.as_bytes().to_vec())
+                };
+
+                let col_stats = ColumnStatistics {
+                    min: v.clone(),
+                    max: v,
+                    null_count: null_count as u64,
+                    in_memory_size: 0,
+                    distinct_of_values: None,
+                };
+                statistics.insert(idx as u32, col_stats);
+            }
+        }
+
+        if let Ok(ret) = filter.apply(&statistics) {
+            if !ret {
+                return true;
             }
         }
         false
     }
 
+    fn get_orc_max_min_stats(
+        column_type: &TableDataType,
+        stats: &OrcColumnStatistics,
+    ) -> Option<(Scalar, Scalar)> {
+        match column_type {
+            TableDataType::Number(NumberDataType::Float32) => {
+                let s = stats.double_statistics.as_ref()?;
+                let max = NumberType::<F32>::upcast_scalar((s.maximum? as f32).into());
+                let min = NumberType::<F32>::upcast_scalar((s.minimum? as f32).into());
+                Some((max, min))
+            }
+            TableDataType::Number(NumberDataType::Float64) => {
+                let s = stats.double_statistics.as_ref()?;
+                let max = NumberType::<F64>::upcast_scalar(s.maximum?.into());
+                let min = NumberType::<F64>::upcast_scalar(s.minimum?.into());
+                Some((max, min))
+            }
+            TableDataType::Number(number_type) => {
+                let s = stats.int_statistics.as_ref()?;
+                let max = orc_int_to_scalar(number_type, s.maximum?)?;
+                let min = orc_int_to_scalar(number_type, s.minimum?)?;
+                Some((max, min))
+            }
+            TableDataType::String => {
+                let s = stats.string_statistics.as_ref()?;
+                let max = StringType::upcast_scalar(s.maximum.clone()?.into_bytes());
+                let min = StringType::upcast_scalar(s.minimum.clone()?.into_bytes());
+                Some((max, min))
+            }
+            TableDataType::Date => {
+                let s = stats.date_statistics.as_ref()?;
+                let max = DateType::upcast_scalar(s.maximum?);
+                let min = DateType::upcast_scalar(s.minimum?);
+                Some((max, min))
+            }
+            TableDataType::Nullable(inner_ty) => {
+                Self::get_orc_max_min_stats(inner_ty.as_ref(), stats)
+            }
+            _ => None,
+        }
+    }
+
     fn get_max_min_stats(
         column_type: &TableDataType,
         stats: &dyn Statistics,
@@ -297,3 +411,19 @@ impl HiveBlockFilter {
         }
     }
 }
+
+// orc files keep the statistics of all integer columns in i64
+fn orc_int_to_scalar(number_type: &NumberDataType, v: i64) -> Option<Scalar> {
+    let scalar = match number_type {
+        NumberDataType::UInt8 => NumberType::<u8>::upcast_scalar(u8::try_from(v).ok()?),
+        NumberDataType::UInt16 => NumberType::<u16>::upcast_scalar(u16::try_from(v).ok()?),
+        NumberDataType::UInt32 => NumberType::<u32>::upcast_scalar(u32::try_from(v).ok()?),
+        NumberDataType::UInt64 => NumberType::<u64>::upcast_scalar(u64::try_from(v).ok()?),
+        NumberDataType::Int8 => NumberType::<i8>::upcast_scalar(i8::try_from(v).ok()?),
+        NumberDataType::Int16 => NumberType::<i16>::upcast_scalar(i16::try_from(v).ok()?),
+        NumberDataType::Int32 => NumberType::<i32>::upcast_scalar(i32::try_from(v).ok()?),
+        NumberDataType::Int64 => NumberType::<i64>::upcast_scalar(v),
+        _ => return None,
+    };
+    Some(scalar)
+}
@@ -0,0 +1,215 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::io::orc::read::deserialize;
+use common_base::base::Progress;
+use common_base::base::ProgressValues;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_storage::orc_stripe_range;
+use common_storage::read_orc_metadata_async;
+use common_storage::read_orc_stripe_async;
+use common_storage::OrcFileMetadata;
+use opendal::Operator;
+
+use crate::hive_partition::HivePartInfo;
+use crate::HiveBlockFilter;
+use crate::HivePartitionFiller;
+
+/// Reads the stripes of the orc files of a hive table. A stripe is read by the part
+/// containing its middle, and is skipped if pruned by its statistics.
+pub struct HiveOrcSource {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    // the projected fields which are not partition keys, read from the orc files
+    data_fields: Vec<TableField>,
+    hive_partition_filler: Option<HivePartitionFiller>,
+    hive_block_filter: Arc<HiveBlockFilter>,
+    // the data fields followed by the partition fields
+    source_schema: DataSchemaRef,
+    output_schema: DataSchemaRef,
+    scan_progress: Arc<Progress>,
+    blocks: VecDeque<DataBlock>,
+}
+
+impl HiveOrcSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        output: Arc<OutputPort>,
+        data_fields: Vec<TableField>,
+        partition_fields: Vec<TableField>,
+        hive_block_filter: Arc<HiveBlockFilter>,
+        output_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        let mut source_fields = data_fields.clone();
+        source_fields.extend(partition_fields.iter().cloned());
+        let source_schema = Arc::new(DataSchema::from(&TableSchema::new(source_fields)));
+        let hive_partition_filler = if !partition_fields.is_empty() {
+            Some(HivePartitionFiller::create(
+                Arc::new(TableSchema::new(partition_fields.clone())),
+                partition_fields,
+            ))
+        } else {
+            None
+        };
+        let scan_progress = ctx.get_scan_progress();
+        AsyncSourcer::create(ctx.clone(), output, HiveOrcSource {
+            ctx,
+            dal,
+            data_fields,
+            hive_partition_filler,
+            hive_block_filter,
+            source_schema,
+            output_schema,
+            scan_progress,
+            blocks: VecDeque::new(),
+        })
+    }
+
+    // the ids of the data fields in the types of the file footer
+    fn get_column_ids(&self, metadata: &OrcFileMetadata, filename: &str) -> Result<Vec<u32>> {
+        let root =
+            metadata.footer.types.first().ok_or_else(|| {
+                ErrorCode::BadBytes(format!("orc file '{}' has no types", filename))
+            })?;
+        self.data_fields
+            .iter()
+            .map(|field| {
+                root.field_names
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(field.name()))
+                    .and_then(|i| root.subtypes.get(i).copied())
+                    .ok_or_else(|| {
+                        ErrorCode::TableSchemaMismatch(format!(
+                            "couldn't find column:{} in orc file '{}'",
+                            field.name(),
+                            filename
+                        ))
+                    })
+            })
+            .collect()
+    }
+
+    #[async_backtrace::framed]
+    async fn read_part(&mut self, part: &HivePartInfo) -> Result<()> {
+        let metadata = read_orc_metadata_async(&self.dal, &part.filename, part.filesize).await?;
+        let column_ids = self.get_column_ids(&metadata, &part.filename)?;
+        let data_types = TableSchema::new(self.data_fields.clone())
+            .to_arrow()
+            .fields
+            .into_iter()
+            .map(|f| f.data_type)
+            .collect::<Vec<_>>();
+        let data_schema = DataSchema::from(&TableSchema::new(self.data_fields.clone()));
+
+        let mut pruned_stripe_cnt = 0;
+        for stripe in 0..metadata.footer.stripes.len() {
+            let range = orc_stripe_range(&metadata, stripe);
+            let mid = range.start + (range.end - range.start) / 2;
+            if !part.range.contains(&mid) {
+                continue;
+            }
+            if self
+                .hive_block_filter
+                .filter_orc_stripe(&metadata, stripe, part.get_partition_map())
+            {
+                pruned_stripe_cnt += 1;
+                continue;
+            }
+
+            let num_rows = metadata.footer.stripes[stripe].number_of_rows() as usize;
+            let block = if column_ids.is_empty() {
+                DataBlock::new(vec![], num_rows)
+            } else {
+                let columns = read_orc_stripe_async(
+                    &self.dal,
+                    &part.filename,
+                    &metadata,
+                    stripe,
+                    &column_ids,
+                )
+                .await?;
+                let arrays = data_types
+                    .iter()
+                    .zip(columns.iter())
+                    .map(|(data_type, column)| deserialize(data_type.clone(), column))
+                    .collect::<common_arrow::arrow::error::Result<Vec<_>>>()
+                    .map_err(|e| {
+                        ErrorCode::BadBytes(format!(
+                            "fail to deserialize stripe {} of orc file '{}': {}",
+                            stripe, part.filename, e
+                        ))
+                    })?;
+                let chunk = ArrowChunk::try_new(arrays)?;
+                DataBlock::from_arrow_chunk(&chunk, &data_schema)?
+            };
+
+            let progress_values = ProgressValues {
+                rows: block.num_rows(),
+                bytes: block.memory_size(),
+            };
+            self.scan_progress.incr(&progress_values);
+
+            let block = match &self.hive_partition_filler {
+                Some(filler) => filler.fill_data(block, part, num_rows)?,
+                None => block,
+            };
+            let block = block.resort(&self.source_schema, &self.output_schema)?;
+            self.blocks.push_back(block);
+        }
+        tracing::debug!(
+            "hive orc predict pushdown have pruned {} stripes",
+            pruned_stripe_cnt
+        );
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for HiveOrcSource {
+    const NAME: &'static str = "HiveOrcSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.blocks.pop_front() {
+                return Ok(Some(block));
+            }
+            match self.ctx.get_partition() {
+                None => return Ok(None),
+                Some(part) => {
+                    let part = HivePartInfo::from_part(&part)?.clone();
+                    self.read_part(&part).await?;
+                }
+            }
+        }
+    }
+}
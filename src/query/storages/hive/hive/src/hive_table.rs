@@ -58,8 +58,10 @@ use storages_common_index::RangeIndex;
 
 use super::hive_catalog::HiveCatalog;
 use super::hive_partition_pruner::HivePartitionPruner;
+use super::hive_table_options::HiveFileFormat;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
+use crate::hive_orc_source::HiveOrcSource;
 use crate::hive_parquet_block_reader::HiveBlockReader;
 use crate::hive_table_source::HiveTableSource;
 use crate::HiveBlockFilter;
@@ -172,6 +174,10 @@ impl HiveTable {
         plan: &DataSourcePlan,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        if self.table_options.file_format == HiveFileFormat::Orc {
+            return self.do_read_orc(ctx, plan, pipeline);
+        }
+
         let push_downs = &plan.push_downs;
         let chunk_size = ctx.get_settings().get_hive_parquet_chunk_size()? as usize;
 
@@ -230,6 +236,54 @@ impl HiveTable {
         Ok(())
     }
 
+    // The orc files are read stripe by stripe, without prewhere.
+    fn do_read_orc(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let projection =
+            match PushDownInfo::projection_of_push_downs(&plan.schema(), &plan.push_downs) {
+                Projection::Columns(projection) => projection,
+                Projection::InnerColumns(_) => {
+                    return Err(ErrorCode::Unimplemented(
+                        "does not support projection inner columns",
+                    ));
+                }
+            };
+        let schema = self.table_info.schema();
+        let (data_projection, partition_fields) = filter_hive_partition_from_partition_keys(
+            schema.clone(),
+            projection,
+            &self.table_options.partition_keys,
+        );
+        let data_fields = data_projection
+            .into_iter()
+            .map(|i| schema.field(i).clone())
+            .collect::<Vec<_>>();
+
+        let hive_block_filter = self.get_block_filter(ctx.clone(), &plan.push_downs)?;
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(plan.parts.len(), max_threads);
+        pipeline.add_source(
+            |output| {
+                HiveOrcSource::create(
+                    ctx.clone(),
+                    self.dal.clone(),
+                    output,
+                    data_fields.clone(),
+                    partition_fields.clone(),
+                    hive_block_filter.clone(),
+                    output_schema.clone(),
+                )
+            },
+            std::cmp::max(1, max_threads),
+        )
+    }
+
     // simple select query is the sql likes `select * from xx limit 10` or
     // `select * from xx where p_date = '20220201' limit 10` where p_date is a partition column;
     // we just need to read a few data from table
@@ -619,8 +673,9 @@ impl Table for HiveTable {
         Ok(None)
     }
 
+    // the orc reader doesn't apply prewhere, the filter is evaluated after the scan.
     fn support_prewhere(&self) -> bool {
-        true
+        self.table_options.file_format == HiveFileFormat::Parquet
     }
 }
 
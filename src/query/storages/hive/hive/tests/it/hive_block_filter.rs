@@ -0,0 +1,175 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::orc::format::proto::ColumnStatistics;
+use common_arrow::arrow::io::orc::format::proto::Footer;
+use common_arrow::arrow::io::orc::format::proto::IntegerStatistics;
+use common_arrow::arrow::io::orc::format::proto::Metadata;
+use common_arrow::arrow::io::orc::format::proto::PostScript;
+use common_arrow::arrow::io::orc::format::proto::StripeInformation;
+use common_arrow::arrow::io::orc::format::proto::StripeStatistics;
+use common_arrow::arrow::io::orc::format::proto::Type;
+use common_expression::type_check::check_function;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_storage::OrcFileMetadata;
+use common_storages_hive::HiveBlockFilter;
+use storages_common_index::RangeIndex;
+
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+#[test]
+fn test_filter_orc_stripe() {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("p", TableDataType::String.wrap_nullable()),
+    ]));
+    let projections = vec![schema.field(0).clone()];
+
+    // stripe 0: a in [0, 9], stripe 1: a in [10, 19], stripe 2 has no statistics
+    let metadata = orc_metadata(&[(0, 9), (10, 19)], 3);
+
+    // a > 15
+    let filter = block_filter(
+        "gt",
+        column_ref("a", DataType::Number(NumberDataType::Int32)),
+        Scalar::Number(NumberScalar::Int32(15)),
+        schema.clone(),
+        projections.clone(),
+    );
+    assert!(filter.filter_orc_stripe(&metadata, 0, HashMap::new()));
+    assert!(!filter.filter_orc_stripe(&metadata, 1, HashMap::new()));
+    assert!(!filter.filter_orc_stripe(&metadata, 2, HashMap::new()));
+
+    // a < 10
+    let filter = block_filter(
+        "lt",
+        column_ref("a", DataType::Number(NumberDataType::Int32)),
+        Scalar::Number(NumberScalar::Int32(10)),
+        schema.clone(),
+        projections.clone(),
+    );
+    assert!(!filter.filter_orc_stripe(&metadata, 0, HashMap::new()));
+    assert!(filter.filter_orc_stripe(&metadata, 1, HashMap::new()));
+    assert!(!filter.filter_orc_stripe(&metadata, 2, HashMap::new()));
+
+    // p = 'x', the partition value is the same for all the stripes of a file
+    let filter = block_filter(
+        "eq",
+        column_ref("p", DataType::String.wrap_nullable()),
+        Scalar::String(b"x".to_vec()),
+        schema,
+        projections,
+    );
+    let partition = |value: &str| HashMap::from([("p".to_string(), value.to_string())]);
+    for stripe in 0..3 {
+        assert!(!filter.filter_orc_stripe(&metadata, stripe, partition("x")));
+        assert!(filter.filter_orc_stripe(&metadata, stripe, partition("y")));
+        assert!(filter.filter_orc_stripe(&metadata, stripe, partition(HIVE_DEFAULT_PARTITION)));
+    }
+}
+
+fn block_filter(
+    func_name: &str,
+    column: Expr<String>,
+    value: Scalar,
+    schema: Arc<TableSchema>,
+    projections: Vec<TableField>,
+) -> HiveBlockFilter {
+    let data_type = match &column {
+        Expr::ColumnRef { data_type, .. } => data_type.clone(),
+        _ => unreachable!(),
+    };
+    let constant = Expr::Constant {
+        span: None,
+        scalar: value,
+        data_type,
+    };
+    let expr = check_function(
+        None,
+        func_name,
+        &[],
+        &[column, constant],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    let range_filter =
+        RangeIndex::try_create(FunctionContext::default(), &expr, schema.clone()).unwrap();
+    HiveBlockFilter::create(Some(range_filter), projections, schema)
+}
+
+fn column_ref(name: &str, data_type: DataType) -> Expr<String> {
+    Expr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type,
+        display_name: name.to_string(),
+    }
+}
+
+/// The metadata of an orc file with the column `a`, each stripe has 10 rows,
+/// only the first stripes have the statistics of `ranges`.
+fn orc_metadata(ranges: &[(i64, i64)], num_stripes: usize) -> OrcFileMetadata {
+    let footer = Footer {
+        stripes: (0..num_stripes)
+            .map(|_| StripeInformation {
+                number_of_rows: Some(10),
+                ..Default::default()
+            })
+            .collect(),
+        types: vec![Type {
+            subtypes: vec![1],
+            field_names: vec!["a".to_string()],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let stripe_stats = ranges
+        .iter()
+        .map(|(min, max)| StripeStatistics {
+            col_stats: vec![
+                // the root struct
+                ColumnStatistics {
+                    number_of_values: Some(10),
+                    ..Default::default()
+                },
+                ColumnStatistics {
+                    number_of_values: Some(10),
+                    int_statistics: Some(IntegerStatistics {
+                        minimum: Some(*min),
+                        maximum: Some(*max),
+                        sum: None,
+                    }),
+                    ..Default::default()
+                },
+            ],
+        })
+        .collect();
+    OrcFileMetadata {
+        postscript: PostScript::default(),
+        footer,
+        metadata: Metadata { stripe_stats },
+    }
+}
@@ -12,4 +12,5 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod hive_block_filter;
 mod hive_file_splitter;
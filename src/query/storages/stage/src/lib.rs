@@ -14,6 +14,7 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod stage_partitioned_sink;
 mod stage_table;
 mod stage_table_sink;
 
@@ -0,0 +1,292 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_catalog::plan::StageTableInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_formats::output_format::OutputFormat;
+use common_formats::FileFormatOptionsExt;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+use opendal::Operator;
+
+use crate::stage_table_sink::StageTableSink;
+
+/// The directory name of the partition whose value is NULL, the same as hive.
+const DEFAULT_PARTITION_NAME: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The max number of partitions buffered at the same time, the largest one is flushed
+/// to a file and closed to open a new one.
+const MAX_OPEN_PARTITIONS: usize = 64;
+
+/// The files being written to a partition directory.
+struct PartitionWriter {
+    output_format: Box<dyn OutputFormat>,
+    buffer: Vec<u8>,
+    // the number of rows not written yet
+    num_rows: usize,
+}
+
+/// Unloads the rows into the hive style `key=value/` directories of their partitions.
+///
+/// The input blocks are the data columns followed by the partition key columns casted
+/// to string, the key columns are not written to the files. Each partition rolls its
+/// own files once they reach `max_file_size`, at most `MAX_OPEN_PARTITIONS` partitions
+/// are buffered at the same time.
+pub struct StagePartitionedSink {
+    ctx: Arc<dyn TableContext>,
+    table_info: StageTableInfo,
+    data_accessor: Operator,
+    partition_by: Vec<String>,
+    writers: HashMap<String, PartitionWriter>,
+    // the number of files written to each partition, kept after its writer is closed
+    batch_ids: HashMap<String, usize>,
+
+    uuid: String,
+    group_id: usize,
+    max_file_size: usize,
+}
+
+impl StagePartitionedSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        table_info: StageTableInfo,
+        data_accessor: Operator,
+        partition_by: Vec<String>,
+        uuid: String,
+        group_id: usize,
+    ) -> Result<ProcessorPtr> {
+        let max_file_size = StageTableSink::adjust_max_file_size(&ctx, &table_info)?;
+        Ok(ProcessorPtr::create(AsyncSinker::create(
+            input,
+            StagePartitionedSink {
+                ctx,
+                table_info,
+                data_accessor,
+                partition_by,
+                writers: HashMap::new(),
+                batch_ids: HashMap::new(),
+                uuid,
+                group_id,
+                max_file_size,
+            },
+        )))
+    }
+
+    fn create_output_format(&self) -> Result<Box<dyn OutputFormat>> {
+        let mut options_ext = FileFormatOptionsExt::create_from_settings(&self.ctx.get_settings())?;
+        options_ext.get_output_format(
+            self.table_info.schema(),
+            self.table_info.stage_info.file_format_params.clone(),
+        )
+    }
+
+    // e.g. `year=2023/month=5`
+    fn partition_path(&self, block: &DataBlock, row: usize) -> String {
+        let offset = block.num_columns() - self.partition_by.len();
+        self.partition_by
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                let value = block.get_by_offset(offset + i).value.index(row);
+                let value = match value {
+                    Some(ScalarRef::String(s)) => {
+                        escape_partition_value(&String::from_utf8_lossy(s))
+                    }
+                    _ => DEFAULT_PARTITION_NAME.to_string(),
+                };
+                format!("{}={}", escape_partition_value(key), value)
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn unload_path(&self, partition: &str, batch_id: usize) -> String {
+        let format_name = format!(
+            "{:?}",
+            self.table_info.stage_info.file_format_params.get_type()
+        );
+        format!(
+            "{}/{}/data_{}_{:0>4}_{:0>8}.{}",
+            self.table_info.files_info.path.trim_end_matches('/'),
+            partition,
+            self.uuid,
+            self.group_id,
+            batch_id,
+            format_name.to_ascii_lowercase()
+        )
+    }
+
+    #[async_backtrace::framed]
+    async fn write_partition(&mut self, partition: &str, block: DataBlock) -> Result<()> {
+        if !self.writers.contains_key(partition) {
+            if self.writers.len() >= MAX_OPEN_PARTITIONS {
+                self.close_largest_partition().await?;
+            }
+            let output_format = self.create_output_format()?;
+            self.writers.insert(partition.to_string(), PartitionWriter {
+                buffer: output_format.serialize_prefix()?,
+                output_format,
+                num_rows: 0,
+            });
+        }
+
+        for i in (0..block.num_rows()).step_by(1024) {
+            let end = (i + 1024).min(block.num_rows());
+            let writer = self.writers.get_mut(partition).unwrap();
+            let bs = writer.output_format.serialize_block(&block.slice(i..end))?;
+            writer.buffer.extend_from_slice(&bs);
+            writer.num_rows += end - i;
+
+            if writer.buffer.len() + writer.output_format.buffer_size() >= self.max_file_size {
+                self.flush_partition(partition).await?;
+            }
+        }
+        Ok(())
+    }
+
+    // write the buffered data of the partition to a file, and start a new file.
+    #[async_backtrace::framed]
+    async fn flush_partition(&mut self, partition: &str) -> Result<()> {
+        let output_format = self.create_output_format()?;
+        let prefix = output_format.serialize_prefix()?;
+
+        let writer = self.writers.get_mut(partition).unwrap();
+        let bs = writer.output_format.finalize()?;
+        writer.buffer.extend_from_slice(&bs);
+        let data = std::mem::replace(&mut writer.buffer, prefix);
+        writer.output_format = output_format;
+        writer.num_rows = 0;
+        let next_batch_id = self.batch_ids.entry(partition.to_string()).or_default();
+        let batch_id = *next_batch_id;
+        *next_batch_id += 1;
+
+        let path = self.unload_path(partition, batch_id);
+        self.data_accessor.write(&path, data).await?;
+        Ok(())
+    }
+
+    // flush the partition buffering the most data and drop its writer.
+    #[async_backtrace::framed]
+    async fn close_largest_partition(&mut self) -> Result<()> {
+        let largest = self
+            .writers
+            .iter_mut()
+            .max_by_key(|(_, writer)| writer.buffer.len() + writer.output_format.buffer_size())
+            .map(|(partition, writer)| (partition.clone(), writer.num_rows));
+        if let Some((partition, num_rows)) = largest {
+            if num_rows > 0 {
+                self.flush_partition(&partition).await?;
+            }
+            self.writers.remove(&partition);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncSink for StagePartitionedSink {
+    const NAME: &'static str = "StagePartitionedSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let partitions = self
+            .writers
+            .iter()
+            .filter(|(_, writer)| writer.num_rows > 0)
+            .map(|(partition, _)| partition.clone())
+            .collect::<Vec<_>>();
+        for partition in partitions {
+            self.flush_partition(&partition).await?;
+        }
+        self.writers.clear();
+        self.batch_ids.clear();
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        let num_rows = data_block.num_rows();
+        if num_rows == 0 {
+            return Ok(false);
+        }
+
+        // the index of the partition of each row
+        let mut partitions: Vec<String> = vec![];
+        let mut partition_indices: HashMap<String, u32> = HashMap::new();
+        let mut indices = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let path = self.partition_path(&data_block, row);
+            let index = match partition_indices.get(&path) {
+                Some(index) => *index,
+                None => {
+                    let index = partitions.len() as u32;
+                    partition_indices.insert(path.clone(), index);
+                    partitions.push(path);
+                    index
+                }
+            };
+            indices.push(index);
+        }
+
+        let blocks = data_block
+            .pop_columns(self.partition_by.len())?
+            .scatter(&indices, partitions.len())?;
+        for (partition, block) in partitions.iter().zip(blocks.into_iter()) {
+            self.write_partition(partition, block).await?;
+        }
+        Ok(false)
+    }
+}
+
+// escape the characters which are not allowed in the path of a partition, like hive.
+fn escape_partition_value(value: &str) -> String {
+    if value.is_empty() {
+        return DEFAULT_PARTITION_NAME.to_string();
+    }
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\u{01}'..='\u{1F}'
+            | '"'
+            | '#'
+            | '%'
+            | '\''
+            | '*'
+            | '/'
+            | ':'
+            | '='
+            | '?'
+            | '\\'
+            | '\u{7F}'
+            | '{'
+            | '['
+            | ']'
+            | '^' => escaped.push_str(&format!("%{:02X}", c as u32)),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
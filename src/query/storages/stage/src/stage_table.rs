@@ -45,6 +45,7 @@ use dashmap::DashMap;
 use opendal::Operator;
 use parking_lot::Mutex;
 
+use crate::stage_partitioned_sink::StagePartitionedSink;
 use crate::stage_table_sink::StageTableSink;
 
 /// TODO: we need to track the data metrics in stage table.
@@ -88,6 +89,37 @@ impl StageTable {
         Ok(infos)
     }
 
+    /// Unload the data into the hive style partition directories under the path of the
+    /// stage. The data blocks are the columns of `table_info` followed by the string
+    /// values of the `partition_by` keys.
+    pub fn append_partitioned_data(
+        ctx: Arc<dyn TableContext>,
+        table_info: &StageTableInfo,
+        partition_by: &[String],
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let op = StageTable::get_op(&table_info.stage_info)?;
+        let uuid = uuid::Uuid::new_v4().to_string();
+        let group_id = AtomicUsize::new(0);
+
+        if table_info.stage_info.copy_options.single {
+            pipeline.resize(1)?;
+        }
+
+        pipeline.add_sink(|input| {
+            let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            StagePartitionedSink::try_create(
+                input,
+                ctx.clone(),
+                table_info.clone(),
+                op.clone(),
+                partition_by.to_vec(),
+                uuid.clone(),
+                gid,
+            )
+        })
+    }
+
     fn get_block_compact_thresholds_with_default(&self) -> BlockThresholds {
         let guard = self.block_compact_threshold.lock();
         match guard.deref() {
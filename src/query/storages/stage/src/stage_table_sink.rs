@@ -98,7 +98,7 @@ impl StageTableSink {
         })))
     }
 
-    fn adjust_max_file_size(
+    pub(crate) fn adjust_max_file_size(
         ctx: &Arc<dyn TableContext>,
         stage_info: &StageTableInfo,
     ) -> Result<usize> {
@@ -0,0 +1,75 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storages_fuse::operations::TableChanges;
+
+/// A group of the blocks of the source table changed together since the offset of a
+/// stream, they are read as a whole, since the unchanged rows are netted out across
+/// the blocks of a rewrite.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct StreamPartInfo {
+    pub changes: TableChanges,
+}
+
+#[typetag::serde(name = "stream")]
+impl PartInfo for StreamPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<StreamPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        for block in self
+            .changes
+            .inserted
+            .iter()
+            .chain(self.changes.deleted.iter())
+        {
+            block.location.hash(&mut s);
+        }
+        s.finish()
+    }
+}
+
+impl StreamPartInfo {
+    pub fn create(changes: TableChanges) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(StreamPartInfo { changes }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&StreamPartInfo> {
+        match info.as_any().downcast_ref::<StreamPartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to StreamPartInfo.",
+            )),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+use common_meta_app::schema::TableInfo;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_storages_fuse::operations::TableChanges;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::CHANGE_ACTION_COL_NAME;
+use common_storages_fuse::CHANGE_IS_UPDATE_COL_NAME;
+use storages_common_table_meta::table::OPT_KEY_STREAM_OFFSET;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_DATABASE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE_ID;
+
+use crate::StreamPartInfo;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+
+/// A stream returns the rows of a fuse table changed since its offset, which is a
+/// snapshot of the source table. The offset is advanced once the stream is consumed
+/// by a DML statement.
+#[derive(Clone)]
+pub struct StreamTable {
+    table_info: TableInfo,
+    source_database: String,
+    source_table: String,
+    source_table_id: u64,
+    offset: Option<String>,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = table_info.options();
+        let (source_database, source_table, source_table_id) = match (
+            options.get(OPT_KEY_STREAM_SOURCE_DATABASE),
+            options.get(OPT_KEY_STREAM_SOURCE_TABLE),
+            options.get(OPT_KEY_STREAM_SOURCE_TABLE_ID),
+        ) {
+            (Some(database), Some(table), Some(table_id)) => {
+                let table_id = table_id.parse::<u64>().map_err(|_| {
+                    ErrorCode::Internal(format!("invalid source table id of stream: {}", table_id))
+                })?;
+                (database.clone(), table.clone(), table_id)
+            }
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "Need the source table when creating StreamTable",
+                ));
+            }
+        };
+        let offset = options.get(OPT_KEY_STREAM_OFFSET).cloned();
+        Ok(Box::new(StreamTable {
+            table_info,
+            source_database,
+            source_table,
+            source_table_id,
+            offset,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM STORAGE (CHANGES OF FUSE TABLE)".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&StreamTable> {
+        tbl.as_any().downcast_ref::<StreamTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine STREAM, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
+    pub fn source_database(&self) -> &str {
+        &self.source_database
+    }
+
+    pub fn source_table_name(&self) -> &str {
+        &self.source_table
+    }
+
+    /// The snapshot location of the source table that the stream has been consumed to,
+    /// `None` if the source table had no snapshot.
+    pub fn offset(&self) -> Option<&str> {
+        self.offset.as_deref()
+    }
+
+    /// Get the source table of the stream, it is resolved by the query context, so
+    /// the snapshot read by a query is the same one its stream offsets advance to.
+    #[async_backtrace::framed]
+    pub async fn source_table(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let table = ctx
+            .get_table(
+                self.table_info.catalog(),
+                &self.source_database,
+                &self.source_table,
+            )
+            .await?;
+        if table.get_id() != self.source_table_id {
+            return Err(ErrorCode::UnknownTable(format!(
+                "the source table {}.{} of stream {} has been dropped",
+                self.source_database, self.source_table, self.table_info.desc
+            )));
+        }
+        Ok(table)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let source = self.source_table(ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(source.as_ref())?;
+        let changes = fuse_table.collect_changes(ctx, self.offset()).await?;
+
+        let read_rows = changes.iter().map(|c| c.row_count()).sum::<u64>();
+        let read_bytes = changes.iter().map(|c| c.block_size()).sum::<u64>();
+        let parts = changes
+            .into_iter()
+            .map(StreamPartInfo::create)
+            .collect::<Vec<_>>();
+        let statistics = PartStatistics::new_estimated(
+            read_rows as usize,
+            read_bytes as usize,
+            parts.len(),
+            parts.len(),
+        );
+        Ok((
+            statistics,
+            Partitions::create_nolazy(PartitionsShuffleKind::Seq, parts),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(plan.parts.len(), max_threads);
+        let output_schema = plan.schema();
+        pipeline.add_source(
+            |output| StreamSource::create(ctx.clone(), output, self.clone(), output_schema.clone()),
+            std::cmp::max(1, max_threads),
+        )
+    }
+}
+
+/// Reads the changes of the parts, each part is a group of the blocks changed together.
+struct StreamSource {
+    ctx: Arc<dyn TableContext>,
+    stream: StreamTable,
+    output_schema: TableSchemaRef,
+    blocks: VecDeque<DataBlock>,
+}
+
+impl StreamSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        stream: StreamTable,
+        output_schema: TableSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, StreamSource {
+            ctx,
+            stream,
+            output_schema,
+            blocks: VecDeque::new(),
+        })
+    }
+
+    /// Read the changed rows, and project them to the output columns of the stream.
+    #[async_backtrace::framed]
+    async fn read_changes(&self, changes: &TableChanges) -> Result<Vec<DataBlock>> {
+        let source = self.stream.source_table(self.ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(source.as_ref())?;
+
+        // The columns of the source table to read, the changes are read in them followed
+        // by the action and the update flag.
+        let source_schema = fuse_table.schema();
+        let mut read_columns = vec![];
+        for field in self.output_schema.fields() {
+            let name = field.name().as_str();
+            if !matches!(name, CHANGE_ACTION_COL_NAME | CHANGE_IS_UPDATE_COL_NAME) {
+                read_columns.push(source_schema.index_of(name)?);
+            }
+        }
+        let num_read_columns = read_columns.len();
+        let mut next_column = 0;
+        let projection = self
+            .output_schema
+            .fields()
+            .iter()
+            .map(|field| match field.name().as_str() {
+                CHANGE_ACTION_COL_NAME => num_read_columns,
+                CHANGE_IS_UPDATE_COL_NAME => num_read_columns + 1,
+                _ => {
+                    next_column += 1;
+                    next_column - 1
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let blocks = fuse_table
+            .read_changes(self.ctx.clone(), changes, &read_columns)
+            .await?;
+        Ok(blocks
+            .into_iter()
+            .map(|block| {
+                let num_rows = block.num_rows();
+                let columns = projection
+                    .iter()
+                    .map(|index| block.get_by_offset(*index).clone())
+                    .collect();
+                DataBlock::new(columns, num_rows)
+            })
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for StreamSource {
+    const NAME: &'static str = "stream";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.blocks.pop_front() {
+                return Ok(Some(block));
+            }
+            match self.ctx.get_partition() {
+                None => return Ok(None),
+                Some(part) => {
+                    let changes = StreamPartInfo::from_part(&part)?.changes.clone();
+                    let blocks = self.read_changes(&changes).await?;
+                    self.blocks.extend(blocks);
+                }
+            }
+        }
+    }
+}
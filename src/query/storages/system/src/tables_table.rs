@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_catalog::catalog::Catalog;
@@ -20,6 +21,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::types::number::UInt64Type;
+use common_expression::types::BooleanType;
 use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
 use common_expression::utils::FromData;
@@ -32,6 +34,10 @@ use common_expression::TableSchemaRefExt;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use storages_common_table_meta::table::MaterializedViewSources;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCES;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
@@ -207,6 +213,42 @@ where TablesTable<T>: HistoryAware
             })
             .collect();
 
+        // A materialized view is stale if any of its source tables is dropped,
+        // or changed since the last refresh of the view.
+        let snapshots: HashMap<u64, Option<&String>> = database_tables
+            .iter()
+            .filter(|v| v.get_table_info().meta.drop_on.is_none())
+            .map(|v| {
+                (
+                    v.get_table_info().ident.table_id,
+                    v.options().get(OPT_KEY_SNAPSHOT_LOCATION),
+                )
+            })
+            .collect();
+        let mut refreshed_owns: Vec<Vec<u8>> = Vec::with_capacity(database_tables.len());
+        let mut is_stale: Vec<Option<bool>> = Vec::with_capacity(database_tables.len());
+        for tbl in &database_tables {
+            let options = tbl.options();
+            let refreshed_on = options
+                .get(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON)
+                .map_or("NULL", |v| v.as_str());
+            refreshed_owns.push(refreshed_on.as_bytes().to_vec());
+
+            let stale = match options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCES) {
+                Some(sources) => {
+                    let sources = MaterializedViewSources::try_from(sources.as_str())?;
+                    let stale = sources.snapshots.iter().any(|(table_id, location)| {
+                        snapshots.get(table_id).map_or(true, |current| {
+                            current.map(|v| v.as_str()) != location.as_deref()
+                        })
+                    });
+                    Some(stale)
+                }
+                None => None,
+            };
+            is_stale.push(stale);
+        }
+
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(catalogs),
             StringType::from_data(databases),
@@ -222,6 +264,8 @@ where TablesTable<T>: HistoryAware
             UInt64Type::from_opt_data(data_size),
             UInt64Type::from_opt_data(data_compressed_size),
             UInt64Type::from_opt_data(index_size),
+            StringType::from_data(refreshed_owns),
+            BooleanType::from_opt_data(is_stale),
         ]))
     }
 }
@@ -257,6 +301,11 @@ where TablesTable<T>: HistoryAware
                 "index_size",
                 TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
             ),
+            TableField::new("refreshed_on", TableDataType::String),
+            TableField::new(
+                "is_stale",
+                TableDataType::Nullable(Box::new(TableDataType::Boolean)),
+            ),
         ])
     }
 
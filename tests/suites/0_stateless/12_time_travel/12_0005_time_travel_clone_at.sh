@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists t12_0005" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists t12_0005_clone" | $MYSQL_CLIENT_CONNECT
+
+## Create table t12_0005
+echo "create table t12_0005(c int)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(1),(2)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(3)" | $MYSQL_CLIENT_CONNECT
+
+## Clone the table at the snapshot of the first insertion
+SNAPSHOT_ID=$(echo "select previous_snapshot_id from fuse_snapshot('default','t12_0005') where row_count=3" | $MYSQL_CLIENT_CONNECT)
+echo "create table t12_0005_clone clone t12_0005 at (snapshot => '$SNAPSHOT_ID')" | $MYSQL_CLIENT_CONNECT
+
+echo "the historical snapshot shared with the clone is kept by the purge of the source"
+echo "optimize table t12_0005 purge" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005" | $MYSQL_CLIENT_CONNECT
+echo "select c from t12_0005_clone order by c" | $MYSQL_CLIENT_CONNECT
+
+## Drop table.
+echo "drop table t12_0005_clone" | $MYSQL_CLIENT_CONNECT
+echo "drop table t12_0005" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists delta_tbl;" | $MYSQL_CLIENT_CONNECT
+
+## Create table, the schema is loaded from the transaction log
+echo "create table delta_tbl engine = delta 's3://testbucket/admin/data/delta/delta_tbl/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}');" | $MYSQL_CLIENT_CONNECT
+
+## The latest version, replayed from the checkpoint of version 2 and the commit of version 3
+echo "select * from delta_tbl order by id;" | $MYSQL_CLIENT_CONNECT
+
+## Partition values
+echo "select id, name from delta_tbl where part = 'a' order by id;" | $MYSQL_CLIENT_CONNECT
+echo "select id, name from delta_tbl where part = 'b' order by id;" | $MYSQL_CLIENT_CONNECT
+echo "select id, name from delta_tbl where part is null order by id;" | $MYSQL_CLIENT_CONNECT
+echo "select part, count(*) from delta_tbl group by part order by part nulls last;" | $MYSQL_CLIENT_CONNECT
+
+## Time travel by version
+echo "select id from delta_tbl at (snapshot => '0') order by id;" | $MYSQL_CLIENT_CONNECT
+echo "select id from delta_tbl at (snapshot => '1') order by id;" | $MYSQL_CLIENT_CONNECT
+## The deletion vectors of version 2 delete id 2 and id 7
+echo "select id from delta_tbl at (snapshot => '2') order by id;" | $MYSQL_CLIENT_CONNECT
+
+## Time travel by timestamp
+echo "select id from delta_tbl at (timestamp => '2023-01-02 12:00:00'::timestamp) order by id;" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from delta_tbl at (timestamp => '2022-12-31 00:00:00'::timestamp);" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "no version of the delta table is committed before"
+
+## Read only
+echo "insert into delta_tbl values (10, 'a10', 'a');" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "ERROR"
+
+## Drop table
+echo "drop table if exists delta_tbl;" | $MYSQL_CLIENT_CONNECT
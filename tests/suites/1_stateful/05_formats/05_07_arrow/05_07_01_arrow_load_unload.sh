@@ -0,0 +1,88 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+echo "drop table if exists test_load_unload" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s_arrow" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_load_unload
+(
+    a VARCHAR NULL,
+    b float,
+    e timestamp
+);" | $MYSQL_CLIENT_CONNECT
+
+insert_data() {
+	echo "insert into test_load_unload values
+	('a\"b', 1, '2044-05-06T03:25:02.868894-07:00')
+	" | $MYSQL_CLIENT_CONNECT
+}
+
+test_format() {
+	insert_data
+
+	# unload clickhouse
+	curl -s -u root: -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" \
+	-d "select * from test_load_unload FORMAT ${1}" > /tmp/test_load_unload.arrow
+
+	echo "truncate table test_load_unload" | $MYSQL_CLIENT_CONNECT
+
+	# load streaming
+	curl -sH "insert_sql:insert into test_load_unload file_format = (type = ARROW)" \
+	-F "upload=@/tmp/test_load_unload.arrow" \
+	-u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" | grep -c "SUCCESS"
+
+	# unload clickhouse again
+	curl -s -u root: -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" \
+	-d "select * from test_load_unload FORMAT ${1}" > /tmp/test_load_unload2.arrow
+
+	diff /tmp/test_load_unload2.arrow /tmp/test_load_unload.arrow
+	rm /tmp/test_load_unload2.arrow /tmp/test_load_unload.arrow
+	echo "truncate table test_load_unload" | $MYSQL_CLIENT_CONNECT
+}
+
+test_format "Arrow"
+
+# unload clickhouse in the ipc streaming format, which starts with the schema message
+# and ends with the end-of-stream marker
+insert_data
+curl -s -u root: -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" \
+	-d "select * from test_load_unload FORMAT ArrowStream" > /tmp/test_load_unload.arrows
+head -c 4 /tmp/test_load_unload.arrows | od -An -tx1 | tr -d ' \n'
+echo
+tail -c 8 /tmp/test_load_unload.arrows | od -An -tx1 | tr -d ' \n'
+echo
+echo "truncate table test_load_unload" | $MYSQL_CLIENT_CONNECT
+
+# an empty result is still a valid arrow file or stream
+curl -s -u root: -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" \
+	-d "select * from test_load_unload FORMAT Arrow" > /tmp/test_load_unload.arrow
+head -c 6 /tmp/test_load_unload.arrow
+echo
+tail -c 6 /tmp/test_load_unload.arrow
+echo
+curl -sH "insert_sql:insert into test_load_unload file_format = (type = ARROW)" \
+	-F "upload=@/tmp/test_load_unload.arrow" \
+	-u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" | grep -c "SUCCESS"
+echo "select count(*) from test_load_unload" | $MYSQL_CLIENT_CONNECT
+
+curl -s -u root: -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" \
+	-d "select * from test_load_unload FORMAT ArrowStream" > /tmp/test_load_unload.arrows
+head -c 4 /tmp/test_load_unload.arrows | od -An -tx1 | tr -d ' \n'
+echo
+tail -c 8 /tmp/test_load_unload.arrows | od -An -tx1 | tr -d ' \n'
+echo
+rm /tmp/test_load_unload.arrow /tmp/test_load_unload.arrows
+
+# unload to stage and load back
+insert_data
+echo "create stage s_arrow file_format = (type = ARROW)" | $MYSQL_CLIENT_CONNECT
+echo "copy into @s_arrow from test_load_unload" | $MYSQL_CLIENT_CONNECT
+echo "truncate table test_load_unload" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_load_unload from @s_arrow pattern = '.*[.]arrow'" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_load_unload" | $MYSQL_CLIENT_CONNECT
+echo "select a from @s_arrow" | $MYSQL_CLIENT_CONNECT
+
+echo "drop stage if exists s_arrow" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists test_load_unload" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,56 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+DATADIR_PATH="/tmp/05_08_01"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+DATADIR="fs://$DATADIR_PATH/"
+
+cp "$CURDIR"/../../../../data/avro/sample.avro ${DATADIR_PATH}/
+cp "$CURDIR"/../../../../data/avro/wrong_sample.avro ${DATADIR_PATH}/
+
+echo "drop stage if exists s_avro;" | $MYSQL_CLIENT_CONNECT
+echo "create stage s_avro url = '${DATADIR}' FILE_FORMAT = (type = AVRO);" | $MYSQL_CLIENT_CONNECT
+
+echo "--- infer_schema:"
+echo "select * from infer_schema(location => '@s_avro/sample.avro');" | $MYSQL_CLIENT_CONNECT
+
+echo "--- select from stage:"
+echo "select * from @s_avro (files => ('sample.avro')) order by id;" | $MYSQL_CLIENT_CONNECT
+echo "select id, name from @s_avro (files => ('sample.avro')) where name is null;" | $MYSQL_CLIENT_CONNECT
+
+echo "--- copy into:"
+echo "drop table if exists test_avro" | $MYSQL_CLIENT_CONNECT
+# the float score is loaded into an int column
+echo "CREATE TABLE test_avro (
+  id       INT,
+  name     VARCHAR NULL,
+  price    DECIMAL(10, 2),
+  created  TIMESTAMP,
+  updated  TIMESTAMP,
+  birthday DATE,
+  score    INT
+);" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_avro from @s_avro files = ('sample.avro');" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_avro order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo "--- on_error:"
+echo "drop table if exists wrong_avro" | $MYSQL_CLIENT_CONNECT
+echo "create table wrong_avro (id INT, name VARCHAR);" | $MYSQL_CLIENT_CONNECT
+
+echo "copy into wrong_avro from @s_avro files = ('wrong_sample.avro') ON_ERROR = continue;" | $MYSQL_CLIENT_CONNECT
+echo "select * from wrong_avro order by id;" | $MYSQL_CLIENT_CONNECT
+echo "truncate table wrong_avro" | $MYSQL_CLIENT_CONNECT
+
+echo "copy into wrong_avro from @s_avro files = ('wrong_sample.avro') ON_ERROR = abort_3;" | $MYSQL_CLIENT_CONNECT
+echo "select count(1) from wrong_avro" | $MYSQL_CLIENT_CONNECT
+echo "truncate table wrong_avro" | $MYSQL_CLIENT_CONNECT
+
+echo "copy into wrong_avro from @s_avro files = ('wrong_sample.avro') ON_ERROR = abort_2;" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "fail to parse AVRO"
+echo "select count(1) from wrong_avro" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists test_avro" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists wrong_avro" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s_avro;" | $MYSQL_CLIENT_CONNECT
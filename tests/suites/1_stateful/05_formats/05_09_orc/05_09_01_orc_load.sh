@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+DATADIR_PATH="/tmp/05_09_01"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+DATADIR="fs://$DATADIR_PATH/"
+
+# the file has 3 stripes: ids 1..4, 5..8 and 9..10
+cp "$CURDIR"/../../../../data/orc/multi_stripes.orc ${DATADIR_PATH}/
+
+echo "drop stage if exists s_orc;" | $MYSQL_CLIENT_CONNECT
+echo "create stage s_orc url = '${DATADIR}' FILE_FORMAT = (type = ORC);" | $MYSQL_CLIENT_CONNECT
+
+echo "--- infer_schema:"
+echo "select * from infer_schema(location => '@s_orc/multi_stripes.orc');" | $MYSQL_CLIENT_CONNECT
+
+echo "--- select from stage:"
+echo "select * from @s_orc (files => ('multi_stripes.orc')) order by id;" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), sum(id) from @s_orc (files => ('multi_stripes.orc')) where id > 4;" | $MYSQL_CLIENT_CONNECT
+
+echo "--- copy into:"
+echo "drop table if exists test_orc" | $MYSQL_CLIENT_CONNECT
+echo "create table test_orc (id INT, name VARCHAR);" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_orc from @s_orc files = ('multi_stripes.orc');" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), min(id), max(id) from test_orc;" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_orc where id in (4, 5, 9, 10) order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists test_orc" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s_orc;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,5 @@
+select 'desc hive.default.t_orc';
+desc hive.default.t_orc;
+select * from hive.default.t_orc order by id;
+select 'stripes pruned by the statistics of id';
+select count(*), sum(id) from hive.default.t_orc where id > 8;